@@ -162,3 +162,71 @@ def segments_ids_containing_point_to_intersections(
         yield Intersection(
             first_segment_id, second_segment_id, relation, start, end
         )
+
+
+def split_segments(
+    segments: Sequence[hints.Segment[hints.ScalarT]],
+    /,
+    *,
+    orienteer: Orienteer[hints.ScalarT],
+    segments_intersector: SegmentsIntersector[hints.ScalarT],
+) -> list[list[hints.Point[hints.ScalarT]]]:
+    result = [[segment.start, segment.end] for segment in segments]
+    overlaps_roots = list(range(len(result)))
+    if segments:
+        for intersection in sweep(
+            segments,
+            orienteer=orienteer,
+            segments_intersector=segments_intersector,
+        ):
+            if intersection.first_segment_id == intersection.second_segment_id:
+                continue
+            for segment_id in (
+                intersection.first_segment_id,
+                intersection.second_segment_id,
+            ):
+                result[segment_id].append(intersection.start)
+                if intersection.end != intersection.start:
+                    result[segment_id].append(intersection.end)
+            if intersection.end != intersection.start:
+                first_root = _to_overlap_root(
+                    overlaps_roots, intersection.first_segment_id
+                )
+                second_root = _to_overlap_root(
+                    overlaps_roots, intersection.second_segment_id
+                )
+                overlaps_roots[first_root] = second_root
+    # sweep does not report each intersection for all overlapping segments,
+    # so collinear overlapping segments share their split points
+    overlaps: dict[int, list[int]] = {}
+    for segment_id in range(len(result)):
+        overlaps.setdefault(
+            _to_overlap_root(overlaps_roots, segment_id), []
+        ).append(segment_id)
+    for segments_ids in overlaps.values():
+        if len(segments_ids) == 1:
+            continue
+        points = [
+            point
+            for segment_id in segments_ids
+            for point in result[segment_id]
+        ]
+        for segment_id in segments_ids:
+            min_point, max_point = sorted(result[segment_id][:2])
+            result[segment_id].extend(
+                point for point in points if min_point <= point <= max_point
+            )
+    for index, points in enumerate(result):
+        is_reversed = points[1] < points[0]
+        points = sorted(set(points))
+        if is_reversed:
+            points.reverse()
+        result[index] = points
+    return result
+
+
+def _to_overlap_root(roots: list[int], segment_id: int, /) -> int:
+    while roots[segment_id] != segment_id:
+        roots[segment_id] = roots[roots[segment_id]]
+        segment_id = roots[segment_id]
+    return segment_id
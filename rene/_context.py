@@ -44,6 +44,12 @@ class Context(Generic[hints.ScalarT]):
     def polygon_cls(self, /) -> type[hints.Polygon[hints.ScalarT]]:
         return self._polygon_cls
 
+    @property
+    def quadratic_surd_cls(
+        self, /
+    ) -> type[hints.QuadraticSurd[hints.ScalarT]]:
+        return self._quadratic_surd_cls
+
     @property
     def segment_cls(self, /) -> type[hints.Segment[hints.ScalarT]]:
         return self._segment_cls
@@ -89,6 +95,7 @@ class Context(Generic[hints.ScalarT]):
     _orienteer: Orienteer[hints.ScalarT]
     _point_cls: type[hints.Point[hints.ScalarT]]
     _polygon_cls: type[hints.Polygon[hints.ScalarT]]
+    _quadratic_surd_cls: type[hints.QuadraticSurd[hints.ScalarT]]
     _segment_cls: type[hints.Segment[hints.ScalarT]]
     _segments_intersection_scale: SegmentsIntersectionScale[hints.ScalarT]
     _segments_intersector: SegmentsIntersector[hints.ScalarT]
@@ -103,6 +110,7 @@ class Context(Generic[hints.ScalarT]):
         '_orienteer',
         '_point_cls',
         '_polygon_cls',
+        '_quadratic_surd_cls',
         '_segment_cls',
         '_segments_intersection_scale',
         '_segments_intersector',
@@ -119,6 +127,7 @@ class Context(Generic[hints.ScalarT]):
         orienteer: Orienteer[hints.ScalarT],
         point_cls: type[hints.Point[hints.ScalarT]],
         polygon_cls: type[hints.Polygon[hints.ScalarT]],
+        quadratic_surd_cls: type[hints.QuadraticSurd[hints.ScalarT]],
         segment_cls: type[hints.Segment[hints.ScalarT]],
         segments_intersection_scale: SegmentsIntersectionScale[hints.ScalarT],
         segments_intersector: SegmentsIntersector[hints.ScalarT],
@@ -133,6 +142,7 @@ class Context(Generic[hints.ScalarT]):
             self._orienteer,
             self._point_cls,
             self._polygon_cls,
+            self._quadratic_surd_cls,
             self._segment_cls,
             self._segments_intersection_scale,
             self._segments_intersector,
@@ -145,6 +155,7 @@ class Context(Generic[hints.ScalarT]):
             orienteer,
             point_cls,
             polygon_cls,
+            quadratic_surd_cls,
             segment_cls,
             segments_intersection_scale,
             segments_intersector,
@@ -11,6 +11,17 @@ class Base(enum.Enum):
         return f'{type(self).__qualname__}.{self.name}'
 
 
+@final
+@enum.unique
+class FillRule(Base):
+    #: point lies in the interior if the boundary winds around it
+    #: odd number of times
+    EVEN_ODD = 0
+    #: point lies in the interior if the boundary winds around it
+    #: non-zero number of times
+    NON_ZERO = 1
+
+
 @final
 @enum.unique
 class Location(Base):
@@ -101,3 +112,42 @@ class Relation(Base):
             return Relation.ENCLOSED
         assert self is Relation.WITHIN
         return Relation.COVER
+
+
+@final
+@enum.unique
+class ViolationKind(Base):
+    #: three consecutive vertices of a contour lie on the same line
+    COLLINEAR_VERTICES = 0
+    #: segment has equal endpoints
+    DEGENERATE_SEGMENT = 1
+    #: hole lies not in the interior of the border
+    HOLE_OUTSIDE_BORDER = 2
+    #: hole touches the border along a segment
+    HOLE_TOUCHES_BORDER = 3
+    #: interiors of holes intersect
+    HOLES_OVERLAP = 4
+    #: border of a polygon is invalid
+    INVALID_BORDER = 5
+    #: hole of a polygon is invalid
+    INVALID_HOLE = 6
+    #: polygon of a multipolygon is invalid
+    INVALID_POLYGON = 7
+    #: interiors of polygons of a multipolygon intersect
+    POLYGONS_OVERLAP = 8
+    #: segments cross
+    SEGMENTS_CROSS = 9
+    #: segments share a part
+    SEGMENTS_OVERLAP = 10
+    #: non-adjacent segments of a contour touch
+    SEGMENTS_TOUCH = 11
+    #: multipolygon has less polygons than required
+    TOO_FEW_POLYGONS = 12
+    #: multisegment has less segments than required
+    TOO_FEW_SEGMENTS = 13
+    #: contour has less vertices than required
+    TOO_FEW_VERTICES = 14
+    #: border of a polygon is not oriented counterclockwise
+    WRONG_BORDER_ORIENTATION = 15
+    #: hole of a polygon is not oriented clockwise
+    WRONG_HOLE_ORIENTATION = 16
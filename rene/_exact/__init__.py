@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import Any, TYPE_CHECKING
 
 from rene._context import Context
 from rene._utils import cross_multiply, to_sign
@@ -13,6 +13,7 @@ from .multipolygon import Multipolygon
 from .multisegment import Multisegment
 from .point import Point
 from .polygon import Polygon
+from .quadratic_surd import QuadraticSurd
 from .segment import Segment
 from .trapezoidation import Trapezoidation
 from .triangulation import (
@@ -26,6 +27,16 @@ if TYPE_CHECKING:
     from rene import hints
 
 
+def nearest_points(
+    first: Any, second: Any, /
+) -> tuple[hints.Point[Fraction], hints.Point[Fraction]]:
+    if not isinstance(
+        first, (Contour, Multipolygon, Multisegment, Point, Polygon, Segment)
+    ):
+        raise TypeError(f'Expected geometry, but got {type(first)!r}.')
+    return first.nearest_points(second)
+
+
 def orient(
     vertex: hints.Point[Fraction],
     first_ray_point: hints.Point[Fraction],
@@ -81,16 +92,19 @@ _context = Context(
     orienteer=orient,
     point_cls=Point,
     polygon_cls=Polygon,
+    quadratic_surd_cls=QuadraticSurd,
     segment_cls=Segment,
     segments_intersection_scale=to_segments_intersection_scale,
     segments_intersector=to_segments_intersection,
 )
+Box._context = _context  # ruff: ignore[private-member-access]
 ConstrainedDelaunayTriangulation._context = _context  # ruff: ignore[private-member-access]
 Contour._context = _context  # ruff: ignore[private-member-access]
 DelaunayTriangulation._context = _context  # ruff: ignore[private-member-access]
 Empty._context = _context  # ruff: ignore[private-member-access]
 Multipolygon._context = _context  # ruff: ignore[private-member-access]
 Multisegment._context = _context  # ruff: ignore[private-member-access]
+Point._context = _context  # ruff: ignore[private-member-access]
 Polygon._context = _context  # ruff: ignore[private-member-access]
 Segment._context = _context  # ruff: ignore[private-member-access]
 Trapezoidation._context = _context  # ruff: ignore[private-member-access]
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from numbers import Rational
-from typing import NoReturn, TypeAlias
+from typing import ClassVar, NoReturn, TYPE_CHECKING, TypeAlias
 
 from rithm.fraction import Fraction
 from rithm.integer import Int
@@ -9,6 +9,9 @@ from typing_extensions import Self, final
 
 from rene._geometries.base_box import BaseBox
 
+if TYPE_CHECKING:
+    from rene._context import Context
+
 _Coordinate: TypeAlias = Fraction | Int | Rational | float | int
 
 
@@ -30,6 +33,7 @@ class Box(BaseBox[Fraction]):
     def min_y(self, /) -> Fraction:
         return self._min_y
 
+    _context: ClassVar[Context[Fraction]]
     _max_x: Fraction
     _max_y: Fraction
     _min_x: Fraction
@@ -10,6 +10,7 @@ from typing_extensions import Self, final
 from rene import hints
 from rene._geometries.base_contour import BaseContour
 from rene._utils import to_contour_segments
+from rene._validation import ensure_valid
 from rene.constants import MIN_CONTOUR_VERTICES_COUNT
 
 
@@ -34,7 +35,13 @@ class Contour(BaseContour[Fraction]):
             f'type {cls.__qualname__!r} is not an acceptable base type'
         )
 
-    def __new__(cls, vertices: Sequence[hints.Point[Fraction]], /) -> Self:
+    def __new__(
+        cls,
+        vertices: Sequence[hints.Point[Fraction]],
+        /,
+        *,
+        validate: bool = False,
+    ) -> Self:
         if len(vertices) < MIN_CONTOUR_VERTICES_COUNT:
             raise ValueError(
                 'Contour should have at least '
@@ -46,6 +53,8 @@ class Contour(BaseContour[Fraction]):
         self._segments = tuple(
             to_contour_segments(self._vertices, self._context.segment_cls)
         )
+        if validate:
+            ensure_valid(self.validate(), 'contour')
         return self
 
 
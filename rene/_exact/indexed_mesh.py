@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+from typing import NoReturn, TYPE_CHECKING
+
+from typing_extensions import Self, final
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from rithm.fraction import Fraction
+
+    from rene import hints
+
+
+@final
+class IndexedMesh:
+    @property
+    def constrained_flags(self, /) -> Sequence[tuple[bool, bool, bool]]:
+        return self._constrained_flags[:]
+
+    @property
+    def neighbours(
+        self, /
+    ) -> Sequence[tuple[int | None, int | None, int | None]]:
+        return self._neighbours[:]
+
+    @property
+    def triangles(self, /) -> Sequence[tuple[int, int, int]]:
+        return self._triangles[:]
+
+    @property
+    def vertices(self, /) -> Sequence[hints.Point[Fraction]]:
+        return self._vertices[:]
+
+    _constrained_flags: list[tuple[bool, bool, bool]]
+    _neighbours: list[tuple[int | None, int | None, int | None]]
+    _triangles: list[tuple[int, int, int]]
+    _vertices: list[hints.Point[Fraction]]
+
+    __module__ = 'rene.exact'
+    __slots__ = '_constrained_flags', '_neighbours', '_triangles', '_vertices'
+
+    def __init_subclass__(cls, /) -> NoReturn:
+        raise TypeError(
+            f'type {cls.__qualname__!r} is not an acceptable base type'
+        )
+
+    def __new__(
+        cls,
+        constrained_flags: list[tuple[bool, bool, bool]],
+        neighbours: list[tuple[int | None, int | None, int | None]],
+        triangles: list[tuple[int, int, int]],
+        vertices: list[hints.Point[Fraction]],
+        /,
+    ) -> Self:
+        self = super().__new__(cls)
+        (
+            self._constrained_flags,
+            self._neighbours,
+            self._triangles,
+            self._vertices,
+        ) = constrained_flags, neighbours, triangles, vertices
+        return self
@@ -9,6 +9,7 @@ from typing_extensions import Self, final
 
 from rene import hints
 from rene._geometries.base_multipolygon import BaseMultipolygon
+from rene._validation import ensure_valid
 from rene.constants import MIN_MULTIPOLYGON_POLYGONS_COUNT
 
 if TYPE_CHECKING:
@@ -32,7 +33,13 @@ class Multipolygon(BaseMultipolygon[Fraction]):
             f'type {cls.__qualname__!r} is not an acceptable base type'
         )
 
-    def __new__(cls, polygons: Sequence[hints.Polygon[Fraction]], /) -> Self:
+    def __new__(
+        cls,
+        polygons: Sequence[hints.Polygon[Fraction]],
+        /,
+        *,
+        validate: bool = False,
+    ) -> Self:
         if len(polygons) < MIN_MULTIPOLYGON_POLYGONS_COUNT:
             raise ValueError(
                 'Multipolygon should have at least '
@@ -41,6 +48,8 @@ class Multipolygon(BaseMultipolygon[Fraction]):
             )
         self = object.__new__(cls)
         self._polygons = tuple(polygons)
+        if validate:
+            ensure_valid(self.validate(), 'multipolygon')
         return self
 
 
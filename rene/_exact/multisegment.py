@@ -9,6 +9,7 @@ from typing_extensions import Self, final
 
 from rene import hints
 from rene._geometries.base_multisegment import BaseMultisegment
+from rene._validation import ensure_valid
 from rene.constants import MIN_MULTISEGMENT_SEGMENTS_COUNT
 
 if TYPE_CHECKING:
@@ -32,7 +33,13 @@ class Multisegment(BaseMultisegment[Fraction]):
             f'type {cls.__qualname__!r} is not an acceptable base type'
         )
 
-    def __new__(cls, segments: Sequence[hints.Segment[Fraction]], /) -> Self:
+    def __new__(
+        cls,
+        segments: Sequence[hints.Segment[Fraction]],
+        /,
+        *,
+        validate: bool = False,
+    ) -> Self:
         if len(segments) < MIN_MULTISEGMENT_SEGMENTS_COUNT:
             raise ValueError(
                 'Multisegment should have at least '
@@ -41,6 +48,8 @@ class Multisegment(BaseMultisegment[Fraction]):
             )
         self = object.__new__(cls)
         self._segments = tuple(segments)
+        if validate:
+            ensure_valid(self.validate(), 'multisegment')
         return self
 
 
@@ -1,5 +1,7 @@
+from __future__ import annotations
+
 from numbers import Rational
-from typing import NoReturn, TypeAlias
+from typing import ClassVar, NoReturn, TYPE_CHECKING, TypeAlias
 
 from rithm.fraction import Fraction
 from rithm.integer import Int
@@ -7,6 +9,9 @@ from typing_extensions import Self, final
 
 from rene._geometries.base_point import BasePoint
 
+if TYPE_CHECKING:
+    from rene._context import Context
+
 _Coordinate: TypeAlias = Fraction | Int | Rational | float | int
 
 
@@ -20,6 +25,7 @@ class Point(BasePoint[Fraction]):
     def y(self, /) -> Fraction:
         return self._y
 
+    _context: ClassVar[Context[Fraction]]
     _x: Fraction
     _y: Fraction
 
@@ -9,6 +9,7 @@ from typing_extensions import Self, final, override
 
 from rene import hints
 from rene._geometries.base_polygon import BasePolygon
+from rene._validation import ensure_valid
 
 if TYPE_CHECKING:
     from rene._context import Context
@@ -42,9 +43,13 @@ class Polygon(BasePolygon[Fraction]):
         border: hints.Contour[Fraction],
         holes: Sequence[hints.Contour[Fraction]],
         /,
+        *,
+        validate: bool = False,
     ) -> Self:
         self = object.__new__(cls)
         self._border, self._holes = border, tuple(holes)
+        if validate:
+            ensure_valid(self.validate(), 'polygon')
         return self
 
 
@@ -0,0 +1,190 @@
+from __future__ import annotations
+
+import math
+from numbers import Rational
+from typing import Any, NoReturn, TypeAlias, overload
+
+from rithm.fraction import Fraction
+from rithm.integer import Int
+from typing_extensions import Self, final
+
+_Component: TypeAlias = Fraction | Int | Rational | float | int
+
+
+@final
+class QuadraticSurd:
+    @property
+    def irrational(self, /) -> Fraction:
+        return self._irrational
+
+    @property
+    def radicand(self, /) -> Fraction:
+        return self._radicand
+
+    @property
+    def rational(self, /) -> Fraction:
+        return self._rational
+
+    _irrational: Fraction
+    _radicand: Fraction
+    _rational: Fraction
+
+    __module__ = 'rene.exact'
+    __slots__ = '_irrational', '_radicand', '_rational'
+
+    def __init_subclass__(cls, /) -> NoReturn:
+        raise TypeError(
+            f'type {cls.__qualname__!r} is not an acceptable base type'
+        )
+
+    def __new__(
+        cls,
+        rational: _Component,
+        irrational: _Component,
+        radicand: _Component,
+        /,
+    ) -> Self:
+        radicand = Fraction(radicand)
+        if radicand < 0:
+            raise ValueError(
+                f'Radicand should be non-negative, but found {radicand}.'
+            )
+        self = super().__new__(cls)
+        self._rational, self._irrational, self._radicand = (
+            Fraction(rational),
+            Fraction(irrational),
+            radicand,
+        )
+        if self._irrational == 0 or self._radicand == 0:
+            self._irrational = self._radicand = Fraction(0)
+        return self
+
+    @overload
+    def __eq__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __eq__(self, other: Any, /) -> Any: ...
+
+    def __eq__(self, other: Any, /) -> Any:
+        return (
+            _to_difference_sign(self, other) == 0
+            if isinstance(other, QuadraticSurd)
+            else NotImplemented
+        )
+
+    def __float__(self, /) -> float:
+        return float(self._rational) + float(self._irrational) * math.sqrt(
+            float(self._radicand)
+        )
+
+    @overload
+    def __ge__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __ge__(self, other: Any, /) -> Any: ...
+
+    def __ge__(self, other: Any, /) -> Any:
+        return (
+            _to_difference_sign(self, other) >= 0
+            if isinstance(other, QuadraticSurd)
+            else NotImplemented
+        )
+
+    @overload
+    def __gt__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __gt__(self, other: Any, /) -> Any: ...
+
+    def __gt__(self, other: Any, /) -> Any:
+        return (
+            _to_difference_sign(self, other) > 0
+            if isinstance(other, QuadraticSurd)
+            else NotImplemented
+        )
+
+    @overload
+    def __le__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __le__(self, other: Any, /) -> Any: ...
+
+    def __le__(self, other: Any, /) -> Any:
+        return (
+            _to_difference_sign(self, other) <= 0
+            if isinstance(other, QuadraticSurd)
+            else NotImplemented
+        )
+
+    @overload
+    def __lt__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __lt__(self, other: Any, /) -> Any: ...
+
+    def __lt__(self, other: Any, /) -> Any:
+        return (
+            _to_difference_sign(self, other) < 0
+            if isinstance(other, QuadraticSurd)
+            else NotImplemented
+        )
+
+    def __repr__(self, /) -> str:
+        return (
+            f'{type(self).__qualname__}({self._rational!r}, '
+            f'{self._irrational!r}, {self._radicand!r})'
+        )
+
+    def __str__(self, /) -> str:
+        return (
+            f'{type(self).__qualname__}({self._rational}, '
+            f'{self._irrational}, {self._radicand})'
+        )
+
+
+def _to_difference_sign(
+    first: QuadraticSurd,
+    second: QuadraticSurd,
+    /,
+) -> int:
+    # surds with different radicands are compared
+    # by squaring the difference's parts when they have the same sign
+    rational_difference = first.rational - second.rational
+    minuend_sign = _to_surd_sign(
+        rational_difference, first.irrational, first.radicand
+    )
+    subtrahend_sign = _to_sign(second.irrational)
+    if subtrahend_sign == 0:
+        return minuend_sign
+    if minuend_sign == 0:
+        return -subtrahend_sign
+    if minuend_sign != subtrahend_sign:
+        return minuend_sign
+    return minuend_sign * _to_surd_sign(
+        rational_difference * rational_difference
+        + first.irrational * first.irrational * first.radicand
+        - second.irrational * second.irrational * second.radicand,
+        (rational_difference + rational_difference) * first.irrational,
+        first.radicand,
+    )
+
+
+def _to_sign(value: Fraction, /) -> int:
+    return (value > 0) - (value < 0)
+
+
+def _to_surd_sign(
+    rational: Fraction,
+    irrational: Fraction,
+    radicand: Fraction,
+    /,
+) -> int:
+    # sign of `rational + irrational * sqrt(radicand)`
+    rational_sign, irrational_sign = _to_sign(rational), _to_sign(irrational)
+    if irrational_sign == 0 or radicand == 0:
+        return rational_sign
+    if rational_sign == 0 or rational_sign == irrational_sign:
+        return irrational_sign
+    return rational_sign * _to_sign(
+        rational * rational - irrational * irrational * radicand
+    )
@@ -6,6 +6,7 @@ from typing import ClassVar, NoReturn, TYPE_CHECKING
 from typing_extensions import Self, final
 
 from rene._seidel.trapezoidation import Trapezoidation as _RawTrapezoidation
+from rene._svg import trapezoidation_to_svg
 from rene._utils import polygon_to_segments_count, validate_seed
 
 if TYPE_CHECKING:
@@ -63,6 +64,10 @@ class Trapezoidation:
     def locate(self, point: hints.Point[Fraction], /) -> Location:
         return self._raw.locate(point)
 
+    def _repr_svg_(self, /) -> str | None:
+        raw = self._raw
+        return trapezoidation_to_svg(raw._edges, raw._endpoints, raw._nodes)
+
     _context: ClassVar[Context[Fraction]]
     _raw: _RawTrapezoidation[Fraction]
 
@@ -10,9 +10,15 @@ from rene._triangulation.constrained_delaunay import (
 from rene._triangulation.delaunay import (
     DelaunayTriangulation as _RawDelaunayTriangulation,
 )
+from rene._triangulation.indexed_mesh import to_indexed_mesh
+from rene._triangulation.voronoi import to_voronoi_cells, to_voronoi_vertices
+from rene._svg import triangles_to_svg
 from rene._utils import shrink_collinear_vertices
 from rene.constants import MIN_CONTOUR_VERTICES_COUNT
 
+from .indexed_mesh import IndexedMesh
+from .voronoi_diagram import VoronoiDiagram
+
 if TYPE_CHECKING:
     from collections.abc import Sequence
 
@@ -24,6 +30,34 @@ if TYPE_CHECKING:
 
 @final
 class ConstrainedDelaunayTriangulation:
+    @classmethod
+    def from_multipolygon(
+        cls, multipolygon: hints.Multipolygon[Fraction], /
+    ) -> Self:
+        return cls(
+            _RawConstrainedDelaunayTriangulation.from_multipolygon(
+                multipolygon,
+                cls._context.orient,
+                cls._context.intersect_segments,
+            )
+        )
+
+    @classmethod
+    def from_points_and_multisegment(
+        cls,
+        points: Sequence[hints.Point[Fraction]],
+        multisegment: hints.Multisegment[Fraction],
+        /,
+    ) -> Self:
+        return cls(
+            _RawConstrainedDelaunayTriangulation.from_points_and_multisegment(
+                points,
+                multisegment,
+                cls._context.orient,
+                cls._context.intersect_segments,
+            )
+        )
+
     @classmethod
     def from_polygon(cls, polygon: hints.Polygon[Fraction], /) -> Self:
         return cls(
@@ -43,6 +77,10 @@ class ConstrainedDelaunayTriangulation:
             )
         )
 
+    @property
+    def endpoints(self, /) -> Sequence[hints.Point[Fraction]]:
+        return self._raw.mesh.endpoints[:]
+
     @property
     def triangles(self, /) -> Sequence[hints.Contour[Fraction]]:
         contour_cls = self._context.contour_cls
@@ -51,6 +89,33 @@ class ConstrainedDelaunayTriangulation:
             for vertices in self._raw.triangles_vertices()
         ]
 
+    def locate_point(
+        self, point: hints.Point[Fraction], /
+    ) -> tuple[
+        tuple[int, ...],
+        hints.Contour[Fraction]
+        | hints.Point[Fraction]
+        | hints.Segment[Fraction]
+        | None,
+    ]:
+        indices = self._raw.locate_point(point)
+        return indices, to_location_geometry(
+            indices, self._raw.mesh.endpoints, self._context
+        )
+
+    def to_indexed_mesh(self, /) -> IndexedMesh:
+        return IndexedMesh(
+            *to_indexed_mesh(
+                self._raw.mesh,
+                self._raw.triangles_base_edges(),
+                self._raw.constraints,
+            ),
+            self._raw.mesh.endpoints[:],
+        )
+
+    def _repr_svg_(self, /) -> str | None:
+        return triangles_to_svg(self._raw.triangles_vertices())
+
     _context: ClassVar[Context[Fraction]]
     _raw: _RawConstrainedDelaunayTriangulation[Fraction]
 
@@ -91,6 +156,10 @@ class DelaunayTriangulation:
             )
         )
 
+    @property
+    def endpoints(self, /) -> Sequence[hints.Point[Fraction]]:
+        return self._raw.mesh.endpoints[:]
+
     @property
     def triangles(self, /) -> Sequence[hints.Contour[Fraction]]:
         contour_cls = self._context.contour_cls
@@ -99,6 +168,53 @@ class DelaunayTriangulation:
             for vertices in self._raw.triangles_vertices()
         ]
 
+    def insert(self, point: hints.Point[Fraction], /) -> bool:
+        return self._raw.insert(point)
+
+    def locate_point(
+        self, point: hints.Point[Fraction], /
+    ) -> tuple[
+        tuple[int, ...],
+        hints.Contour[Fraction]
+        | hints.Point[Fraction]
+        | hints.Segment[Fraction]
+        | None,
+    ]:
+        indices = self._raw.locate_point(point)
+        return indices, to_location_geometry(
+            indices, self._raw.mesh.endpoints, self._context
+        )
+
+    def remove(self, point: hints.Point[Fraction], /) -> bool:
+        return self._raw.remove(point)
+
+    def to_indexed_mesh(self, /) -> IndexedMesh:
+        return IndexedMesh(
+            *to_indexed_mesh(
+                self._raw.mesh, self._raw.triangles_base_edges(), ()
+            ),
+            self._raw.mesh.endpoints[:],
+        )
+
+    def to_voronoi(self, box: hints.Box[Fraction], /) -> VoronoiDiagram:
+        context = self._context
+        cells = to_voronoi_cells(
+            self._raw.mesh, box, context.point_cls, context.orient
+        )
+        return VoronoiDiagram(
+            [
+                context.polygon_cls(context.contour_cls(vertices), [])
+                for _, vertices in cells
+            ],
+            [site for site, _ in cells],
+            to_voronoi_vertices(
+                self._raw.triangles_vertices(), context.point_cls
+            ),
+        )
+
+    def _repr_svg_(self, /) -> str | None:
+        return triangles_to_svg(self._raw.triangles_vertices())
+
     _context: Context[Fraction]
     _raw: _RawDelaunayTriangulation[Fraction]
 
@@ -117,3 +233,27 @@ class DelaunayTriangulation:
 
     def __bool__(self, /) -> bool:
         return bool(self._raw)
+
+
+def to_location_geometry(
+    indices: tuple[int, ...],
+    endpoints: Sequence[hints.Point[Fraction]],
+    context: Context[Fraction],
+    /,
+) -> (
+    hints.Contour[Fraction]
+    | hints.Point[Fraction]
+    | hints.Segment[Fraction]
+    | None
+):
+    if not indices:
+        return None
+    if len(indices) == 1:
+        (index,) = indices
+        return endpoints[index]
+    if len(indices) == 2:
+        start_index, end_index = indices
+        return context.segment_cls(
+            endpoints[start_index], endpoints[end_index]
+        )
+    return context.contour_cls([endpoints[index] for index in indices])
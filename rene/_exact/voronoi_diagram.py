@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+from typing import NoReturn, TYPE_CHECKING
+
+from typing_extensions import Self, final
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from rithm.fraction import Fraction
+
+    from rene import hints
+
+
+@final
+class VoronoiDiagram:
+    @property
+    def cells(self, /) -> Sequence[hints.Polygon[Fraction]]:
+        return self._cells[:]
+
+    @property
+    def sites(self, /) -> Sequence[hints.Point[Fraction]]:
+        return self._sites[:]
+
+    @property
+    def vertices(self, /) -> Sequence[hints.Point[Fraction]]:
+        return self._vertices[:]
+
+    _cells: list[hints.Polygon[Fraction]]
+    _sites: list[hints.Point[Fraction]]
+    _vertices: list[hints.Point[Fraction]]
+
+    __module__ = 'rene.exact'
+    __slots__ = '_cells', '_sites', '_vertices'
+
+    def __init_subclass__(cls, /) -> NoReturn:
+        raise TypeError(
+            f'type {cls.__qualname__!r} is not an acceptable base type'
+        )
+
+    def __new__(
+        cls,
+        cells: list[hints.Polygon[Fraction]],
+        sites: list[hints.Point[Fraction]],
+        vertices: list[hints.Point[Fraction]],
+        /,
+    ) -> Self:
+        self = super().__new__(cls)
+        self._cells, self._sites, self._vertices = cells, sites, vertices
+        return self
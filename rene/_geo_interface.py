@@ -0,0 +1,274 @@
+from __future__ import annotations
+
+from collections.abc import Callable, Iterable
+from typing import Any, TypeVar
+
+from rithm.fraction import Fraction
+
+from rene import hints
+
+_T = TypeVar('_T')
+_ContourT = TypeVar('_ContourT', bound=hints.Contour[Fraction])
+_MultipolygonT = TypeVar('_MultipolygonT', bound=hints.Multipolygon[Fraction])
+_MultisegmentT = TypeVar('_MultisegmentT', bound=hints.Multisegment[Fraction])
+_PointT = TypeVar('_PointT', bound=hints.Point[Fraction])
+_PolygonT = TypeVar('_PolygonT', bound=hints.Polygon[Fraction])
+_SegmentT = TypeVar('_SegmentT', bound=hints.Segment[Fraction])
+
+_Coordinates = tuple[Fraction, Fraction]
+_Position = tuple[float, float]
+
+_COORDINATES_MEMBER_NAME = 'coordinates'
+_TYPE_MEMBER_NAME = 'type'
+_MIN_LINE_STRING_POINTS_COUNT = 2
+_MIN_RING_POINTS_COUNT = 4
+
+
+def contour_from_geo_interface(
+    value: Any,
+    contour_cls: type[_ContourT],
+    point_cls: type[hints.Point[Fraction]],
+    /,
+) -> _ContourT:
+    return contour_cls(
+        [
+            point_cls(x, y)
+            for x, y in _to_ring(_extract_coordinates(value, 'LineString'))
+        ]
+    )
+
+
+def contour_to_geo_interface(
+    contour: hints.Contour[Fraction], /
+) -> dict[str, Any]:
+    return _to_geo_interface(
+        'LineString', _ring_to_positions(contour.vertices)
+    )
+
+
+def multipolygon_from_geo_interface(
+    value: Any,
+    contour_cls: type[hints.Contour[Fraction]],
+    multipolygon_cls: type[_MultipolygonT],
+    point_cls: type[hints.Point[Fraction]],
+    polygon_cls: type[hints.Polygon[Fraction]],
+    /,
+) -> _MultipolygonT:
+    return multipolygon_cls(
+        [
+            _to_polygon(rings, contour_cls, point_cls, polygon_cls)
+            for rings in _extract_list(
+                _extract_coordinates(value, 'MultiPolygon'), _to_rings
+            )
+        ]
+    )
+
+
+def multipolygon_to_geo_interface(
+    multipolygon: hints.Multipolygon[Fraction], /
+) -> dict[str, Any]:
+    return _to_geo_interface(
+        'MultiPolygon',
+        tuple(
+            _polygon_to_positions(polygon)
+            for polygon in multipolygon.polygons
+        ),
+    )
+
+
+def multisegment_from_geo_interface(
+    value: Any,
+    multisegment_cls: type[_MultisegmentT],
+    point_cls: type[hints.Point[Fraction]],
+    segment_cls: type[hints.Segment[Fraction]],
+    /,
+) -> _MultisegmentT:
+    line_strings = _extract_list(
+        _extract_coordinates(value, 'MultiLineString'), _to_line_string
+    )
+    return multisegment_cls(
+        [
+            segment_cls(point_cls(*start), point_cls(*end))
+            for points in line_strings
+            for start, end in zip(points, points[1:])
+        ]
+    )
+
+
+def multisegment_to_geo_interface(
+    multisegment: hints.Multisegment[Fraction], /
+) -> dict[str, Any]:
+    return _to_geo_interface(
+        'MultiLineString',
+        tuple(
+            _points_to_positions((segment.start, segment.end))
+            for segment in multisegment.segments
+        ),
+    )
+
+
+def point_from_geo_interface(
+    value: Any, point_cls: type[_PointT], /
+) -> _PointT:
+    return point_cls(*_to_coordinates(_extract_coordinates(value, 'Point')))
+
+
+def point_to_geo_interface(point: hints.Point[Fraction], /) -> dict[str, Any]:
+    return _to_geo_interface('Point', _point_to_position(point))
+
+
+def polygon_from_geo_interface(
+    value: Any,
+    contour_cls: type[hints.Contour[Fraction]],
+    point_cls: type[hints.Point[Fraction]],
+    polygon_cls: type[_PolygonT],
+    /,
+) -> _PolygonT:
+    return _to_polygon(
+        _to_rings(_extract_coordinates(value, 'Polygon')),
+        contour_cls,
+        point_cls,
+        polygon_cls,
+    )
+
+
+def polygon_to_geo_interface(
+    polygon: hints.Polygon[Fraction], /
+) -> dict[str, Any]:
+    return _to_geo_interface('Polygon', _polygon_to_positions(polygon))
+
+
+def segment_from_geo_interface(
+    value: Any,
+    point_cls: type[hints.Point[Fraction]],
+    segment_cls: type[_SegmentT],
+    /,
+) -> _SegmentT:
+    points = _to_points(_extract_coordinates(value, 'LineString'))
+    if len(points) != 2:
+        raise _to_invalid_geometry_error(
+            'segment should have exactly 2 points'
+        )
+    start, end = points
+    return segment_cls(point_cls(*start), point_cls(*end))
+
+
+def segment_to_geo_interface(
+    segment: hints.Segment[Fraction], /
+) -> dict[str, Any]:
+    return _to_geo_interface(
+        'LineString', _points_to_positions((segment.start, segment.end))
+    )
+
+
+def _extract_coordinates(value: Any, geometry_type: str, /) -> Any:
+    value = getattr(value, '__geo_interface__', value)
+    actual_type = _extract_member(value, _TYPE_MEMBER_NAME)
+    if actual_type != geometry_type:
+        raise ValueError(f'Unexpected geometry type {actual_type!r}.')
+    return _extract_member(value, _COORDINATES_MEMBER_NAME)
+
+
+def _extract_list(
+    value: Any, extract_item: Callable[[Any], _T], /
+) -> list[_T]:
+    return [extract_item(item) for item in value]
+
+
+def _extract_member(value: Any, name: str, /) -> Any:
+    try:
+        return value[name]
+    except Exception:
+        raise ValueError(f'Missing "{name}" member.') from None
+
+
+def _point_to_position(point: hints.Point[Fraction], /) -> _Position:
+    return float(point.x), float(point.y)
+
+
+def _points_to_positions(
+    points: Iterable[hints.Point[Fraction]], /
+) -> tuple[_Position, ...]:
+    return tuple(_point_to_position(point) for point in points)
+
+
+def _polygon_to_positions(
+    polygon: hints.Polygon[Fraction], /
+) -> tuple[tuple[_Position, ...], ...]:
+    return tuple(
+        _ring_to_positions(contour.vertices)
+        for contour in (polygon.border, *polygon.holes)
+    )
+
+
+def _ring_to_positions(
+    vertices: Iterable[hints.Point[Fraction]], /
+) -> tuple[_Position, ...]:
+    result = _points_to_positions(vertices)
+    return (*result, result[0])
+
+
+def _to_coordinates(value: Any, /) -> _Coordinates:
+    coordinates = _extract_list(value, Fraction)
+    if len(coordinates) != 2:
+        raise _to_invalid_geometry_error(
+            'position should have exactly 2 coordinates'
+        )
+    x, y = coordinates
+    return x, y
+
+
+def _to_geo_interface(
+    geometry_type: str, coordinates: Any, /
+) -> dict[str, Any]:
+    return {
+        _TYPE_MEMBER_NAME: geometry_type,
+        _COORDINATES_MEMBER_NAME: coordinates,
+    }
+
+
+def _to_invalid_geometry_error(reason: str, /) -> ValueError:
+    return ValueError(f'Invalid geometry: {reason}.')
+
+
+def _to_line_string(value: Any, /) -> list[_Coordinates]:
+    result = _to_points(value)
+    if len(result) < _MIN_LINE_STRING_POINTS_COUNT:
+        raise _to_invalid_geometry_error(
+            'line string should have at least 2 points'
+        )
+    return result
+
+
+def _to_points(value: Any, /) -> list[_Coordinates]:
+    return _extract_list(value, _to_coordinates)
+
+
+def _to_polygon(
+    rings: list[list[_Coordinates]],
+    contour_cls: type[hints.Contour[Fraction]],
+    point_cls: type[hints.Point[Fraction]],
+    polygon_cls: type[_PolygonT],
+    /,
+) -> _PolygonT:
+    border, *holes = [
+        contour_cls([point_cls(x, y) for x, y in ring]) for ring in rings
+    ]
+    return polygon_cls(border, holes)
+
+
+def _to_ring(value: Any, /) -> list[_Coordinates]:
+    result = _to_points(value)
+    if len(result) < _MIN_RING_POINTS_COUNT or result[0] != result[-1]:
+        raise _to_invalid_geometry_error(
+            'ring should be closed and have at least 4 points'
+        )
+    del result[-1]
+    return result
+
+
+def _to_rings(value: Any, /) -> list[list[_Coordinates]]:
+    result = _extract_list(value, _to_ring)
+    if not result:
+        raise _to_invalid_geometry_error('polygon should have a border')
+    return result
@@ -1,15 +1,20 @@
 from __future__ import annotations
 
 from abc import ABC, abstractmethod
-from typing import Any, Generic, overload
+from typing import Any, ClassVar, Generic, overload
 
 from typing_extensions import Self
 
 from rene import hints
+from rene._context import Context
+from rene._transformation import to_affine_matrix, transform_box
+from rene._utils import collect_convex_hull_vertices, to_convex_hull
 from rene.enums import Relation
 
 
 class BaseBox(ABC, Generic[hints.ScalarT]):
+    _context: ClassVar[Context[Any]]
+
     @property
     @abstractmethod
     def max_x(self, /) -> hints.ScalarT: ...
@@ -26,6 +31,30 @@ class BaseBox(ABC, Generic[hints.ScalarT]):
     @abstractmethod
     def min_y(self, /) -> hints.ScalarT: ...
 
+    def convex_hull(
+        self, /
+    ) -> (
+        hints.Point[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+        | hints.Segment[hints.ScalarT]
+    ):
+        context = self._context
+        point_cls = context.point_cls
+        return collect_convex_hull_vertices(
+            to_convex_hull(
+                [
+                    point_cls(self.min_x, self.min_y),
+                    point_cls(self.max_x, self.min_y),
+                    point_cls(self.max_x, self.max_y),
+                    point_cls(self.min_x, self.max_y),
+                ],
+                context.orient,
+            ),
+            context.contour_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        )
+
     def covers(self, other: Self, /) -> bool:
         return (
             other.max_x < self.max_x
@@ -390,6 +419,11 @@ class BaseBox(ABC, Generic[hints.ScalarT]):
         assert self.max_x < other.min_x
         return Relation.DISJOINT
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
     def touches(self, other: Self, /) -> bool:
         return (
             (self.min_x == other.max_x or self.max_x == other.min_x)
@@ -399,6 +433,18 @@ class BaseBox(ABC, Generic[hints.ScalarT]):
             and (self.min_y == other.max_y or other.min_y == self.max_y)
         )
 
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_box(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
     def within(self, other: Self, /) -> bool:
         return (
             self.max_x < other.max_x
@@ -21,14 +21,34 @@ from rene._clipping import (
     unite_multisegmental_with_multisegmental,
     unite_multisegmental_with_segment,
 )
-from rene._relating import contour
+from rene._geo_interface import (
+    contour_from_geo_interface,
+    contour_to_geo_interface,
+)
+from rene._metric import (
+    to_discrete_hausdorff_distance_squared,
+    to_hausdorff_distance_squared,
+    to_nearest_points,
+    to_squared_distance,
+)
+from rene._normalization import normalize_contour
+from rene._relating import contour, matrix
+from rene._repairing import make_valid
+from rene._svg import contour_to_svg
+from rene._transformation import to_affine_matrix, transform_contour
 from rene._utils import (
     are_contour_vertices_non_degenerate,
+    collect_convex_hull_vertices,
+    cross_multiply,
     to_arg_min,
+    to_contour_centroid,
     to_contour_orientation,
+    to_convex_hull,
 )
+from rene._validation import Violation, validate_contour
+from rene._wkt import contour_from_wkt, contour_to_wkt
 from rene.constants import MIN_CONTOUR_VERTICES_COUNT
-from rene.enums import Location, Orientation, Relation
+from rene.enums import FillRule, Location, Orientation, Relation
 
 from .base_compound import BaseCompound
 from .utils import (
@@ -46,6 +66,10 @@ if TYPE_CHECKING:
 
 
 class BaseContour(ABC, BaseCompound[hints.ScalarT]):
+    @property
+    def area(self, /) -> hints.ScalarT:
+        return abs(self.signed_area)
+
     @property
     def bounding_box(self, /) -> hints.Box[hints.ScalarT]:
         vertices = iter(self.vertices)
@@ -63,6 +87,10 @@ class BaseContour(ABC, BaseCompound[hints.ScalarT]):
                 min_y = vertex.y
         return self._context.box_cls(min_x, max_x, min_y, max_y)
 
+    @property
+    def centroid(self, /) -> hints.Point[hints.ScalarT]:
+        return to_contour_centroid(self.vertices, self._context.point_cls)
+
     @property
     def orientation(self, /) -> Orientation:
         vertices = self.vertices
@@ -75,10 +103,84 @@ class BaseContour(ABC, BaseCompound[hints.ScalarT]):
     @abstractmethod
     def segments(self, /) -> Sequence[hints.Segment[hints.ScalarT]]: ...
 
+    @property
+    def signed_area(self, /) -> hints.ScalarT:
+        vertices = self.vertices
+        first_vertex = vertices[0]
+        result = cross_multiply(
+            first_vertex, vertices[1], first_vertex, vertices[2]
+        )
+        for index in range(2, len(vertices) - 1):
+            result += cross_multiply(
+                first_vertex,
+                vertices[index],
+                first_vertex,
+                vertices[index + 1],
+            )
+        return result / 2
+
     @property
     @abstractmethod
     def vertices(self, /) -> Sequence[hints.Point[hints.ScalarT]]: ...
 
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]:
+        return contour_to_geo_interface(self)
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self:
+        return contour_from_geo_interface(value, cls, cls._context.point_cls)
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        return contour_from_wkt(wkt, cls, cls._context.point_cls)
+
+    def convex_hull(
+        self, /
+    ) -> (
+        hints.Point[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+        | hints.Segment[hints.ScalarT]
+    ):
+        context = self._context
+        return collect_convex_hull_vertices(
+            to_convex_hull(self.vertices, context.orient),
+            context.contour_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        )
+
+    def discrete_hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.ScalarT, hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]
+    ]:
+        return to_discrete_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
+    def distance_squared(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> hints.ScalarT:
+        return to_squared_distance(self, other, context=self._context)
+
+    def hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.QuadraticSurd[hints.ScalarT],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+    ]:
+        return to_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
     def is_valid(self, /) -> bool:
         if not are_contour_vertices_non_degenerate(
             self.vertices, self._context.orient
@@ -108,6 +210,37 @@ class BaseContour(ABC, BaseCompound[hints.ScalarT]):
             else Location.BOUNDARY
         )
 
+    def make_valid(
+        self, fill_rule: FillRule, /
+    ) -> (
+        hints.Empty[hints.ScalarT]
+        | hints.Multipolygon[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+    ):
+        return make_valid(
+            [self],
+            fill_rule,
+            context=self._context,
+        )
+
+    def nearest_points(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]:
+        return to_nearest_points(self, other, context=self._context)
+
+    def normalize(self, /) -> Self:
+        return normalize_contour(self, context=self._context)
+
+    def relate_matrix(self, other: hints.Compound[hints.ScalarT], /) -> str:
+        return str(matrix.relate_matrix(self, other, context=self._context))
+
+    def relate_pattern(
+        self, other: hints.Compound[hints.ScalarT], pattern: str, /
+    ) -> bool:
+        return matrix.relate_matrix(
+            self, other, context=self._context
+        ).matches(pattern)
+
     def relate_to(self, other: hints.Compound[hints.ScalarT], /) -> Relation:
         context = self._context
         if is_contour(other, context=context):
@@ -136,6 +269,32 @@ class BaseContour(ABC, BaseCompound[hints.ScalarT]):
             return contour.relate_to_segment(self, other, context.orient)
         raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return contour_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_contour(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def validate(self, /) -> list[Violation[hints.ScalarT]]:
+        return validate_contour(self, context=self._context)
+
+    def _repr_svg_(self, /) -> str | None:
+        return contour_to_svg(self)
+
     @abstractmethod
     def __new__(
         cls, vertices: Sequence[hints.Point[hints.ScalarT]], /
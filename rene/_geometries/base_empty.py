@@ -3,6 +3,10 @@ from typing import Any, TypeVar, overload
 from typing_extensions import Self
 
 from rene import hints
+from rene._relating import matrix
+from rene._svg import empty_to_svg
+from rene._transformation import to_affine_matrix
+from rene._wkt import empty_from_wkt, empty_to_wkt
 from rene.enums import Location, Relation
 
 from .base_compound import BaseCompound
@@ -12,9 +16,26 @@ _CompoundT = TypeVar('_CompoundT', bound=hints.Compound[Any])
 
 
 class BaseEmpty(BaseCompound[hints.ScalarT]):
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        return empty_from_wkt(wkt, cls)
+
+    def convex_hull(self, /) -> Self:
+        return self
+
     def locate(self, _point: hints.Point[hints.ScalarT], /) -> Location:
         return Location.EXTERIOR
 
+    def relate_matrix(self, other: hints.Compound[hints.ScalarT], /) -> str:
+        return str(matrix.relate_matrix(self, other, context=self._context))
+
+    def relate_pattern(
+        self, other: hints.Compound[hints.ScalarT], pattern: str, /
+    ) -> bool:
+        return matrix.relate_matrix(
+            self, other, context=self._context
+        ).matches(pattern)
+
     def relate_to(self, other: hints.Compound[hints.ScalarT], /) -> Relation:
         return (
             Relation.EQUAL
@@ -22,6 +43,27 @@ class BaseEmpty(BaseCompound[hints.ScalarT]):
             else Relation.DISJOINT
         )
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return empty_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        # matrix is checked for consistency with other geometries
+        to_affine_matrix(matrix, context=self._context)
+        return self
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def _repr_svg_(self, /) -> str | None:
+        return empty_to_svg(self)
+
     @overload
     def __and__(self, other: hints.Compound[hints.ScalarT], /) -> Self: ...
 
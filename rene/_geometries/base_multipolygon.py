@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from abc import ABC, abstractmethod
+from itertools import chain
 from typing import Any, ClassVar, TYPE_CHECKING, overload
 
 from typing_extensions import Self
@@ -18,8 +19,33 @@ from rene._clipping import (
     unite_multipolygon_with_multipolygon,
     unite_multipolygon_with_polygon,
 )
-from rene._relating import multipolygon
-from rene.enums import Location, Relation
+from rene._geo_interface import (
+    multipolygon_from_geo_interface,
+    multipolygon_to_geo_interface,
+)
+from rene._metric import (
+    to_discrete_hausdorff_distance_squared,
+    to_hausdorff_distance_squared,
+    to_nearest_points,
+    to_squared_distance,
+)
+from rene._normalization import normalize_multipolygon
+from rene._relating import matrix, multipolygon
+from rene._repairing import make_valid
+from rene._svg import multipolygon_to_svg
+from rene._transformation import to_affine_matrix, transform_multipolygon
+from rene._utils import (
+    collect_convex_hull_vertices,
+    to_convex_hull,
+    to_vertices_centroid,
+)
+from rene._validation import (
+    Violation,
+    is_multipolygon_valid,
+    validate_multipolygon,
+)
+from rene._wkt import multipolygon_from_wkt, multipolygon_to_wkt
+from rene.enums import FillRule, Location, Relation
 
 from .base_compound import BaseCompound
 from .utils import (
@@ -44,6 +70,14 @@ class BaseMultipolygon(ABC, BaseCompound[hints.ScalarT]):
     def polygons(self, /) -> Sequence[hints.Polygon[hints.ScalarT]]:
         raise NotImplementedError
 
+    @property
+    def area(self, /) -> hints.ScalarT:
+        polygons = iter(self.polygons)
+        result = next(polygons).area
+        for polygon in polygons:
+            result += polygon.area
+        return result
+
     @property
     def bounding_box(self, /) -> hints.Box[hints.ScalarT]:
         polygons = iter(self.polygons)
@@ -66,6 +100,124 @@ class BaseMultipolygon(ABC, BaseCompound[hints.ScalarT]):
                 min_y = polygon_bounding_box.min_y
         return self._context.box_cls(min_x, max_x, min_y, max_y)
 
+    @property
+    def centroid(self, /) -> hints.Point[hints.ScalarT]:
+        polygons = self.polygons
+        first_polygon = polygons[0]
+        area = first_polygon.area
+        first_polygon_centroid = first_polygon.centroid
+        x_numerator, y_numerator = (
+            area * first_polygon_centroid.x,
+            area * first_polygon_centroid.y,
+        )
+        for polygon in polygons[1:]:
+            polygon_area, polygon_centroid = polygon.area, polygon.centroid
+            x_numerator += polygon_area * polygon_centroid.x
+            y_numerator += polygon_area * polygon_centroid.y
+            area += polygon_area
+        if not area:
+            return to_vertices_centroid(
+                chain.from_iterable(
+                    chain(
+                        polygon.border.vertices,
+                        chain.from_iterable(
+                            hole.vertices for hole in polygon.holes
+                        ),
+                    )
+                    for polygon in polygons
+                ),
+                self._context.point_cls,
+            )
+        return self._context.point_cls(x_numerator / area, y_numerator / area)
+
+    @property
+    def signed_area(self, /) -> hints.ScalarT:
+        polygons = iter(self.polygons)
+        result = next(polygons).signed_area
+        for polygon in polygons:
+            result += polygon.signed_area
+        return result
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]:
+        return multipolygon_to_geo_interface(self)
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self:
+        context = cls._context
+        return multipolygon_from_geo_interface(
+            value,
+            context.contour_cls,
+            cls,
+            context.point_cls,
+            context.polygon_cls,
+        )
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        context = cls._context
+        return multipolygon_from_wkt(
+            wkt,
+            context.contour_cls,
+            cls,
+            context.point_cls,
+            context.polygon_cls,
+        )
+
+    def convex_hull(
+        self, /
+    ) -> (
+        hints.Point[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+        | hints.Segment[hints.ScalarT]
+    ):
+        context = self._context
+        return collect_convex_hull_vertices(
+            to_convex_hull(
+                chain.from_iterable(
+                    polygon.border.vertices for polygon in self.polygons
+                ),
+                context.orient,
+            ),
+            context.contour_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        )
+
+    def discrete_hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.ScalarT, hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]
+    ]:
+        return to_discrete_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
+    def distance_squared(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> hints.ScalarT:
+        return to_squared_distance(self, other, context=self._context)
+
+    def hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.QuadraticSurd[hints.ScalarT],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+    ]:
+        return to_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
+    def is_valid(self, /) -> bool:
+        return is_multipolygon_valid(self)
+
     def locate(self, point: hints.Point[hints.ScalarT], /) -> Location:
         for polygon in self.polygons:
             location = polygon.locate(point)
@@ -73,6 +225,41 @@ class BaseMultipolygon(ABC, BaseCompound[hints.ScalarT]):
                 return location
         return Location.EXTERIOR
 
+    def make_valid(
+        self, fill_rule: FillRule, /
+    ) -> (
+        hints.Empty[hints.ScalarT]
+        | hints.Multipolygon[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+    ):
+        return make_valid(
+            [
+                contour
+                for polygon in self.polygons
+                for contour in (polygon.border, *polygon.holes)
+            ],
+            fill_rule,
+            context=self._context,
+        )
+
+    def nearest_points(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]:
+        return to_nearest_points(self, other, context=self._context)
+
+    def normalize(self, /) -> Self:
+        return normalize_multipolygon(self, context=self._context)
+
+    def relate_matrix(self, other: hints.Compound[hints.ScalarT], /) -> str:
+        return str(matrix.relate_matrix(self, other, context=self._context))
+
+    def relate_pattern(
+        self, other: hints.Compound[hints.ScalarT], pattern: str, /
+    ) -> bool:
+        return matrix.relate_matrix(
+            self, other, context=self._context
+        ).matches(pattern)
+
     def relate_to(self, other: hints.Compound[hints.ScalarT], /) -> Relation:
         context = self._context
         if is_contour(other, context=context):
@@ -99,6 +286,32 @@ class BaseMultipolygon(ABC, BaseCompound[hints.ScalarT]):
             )
         raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return multipolygon_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_multipolygon(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def validate(self, /) -> list[Violation[hints.ScalarT]]:
+        return validate_multipolygon(self)
+
+    def _repr_svg_(self, /) -> str | None:
+        return multipolygon_to_svg(self)
+
     _context: ClassVar[Context[Any]]
 
     @abstractmethod
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from abc import ABC, abstractmethod
+from itertools import chain
 from typing import Any, TYPE_CHECKING, overload
 
 from typing_extensions import Self
@@ -21,7 +22,27 @@ from rene._clipping import (
     unite_multisegmental_with_multisegmental,
     unite_multisegmental_with_segment,
 )
-from rene._relating import multisegment
+from rene._geo_interface import (
+    multisegment_from_geo_interface,
+    multisegment_to_geo_interface,
+)
+from rene._metric import (
+    to_discrete_hausdorff_distance_squared,
+    to_hausdorff_distance_squared,
+    to_nearest_points,
+    to_squared_distance,
+)
+from rene._normalization import normalize_multisegment
+from rene._relating import matrix, multisegment
+from rene._svg import multisegment_to_svg
+from rene._transformation import to_affine_matrix, transform_multisegment
+from rene._utils import (
+    collect_convex_hull_vertices,
+    to_convex_hull,
+    to_vertices_centroid,
+)
+from rene._validation import Violation, validate_multisegment
+from rene._wkt import multisegment_from_wkt, multisegment_to_wkt
 from rene.enums import Location, Relation
 
 from .base_compound import BaseCompound
@@ -67,6 +88,84 @@ class BaseMultisegment(ABC, BaseCompound[hints.ScalarT]):
                 min_y = segment_min_y
         return self._context.box_cls(min_x, max_x, min_y, max_y)
 
+    @property
+    def centroid(self, /) -> hints.Point[hints.ScalarT]:
+        return to_vertices_centroid(
+            chain.from_iterable(
+                (segment.start, segment.end) for segment in self.segments
+            ),
+            self._context.point_cls,
+        )
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]:
+        return multisegment_to_geo_interface(self)
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self:
+        context = cls._context
+        return multisegment_from_geo_interface(
+            value, cls, context.point_cls, context.segment_cls
+        )
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        context = cls._context
+        return multisegment_from_wkt(
+            wkt, cls, context.point_cls, context.segment_cls
+        )
+
+    def convex_hull(
+        self, /
+    ) -> (
+        hints.Point[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+        | hints.Segment[hints.ScalarT]
+    ):
+        context = self._context
+        return collect_convex_hull_vertices(
+            to_convex_hull(
+                chain.from_iterable(
+                    (segment.start, segment.end) for segment in self.segments
+                ),
+                context.orient,
+            ),
+            context.contour_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        )
+
+    def discrete_hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.ScalarT, hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]
+    ]:
+        return to_discrete_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
+    def distance_squared(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> hints.ScalarT:
+        return to_squared_distance(self, other, context=self._context)
+
+    def hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.QuadraticSurd[hints.ScalarT],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+    ]:
+        return to_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
     def is_valid(self, /) -> bool:
         return all(
             intersection.relation is Relation.TOUCH
@@ -84,6 +183,24 @@ class BaseMultisegment(ABC, BaseCompound[hints.ScalarT]):
                 return location
         return Location.EXTERIOR
 
+    def nearest_points(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]:
+        return to_nearest_points(self, other, context=self._context)
+
+    def normalize(self, /) -> Self:
+        return normalize_multisegment(self, context=self._context)
+
+    def relate_matrix(self, other: hints.Compound[hints.ScalarT], /) -> str:
+        return str(matrix.relate_matrix(self, other, context=self._context))
+
+    def relate_pattern(
+        self, other: hints.Compound[hints.ScalarT], pattern: str, /
+    ) -> bool:
+        return matrix.relate_matrix(
+            self, other, context=self._context
+        ).matches(pattern)
+
     def relate_to(self, other: hints.Compound[hints.ScalarT], /) -> Relation:
         context = self._context
         if is_contour(other, context=context):
@@ -121,6 +238,32 @@ class BaseMultisegment(ABC, BaseCompound[hints.ScalarT]):
             )
         raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return multisegment_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_multisegment(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def validate(self, /) -> list[Violation[hints.ScalarT]]:
+        return validate_multisegment(self, context=self._context)
+
+    def _repr_svg_(self, /) -> str | None:
+        return multisegment_to_svg(self)
+
     @abstractmethod
     def __new__(
         cls, segments: Sequence[hints.Segment[hints.ScalarT]], /
@@ -1,12 +1,23 @@
 from abc import ABC, abstractmethod
-from typing import Any, Generic, overload
+from typing import Any, ClassVar, Generic, overload
 
 from typing_extensions import Self
 
 from rene import hints
+from rene._context import Context
+from rene._geo_interface import (
+    point_from_geo_interface,
+    point_to_geo_interface,
+)
+from rene._metric import to_nearest_points, to_squared_distance
+from rene._svg import point_to_svg
+from rene._transformation import to_affine_matrix, transform_point
+from rene._wkt import point_from_wkt, point_to_wkt
 
 
 class BasePoint(ABC, Generic[hints.ScalarT]):
+    _context: ClassVar[Context[Any]]
+
     @property
     @abstractmethod
     def x(self, /) -> hints.ScalarT: ...
@@ -15,6 +26,54 @@ class BasePoint(ABC, Generic[hints.ScalarT]):
     @abstractmethod
     def y(self, /) -> hints.ScalarT: ...
 
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]:
+        return point_to_geo_interface(self)
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self:
+        return point_from_geo_interface(value, cls)
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        return point_from_wkt(wkt, cls)
+
+    def convex_hull(self, /) -> Self:
+        return self
+
+    def distance_squared(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> hints.ScalarT:
+        return to_squared_distance(self, other, context=self._context)
+
+    def nearest_points(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]:
+        return to_nearest_points(self, other, context=self._context)
+
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return point_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_point(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def _repr_svg_(self, /) -> str | None:
+        return point_to_svg(self)
+
     @abstractmethod
     def __new__(cls, x: hints.ScalarT, y: hints.ScalarT, /) -> Self:
         raise NotImplementedError
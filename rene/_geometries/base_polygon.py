@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from abc import ABC, abstractmethod
+from itertools import chain
 from typing import Any, TYPE_CHECKING, overload
 
 from typing_extensions import Self
@@ -18,9 +19,30 @@ from rene._clipping import (
     unite_polygon_with_multipolygon,
     unite_polygon_with_polygon,
 )
-from rene._relating import polygon
-from rene._utils import locate_point_in_region
-from rene.enums import Location, Relation
+from rene._geo_interface import (
+    polygon_from_geo_interface,
+    polygon_to_geo_interface,
+)
+from rene._metric import (
+    to_discrete_hausdorff_distance_squared,
+    to_hausdorff_distance_squared,
+    to_nearest_points,
+    to_squared_distance,
+)
+from rene._normalization import normalize_polygon
+from rene._relating import matrix, polygon
+from rene._repairing import make_valid
+from rene._svg import polygon_to_svg
+from rene._transformation import to_affine_matrix, transform_polygon
+from rene._utils import (
+    collect_convex_hull_vertices,
+    locate_point_in_region,
+    to_convex_hull,
+    to_vertices_centroid,
+)
+from rene._validation import Violation, is_polygon_valid, validate_polygon
+from rene._wkt import polygon_from_wkt, polygon_to_wkt
+from rene.enums import FillRule, Location, Relation
 
 from .base_compound import BaseCompound
 from .utils import (
@@ -46,10 +68,113 @@ class BasePolygon(ABC, BaseCompound[hints.ScalarT]):
     @abstractmethod
     def holes(self, /) -> Sequence[hints.Contour[hints.ScalarT]]: ...
 
+    @property
+    def area(self, /) -> hints.ScalarT:
+        result = self.border.area
+        for hole in self.holes:
+            result -= hole.area
+        return result
+
     @property
     def bounding_box(self, /) -> hints.Box[hints.ScalarT]:
         return self.border.bounding_box
 
+    @property
+    def centroid(self, /) -> hints.Point[hints.ScalarT]:
+        border, holes = self.border, self.holes
+        area = border.area
+        border_centroid = border.centroid
+        x_numerator, y_numerator = (
+            area * border_centroid.x,
+            area * border_centroid.y,
+        )
+        for hole in holes:
+            hole_area, hole_centroid = hole.area, hole.centroid
+            x_numerator -= hole_area * hole_centroid.x
+            y_numerator -= hole_area * hole_centroid.y
+            area -= hole_area
+        if not area:
+            return to_vertices_centroid(
+                chain(
+                    border.vertices,
+                    chain.from_iterable(hole.vertices for hole in holes),
+                ),
+                self._context.point_cls,
+            )
+        return self._context.point_cls(x_numerator / area, y_numerator / area)
+
+    @property
+    def signed_area(self, /) -> hints.ScalarT:
+        area = self.area
+        return -area if self.border.signed_area < 0 else area
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]:
+        return polygon_to_geo_interface(self)
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self:
+        context = cls._context
+        return polygon_from_geo_interface(
+            value, context.contour_cls, context.point_cls, cls
+        )
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        context = cls._context
+        return polygon_from_wkt(
+            wkt, context.contour_cls, context.point_cls, cls
+        )
+
+    def convex_hull(
+        self, /
+    ) -> (
+        hints.Point[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+        | hints.Segment[hints.ScalarT]
+    ):
+        context = self._context
+        return collect_convex_hull_vertices(
+            to_convex_hull(self.border.vertices, context.orient),
+            context.contour_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        )
+
+    def discrete_hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.ScalarT, hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]
+    ]:
+        return to_discrete_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
+    def distance_squared(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> hints.ScalarT:
+        return to_squared_distance(self, other, context=self._context)
+
+    def hausdorff_distance_squared(
+        self, other: hints.HausdorffMetrizable[hints.ScalarT], /
+    ) -> tuple[
+        hints.QuadraticSurd[hints.ScalarT],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+        tuple[
+            hints.QuadraticSurd[hints.ScalarT],
+            hints.QuadraticSurd[hints.ScalarT],
+        ],
+    ]:
+        return to_hausdorff_distance_squared(
+            self, other, context=self._context
+        )
+
+    def is_valid(self, /) -> bool:
+        return is_polygon_valid(self, context=self._context)
+
     def locate(self, point: hints.Point[hints.ScalarT], /) -> Location:
         location_without_holes = locate_point_in_region(
             self.border, point, self._context.orient
@@ -65,6 +190,37 @@ class BasePolygon(ABC, BaseCompound[hints.ScalarT]):
                     return Location.BOUNDARY
         return location_without_holes
 
+    def make_valid(
+        self, fill_rule: FillRule, /
+    ) -> (
+        hints.Empty[hints.ScalarT]
+        | hints.Multipolygon[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+    ):
+        return make_valid(
+            [self.border, *self.holes],
+            fill_rule,
+            context=self._context,
+        )
+
+    def nearest_points(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]:
+        return to_nearest_points(self, other, context=self._context)
+
+    def normalize(self, /) -> Self:
+        return normalize_polygon(self, context=self._context)
+
+    def relate_matrix(self, other: hints.Compound[hints.ScalarT], /) -> str:
+        return str(matrix.relate_matrix(self, other, context=self._context))
+
+    def relate_pattern(
+        self, other: hints.Compound[hints.ScalarT], pattern: str, /
+    ) -> bool:
+        return matrix.relate_matrix(
+            self, other, context=self._context
+        ).matches(pattern)
+
     def relate_to(self, other: hints.Compound[hints.ScalarT], /) -> Relation:
         context = self._context
         if is_contour(other, context=context):
@@ -91,6 +247,32 @@ class BasePolygon(ABC, BaseCompound[hints.ScalarT]):
             )
         raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return polygon_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_polygon(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def validate(self, /) -> list[Violation[hints.ScalarT]]:
+        return validate_polygon(self, context=self._context)
+
+    def _repr_svg_(self, /) -> str | None:
+        return polygon_to_svg(self)
+
     @abstractmethod
     def __new__(
         cls,
@@ -18,6 +18,10 @@ from rene._clipping import (
     unite_segment_with_multisegmental,
     unite_segment_with_segment,
 )
+from rene._geo_interface import (
+    segment_from_geo_interface,
+    segment_to_geo_interface,
+)
 from rene._geometries.base_compound import BaseCompound
 from rene._geometries.utils import (
     is_contour,
@@ -28,8 +32,16 @@ from rene._geometries.utils import (
     is_polygon,
     is_segment,
 )
-from rene._relating import segment
-from rene._utils import locate_point_in_segment
+from rene._metric import to_nearest_points, to_squared_distance
+from rene._relating import matrix, segment
+from rene._svg import segment_to_svg
+from rene._transformation import to_affine_matrix, transform_segment
+from rene._utils import (
+    collect_convex_hull_vertices,
+    locate_point_in_segment,
+    to_convex_hull,
+)
+from rene._wkt import segment_from_wkt, segment_to_wkt
 from rene.enums import Location, Relation
 
 
@@ -51,11 +63,64 @@ class BaseSegment(ABC, BaseCompound[hints.ScalarT]):
             max(self.end.y, self.start.y),
         )
 
+    @property
+    def centroid(self, /) -> hints.Point[hints.ScalarT]:
+        return self._context.point_cls(
+            (self.start.x + self.end.x) / 2, (self.start.y + self.end.y) / 2
+        )
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]:
+        return segment_to_geo_interface(self)
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self:
+        return segment_from_geo_interface(value, cls._context.point_cls, cls)
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self:
+        return segment_from_wkt(wkt, cls._context.point_cls, cls)
+
+    def convex_hull(
+        self, /
+    ) -> (
+        hints.Point[hints.ScalarT]
+        | hints.Polygon[hints.ScalarT]
+        | hints.Segment[hints.ScalarT]
+    ):
+        context = self._context
+        return collect_convex_hull_vertices(
+            to_convex_hull([self.start, self.end], context.orient),
+            context.contour_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        )
+
+    def distance_squared(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> hints.ScalarT:
+        return to_squared_distance(self, other, context=self._context)
+
     def locate(self, point: hints.Point[hints.ScalarT], /) -> Location:
         return locate_point_in_segment(
             self.start, self.end, point, self._context.orient
         )
 
+    def nearest_points(
+        self, other: hints.Metrizable[hints.ScalarT], /
+    ) -> tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]:
+        return to_nearest_points(self, other, context=self._context)
+
+    def relate_matrix(self, other: hints.Compound[hints.ScalarT], /) -> str:
+        return str(matrix.relate_matrix(self, other, context=self._context))
+
+    def relate_pattern(
+        self, other: hints.Compound[hints.ScalarT], pattern: str, /
+    ) -> bool:
+        return matrix.relate_matrix(
+            self, other, context=self._context
+        ).matches(pattern)
+
     def relate_to(self, other: hints.Compound[hints.ScalarT], /) -> Relation:
         context = self._context
         if is_contour(other, context=context):
@@ -81,6 +146,29 @@ class BaseSegment(ABC, BaseCompound[hints.ScalarT]):
             return segment.relate_to_segment(self, other, context.orient)
         raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def scale(
+        self, factor_x: hints.ScalarT, factor_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((factor_x, 0, 0), (0, factor_y, 0)))
+
+    def to_wkt(self, /) -> str:
+        return segment_to_wkt(self)
+
+    def transform(self, matrix: hints.AffineMatrix[hints.ScalarT], /) -> Self:
+        return transform_segment(
+            self,
+            to_affine_matrix(matrix, context=self._context),
+            context=self._context,
+        )
+
+    def translate(
+        self, step_x: hints.ScalarT, step_y: hints.ScalarT, /
+    ) -> Self:
+        return self.transform(((1, 0, step_x), (0, 1, step_y)))
+
+    def _repr_svg_(self, /) -> str | None:
+        return segment_to_svg(self)
+
     @abstractmethod
     def __new__(
         cls,
@@ -0,0 +1,927 @@
+from __future__ import annotations
+
+from collections.abc import Iterable
+from itertools import chain
+from operator import itemgetter
+from typing import Any, Union
+
+from typing_extensions import TypeIs
+
+from rene import hints
+from rene._context import Context
+from rene._geometries.utils import (
+    is_contour,
+    is_multipolygon,
+    is_multisegment,
+    is_multisegmental,
+    is_polygon,
+    is_segment,
+)
+from rene._utils import cross_multiply, dot_multiply
+from rene.enums import Location, Orientation
+
+_Metrizable = Union[
+    hints.Contour[hints.ScalarT],
+    hints.Multipolygon[hints.ScalarT],
+    hints.Multisegment[hints.ScalarT],
+    hints.Point[hints.ScalarT],
+    hints.Polygon[hints.ScalarT],
+    hints.Segment[hints.ScalarT],
+]
+_Multisegmental = Union[
+    hints.Contour[hints.ScalarT], hints.Multisegment[hints.ScalarT]
+]
+_Shaped = Union[
+    hints.Multipolygon[hints.ScalarT], hints.Polygon[hints.ScalarT]
+]
+_HausdorffWitness = tuple[
+    hints.ScalarT, hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]
+]
+_PointsPair = tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]
+# pieces of squared distance from the point `start + t * direction`
+# to its nearest point `origin + t * shift`
+# as coefficients of the quadratic polynomial in `t`
+# followed by `origin` & `shift`
+_Piece = tuple[
+    hints.ScalarT,
+    hints.ScalarT,
+    hints.ScalarT,
+    tuple[hints.ScalarT, hints.ScalarT],
+    tuple[hints.ScalarT, hints.ScalarT],
+]
+# subintervals of `[0, 1]` in ascending order
+# with indices of pieces attaining the smallest distance on them
+_Envelope = list[
+    tuple[
+        hints.QuadraticSurd[hints.ScalarT],
+        hints.QuadraticSurd[hints.ScalarT],
+        int,
+    ]
+]
+_SurdCoordinates = tuple[
+    hints.QuadraticSurd[hints.ScalarT], hints.QuadraticSurd[hints.ScalarT]
+]
+_SurdHausdorffWitness = tuple[
+    hints.QuadraticSurd[hints.ScalarT],
+    _SurdCoordinates[hints.ScalarT],
+    _SurdCoordinates[hints.ScalarT],
+]
+
+
+def to_discrete_hausdorff_distance_squared(
+    first: _Multisegmental[hints.ScalarT] | _Shaped[hints.ScalarT],
+    second: Any,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _HausdorffWitness[hints.ScalarT]:
+    _ensure_hausdorff_metrizable(second, context=context)
+    first_vertices = _to_boundary_vertices(first, context=context)
+    second_vertices = _to_boundary_vertices(second, context=context)
+    first_squared_distance, first_vertex, second_nearest_vertex = (
+        _to_directed_discrete_hausdorff_distance_squared(
+            first_vertices, second_vertices
+        )
+    )
+    second_squared_distance, second_vertex, first_nearest_vertex = (
+        _to_directed_discrete_hausdorff_distance_squared(
+            second_vertices, first_vertices
+        )
+    )
+    return (
+        (second_squared_distance, first_nearest_vertex, second_vertex)
+        if second_squared_distance > first_squared_distance
+        else (first_squared_distance, first_vertex, second_nearest_vertex)
+    )
+
+
+def to_hausdorff_distance_squared(
+    first: _Multisegmental[hints.ScalarT] | _Shaped[hints.ScalarT],
+    second: Any,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _SurdHausdorffWitness[hints.ScalarT]:
+    _ensure_hausdorff_metrizable(second, context=context)
+    first_segments = _to_boundary_segments(first, context=context)
+    second_segments = _to_boundary_segments(second, context=context)
+    first_squared_distance, first_point, second_nearest_point = (
+        _to_directed_hausdorff_distance_squared(
+            first_segments, second_segments, context=context
+        )
+    )
+    second_squared_distance, second_point, first_nearest_point = (
+        _to_directed_hausdorff_distance_squared(
+            second_segments, first_segments, context=context
+        )
+    )
+    return (
+        (second_squared_distance, first_nearest_point, second_point)
+        if second_squared_distance > first_squared_distance
+        else (first_squared_distance, first_point, second_nearest_point)
+    )
+
+
+def to_nearest_points(
+    first: _Metrizable[hints.ScalarT],
+    second: Any,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _PointsPair[hints.ScalarT]:
+    if not _is_metrizable(second, context=context):
+        raise TypeError(f'Expected geometry, but got {type(second)!r}.')
+    return _to_nearest_points(first, second, context=context)
+
+
+def to_squared_distance(
+    first: _Metrizable[hints.ScalarT],
+    second: Any,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.ScalarT:
+    if not _is_metrizable(second, context=context):
+        raise TypeError(f'Expected geometry, but got {type(second)!r}.')
+    return _to_squared_distance(first, second, context=context)
+
+
+def _cross_multiply_vectors(
+    first: tuple[hints.ScalarT, hints.ScalarT],
+    second: tuple[hints.ScalarT, hints.ScalarT],
+    /,
+) -> hints.ScalarT:
+    return first[0] * second[1] - first[1] * second[0]
+
+
+def _do_segments_cross(
+    first_start: hints.Point[hints.ScalarT],
+    first_end: hints.Point[hints.ScalarT],
+    second_start: hints.Point[hints.ScalarT],
+    second_end: hints.Point[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> bool:
+    first_start_orientation = context.orient(
+        second_start, second_end, first_start
+    )
+    first_end_orientation = context.orient(second_start, second_end, first_end)
+    second_start_orientation = context.orient(
+        first_start, first_end, second_start
+    )
+    second_end_orientation = context.orient(
+        first_start, first_end, second_end
+    )
+    return (
+        first_start_orientation is not Orientation.COLLINEAR
+        and first_end_orientation is not Orientation.COLLINEAR
+        and first_start_orientation is not first_end_orientation
+        and second_start_orientation is not Orientation.COLLINEAR
+        and second_end_orientation is not Orientation.COLLINEAR
+        and second_start_orientation is not second_end_orientation
+    )
+
+
+def _dot_multiply_vectors(
+    first: tuple[hints.ScalarT, hints.ScalarT],
+    second: tuple[hints.ScalarT, hints.ScalarT],
+    /,
+) -> hints.ScalarT:
+    return first[0] * second[0] + first[1] * second[1]
+
+
+def _ensure_hausdorff_metrizable(
+    value: Any, /, *, context: Context[hints.ScalarT]
+) -> None:
+    if not isinstance(
+        value,
+        (
+            context.contour_cls,
+            context.multipolygon_cls,
+            context.multisegment_cls,
+            context.polygon_cls,
+        ),
+    ):
+        raise TypeError(
+            'Expected contour, multipolygon, multisegment or polygon, '
+            f'but got {type(value)!r}.'
+        )
+
+
+def _is_metrizable(
+    value: Any, /, *, context: Context[hints.ScalarT]
+) -> TypeIs[_Metrizable[hints.ScalarT]]:
+    return isinstance(
+        value,
+        (
+            context.contour_cls,
+            context.multipolygon_cls,
+            context.multisegment_cls,
+            context.point_cls,
+            context.polygon_cls,
+            context.segment_cls,
+        ),
+    )
+
+
+def _merge_envelopes(
+    envelopes: list[_Envelope[hints.ScalarT]],
+    pieces: list[_Piece[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _Envelope[hints.ScalarT]:
+    while len(envelopes) > 1:
+        envelopes = [
+            (
+                _merge_envelopes_pair(
+                    envelopes[index],
+                    envelopes[index + 1],
+                    pieces,
+                    context=context,
+                )
+                if index + 1 < len(envelopes)
+                else envelopes[index]
+            )
+            for index in range(0, len(envelopes), 2)
+        ]
+    return envelopes[0]
+
+
+def _merge_envelopes_pair(
+    first: _Envelope[hints.ScalarT],
+    second: _Envelope[hints.ScalarT],
+    pieces: list[_Piece[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _Envelope[hints.ScalarT]:
+    result: _Envelope[hints.ScalarT] = []
+    first_index = second_index = 0
+    start = first[0][0]
+    while first_index < len(first) and second_index < len(second):
+        _, first_end, first_piece_index = first[first_index]
+        _, second_end, second_piece_index = second[second_index]
+        end = min(first_end, second_end)
+        _push_lower_pieces(
+            start,
+            end,
+            first_piece_index,
+            second_piece_index,
+            pieces,
+            result,
+            context=context,
+        )
+        start = end
+        if first_end == end:
+            first_index += 1
+        if second_end == end:
+            second_index += 1
+    return result
+
+
+def _push_lower_pieces(
+    start: hints.QuadraticSurd[hints.ScalarT],
+    end: hints.QuadraticSurd[hints.ScalarT],
+    first_piece_index: int,
+    second_piece_index: int,
+    pieces: list[_Piece[hints.ScalarT]],
+    envelope: _Envelope[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> None:
+    first_piece, second_piece = (
+        pieces[first_piece_index],
+        pieces[second_piece_index],
+    )
+    quadratic = first_piece[0] - second_piece[0]
+    linear = first_piece[1] - second_piece[1]
+    constant = first_piece[2] - second_piece[2]
+    zero = quadratic - quadratic
+    # roots of the difference where it changes sign in ascending order
+    # along with the sign of the difference before them
+    roots: list[hints.QuadraticSurd[hints.ScalarT]]
+    if quadratic == 0:
+        if linear == 0:
+            roots, initial_sign = [], _to_sign(constant)
+        else:
+            roots, initial_sign = (
+                [context.quadratic_surd_cls(-constant / linear, zero, zero)],
+                -_to_sign(linear),
+            )
+    else:
+        discriminant = linear * linear - 4 * quadratic * constant
+        if discriminant > 0:
+            doubled_quadratic = quadratic + quadratic
+            center = -linear / doubled_quadratic
+            scale = abs(1 / doubled_quadratic)
+            roots = [
+                context.quadratic_surd_cls(center, -scale, discriminant),
+                context.quadratic_surd_cls(center, scale, discriminant),
+            ]
+        else:
+            roots = []
+        initial_sign = _to_sign(quadratic)
+    part_start = start
+    for part_end in [*[root for root in roots if start < root < end], end]:
+        passed_roots_count = sum(root <= part_start for root in roots)
+        sign = (
+            initial_sign if passed_roots_count % 2 == 0 else -initial_sign
+        )
+        piece_index = (
+            second_piece_index if sign > 0 else first_piece_index
+        )
+        if envelope and envelope[-1][2] == piece_index:
+            envelope[-1] = envelope[-1][0], part_end, piece_index
+        else:
+            envelope.append((part_start, part_end, piece_index))
+        part_start = part_end
+
+
+def _to_boundary_segments(
+    geometry: _Shaped[hints.ScalarT] | _Multisegmental[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[hints.Segment[hints.ScalarT]]:
+    if is_multisegmental(geometry, context=context):
+        return list(geometry.segments)
+    if is_polygon(geometry, context=context):
+        return list(_to_polygon_boundary_segments(geometry))
+    assert is_multipolygon(geometry, context=context), geometry
+    return [
+        segment
+        for polygon in geometry.polygons
+        for segment in _to_polygon_boundary_segments(polygon)
+    ]
+
+
+def _to_boundary_vertices(
+    geometry: _Shaped[hints.ScalarT] | _Multisegmental[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[hints.Point[hints.ScalarT]]:
+    if is_contour(geometry, context=context):
+        return list(geometry.vertices)
+    if is_multisegment(geometry, context=context):
+        return [
+            endpoint
+            for segment in geometry.segments
+            for endpoint in (segment.start, segment.end)
+        ]
+    if is_polygon(geometry, context=context):
+        return [
+            *geometry.border.vertices,
+            *chain.from_iterable(hole.vertices for hole in geometry.holes),
+        ]
+    assert is_multipolygon(geometry, context=context), geometry
+    return [
+        vertex
+        for polygon in geometry.polygons
+        for vertex in _to_boundary_vertices(polygon, context=context)
+    ]
+
+
+def _to_directed_discrete_hausdorff_distance_squared(
+    vertices: list[hints.Point[hints.ScalarT]],
+    other_vertices: list[hints.Point[hints.ScalarT]],
+    /,
+) -> _HausdorffWitness[hints.ScalarT]:
+    result: _HausdorffWitness[hints.ScalarT] | None = None
+    for vertex in vertices:
+        squared_distance, nearest_vertex = min(
+            (
+                (
+                    _to_points_squared_distance(vertex, other_vertex),
+                    other_vertex,
+                )
+                for other_vertex in other_vertices
+            ),
+            key=itemgetter(0),
+        )
+        if result is None or squared_distance > result[0]:
+            result = squared_distance, vertex, nearest_vertex
+    assert result is not None
+    return result
+
+
+def _to_directed_hausdorff_distance_squared(
+    segments: list[hints.Segment[hints.ScalarT]],
+    other_segments: list[hints.Segment[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _SurdHausdorffWitness[hints.ScalarT]:
+    # distances to points & lines are convex along the segment,
+    # so the largest distance is attained at an endpoint
+    # of some subinterval of the lower envelope
+    result: _SurdHausdorffWitness[hints.ScalarT] | None = None
+    for segment in segments:
+        start = segment.start.x, segment.start.y
+        direction = segment.end.x - start[0], segment.end.y - start[1]
+        pieces: list[_Piece[hints.ScalarT]] = []
+        envelope = _merge_envelopes(
+            [
+                _to_segment_envelope(
+                    start, direction, other_segment, pieces, context=context
+                )
+                for other_segment in other_segments
+            ],
+            pieces,
+            context=context,
+        )
+        for start_parameter, end_parameter, piece_index in envelope:
+            piece = pieces[piece_index]
+            for parameter in (start_parameter, end_parameter):
+                squared_distance = _to_piece_value(
+                    piece, parameter, context=context
+                )
+                if result is None or squared_distance > result[0]:
+                    result = (
+                        squared_distance,
+                        _to_parameter_point(
+                            start, direction, parameter, context=context
+                        ),
+                        _to_parameter_point(
+                            piece[3], piece[4], parameter, context=context
+                        ),
+                    )
+    assert result is not None
+    return result
+
+
+def _to_nearest_points(
+    first: _Metrizable[hints.ScalarT],
+    second: _Metrizable[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _PointsPair[hints.ScalarT]:
+    if is_multisegmental(first, context=context):
+        return min(
+            (
+                _to_nearest_points(segment, second, context=context)
+                for segment in first.segments
+            ),
+            key=_to_points_pair_squared_distance,
+        )
+    if is_multipolygon(first, context=context):
+        return min(
+            (
+                _to_nearest_points(polygon, second, context=context)
+                for polygon in first.polygons
+            ),
+            key=_to_points_pair_squared_distance,
+        )
+    if is_polygon(first, context=context):
+        if is_polygon(second, context=context):
+            return _to_polygons_nearest_points(first, second, context=context)
+        if is_segment(second, context=context):
+            if first.locate(second.start) is not Location.EXTERIOR:
+                return second.start, second.start
+            return min(
+                (
+                    _to_nearest_points(segment, second, context=context)
+                    for segment in _to_polygon_boundary_segments(first)
+                ),
+                key=_to_points_pair_squared_distance,
+            )
+        if isinstance(second, context.point_cls):
+            if first.locate(second) is not Location.EXTERIOR:
+                return second, second
+            return min(
+                (
+                    _to_nearest_points(segment, second, context=context)
+                    for segment in _to_polygon_boundary_segments(first)
+                ),
+                key=_to_points_pair_squared_distance,
+            )
+    elif is_segment(first, context=context):
+        if is_segment(second, context=context):
+            return _to_segments_nearest_points(
+                first.start,
+                first.end,
+                second.start,
+                second.end,
+                context=context,
+            )
+        if isinstance(second, context.point_cls):
+            return (
+                _to_point_segment_nearest_point(
+                    second, first.start, first.end, context=context
+                ),
+                second,
+            )
+    elif isinstance(second, context.point_cls):
+        return first, second
+    other_point, point = _to_nearest_points(second, first, context=context)
+    return point, other_point
+
+
+def _to_parameter_point(
+    origin: tuple[hints.ScalarT, hints.ScalarT],
+    shift: tuple[hints.ScalarT, hints.ScalarT],
+    parameter: hints.QuadraticSurd[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _SurdCoordinates[hints.ScalarT]:
+    return (
+        context.quadratic_surd_cls(
+            origin[0] + shift[0] * parameter.rational,
+            shift[0] * parameter.irrational,
+            parameter.radicand,
+        ),
+        context.quadratic_surd_cls(
+            origin[1] + shift[1] * parameter.rational,
+            shift[1] * parameter.irrational,
+            parameter.radicand,
+        ),
+    )
+
+
+def _to_piece_value(
+    piece: _Piece[hints.ScalarT],
+    parameter: hints.QuadraticSurd[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.QuadraticSurd[hints.ScalarT]:
+    quadratic, linear, constant, _, _ = piece
+    rational, irrational, radicand = (
+        parameter.rational,
+        parameter.irrational,
+        parameter.radicand,
+    )
+    return context.quadratic_surd_cls(
+        quadratic * (rational * rational + irrational * irrational * radicand)
+        + linear * rational
+        + constant,
+        (2 * quadratic * rational + linear) * irrational,
+        radicand,
+    )
+
+
+def _to_point_piece(
+    start: tuple[hints.ScalarT, hints.ScalarT],
+    direction: tuple[hints.ScalarT, hints.ScalarT],
+    point: tuple[hints.ScalarT, hints.ScalarT],
+    /,
+) -> _Piece[hints.ScalarT]:
+    offset = start[0] - point[0], start[1] - point[1]
+    zero = offset[0] - offset[0]
+    return (
+        _dot_multiply_vectors(direction, direction),
+        2 * _dot_multiply_vectors(offset, direction),
+        _dot_multiply_vectors(offset, offset),
+        point,
+        (zero, zero),
+    )
+
+
+def _to_point_segment_nearest_point(
+    point: hints.Point[hints.ScalarT],
+    start: hints.Point[hints.ScalarT],
+    end: hints.Point[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Point[hints.ScalarT]:
+    projection_scale = dot_multiply(start, point, start, end)
+    if projection_scale <= 0:
+        return start
+    segment_squared_length = _to_points_squared_distance(start, end)
+    if projection_scale >= segment_squared_length:
+        return end
+    scale = projection_scale / segment_squared_length
+    return context.point_cls(
+        start.x + (end.x - start.x) * scale,
+        start.y + (end.y - start.y) * scale,
+    )
+
+
+def _to_point_segment_squared_distance(
+    point: hints.Point[hints.ScalarT],
+    start: hints.Point[hints.ScalarT],
+    end: hints.Point[hints.ScalarT],
+    /,
+) -> hints.ScalarT:
+    projection_scale = dot_multiply(start, point, start, end)
+    if projection_scale <= 0:
+        return _to_points_squared_distance(point, start)
+    segment_squared_length = _to_points_squared_distance(start, end)
+    if projection_scale >= segment_squared_length:
+        return _to_points_squared_distance(point, end)
+    return (
+        cross_multiply(start, end, start, point) ** 2
+        / segment_squared_length
+    )
+
+
+def _to_points_pair_squared_distance(
+    points_pair: _PointsPair[hints.ScalarT], /
+) -> hints.ScalarT:
+    return _to_points_squared_distance(*points_pair)
+
+
+def _to_points_squared_distance(
+    first: hints.Point[hints.ScalarT], second: hints.Point[hints.ScalarT], /
+) -> hints.ScalarT:
+    return (first.x - second.x) ** 2 + (first.y - second.y) ** 2
+
+
+def _to_polygon_boundary_segments(
+    polygon: hints.Polygon[hints.ScalarT], /
+) -> Iterable[hints.Segment[hints.ScalarT]]:
+    return chain(
+        polygon.border.segments,
+        chain.from_iterable(hole.segments for hole in polygon.holes),
+    )
+
+
+def _to_polygons_nearest_points(
+    first: hints.Polygon[hints.ScalarT],
+    second: hints.Polygon[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _PointsPair[hints.ScalarT]:
+    first_vertex, second_vertex = (
+        first.border.vertices[0],
+        second.border.vertices[0],
+    )
+    if first.locate(second_vertex) is not Location.EXTERIOR:
+        return second_vertex, second_vertex
+    if second.locate(first_vertex) is not Location.EXTERIOR:
+        return first_vertex, first_vertex
+    return min(
+        (
+            _to_nearest_points(first_segment, second_segment, context=context)
+            for first_segment in _to_polygon_boundary_segments(first)
+            for second_segment in _to_polygon_boundary_segments(second)
+        ),
+        key=_to_points_pair_squared_distance,
+    )
+
+
+def _to_polygons_squared_distance(
+    first: hints.Polygon[hints.ScalarT],
+    second: hints.Polygon[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.ScalarT:
+    if (
+        first.locate(second.border.vertices[0]) is not Location.EXTERIOR
+        or second.locate(first.border.vertices[0]) is not Location.EXTERIOR
+    ):
+        return _to_zero(first.border.vertices[0])
+    return min(
+        _to_squared_distance(first_segment, second_segment, context=context)
+        for first_segment in _to_polygon_boundary_segments(first)
+        for second_segment in _to_polygon_boundary_segments(second)
+    )
+
+
+def _to_segment_envelope(
+    start: tuple[hints.ScalarT, hints.ScalarT],
+    direction: tuple[hints.ScalarT, hints.ScalarT],
+    other_segment: hints.Segment[hints.ScalarT],
+    pieces: list[_Piece[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _Envelope[hints.ScalarT]:
+    # pieces of the squared distance to the other segment along the segment
+    # for the parts closest to the other segment's start,
+    # to its interior & to its end
+    other_start = other_segment.start.x, other_segment.start.y
+    other_end = other_segment.end.x, other_segment.end.y
+    other_direction = (
+        other_end[0] - other_start[0],
+        other_end[1] - other_start[1],
+    )
+    other_squared_length = _dot_multiply_vectors(
+        other_direction, other_direction
+    )
+    zero = other_squared_length - other_squared_length
+    one = zero + 1
+    if other_squared_length == 0:
+        pieces.append(_to_point_piece(start, direction, other_start))
+        return [
+            (
+                context.quadratic_surd_cls(zero, zero, zero),
+                context.quadratic_surd_cls(one, zero, zero),
+                len(pieces) - 1,
+            )
+        ]
+    offset = start[0] - other_start[0], start[1] - other_start[1]
+    # projection of the point onto the other segment's line has scale
+    # `(projection_numerator + projection_slope * t) / squared_length`
+    projection_numerator = _dot_multiply_vectors(offset, other_direction)
+    projection_slope = _dot_multiply_vectors(direction, other_direction)
+    regions: list[tuple[hints.ScalarT, hints.ScalarT, int]]
+    if projection_slope == 0:
+        region_index = (
+            0
+            if projection_numerator <= 0
+            else (1 if projection_numerator < other_squared_length else 2)
+        )
+        regions = [(zero, one, region_index)]
+    else:
+        start_crossing = -projection_numerator / projection_slope
+        end_crossing = (
+            other_squared_length - projection_numerator
+        ) / projection_slope
+        first_crossing, second_crossing, regions_indices = (
+            (start_crossing, end_crossing, (0, 1, 2))
+            if projection_slope > 0
+            else (end_crossing, start_crossing, (2, 1, 0))
+        )
+        first_crossing = min(max(first_crossing, zero), one)
+        second_crossing = min(max(second_crossing, zero), one)
+        regions = [
+            (zero, first_crossing, regions_indices[0]),
+            (first_crossing, second_crossing, regions_indices[1]),
+            (second_crossing, one, regions_indices[2]),
+        ]
+    result: _Envelope[hints.ScalarT] = []
+    for region_start, region_end, region_index in regions:
+        if region_start >= region_end:
+            continue
+        result.append(
+            (
+                context.quadratic_surd_cls(region_start, zero, zero),
+                context.quadratic_surd_cls(region_end, zero, zero),
+                len(pieces),
+            )
+        )
+        if region_index == 0:
+            pieces.append(_to_point_piece(start, direction, other_start))
+        elif region_index == 1:
+            cross_offset = _cross_multiply_vectors(other_direction, offset)
+            cross_slope = _cross_multiply_vectors(other_direction, direction)
+            scale_start = projection_numerator / other_squared_length
+            scale_slope = projection_slope / other_squared_length
+            pieces.append(
+                (
+                    cross_slope * cross_slope / other_squared_length,
+                    2 * cross_offset * cross_slope / other_squared_length,
+                    cross_offset * cross_offset / other_squared_length,
+                    (
+                        other_start[0] + other_direction[0] * scale_start,
+                        other_start[1] + other_direction[1] * scale_start,
+                    ),
+                    (
+                        other_direction[0] * scale_slope,
+                        other_direction[1] * scale_slope,
+                    ),
+                )
+            )
+        else:
+            pieces.append(_to_point_piece(start, direction, other_end))
+    return result
+
+
+def _to_segments_nearest_points(
+    first_start: hints.Point[hints.ScalarT],
+    first_end: hints.Point[hints.ScalarT],
+    second_start: hints.Point[hints.ScalarT],
+    second_end: hints.Point[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> _PointsPair[hints.ScalarT]:
+    if _do_segments_cross(
+        first_start, first_end, second_start, second_end, context=context
+    ):
+        cross_point = context.intersect_segments(
+            first_start, first_end, second_start, second_end
+        )
+        return cross_point, cross_point
+    return min(
+        [
+            (
+                first_start,
+                _to_point_segment_nearest_point(
+                    first_start, second_start, second_end, context=context
+                ),
+            ),
+            (
+                first_end,
+                _to_point_segment_nearest_point(
+                    first_end, second_start, second_end, context=context
+                ),
+            ),
+            (
+                _to_point_segment_nearest_point(
+                    second_start, first_start, first_end, context=context
+                ),
+                second_start,
+            ),
+            (
+                _to_point_segment_nearest_point(
+                    second_end, first_start, first_end, context=context
+                ),
+                second_end,
+            ),
+        ],
+        key=_to_points_pair_squared_distance,
+    )
+
+
+def _to_segments_squared_distance(
+    first_start: hints.Point[hints.ScalarT],
+    first_end: hints.Point[hints.ScalarT],
+    second_start: hints.Point[hints.ScalarT],
+    second_end: hints.Point[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.ScalarT:
+    if _do_segments_cross(
+        first_start, first_end, second_start, second_end, context=context
+    ):
+        return _to_zero(first_start)
+    return min(
+        _to_point_segment_squared_distance(
+            first_start, second_start, second_end
+        ),
+        _to_point_segment_squared_distance(
+            first_end, second_start, second_end
+        ),
+        _to_point_segment_squared_distance(
+            second_start, first_start, first_end
+        ),
+        _to_point_segment_squared_distance(
+            second_end, first_start, first_end
+        ),
+    )
+
+
+def _to_sign(value: hints.ScalarT, /) -> int:
+    return (value > 0) - (value < 0)
+
+
+def _to_squared_distance(
+    first: _Metrizable[hints.ScalarT],
+    second: _Metrizable[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.ScalarT:
+    if is_multisegmental(first, context=context):
+        return min(
+            _to_squared_distance(segment, second, context=context)
+            for segment in first.segments
+        )
+    if is_multipolygon(first, context=context):
+        return min(
+            _to_squared_distance(polygon, second, context=context)
+            for polygon in first.polygons
+        )
+    if is_polygon(first, context=context):
+        if is_polygon(second, context=context):
+            return _to_polygons_squared_distance(
+                first, second, context=context
+            )
+        if is_segment(second, context=context) or isinstance(
+            second, context.point_cls
+        ):
+            if (
+                first.locate(
+                    second.start
+                    if is_segment(second, context=context)
+                    else second
+                )
+                is not Location.EXTERIOR
+            ):
+                return _to_zero(first.border.vertices[0])
+            return min(
+                _to_squared_distance(segment, second, context=context)
+                for segment in _to_polygon_boundary_segments(first)
+            )
+    elif is_segment(first, context=context):
+        if is_segment(second, context=context):
+            return _to_segments_squared_distance(
+                first.start,
+                first.end,
+                second.start,
+                second.end,
+                context=context,
+            )
+        if isinstance(second, context.point_cls):
+            return _to_point_segment_squared_distance(
+                second, first.start, first.end
+            )
+    elif isinstance(second, context.point_cls):
+        return _to_points_squared_distance(first, second)
+    return _to_squared_distance(second, first, context=context)
+
+
+def _to_zero(point: hints.Point[hints.ScalarT], /) -> hints.ScalarT:
+    # zero of the same type as coordinates
+    return point.x - point.x
@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+from rene import hints
+from rene._context import Context
+from rene._utils import shrink_collinear_vertices, to_arg_min
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
+from rene.enums import Orientation
+
+
+def normalize_contour(
+    contour: hints.Contour[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Contour[hints.ScalarT]:
+    vertices = list(contour.vertices)
+    min_vertex_index = to_arg_min(vertices)
+    vertices = vertices[min_vertex_index:] + vertices[:min_vertex_index]
+    if contour.orientation is Orientation.CLOCKWISE:
+        vertices[1:] = vertices[:0:-1]
+    shrunk_vertices = shrink_collinear_vertices(vertices, context.orient)
+    return context.contour_cls(
+        # degenerate contours are kept as is
+        vertices
+        if len(shrunk_vertices) < MIN_CONTOUR_VERTICES_COUNT
+        else shrunk_vertices
+    )
+
+
+def normalize_multipolygon(
+    multipolygon: hints.Multipolygon[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Multipolygon[hints.ScalarT]:
+    return context.multipolygon_cls(
+        sorted(
+            [
+                normalize_polygon(polygon, context=context)
+                for polygon in multipolygon.polygons
+            ],
+            key=_to_polygon_key,
+        )
+    )
+
+
+def normalize_multisegment(
+    multisegment: hints.Multisegment[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Multisegment[hints.ScalarT]:
+    return context.multisegment_cls(
+        [
+            context.segment_cls(start, end)
+            for start, end in sorted(
+                (
+                    (segment.start, segment.end)
+                    if segment.start < segment.end
+                    else (segment.end, segment.start)
+                )
+                for segment in multisegment.segments
+            )
+        ]
+    )
+
+
+def normalize_polygon(
+    polygon: hints.Polygon[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Polygon[hints.ScalarT]:
+    holes = []
+    for hole in polygon.holes:
+        vertices = list(normalize_contour(hole, context=context).vertices)
+        # normalized contours are counterclockwise
+        vertices[1:] = vertices[:0:-1]
+        holes.append(context.contour_cls(vertices))
+    return context.polygon_cls(
+        normalize_contour(polygon.border, context=context),
+        sorted(holes, key=_to_contour_key),
+    )
+
+
+def _to_contour_key(
+    contour: hints.Contour[hints.ScalarT], /
+) -> list[hints.Point[hints.ScalarT]]:
+    return list(contour.vertices)
+
+
+def _to_polygon_key(
+    polygon: hints.Polygon[hints.ScalarT], /
+) -> tuple[
+    list[hints.Point[hints.ScalarT]], list[list[hints.Point[hints.ScalarT]]]
+]:
+    return _to_contour_key(polygon.border), [
+        _to_contour_key(hole) for hole in polygon.holes
+    ]
@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+import enum
+
+from rene.enums import Location
+
+
+class Dimension(enum.IntEnum):
+    EMPTY = -1
+    ZERO = 0
+    ONE = 1
+    TWO = 2
+
+
+class IntersectionMatrix:
+    def include(
+        self, first: Location, second: Location, dimension: Dimension, /
+    ) -> None:
+        index = _to_entry_index(first, second)
+        if self._entries[index] < dimension:
+            self._entries[index] = dimension
+
+    def matches(self, pattern: str, /) -> bool:
+        if len(pattern) != len(self._entries):
+            raise ValueError(
+                'Pattern should have 9 characters, '
+                f'but got {len(pattern)}.'
+            )
+        result = True
+        for position, (character, dimension) in enumerate(
+            zip(pattern, self._entries)
+        ):
+            if character == '*':
+                entry_matches = True
+            elif character == 'T':
+                entry_matches = dimension is not Dimension.EMPTY
+            elif character == 'F':
+                entry_matches = dimension is Dimension.EMPTY
+            elif character in ('0', '1', '2'):
+                entry_matches = dimension == int(character)
+            else:
+                raise ValueError(
+                    f'Invalid pattern character at position {position}.'
+                )
+            result = result and entry_matches
+        return result
+
+    def to_transposed(self, /) -> IntersectionMatrix:
+        result = IntersectionMatrix()
+        result._entries = [
+            self._entries[(index % 3) * 3 + index // 3] for index in range(9)
+        ]
+        return result
+
+    __slots__ = ('_entries',)
+
+    def __init__(self, /) -> None:
+        self._entries = [Dimension.EMPTY] * 9
+        self.include(Location.EXTERIOR, Location.EXTERIOR, Dimension.TWO)
+
+    def __str__(self, /) -> str:
+        return ''.join(
+            'F' if dimension is Dimension.EMPTY else str(int(dimension))
+            for dimension in self._entries
+        )
+
+
+def _to_entry_index(first: Location, second: Location, /) -> int:
+    return _to_location_index(first) * 3 + _to_location_index(second)
+
+
+def _to_location_index(location: Location, /) -> int:
+    return (
+        0
+        if location is Location.INTERIOR
+        else (1 if location is Location.BOUNDARY else 2)
+    )
@@ -9,11 +9,13 @@ from typing_extensions import Self
 
 from rene import hints
 from rene._utils import all_same, is_even, square, to_sorted_pair
-from rene.enums import Orientation, Relation
+from rene.enums import Location, Orientation, Relation
 
 from .event import Event, is_event_left, is_event_right, left_event_to_position
 from .events_queue_key import EventsQueueKey
+from .intersection_matrix import Dimension, IntersectionMatrix
 from .sweep_line_key import SweepLineKey
+from .utils import to_linear_location
 
 if TYPE_CHECKING:
     from collections.abc import Iterable, Iterator, Sequence
@@ -112,6 +114,24 @@ class Operation(Generic[hints.ScalarT]):
     def to_event_start(self, event: Event, /) -> hints.Point[hints.ScalarT]:
         return self.endpoints[event]
 
+    def to_matrix(self, /) -> IntersectionMatrix:
+        result = IntersectionMatrix()
+        event = self._pop()
+        previous_start = self.to_event_start(event)
+        same_start_events = [event]
+        self._process_event(event)
+        while self:
+            event = self._pop()
+            start = self.to_event_start(event)
+            if start != previous_start:
+                self._update_matrix(same_start_events, result)
+                same_start_events.clear()
+                previous_start = start
+            same_start_events.append(event)
+            self._process_event(event)
+        self._update_matrix(same_start_events, result)
+        return result
+
     def to_relation(
         self,
         /,
@@ -477,6 +497,47 @@ class Operation(Generic[hints.ScalarT]):
             orienteer=self._orienteer,
         )
 
+    def _update_matrix(
+        self,
+        same_start_events: Sequence[Event],
+        matrix: IntersectionMatrix,
+        /,
+    ) -> None:
+        assert same_start_events
+        from_first_operand_events_count = sum(
+            self.is_event_from_first_operand(event)
+            for event in same_start_events
+        )
+        matrix.include(
+            to_linear_location(from_first_operand_events_count),
+            to_linear_location(
+                len(same_start_events) - from_first_operand_events_count
+            ),
+            Dimension.ZERO,
+        )
+        left_events = [
+            event for event in same_start_events if is_event_left(event)
+        ]
+        index = 0
+        while index < len(left_events):
+            event = left_events[index]
+            if index + 1 < len(left_events) and self.to_event_end(
+                left_events[index + 1]
+            ) == self.to_event_end(event):
+                index += 1
+                matrix.include(
+                    Location.INTERIOR, Location.INTERIOR, Dimension.ONE
+                )
+            elif self.is_event_from_first_operand(event):
+                matrix.include(
+                    Location.INTERIOR, Location.EXTERIOR, Dimension.ONE
+                )
+            else:
+                matrix.include(
+                    Location.EXTERIOR, Location.INTERIOR, Dimension.ONE
+                )
+            index += 1
+
     def to_signed_point_event_squared_cosine(
         self, point: hints.Point[hints.ScalarT], event: Event
     ) -> hints.ScalarT:
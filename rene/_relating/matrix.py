@@ -0,0 +1,154 @@
+from __future__ import annotations
+
+from collections.abc import Sequence
+from itertools import chain, groupby
+from typing import Any
+
+from rene import hints
+from rene._context import Context
+from rene._geometries.utils import (
+    is_empty,
+    is_multipolygon,
+    is_multisegmental,
+    is_polygon,
+    is_segment,
+)
+from rene._utils import is_even
+from rene.enums import Location
+
+from . import linear, mixed, shaped
+from .intersection_matrix import Dimension, IntersectionMatrix
+
+
+def relate_matrix(
+    first: hints.Compound[hints.ScalarT],
+    second: Any,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> IntersectionMatrix:
+    second_dimension, second_segments = _to_dimension_with_segments(
+        second, context=context
+    )
+    first_dimension, first_segments = _to_dimension_with_segments(
+        first, context=context
+    )
+    if second_dimension is Dimension.EMPTY:
+        return relate_to_empty(first_dimension, first_segments)
+    if first_dimension is Dimension.EMPTY:
+        return relate_to_empty(
+            second_dimension, second_segments
+        ).to_transposed()
+    return relate_segments(
+        first_dimension,
+        first_segments,
+        second_dimension,
+        second_segments,
+        context=context,
+    )
+
+
+def relate_segments(
+    first_dimension: Dimension,
+    first_segments: Sequence[hints.Segment[hints.ScalarT]],
+    second_dimension: Dimension,
+    second_segments: Sequence[hints.Segment[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> IntersectionMatrix:
+    assert first_segments
+    assert second_segments
+    operation_cls: Any
+    if first_dimension is Dimension.ONE:
+        operation_cls = (
+            linear.Operation
+            if second_dimension is Dimension.ONE
+            else mixed.LinearShapedOperation
+        )
+    else:
+        assert first_dimension is Dimension.TWO, first_dimension
+        operation_cls = (
+            mixed.ShapedLinearOperation
+            if second_dimension is Dimension.ONE
+            else shaped.Operation
+        )
+    return operation_cls.from_segments_iterables(
+        first_segments,
+        second_segments,
+        context.orient,
+        context.intersect_segments,
+    ).to_matrix()
+
+
+def relate_to_empty(
+    dimension: Dimension,
+    segments: Sequence[hints.Segment[hints.ScalarT]],
+    /,
+) -> IntersectionMatrix:
+    result = IntersectionMatrix()
+    result.include(Location.INTERIOR, Location.EXTERIOR, dimension)
+    result.include(
+        Location.BOUNDARY,
+        Location.EXTERIOR,
+        (
+            (
+                Dimension.ZERO
+                if _has_odd_endpoint(segments)
+                else Dimension.EMPTY
+            )
+            if dimension is Dimension.ONE
+            else (
+                Dimension.ONE
+                if dimension is Dimension.TWO
+                else Dimension.EMPTY
+            )
+        ),
+    )
+    return result
+
+
+def _has_odd_endpoint(
+    segments: Sequence[hints.Segment[hints.ScalarT]], /
+) -> bool:
+    return any(
+        not is_even(sum(1 for _ in same_endpoints))
+        for _, same_endpoints in groupby(
+            sorted(
+                chain.from_iterable(
+                    (segment.start, segment.end) for segment in segments
+                )
+            )
+        )
+    )
+
+
+def _to_dimension_with_segments(
+    geometry: Any, /, *, context: Context[hints.ScalarT]
+) -> tuple[Dimension, list[hints.Segment[hints.ScalarT]]]:
+    if is_empty(geometry, context=context):
+        return Dimension.EMPTY, []
+    if is_segment(geometry, context=context):
+        return Dimension.ONE, [geometry]
+    if is_multisegmental(geometry, context=context):
+        return Dimension.ONE, list(geometry.segments)
+    if is_polygon(geometry, context=context):
+        return Dimension.TWO, _to_polygon_boundary_segments(geometry)
+    if is_multipolygon(geometry, context=context):
+        return Dimension.TWO, [
+            segment
+            for polygon in geometry.polygons
+            for segment in _to_polygon_boundary_segments(polygon)
+        ]
+    raise TypeError(
+        f'Expected compound geometry, but got {type(geometry)!r}.'
+    )
+
+
+def _to_polygon_boundary_segments(
+    polygon: hints.Polygon[hints.ScalarT], /
+) -> list[hints.Segment[hints.ScalarT]]:
+    return [
+        *polygon.border.segments,
+        *chain.from_iterable(hole.segments for hole in polygon.holes),
+    ]
@@ -10,11 +10,13 @@ from typing_extensions import Self
 
 from rene import hints
 from rene._utils import all_same, is_even
-from rene.enums import Orientation, Relation
+from rene.enums import Location, Orientation, Relation
 
 from .event import Event, is_event_left, is_event_right, left_event_to_position
 from .events_queue_key import EventsQueueKey
+from .intersection_matrix import Dimension, IntersectionMatrix
 from .sweep_line_key import SweepLineKey
+from .utils import to_linear_location
 
 if TYPE_CHECKING:
     from collections.abc import Iterable, Sequence
@@ -71,6 +73,27 @@ class Operation(ABC, Generic[hints.ScalarT]):
     def to_event_start(self, event: Event, /) -> hints.Point[hints.ScalarT]:
         return self.endpoints[event]
 
+    def to_matrix(self, /) -> IntersectionMatrix:
+        result = IntersectionMatrix()
+        result.include(
+            Location.EXTERIOR, Location.INTERIOR, Dimension.TWO
+        )
+        event = self._pop()
+        previous_start = self.to_event_start(event)
+        same_start_events = [event]
+        self._process_event(event)
+        while self:
+            event = self._pop()
+            start = self.to_event_start(event)
+            if start != previous_start:
+                self._update_matrix(same_start_events, result)
+                same_start_events.clear()
+                previous_start = start
+            same_start_events.append(event)
+            self._process_event(event)
+        self._update_matrix(same_start_events, result)
+        return result
+
     def to_relation(
         self, /, *, linear_is_subset_of_shaped: bool, min_max_x: hints.ScalarT
     ) -> Relation:
@@ -473,7 +496,7 @@ class Operation(ABC, Generic[hints.ScalarT]):
 
     def _remove(self, event: Event, /) -> None:
         assert is_event_left(event)
-        self._sweep_line_data[self._to_sweep_line_key(event)] = event
+        del self._sweep_line_data[self._to_sweep_line_key(event)]
 
     def _to_left_event(self, event: Event, /) -> Event:
         return (
@@ -496,6 +519,59 @@ class Operation(ABC, Generic[hints.ScalarT]):
             orienteer=self._orienteer,
         )
 
+    def _update_matrix(
+        self,
+        same_start_events: Sequence[Event],
+        matrix: IntersectionMatrix,
+        /,
+    ) -> None:
+        assert same_start_events
+        linear_events_count = sum(
+            self.is_event_from_linear(event) for event in same_start_events
+        )
+        shaped_location = (
+            Location.BOUNDARY
+            if linear_events_count < len(same_start_events)
+            else (
+                Location.INTERIOR
+                if self.is_event_inside(same_start_events[0])
+                else Location.EXTERIOR
+            )
+        )
+        matrix.include(
+            to_linear_location(linear_events_count),
+            shaped_location,
+            Dimension.ZERO,
+        )
+        left_events = [
+            event for event in same_start_events if is_event_left(event)
+        ]
+        index = 0
+        while index < len(left_events):
+            event = left_events[index]
+            if index + 1 < len(left_events) and self.to_event_end(
+                left_events[index + 1]
+            ) == self.to_event_end(event):
+                index += 1
+                matrix.include(
+                    Location.INTERIOR, Location.BOUNDARY, Dimension.ONE
+                )
+            elif self.is_event_from_linear(event):
+                matrix.include(
+                    Location.INTERIOR,
+                    (
+                        Location.INTERIOR
+                        if self.is_event_inside(event)
+                        else Location.EXTERIOR
+                    ),
+                    Dimension.ONE,
+                )
+            else:
+                matrix.include(
+                    Location.EXTERIOR, Location.BOUNDARY, Dimension.ONE
+                )
+            index += 1
+
 
 class LinearShapedOperation(Operation[hints.ScalarT]):
     @classmethod
@@ -564,6 +640,9 @@ class ShapedLinearOperation(Operation[hints.ScalarT]):
             self._to_left_event(event)
         )
 
+    def to_matrix(self, /) -> IntersectionMatrix:
+        return super().to_matrix().to_transposed()
+
 
 class RelationState(Generic[hints.ScalarT]):
     def update(
@@ -9,7 +9,7 @@ from typing_extensions import Self
 
 from rene import hints
 from rene._utils import all_same, is_even
-from rene.enums import Orientation, Relation
+from rene.enums import Location, Orientation, Relation
 
 from .event import (
     Event,
@@ -19,6 +19,7 @@ from .event import (
     left_event_to_position,
 )
 from .events_queue_key import EventsQueueKey
+from .intersection_matrix import Dimension, IntersectionMatrix
 from .sweep_line_key import SweepLineKey
 
 if TYPE_CHECKING:
@@ -100,6 +101,24 @@ class Operation(Generic[hints.ScalarT]):
     def to_event_start(self, event: Event, /) -> hints.Point[hints.ScalarT]:
         return self.endpoints[event]
 
+    def to_matrix(self, /) -> IntersectionMatrix:
+        result = IntersectionMatrix()
+        event = self._pop()
+        previous_start = self.to_event_start(event)
+        same_start_events = [event]
+        self._process_event(event)
+        while self:
+            event = self._pop()
+            start = self.to_event_start(event)
+            if start != previous_start:
+                self._update_matrix(same_start_events, result)
+                same_start_events.clear()
+                previous_start = start
+            same_start_events.append(event)
+            self._process_event(event)
+        self._update_matrix(same_start_events, result)
+        return result
+
     def to_relation(
         self,
         /,
@@ -545,6 +564,32 @@ class Operation(Generic[hints.ScalarT]):
             orienteer=self._orienteer,
         )
 
+    def _update_matrix(
+        self,
+        same_start_events: Sequence[Event],
+        matrix: IntersectionMatrix,
+        /,
+    ) -> None:
+        assert same_start_events
+        if not all_same(
+            self.is_event_from_first_operand(event)
+            for event in same_start_events
+        ):
+            matrix.include(
+                Location.BOUNDARY, Location.BOUNDARY, Dimension.ZERO
+            )
+        for event in same_start_events:
+            if not is_event_left(event):
+                continue
+            is_from_first_operand = self.is_event_from_first_operand(event)
+            for location, other_location, dimension in _EVENT_KINDS_ENTRIES[
+                self.classify_event(event)
+            ]:
+                if is_from_first_operand:
+                    matrix.include(location, other_location, dimension)
+                else:
+                    matrix.include(other_location, location, dimension)
+
 
 class EventKind(enum.IntEnum):
     COMMON_POLYLINE_SEGMENT = enum.auto()
@@ -559,6 +604,32 @@ class OverlapKind(enum.IntEnum):
     DIFFERENT_ORIENTATION = 2
 
 
+_EVENT_KINDS_ENTRIES: dict[
+    EventKind, tuple[tuple[Location, Location, Dimension], ...]
+] = {
+    EventKind.COMMON_POLYLINE_SEGMENT: (
+        (Location.BOUNDARY, Location.BOUNDARY, Dimension.ONE),
+        (Location.INTERIOR, Location.EXTERIOR, Dimension.TWO),
+        (Location.EXTERIOR, Location.INTERIOR, Dimension.TWO),
+    ),
+    EventKind.COMMON_REGION_EDGE: (
+        (Location.BOUNDARY, Location.BOUNDARY, Dimension.ONE),
+        (Location.INTERIOR, Location.INTERIOR, Dimension.TWO),
+        (Location.EXTERIOR, Location.EXTERIOR, Dimension.TWO),
+    ),
+    EventKind.INSIDE: (
+        (Location.BOUNDARY, Location.INTERIOR, Dimension.ONE),
+        (Location.INTERIOR, Location.INTERIOR, Dimension.TWO),
+        (Location.EXTERIOR, Location.INTERIOR, Dimension.TWO),
+    ),
+    EventKind.OUTSIDE: (
+        (Location.BOUNDARY, Location.EXTERIOR, Dimension.ONE),
+        (Location.INTERIOR, Location.EXTERIOR, Dimension.TWO),
+        (Location.EXTERIOR, Location.EXTERIOR, Dimension.TWO),
+    ),
+}
+
+
 class RelationState(Generic[hints.ScalarT]):
     def update(
         self,
@@ -3,6 +3,9 @@ from __future__ import annotations
 import typing as t
 from itertools import chain
 
+from rene._utils import is_even
+from rene.enums import Location
+
 if t.TYPE_CHECKING:
     from rene import hints
 
@@ -20,3 +23,15 @@ def polygon_to_segments(
             if not hole.bounding_box.disjoint_with(bounding_box)
         ),
     )
+
+
+def to_linear_location(events_count: int, /) -> Location:
+    return (
+        Location.EXTERIOR
+        if events_count == 0
+        else (
+            Location.INTERIOR
+            if is_even(events_count)
+            else Location.BOUNDARY
+        )
+    )
@@ -0,0 +1,394 @@
+from __future__ import annotations
+
+from collections.abc import Iterable, Sequence
+from functools import cmp_to_key
+from typing import TYPE_CHECKING, Generic
+
+from dendroid import red_black
+from typing_extensions import Self
+
+from rene import hints
+from rene._bentley_ottmann.base import split_segments
+from rene._context import Context
+from rene._utils import (
+    collect_maybe_empty_polygons,
+    cross_multiply,
+    locate_point_in_region,
+    shrink_collinear_vertices,
+    to_arg_min,
+)
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
+from rene.enums import FillRule, Location, Orientation
+
+if TYPE_CHECKING:
+    from dendroid.hints import Map
+
+    from rene._hints import Orienteer
+
+_Edge = tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]
+_Piece = tuple[_Edge[hints.ScalarT], int]
+
+
+def make_valid(
+    contours: Iterable[hints.Contour[hints.ScalarT]],
+    fill_rule: FillRule,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> (
+    hints.Empty[hints.ScalarT]
+    | hints.Multipolygon[hints.ScalarT]
+    | hints.Polygon[hints.ScalarT]
+):
+    if not isinstance(fill_rule, FillRule):
+        raise TypeError(f'Expected fill rule, but got {fill_rule!r}.')
+    return collect_maybe_empty_polygons(
+        _to_valid_polygons(contours, fill_rule, context=context),
+        context.empty_cls,
+        context.multipolygon_cls,
+    )
+
+
+def _to_valid_polygons(
+    contours: Iterable[hints.Contour[hints.ScalarT]],
+    fill_rule: FillRule,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[hints.Polygon[hints.ScalarT]]:
+    segments = [
+        segment
+        for contour in contours
+        for segment in contour.segments
+        if segment.start != segment.end
+    ]
+    pieces_windings: dict[_Edge[hints.ScalarT], int] = {}
+    for points in split_segments(
+        segments,
+        orienteer=context.orient,
+        segments_intersector=context.intersect_segments,
+    ):
+        for start, end in zip(points, points[1:]):
+            if start < end:
+                key, winding = (start, end), 1
+            else:
+                key, winding = (end, start), -1
+            pieces_windings[key] = pieces_windings.get(key, 0) + winding
+    pieces = [
+        (key, winding)
+        for key, winding in sorted(pieces_windings.items())
+        if winding != 0
+    ]
+
+    def is_inside(winding: int, /) -> bool:
+        return (
+            winding % 2 != 0
+            if fill_rule is FillRule.EVEN_ODD
+            else winding != 0
+        )
+
+    edges: list[_Edge[hints.ScalarT]] = []
+    for ((start, end), winding), left_winding in zip(
+        pieces, _to_left_windings(pieces, context=context)
+    ):
+        is_left_inside = is_inside(left_winding)
+        if is_left_inside is not is_inside(left_winding - winding):
+            edges.append((start, end) if is_left_inside else (end, start))
+    return _edges_to_polygons(edges, context=context)
+
+
+def _compare_clockwise_angles(
+    vertex: hints.Point[hints.ScalarT],
+    reference: hints.Point[hints.ScalarT],
+    first_point: hints.Point[hints.ScalarT],
+    second_point: hints.Point[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> int:
+    # rays opposite to the reference one go first in the second half
+    first_half = (
+        context.orient(vertex, reference, first_point)
+        is not Orientation.CLOCKWISE
+    )
+    second_half = (
+        context.orient(vertex, reference, second_point)
+        is not Orientation.CLOCKWISE
+    )
+    if first_half is not second_half:
+        return 1 if first_half else -1
+    orientation = context.orient(vertex, first_point, second_point)
+    return (
+        -1
+        if orientation is Orientation.CLOCKWISE
+        else (0 if orientation is Orientation.COLLINEAR else 1)
+    )
+
+
+def _edges_to_cycles(
+    edges: Sequence[_Edge[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[list[int]]:
+    outgoing_edges: dict[hints.Point[hints.ScalarT], list[int]] = {}
+    for edge, (start, _) in enumerate(edges):
+        outgoing_edges.setdefault(start, []).append(edge)
+    are_edges_used = [False] * len(edges)
+    result = []
+    for first_edge in range(len(edges)):
+        if are_edges_used[first_edge]:
+            continue
+        path: list[int] = []
+        starts_positions: dict[hints.Point[hints.ScalarT], int] = {}
+        edge = first_edge
+        while True:
+            are_edges_used[edge] = True
+            start, end = edges[edge]
+            starts_positions[start] = len(path)
+            path.append(edge)
+            position = starts_positions.get(end)
+            if position is not None:
+                cycle = path[position:]
+                del path[position:]
+                for cycle_edge in cycle:
+                    del starts_positions[edges[cycle_edge][0]]
+                result.append(cycle)
+                if not path:
+                    break
+            next_edge = None
+            for candidate in outgoing_edges[end]:
+                if are_edges_used[candidate]:
+                    continue
+                if (
+                    next_edge is None
+                    or _compare_clockwise_angles(
+                        end,
+                        start,
+                        edges[candidate][1],
+                        edges[next_edge][1],
+                        context=context,
+                    )
+                    < 0
+                ):
+                    next_edge = candidate
+            assert next_edge is not None
+            edge = next_edge
+    return result
+
+
+def _edges_to_polygons(
+    edges: Sequence[_Edge[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[hints.Polygon[hints.ScalarT]]:
+    borders: list[tuple[hints.Contour[hints.ScalarT], hints.ScalarT]] = []
+    holes: list[
+        tuple[hints.Contour[hints.ScalarT], hints.Point[hints.ScalarT]]
+    ] = []
+    for cycle in _edges_to_cycles(edges, context=context):
+        vertices = [edges[edge][0] for edge in cycle]
+        min_vertex_index = to_arg_min(vertices)
+        # minimal vertex is never collinear with its neighbours
+        vertices = vertices[min_vertex_index:] + vertices[:min_vertex_index]
+        vertices = shrink_collinear_vertices(vertices, context.orient)
+        if (
+            context.orient(vertices[-1], vertices[0], vertices[1])
+            is Orientation.COUNTERCLOCKWISE
+        ):
+            borders.append(
+                (context.contour_cls(vertices), _to_doubled_area(vertices))
+            )
+        else:
+            start, end = edges[cycle[0]]
+            holes.append(
+                (
+                    context.contour_cls(vertices),
+                    _to_midpoint(start, end, context=context),
+                )
+            )
+    borders_holes: list[list[hints.Contour[hints.ScalarT]]] = [
+        [] for _ in borders
+    ]
+    for hole, hole_point in holes:
+        # hole point lies on the hole only, so the innermost border
+        # which contains it in its interior is the hole's one
+        border_index = None
+        for index, (border, area) in enumerate(borders):
+            if locate_point_in_region(
+                border, hole_point, context.orient
+            ) is Location.INTERIOR and (
+                border_index is None or area < borders[border_index][1]
+            ):
+                border_index = index
+        assert border_index is not None
+        borders_holes[border_index].append(hole)
+    return [
+        context.polygon_cls(border, border_holes)
+        for (border, _), border_holes in zip(borders, borders_holes)
+    ]
+
+
+def _to_doubled_area(
+    vertices: Sequence[hints.Point[hints.ScalarT]], /
+) -> hints.ScalarT:
+    assert len(vertices) >= MIN_CONTOUR_VERTICES_COUNT
+    first_vertex = vertices[0]
+    result = cross_multiply(
+        first_vertex, vertices[1], first_vertex, vertices[2]
+    )
+    for index in range(3, len(vertices)):
+        result += cross_multiply(
+            first_vertex, vertices[index - 1], first_vertex, vertices[index]
+        )
+    return result
+
+
+def _to_left_windings(
+    pieces: Sequence[_Piece[hints.ScalarT]],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[int]:
+    # sweeping pieces from left to right keeping windings of regions
+    # right above pieces crossing the sweep line,
+    # so the region below a piece is the one above its predecessor
+    ends_indices = sorted(
+        range(len(pieces)), key=lambda index: pieces[index][0][1]
+    )
+    result = [0] * len(pieces)
+    sweep_line: Map[_SweepLineKey[hints.ScalarT], int] = red_black.map_()
+    starts_position = ends_position = 0
+    while starts_position < len(pieces):
+        point = pieces[starts_position][0][0]
+        while ends_position < len(ends_indices):
+            (start, end), _ = pieces[ends_indices[ends_position]]
+            if end > point:
+                break
+            if start.x != end.x:
+                del sweep_line[_SweepLineKey(start, end, context.orient)]
+            ends_position += 1
+        next_starts_position = starts_position
+        while (
+            next_starts_position < len(pieces)
+            and pieces[next_starts_position][0][0] == point
+        ):
+            next_starts_position += 1
+        vertical_index = None
+        indices = []
+        for index in range(starts_position, next_starts_position):
+            if pieces[index][0][1].x == point.x:
+                vertical_index = index
+            else:
+                indices.append(index)
+        # inserting from bottom to top, so each piece
+        # is inserted right above the one it is adjacent to
+        indices.sort(
+            key=cmp_to_key(
+                lambda first_index, second_index: _orientation_to_sign(
+                    context.orient(
+                        point,
+                        pieces[second_index][0][1],
+                        pieces[first_index][0][1],
+                    )
+                )
+            )
+        )
+        for index in indices:
+            (start, end), winding = pieces[index]
+            key = _SweepLineKey(start, end, context.orient)
+            sweep_line[key] = 0
+            try:
+                below_winding = sweep_line.prev(key)
+            except KeyError:
+                below_winding = 0
+            sweep_line[key] = result[index] = below_winding + winding
+        if vertical_index is not None:
+            # region to the right of the vertical piece
+            # is the one right above its start
+            key = _SweepLineKey(point, point, context.orient)
+            sweep_line[key] = 0
+            try:
+                right_winding = sweep_line.prev(key)
+            except KeyError:
+                right_winding = 0
+            del sweep_line[key]
+            result[vertical_index] = right_winding + pieces[vertical_index][1]
+        starts_position = next_starts_position
+    return result
+
+
+def _orientation_to_sign(orientation: Orientation, /) -> int:
+    return (
+        -1
+        if orientation is Orientation.CLOCKWISE
+        else (0 if orientation is Orientation.COLLINEAR else 1)
+    )
+
+
+class _SweepLineKey(Generic[hints.ScalarT]):
+    """
+    Piece crossing the sweep line ordered from bottom to top,
+    degenerate one stands for the point right above its location.
+    """
+
+    end: hints.Point[hints.ScalarT]
+    start: hints.Point[hints.ScalarT]
+    _orienteer: Orienteer[hints.ScalarT]
+
+    __slots__ = '_orienteer', 'end', 'start'
+
+    def __init__(
+        self,
+        start: hints.Point[hints.ScalarT],
+        end: hints.Point[hints.ScalarT],
+        orienteer: Orienteer[hints.ScalarT],
+        /,
+    ) -> None:
+        self.end, self.start, self._orienteer = end, start, orienteer
+
+    def __lt__(self, other: Self, /) -> bool:
+        start, end = self.start, self.end
+        other_start, other_end = other.start, other.end
+        if start == other_start and end == other_end:
+            return False
+        if start == end:
+            return (
+                self._orienteer(other_start, other_end, start)
+                is Orientation.CLOCKWISE
+            )
+        if other_start == other_end:
+            return (
+                self._orienteer(start, end, other_start)
+                is not Orientation.CLOCKWISE
+            )
+        start_orientation = self._orienteer(start, end, other_start)
+        end_orientation = self._orienteer(start, end, other_end)
+        if start_orientation is Orientation.COLLINEAR:
+            other_orientation = end_orientation
+        elif (
+            end_orientation is Orientation.COLLINEAR
+            or start_orientation is end_orientation
+        ):
+            other_orientation = start_orientation
+        else:
+            # pieces do not cross, so the piece lies
+            # on one side of the line through the other one
+            orientation = self._orienteer(other_start, other_end, start)
+            if orientation is Orientation.COLLINEAR:
+                orientation = self._orienteer(other_start, other_end, end)
+            return orientation is Orientation.CLOCKWISE
+        if other_orientation is Orientation.COLLINEAR:
+            return (start, end) < (other_start, other_end)
+        return other_orientation is Orientation.COUNTERCLOCKWISE
+
+
+def _to_midpoint(
+    start: hints.Point[hints.ScalarT],
+    end: hints.Point[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Point[hints.ScalarT]:
+    return context.point_cls((start.x + end.x) / 2, (start.y + end.y) / 2)
@@ -0,0 +1,322 @@
+from __future__ import annotations
+
+from collections.abc import Iterable, Sequence
+from decimal import Decimal
+from typing import Any
+
+from rithm.fraction import Fraction
+
+from rene import hints
+from rene._seidel.leaf import Leaf
+
+_Coordinates = tuple[float, float]
+_Polyline = tuple[list[_Coordinates], bool]
+_ViewBox = tuple[float, float, float, float]
+
+_DEFAULT_MARGIN = 1.0
+_FILL = '#66cc99'
+_FILL_OPACITY = 0.5
+_MARGIN_RATIO = 0.05
+_POINT_RADIUS = 0.01
+_SIZE = 300
+_STROKE = '#555555'
+_STROKE_WIDTH = 1.0
+
+
+def contour_to_svg(contour: hints.Contour[Fraction], /) -> str | None:
+    try:
+        canvas = _Canvas(_to_view_box(contour.bounding_box))
+        canvas.add_path(_to_path_data([_contour_to_polyline(contour)]), False)
+    except OverflowError:
+        return None
+    return canvas.finish()
+
+
+def empty_to_svg(_empty: hints.Empty[Fraction], /) -> str:
+    return _Canvas(None).finish()
+
+
+def multipolygon_to_svg(
+    multipolygon: hints.Multipolygon[Fraction], /
+) -> str | None:
+    try:
+        canvas = _Canvas(_to_view_box(multipolygon.bounding_box))
+        canvas.add_path(
+            _to_path_data(
+                [
+                    polyline
+                    for polygon in multipolygon.polygons
+                    for polyline in _polygon_to_polylines(polygon)
+                ]
+            ),
+            True,
+        )
+    except OverflowError:
+        return None
+    return canvas.finish()
+
+
+def multisegment_to_svg(
+    multisegment: hints.Multisegment[Fraction], /
+) -> str | None:
+    try:
+        canvas = _Canvas(_to_view_box(multisegment.bounding_box))
+        canvas.add_path(
+            _to_path_data(
+                [
+                    (
+                        [
+                            _to_coordinates(segment.start),
+                            _to_coordinates(segment.end),
+                        ],
+                        False,
+                    )
+                    for segment in multisegment.segments
+                ]
+            ),
+            False,
+        )
+    except OverflowError:
+        return None
+    return canvas.finish()
+
+
+def point_to_svg(point: hints.Point[Fraction], /) -> str | None:
+    try:
+        x, y = _to_coordinates(point)
+    except OverflowError:
+        return None
+    canvas = _Canvas((x, x, y, y))
+    canvas.add_point((x, y))
+    return canvas.finish()
+
+
+def polygon_to_svg(polygon: hints.Polygon[Fraction], /) -> str | None:
+    try:
+        canvas = _Canvas(_to_view_box(polygon.bounding_box))
+        canvas.add_path(_to_path_data(_polygon_to_polylines(polygon)), True)
+    except OverflowError:
+        return None
+    return canvas.finish()
+
+
+def segment_to_svg(segment: hints.Segment[Fraction], /) -> str | None:
+    try:
+        canvas = _Canvas(_to_view_box(segment.bounding_box))
+        canvas.add_path(
+            _to_path_data(
+                [
+                    (
+                        [
+                            _to_coordinates(segment.start),
+                            _to_coordinates(segment.end),
+                        ],
+                        False,
+                    )
+                ]
+            ),
+            False,
+        )
+    except OverflowError:
+        return None
+    return canvas.finish()
+
+
+def trapezoidation_to_svg(
+    edges: Sequence[Any],
+    endpoints: Sequence[hints.Point[Fraction]],
+    nodes: Iterable[Any],
+    /,
+) -> str | None:
+    try:
+        canvas = _Canvas(
+            _coordinates_to_view_box(
+                [_to_coordinates(endpoint) for endpoint in endpoints]
+            )
+        )
+        for node in nodes:
+            if not isinstance(node, Leaf):
+                continue
+            trapezoid = node.trapezoid
+            left_x = float(endpoints[trapezoid.left_point_index].x)
+            right_x = float(endpoints[trapezoid.right_point_index].x)
+            if left_x == right_x:
+                continue
+            below = _to_edge_coordinates(
+                edges[trapezoid.below_edge_index], endpoints
+            )
+            above = _to_edge_coordinates(
+                edges[trapezoid.above_edge_index], endpoints
+            )
+            canvas.add_path(
+                _to_path_data(
+                    [
+                        (
+                            [
+                                (left_x, _edge_y_at(below, left_x)),
+                                (right_x, _edge_y_at(below, right_x)),
+                                (right_x, _edge_y_at(above, right_x)),
+                                (left_x, _edge_y_at(above, left_x)),
+                            ],
+                            True,
+                        )
+                    ]
+                ),
+                trapezoid.is_component,
+            )
+    except OverflowError:
+        return None
+    return canvas.finish()
+
+
+def triangles_to_svg(
+    triangles_vertices: Iterable[Sequence[hints.Point[Fraction]]], /
+) -> str | None:
+    try:
+        polylines: list[_Polyline] = [
+            ([_to_coordinates(vertex) for vertex in vertices], True)
+            for vertices in triangles_vertices
+        ]
+    except OverflowError:
+        return None
+    canvas = _Canvas(
+        _coordinates_to_view_box(
+            [
+                coordinates
+                for vertices, _ in polylines
+                for coordinates in vertices
+            ]
+        )
+    )
+    if polylines:
+        canvas.add_path(_to_path_data(polylines), True)
+    return canvas.finish()
+
+
+class _Canvas:
+    def __init__(self, view_box: _ViewBox | None, /) -> None:
+        self._body: list[str] = []
+        self._view: tuple[float, float, float, float, float] | None
+        if view_box is None:
+            self._point_radius = 0.0
+            self._view = None
+        else:
+            min_x, max_x, min_y, max_y = view_box
+            width, height = max_x - min_x, max_y - min_y
+            size = max(width, height)
+            margin = size * _MARGIN_RATIO if size > 0.0 else _DEFAULT_MARGIN
+            view_width, view_height = (
+                width + 2.0 * margin,
+                height + 2.0 * margin,
+            )
+            self._point_radius = _POINT_RADIUS * max(view_width, view_height)
+            self._view = (
+                min_x - margin,
+                min_y - margin,
+                view_width,
+                view_height,
+                min_y + max_y,
+            )
+
+    def add_path(self, data: str, is_filled: bool, /) -> None:
+        self._body.append(
+            f'<path d="{data}"'
+            + (' fill-rule="evenodd"' if is_filled else ' fill="none"')
+            + ' vector-effect="non-scaling-stroke"/>'
+        )
+
+    def add_point(self, coordinates: _Coordinates, /) -> None:
+        x, y = coordinates
+        self._body.append(
+            f'<circle cx="{_format(x)}" cy="{_format(y)}"'
+            f' r="{_format(self._point_radius)}" stroke="none"/>'
+        )
+
+    def finish(self, /) -> str:
+        if self._view is None:
+            view_box, flip = '0 0 1 1', 0.0
+        else:
+            min_x, min_y, width, height, flip = self._view
+            view_box = ' '.join(map(_format, (min_x, min_y, width, height)))
+        return (
+            '<svg xmlns="http://www.w3.org/2000/svg"'
+            f' viewBox="{view_box}" width="{_SIZE}" height="{_SIZE}">'
+            f'<g transform="matrix(1 0 0 -1 0 {_format(flip)})"'
+            f' fill="{_FILL}" fill-opacity="{_format(_FILL_OPACITY)}"'
+            f' stroke="{_STROKE}" stroke-width="{_format(_STROKE_WIDTH)}">'
+            + ''.join(self._body)
+            + '</g></svg>'
+        )
+
+
+def _contour_to_polyline(contour: hints.Contour[Fraction], /) -> _Polyline:
+    return [_to_coordinates(vertex) for vertex in contour.vertices], True
+
+
+def _coordinates_to_view_box(
+    coordinates: Sequence[_Coordinates], /
+) -> _ViewBox | None:
+    if not coordinates:
+        return None
+    xs = [x for x, _ in coordinates]
+    ys = [y for _, y in coordinates]
+    return min(xs), max(xs), min(ys), max(ys)
+
+
+def _edge_y_at(
+    edge: tuple[_Coordinates, _Coordinates], x: float, /
+) -> float:
+    (start_x, start_y), (end_x, end_y) = edge
+    return (
+        start_y
+        if start_x == end_x
+        else start_y + (end_y - start_y) * (x - start_x) / (end_x - start_x)
+    )
+
+
+def _format(value: float, /) -> str:
+    result = format(Decimal(repr(value)), 'f')
+    return result[: -len('.0')] if result.endswith('.0') else result
+
+
+def _polygon_to_polylines(
+    polygon: hints.Polygon[Fraction], /
+) -> list[_Polyline]:
+    return [
+        _contour_to_polyline(contour)
+        for contour in (polygon.border, *polygon.holes)
+    ]
+
+
+def _to_coordinates(point: hints.Point[Fraction], /) -> _Coordinates:
+    return float(point.x), float(point.y)
+
+
+def _to_edge_coordinates(
+    edge: Any, endpoints: Sequence[hints.Point[Fraction]], /
+) -> tuple[_Coordinates, _Coordinates]:
+    return (
+        _to_coordinates(endpoints[edge.left_point_index]),
+        _to_coordinates(endpoints[edge.right_point_index]),
+    )
+
+
+def _to_path_data(polylines: Iterable[_Polyline], /) -> str:
+    parts = []
+    for vertices, is_closed in polylines:
+        parts.extend(
+            f'{"L" if index else "M"}{_format(x)},{_format(y)}'
+            for index, (x, y) in enumerate(vertices)
+        )
+        if is_closed:
+            parts.append('Z')
+    return ' '.join(parts)
+
+
+def _to_view_box(box: hints.Box[Fraction], /) -> _ViewBox:
+    return (
+        float(box.min_x),
+        float(box.max_x),
+        float(box.min_y),
+        float(box.max_y),
+    )
@@ -0,0 +1,157 @@
+from __future__ import annotations
+
+from typing import Any
+
+from rene import hints
+from rene._context import Context
+
+# columns of the matrix, i.e. images of the basis vectors & of the origin
+_Columns = tuple[
+    hints.Point[hints.ScalarT],
+    hints.Point[hints.ScalarT],
+    hints.Point[hints.ScalarT],
+]
+
+_AFFINE_MATRIX_ROW_SIZE = 3
+_AFFINE_MATRIX_ROWS_COUNT = 2
+
+
+def to_affine_matrix(
+    value: Any, /, *, context: Context[hints.ScalarT]
+) -> _Columns[hints.ScalarT]:
+    if not (
+        isinstance(value, tuple)
+        and len(value) == _AFFINE_MATRIX_ROWS_COUNT
+        and all(
+            isinstance(row, tuple) and len(row) == _AFFINE_MATRIX_ROW_SIZE
+            for row in value
+        )
+    ):
+        raise TypeError(f'Expected affine matrix, but got {value!r}.')
+    (xx, xy, x_offset), (yx, yy, y_offset) = value
+    return (
+        context.point_cls(xx, yx),
+        context.point_cls(xy, yy),
+        context.point_cls(x_offset, y_offset),
+    )
+
+
+def transform_box(
+    box: hints.Box[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Box[hints.ScalarT]:
+    corners = [
+        transform_point(context.point_cls(x, y), matrix, context=context)
+        for x, y in [
+            (box.min_x, box.min_y),
+            (box.max_x, box.min_y),
+            (box.max_x, box.max_y),
+            (box.min_x, box.max_y),
+        ]
+    ]
+    xs = [corner.x for corner in corners]
+    ys = [corner.y for corner in corners]
+    return context.box_cls(min(xs), max(xs), min(ys), max(ys))
+
+
+def transform_contour(
+    contour: hints.Contour[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Contour[hints.ScalarT]:
+    vertices = [
+        transform_point(vertex, matrix, context=context)
+        for vertex in contour.vertices
+    ]
+    # reflections flip the orientation, so it is restored
+    if _to_determinant(matrix) < 0:
+        vertices[1:] = vertices[:0:-1]
+    return context.contour_cls(vertices)
+
+
+def transform_multipolygon(
+    multipolygon: hints.Multipolygon[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Multipolygon[hints.ScalarT]:
+    return context.multipolygon_cls(
+        [
+            transform_polygon(polygon, matrix, context=context)
+            for polygon in multipolygon.polygons
+        ]
+    )
+
+
+def transform_multisegment(
+    multisegment: hints.Multisegment[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Multisegment[hints.ScalarT]:
+    return context.multisegment_cls(
+        [
+            transform_segment(segment, matrix, context=context)
+            for segment in multisegment.segments
+        ]
+    )
+
+
+def transform_point(
+    point: hints.Point[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Point[hints.ScalarT]:
+    first_column, second_column, offset = matrix
+    return context.point_cls(
+        first_column.x * point.x + second_column.x * point.y + offset.x,
+        first_column.y * point.x + second_column.y * point.y + offset.y,
+    )
+
+
+def transform_polygon(
+    polygon: hints.Polygon[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Polygon[hints.ScalarT]:
+    # singular matrices collapse polygons into line segments
+    if _to_determinant(matrix) == 0:
+        raise ValueError(
+            'Affine matrix should be non-singular for areal geometries.'
+        )
+    return context.polygon_cls(
+        transform_contour(polygon.border, matrix, context=context),
+        [
+            transform_contour(hole, matrix, context=context)
+            for hole in polygon.holes
+        ],
+    )
+
+
+def transform_segment(
+    segment: hints.Segment[hints.ScalarT],
+    matrix: _Columns[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Segment[hints.ScalarT]:
+    return context.segment_cls(
+        transform_point(segment.start, matrix, context=context),
+        transform_point(segment.end, matrix, context=context),
+    )
+
+
+def _to_determinant(matrix: _Columns[hints.ScalarT], /) -> hints.ScalarT:
+    first_column, second_column, _ = matrix
+    return first_column.x * second_column.y - second_column.x * first_column.y
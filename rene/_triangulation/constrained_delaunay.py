@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+from bisect import bisect_left, bisect_right
 from collections import deque
 from itertools import chain, groupby
 from operator import attrgetter
@@ -8,23 +9,96 @@ from typing import Generic, TYPE_CHECKING
 from typing_extensions import Self
 
 from rene import hints
+from rene._bentley_ottmann.base import split_segments
 from rene._relating import segment_endpoints
-from rene._utils import locate_point_in_point_point_point_circle
+from rene._utils import deduplicate, locate_point_in_point_point_point_circle
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
 from rene.enums import Location, Orientation, Relation
 
-from .mesh import Mesh, build_delaunay_triangulation, orient_point_to_edge
-from .quad_edge import QuadEdge, UNDEFINED_EDGE, to_opposite_edge
+from .mesh import (
+    EndpointLocation,
+    Mesh,
+    build_delaunay_triangulation,
+    is_triangle_face,
+    locate_endpoint,
+    locate_point_by_scan,
+    orient_point_to_edge,
+    to_point_location,
+    walk_to_endpoint,
+)
+from .quad_edge import (
+    QuadEdge,
+    UNDEFINED_EDGE,
+    to_opposite_edge,
+    to_unique_edge,
+)
 from .vertices import ContourVertex, PolygonVertexPosition
 
 if TYPE_CHECKING:
     from collections.abc import Container, Iterable, Sequence
 
-    from rene._hints import Orienteer
+    from rene._hints import Orienteer, SegmentsIntersector
 
 BORDER_CONTOUR_INDEX = 0
 
 
 class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
+    @classmethod
+    def from_multipolygon(
+        cls,
+        multipolygon: hints.Multipolygon[hints.ScalarT],
+        orienteer: Orienteer[hints.ScalarT],
+        segments_intersector: SegmentsIntersector[hints.ScalarT],
+        /,
+    ) -> Self:
+        self, constraints_multiplicities = cls.from_segments(
+            [],
+            to_pieces(
+                [
+                    segment
+                    for polygon in multipolygon.polygons
+                    for contour in (polygon.border, *polygon.holes)
+                    for segment in contour.segments
+                ],
+                orienteer,
+                segments_intersector,
+            ),
+            orienteer,
+        )
+        self.exclude_exterior_faces(constraints_multiplicities)
+        return self
+
+    @classmethod
+    def from_points_and_multisegment(
+        cls,
+        points: Sequence[hints.Point[hints.ScalarT]],
+        multisegment: hints.Multisegment[hints.ScalarT],
+        orienteer: Orienteer[hints.ScalarT],
+        segments_intersector: SegmentsIntersector[hints.ScalarT],
+        /,
+    ) -> Self:
+        degenerate_segments_points = []
+        segments = []
+        for segment in multisegment.segments:
+            if segment.start == segment.end:
+                # degenerate segments are treated as points
+                degenerate_segments_points.append(segment.start)
+            else:
+                segments.append(segment)
+        free_points = deduplicate(
+            sorted([*points, *degenerate_segments_points])
+        )
+        self, _ = cls.from_segments(
+            free_points,
+            split_pieces_by_points(
+                to_pieces(segments, orienteer, segments_intersector),
+                free_points,
+                orienteer,
+            ),
+            orienteer,
+        )
+        return self
+
     @classmethod
     def from_polygon(
         cls,
@@ -77,19 +151,72 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
             for hole_index, hole_size in enumerate(contours_sizes[1:], start=1)
             if hole_size == 3
         ]
-        self = cls(
-            left_side,
-            right_side,
+        self = cls(left_side, right_side, mesh, set(), set(), orienteer)
+        self.constrain(
+            contours_sizes, contours_vertices, polygon_vertices_positions
+        )
+        self.bound(contours_sizes, polygon_vertices_positions)
+        self.cut(contours_vertices, polygon_vertices_positions)
+        self._constraints = {
+            edge
+            for edge in mesh.to_unique_edges()
+            if is_polygon_edge(
+                mesh, edge, contours_sizes, polygon_vertices_positions
+            )
+        }
+        self._exterior_faces_edges = to_triangular_holes_edges(
             mesh,
+            self.triangles_base_edges(),
             polygon_vertices_positions,
             triangular_holes_indices,
-            orienteer,
         )
-        self.constrain(contours_sizes, contours_vertices)
-        self.bound(contours_sizes)
-        self.cut(contours_vertices)
         return self
 
+    @classmethod
+    def from_segments(
+        cls,
+        points: Sequence[hints.Point[hints.ScalarT]],
+        segments: Sequence[
+            tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]
+        ],
+        orienteer: Orienteer[hints.ScalarT],
+        /,
+    ) -> tuple[Self, dict[QuadEdge, int]]:
+        """
+        Triangulates given points along with endpoints of the segments
+        constraining the segments, which should not contain endpoints inside,
+        returns the triangulation along with multiplicities of constraints.
+        """
+        endpoints = deduplicate(
+            sorted(
+                [
+                    *points,
+                    *[
+                        endpoint
+                        for segment in segments
+                        for endpoint in segment
+                    ],
+                ]
+            )
+        )
+        pieces = to_pieces_indices_multiplicities(endpoints, segments)
+        mesh = Mesh.from_points(endpoints)
+        left_side, right_side = build_delaunay_triangulation(mesh, orienteer)
+        self = cls(left_side, right_side, mesh, set(), set(), orienteer)
+        vertices_edges = to_vertices_edges(mesh)
+        constraints_multiplicities = {
+            to_unique_edge(
+                self.set_piece(vertices_edges, start_index, end_index)
+            ): multiplicity
+            for (start_index, end_index), multiplicity in pieces.items()
+        }
+        self._constraints = set(constraints_multiplicities)
+        return self, constraints_multiplicities
+
+    @property
+    def constraints(self, /) -> Container[QuadEdge]:
+        return self._constraints
+
     @property
     def left_side(self, /) -> QuadEdge:
         return self._left_side
@@ -102,14 +229,19 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
     def right_side(self, /) -> QuadEdge:
         return self._right_side
 
-    def bound(self, contours_sizes: list[int], /) -> None:
+    def bound(
+        self,
+        contours_sizes: list[int],
+        polygon_vertices_positions: list[list[PolygonVertexPosition]],
+        /,
+    ) -> None:
         mesh = self.mesh
         boundary_edges = self.to_unique_boundary_edges()
         extraneous_mouths = [
             edge
             for edge in boundary_edges
             if not is_polygon_edge(
-                mesh, edge, contours_sizes, self._polygon_vertices_positions
+                mesh, edge, contours_sizes, polygon_vertices_positions
             )
         ]
         while extraneous_mouths:
@@ -122,14 +254,14 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
                 mesh,
                 first_candidate,
                 contours_sizes,
-                self._polygon_vertices_positions,
+                polygon_vertices_positions,
             ):
                 extraneous_mouths.append(first_candidate)
             if not is_polygon_edge(
                 mesh,
                 second_candidate,
                 contours_sizes,
-                self._polygon_vertices_positions,
+                polygon_vertices_positions,
             ):
                 extraneous_mouths.append(second_candidate)
 
@@ -137,18 +269,17 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
         self,
         contours_sizes: list[int],
         contours_vertices: list[Sequence[hints.Point[hints.ScalarT]]],
+        polygon_vertices_positions: list[list[PolygonVertexPosition]],
         /,
     ) -> None:
         mesh = self.mesh
         contours_constraints_flags = to_contours_constraints_flags(
-            mesh, contours_sizes, self._polygon_vertices_positions
+            mesh, contours_sizes, polygon_vertices_positions
         )
         for edge in mesh.to_edges():
             vertex_start_index = mesh.to_start_index(edge)
             vertex_point = mesh.endpoints[vertex_start_index]
-            vertex_positions = self._polygon_vertices_positions[
-                vertex_start_index
-            ]
+            vertex_positions = polygon_vertices_positions[vertex_start_index]
             for vertex_position in vertex_positions:
                 contour_index, vertex_index = (
                     vertex_position.contour_index,
@@ -188,7 +319,10 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
                     ] = True
 
     def cut(
-        self, contours_vertices: list[Sequence[hints.Point[hints.ScalarT]]], /
+        self,
+        contours_vertices: list[Sequence[hints.Point[hints.ScalarT]]],
+        polygon_vertices_positions: list[list[PolygonVertexPosition]],
+        /,
     ) -> None:
         mesh = self.mesh
         for edge in mesh.to_unique_edges():
@@ -196,7 +330,7 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
                 mesh,
                 edge,
                 contours_vertices,
-                self._polygon_vertices_positions,
+                polygon_vertices_positions,
                 self._orienteer,
             ):
                 self.delete_edge(edge)
@@ -213,7 +347,131 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
             self._left_side = self.mesh.to_left_from_start(self.left_side)
         self.mesh.delete_edge(edge)
 
+    def exclude_exterior_faces(
+        self, constraints_multiplicities: dict[QuadEdge, int], /
+    ) -> None:
+        """
+        Excludes triangles separated from the outer face
+        by an even number of constraints counting their multiplicities.
+        """
+        mesh = self.mesh
+
+        def is_odd_constraint(edge: QuadEdge, /) -> bool:
+            return (
+                constraints_multiplicities.get(to_unique_edge(edge), 0) % 2
+                == 1
+            )
+
+        triangles_edges = []
+        for base_edge in self.triangles_base_edges():
+            next_edge = mesh.to_left_from_end(base_edge)
+            triangles_edges.append(
+                (base_edge, next_edge, mesh.to_left_from_end(next_edge))
+            )
+        edges_triangles_indices = {
+            edge: triangle_index
+            for triangle_index, triangle_edges in enumerate(triangles_edges)
+            for edge in triangle_edges
+        }
+        interior_flags: list[bool | None] = [None] * len(triangles_edges)
+        queue = []
+        for triangle_index, triangle_edges in enumerate(triangles_edges):
+            for edge in triangle_edges:
+                if (
+                    interior_flags[triangle_index] is None
+                    and to_opposite_edge(edge) not in edges_triangles_indices
+                ):
+                    interior_flags[triangle_index] = is_odd_constraint(edge)
+                    queue.append(triangle_index)
+        while queue:
+            triangle_index = queue.pop()
+            is_interior = interior_flags[triangle_index]
+            assert is_interior is not None
+            for edge in triangles_edges[triangle_index]:
+                neighbour_index = edges_triangles_indices.get(
+                    to_opposite_edge(edge)
+                )
+                if (
+                    neighbour_index is not None
+                    and interior_flags[neighbour_index] is None
+                ):
+                    interior_flags[neighbour_index] = (
+                        is_interior is not is_odd_constraint(edge)
+                    )
+                    queue.append(neighbour_index)
+        self._exterior_faces_edges = {
+            edge
+            for triangle_edges, is_interior in zip(
+                triangles_edges, interior_flags, strict=True
+            )
+            if is_interior is False
+            for edge in triangle_edges
+        }
+
+    def locate_point(
+        self, point: hints.Point[hints.ScalarT], /
+    ) -> tuple[int, ...]:
+        """
+        Locates the point in the triangulation,
+        points inside of holes are considered exterior.
+        """
+        mesh = self.mesh
+        if self and self._is_inner_face(self.left_side):
+            walk_result = walk_to_endpoint(
+                mesh,
+                self.left_side,
+                point,
+                self._is_inner_face,
+                2 * len(mesh.endpoints),
+                self._orienteer,
+            )
+            if (
+                walk_result is not None
+                and walk_result[0] is not EndpointLocation.EXTERIOR
+            ):
+                return to_point_location(mesh, *walk_result)
+        return locate_point_by_scan(
+            mesh, point, self._is_inner_face, self._orienteer
+        )
+
+    def set_piece(
+        self,
+        vertices_edges: list[QuadEdge],
+        start_index: int,
+        end_index: int,
+        /,
+    ) -> QuadEdge:
+        """
+        Constrains the piece between endpoints with given indices,
+        returns the edge from the start of the piece to its end.
+        """
+        mesh = self.mesh
+        start, end = mesh.endpoints[start_index], mesh.endpoints[end_index]
+        edge = vertices_edges[start_index]
+        if mesh.to_start_index(edge) != start_index:
+            # the edge has been swapped by previous constraints
+            location, edge = locate_endpoint(
+                mesh, self.left_side, start, self._orienteer
+            )
+            assert location is EndpointLocation.VERTEX
+        result = to_edge_with_end(mesh, edge, end_index)
+        if result is None:
+            angle_base_edge = to_angle_containing_constraint_base(
+                mesh, edge, end, self._orienteer
+            )
+            crossings = detect_crossings(
+                mesh, angle_base_edge, start, end, self._orienteer
+            )
+            set_constraint(mesh, start, end, crossings, self._orienteer)
+            result = to_edge_with_end(mesh, angle_base_edge, end_index)
+            assert result is not None
+        vertices_edges[start_index] = result
+        vertices_edges[end_index] = to_opposite_edge(result)
+        return result
+
     def to_boundary_points(self, /) -> list[hints.Point[hints.ScalarT]]:
+        if len(self.mesh.endpoints) < MIN_CONTOUR_VERTICES_COUNT:
+            return self.mesh.endpoints[:]
         edge_to_start = self.mesh.to_start
         return [
             edge_to_start(edge) for edge in self.to_unique_boundary_edges()
@@ -233,15 +491,7 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
             return result
         return list(self.mesh.to_unique_edges())
 
-    def triangles_vertices(
-        self,
-    ) -> list[
-        tuple[
-            hints.Point[hints.ScalarT],
-            hints.Point[hints.ScalarT],
-            hints.Point[hints.ScalarT],
-        ]
-    ]:
+    def triangles_base_edges(self, /) -> list[QuadEdge]:
         mesh = self.mesh
         result = []
         for edge in mesh.to_edges():
@@ -259,32 +509,45 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
                     mesh, edge, third_vertex, self._orienteer
                 )
                 is Orientation.COUNTERCLOCKWISE
-                and not (
-                    self._triangular_holes_indices
-                    and are_triangular_hole_vertices(
-                        self._polygon_vertices_positions[
-                            mesh.to_start_index(edge)
-                        ],
-                        self._polygon_vertices_positions[
-                            mesh.to_end_index(edge)
-                        ],
-                        self._polygon_vertices_positions[
-                            mesh.to_end_index(mesh.to_left_from_start(edge))
-                        ],
-                        self._triangular_holes_indices,
-                    )
-                )
+                and not self._is_exterior_face(edge)
             ):
-                result.append((first_vertex, second_vertex, third_vertex))
+                result.append(edge)
         return result
 
+    def triangles_vertices(
+        self,
+    ) -> list[
+        tuple[
+            hints.Point[hints.ScalarT],
+            hints.Point[hints.ScalarT],
+            hints.Point[hints.ScalarT],
+        ]
+    ]:
+        mesh = self.mesh
+        return [
+            (
+                mesh.to_start(edge),
+                mesh.to_end(edge),
+                mesh.to_end(mesh.to_left_from_start(edge)),
+            )
+            for edge in self.triangles_base_edges()
+        ]
+
+    def _is_exterior_face(self, edge: QuadEdge, /) -> bool:
+        return edge in self._exterior_faces_edges
+
+    def _is_inner_face(self, edge: QuadEdge, /) -> bool:
+        return is_triangle_face(
+            self.mesh, edge, self._orienteer
+        ) and not self._is_exterior_face(edge)
+
     __slots__ = (
+        '_constraints',
+        '_exterior_faces_edges',
         '_left_side',
         '_mesh',
         '_orienteer',
-        '_polygon_vertices_positions',
         '_right_side',
-        '_triangular_holes_indices',
     )
 
     def __init__(
@@ -292,25 +555,25 @@ class ConstrainedDelaunayTriangulation(Generic[hints.ScalarT]):
         left_side: QuadEdge,
         right_side: QuadEdge,
         mesh: Mesh[hints.ScalarT],
-        polygon_vertices_positions: list[list[PolygonVertexPosition]],
-        triangular_holes_indices: Container[int],
+        constraints: Container[QuadEdge],
+        exterior_faces_edges: Container[QuadEdge],
         orienteer: Orienteer[hints.ScalarT],
         /,
     ) -> None:
         (
+            self._constraints,
+            self._exterior_faces_edges,
             self._left_side,
             self._mesh,
             self._orienteer,
-            self._polygon_vertices_positions,
             self._right_side,
-            self._triangular_holes_indices,
         ) = (
+            constraints,
+            exterior_faces_edges,
             left_side,
             mesh,
             orienteer,
-            polygon_vertices_positions,
             right_side,
-            triangular_holes_indices,
         )
 
     def __bool__(self, /) -> bool:
@@ -731,6 +994,41 @@ def set_constraint(
     restore_delaunay_criterion(mesh, new_edges, orienteer)
 
 
+def split_pieces_by_points(
+    pieces: Sequence[
+        tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]
+    ],
+    points: Sequence[hints.Point[hints.ScalarT]],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> list[tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]]:
+    """
+    Splits pieces by sorted unique points lying on them.
+
+    Points lying on a piece are lexicographically between its endpoints,
+    so only the points from that range are checked for collinearity.
+    """
+    if not points:
+        return list(pieces)
+    result = []
+    for start, end in pieces:
+        min_endpoint, max_endpoint = sorted((start, end))
+        piece_start = min_endpoint
+        for point in points[
+            bisect_right(points, min_endpoint) : bisect_left(
+                points, max_endpoint
+            )
+        ]:
+            if (
+                orienteer(min_endpoint, max_endpoint, point)
+                is Orientation.COLLINEAR
+            ):
+                result.append((piece_start, point))
+                piece_start = point
+        result.append((piece_start, max_endpoint))
+    return result
+
+
 def to_angle_containing_constraint_base(
     mesh: Mesh[hints.ScalarT],
     edge: QuadEdge,
@@ -770,3 +1068,89 @@ def to_constraint_index(
         if abs(second_vertex_index - first_vertex_index) == 1
         else 0
     )
+
+
+def to_pieces(
+    segments: Sequence[hints.Segment[hints.ScalarT]],
+    orienteer: Orienteer[hints.ScalarT],
+    segments_intersector: SegmentsIntersector[hints.ScalarT],
+    /,
+) -> list[tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]]:
+    """
+    Splits segments at their intersections,
+    returns pieces between consecutive split points.
+    """
+    return [
+        (start, end)
+        for segment_points in split_segments(
+            segments,
+            orienteer=orienteer,
+            segments_intersector=segments_intersector,
+        )
+        for start, end in zip(segment_points, segment_points[1:])
+    ]
+
+
+def to_pieces_indices_multiplicities(
+    endpoints: Sequence[hints.Point[hints.ScalarT]],
+    pieces: Sequence[
+        tuple[hints.Point[hints.ScalarT], hints.Point[hints.ScalarT]]
+    ],
+    /,
+) -> dict[tuple[int, int], int]:
+    """
+    Returns pairs of indices of pieces' endpoints
+    in sorted unique endpoints with their multiplicities.
+    """
+    result: dict[tuple[int, int], int] = {}
+    for start, end in pieces:
+        start_index = bisect_left(endpoints, start)
+        end_index = bisect_left(endpoints, end)
+        assert start_index != end_index
+        piece = (min(start_index, end_index), max(start_index, end_index))
+        result[piece] = result.get(piece, 0) + 1
+    return dict(sorted(result.items()))
+
+
+def to_edge_with_end(
+    mesh: Mesh[hints.ScalarT], edge: QuadEdge, end_index: int, /
+) -> QuadEdge | None:
+    """
+    Returns the edge with the same start as the given one
+    which ends at the endpoint with the given index if there is any.
+    """
+    candidate = edge
+    while True:
+        if mesh.to_end_index(candidate) == end_index:
+            return candidate
+        candidate = mesh.to_left_from_start(candidate)
+        if candidate == edge:
+            return None
+
+
+def to_triangular_holes_edges(
+    mesh: Mesh[hints.ScalarT],
+    triangles_base_edges: Iterable[QuadEdge],
+    polygon_vertices_positions: list[list[PolygonVertexPosition]],
+    triangular_holes_indices: Container[int],
+    /,
+) -> set[QuadEdge]:
+    result = set()
+    for base_edge in triangles_base_edges:
+        next_edge = mesh.to_left_from_end(base_edge)
+        last_edge = mesh.to_left_from_end(next_edge)
+        if are_triangular_hole_vertices(
+            polygon_vertices_positions[mesh.to_start_index(base_edge)],
+            polygon_vertices_positions[mesh.to_start_index(next_edge)],
+            polygon_vertices_positions[mesh.to_start_index(last_edge)],
+            triangular_holes_indices,
+        ):
+            result.update((base_edge, next_edge, last_edge))
+    return result
+
+
+def to_vertices_edges(mesh: Mesh[hints.ScalarT], /) -> list[QuadEdge]:
+    result = [UNDEFINED_EDGE] * len(mesh.endpoints)
+    for edge in mesh.to_edges():
+        result[mesh.to_start_index(edge)] = edge
+    return result
@@ -6,10 +6,22 @@ from typing_extensions import Self
 
 from rene import hints
 from rene._utils import deduplicate
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
 from rene.enums import Orientation
 
-from .mesh import Mesh, build_delaunay_triangulation, orient_point_to_edge
-from .quad_edge import QuadEdge, to_opposite_edge
+from .mesh import (
+    EndpointLocation,
+    Mesh,
+    build_delaunay_triangulation,
+    is_triangle_face,
+    legalize,
+    locate_endpoint,
+    locate_point_by_scan,
+    orient_point_to_edge,
+    remove_vertex,
+    to_point_location,
+)
+from .quad_edge import QuadEdge, UNDEFINED_EDGE, to_opposite_edge
 
 if TYPE_CHECKING:
     from collections.abc import Sequence
@@ -43,6 +55,102 @@ class DelaunayTriangulation(Generic[hints.ScalarT]):
     def right_side(self, /) -> QuadEdge:
         return self._right_side
 
+    def insert(self, endpoint: hints.Point[hints.ScalarT], /) -> bool:
+        """
+        Inserts the endpoint preserving the Delaunay property,
+        returns `False` if the endpoint is already present.
+        """
+        mesh = self.mesh
+        if self._is_degenerate():
+            if endpoint in mesh.endpoints:
+                return False
+            if (
+                len(mesh.endpoints) < 2
+                or orient_point_to_edge(
+                    mesh, self.left_side, endpoint, self._orienteer
+                )
+                is Orientation.COLLINEAR
+            ):
+                self._rebuild([*mesh.endpoints, endpoint])
+            else:
+                self._insert_exterior(endpoint)
+            return True
+        location, edge = locate_endpoint(
+            mesh, self.left_side, endpoint, self._orienteer
+        )
+        if location is EndpointLocation.BOUNDARY:
+            endpoint_index = mesh.push_endpoint(endpoint)
+            if is_triangle_face(mesh, to_opposite_edge(edge), self._orienteer):
+                side = mesh.to_right_from_start(edge)
+                mesh.delete_edge(edge)
+                legalize(
+                    mesh,
+                    mesh.insert_into_face(side, endpoint_index),
+                    self._orienteer,
+                )
+            else:
+                start_index = mesh.to_start_index(edge)
+                next_edge = mesh.to_left_from_end(edge)
+                mesh.delete_edge(edge)
+                last_edge, edges = mesh.insert_into_open_face(
+                    next_edge, endpoint_index, start_index
+                )
+                legalize(mesh, edges, self._orienteer)
+                self._update_sides(last_edge)
+        elif location is EndpointLocation.EXTERIOR:
+            self._insert_exterior(endpoint)
+        elif location is EndpointLocation.INTERIOR:
+            endpoint_index = mesh.push_endpoint(endpoint)
+            legalize(
+                mesh,
+                mesh.insert_into_face(edge, endpoint_index),
+                self._orienteer,
+            )
+        else:
+            assert location is EndpointLocation.VERTEX
+            return False
+        return True
+
+    def locate_point(
+        self, point: hints.Point[hints.ScalarT], /
+    ) -> tuple[int, ...]:
+        """
+        Locates the point in the triangulation.
+        """
+        if self._is_degenerate():
+            return locate_point_by_scan(
+                self.mesh, point, lambda _: False, self._orienteer
+            )
+        location, edge = locate_endpoint(
+            self.mesh, self.left_side, point, self._orienteer
+        )
+        return to_point_location(self.mesh, location, edge)
+
+    def remove(self, endpoint: hints.Point[hints.ScalarT], /) -> bool:
+        """
+        Removes the endpoint preserving the Delaunay property,
+        returns `False` if the endpoint is not present.
+        """
+        mesh = self.mesh
+        if self._is_degenerate():
+            if endpoint not in mesh.endpoints:
+                return False
+            endpoints = list(mesh.endpoints)
+            endpoints.remove(endpoint)
+            self._rebuild(endpoints)
+            return True
+        location, edge = locate_endpoint(
+            mesh, self.left_side, endpoint, self._orienteer
+        )
+        if location is not EndpointLocation.VERTEX:
+            return False
+        endpoint_index = mesh.to_start_index(edge)
+        outer_edge = remove_vertex(mesh, edge, self._orienteer)
+        mesh.swap_remove_endpoint(endpoint_index)
+        if outer_edge is not None:
+            self._update_sides(to_opposite_edge(outer_edge))
+        return True
+
     def to_boundary_points(self, /) -> list[hints.Point[hints.ScalarT]]:
         if self:
             result = []
@@ -57,15 +165,7 @@ class DelaunayTriangulation(Generic[hints.ScalarT]):
             return result
         return self.mesh.endpoints
 
-    def triangles_vertices(
-        self,
-    ) -> list[
-        tuple[
-            hints.Point[hints.ScalarT],
-            hints.Point[hints.ScalarT],
-            hints.Point[hints.ScalarT],
-        ]
-    ]:
+    def triangles_base_edges(self, /) -> list[QuadEdge]:
         mesh = self.mesh
         result = []
         for edge in mesh.to_edges():
@@ -88,9 +188,96 @@ class DelaunayTriangulation(Generic[hints.ScalarT]):
                     is Orientation.COUNTERCLOCKWISE
                 )
             ):
-                result.append((first_vertex, second_vertex, third_vertex))
+                result.append(edge)
         return result
 
+    def triangles_vertices(
+        self,
+    ) -> list[
+        tuple[
+            hints.Point[hints.ScalarT],
+            hints.Point[hints.ScalarT],
+            hints.Point[hints.ScalarT],
+        ]
+    ]:
+        mesh = self.mesh
+        return [
+            (
+                mesh.to_start(edge),
+                mesh.to_end(edge),
+                mesh.to_end(mesh.to_left_from_start(edge)),
+            )
+            for edge in self.triangles_base_edges()
+        ]
+
+    def _insert_exterior(
+        self, endpoint: hints.Point[hints.ScalarT], /
+    ) -> None:
+        mesh = self.mesh
+        boundary_edges = self._to_boundary_edges()
+        are_visible = [
+            orient_point_to_edge(mesh, edge, endpoint, self._orienteer)
+            is Orientation.CLOCKWISE
+            for edge in boundary_edges
+        ]
+        edges_count = len(boundary_edges)
+        first_position = next(
+            position
+            for position in range(edges_count)
+            if are_visible[position] and not are_visible[position - 1]
+        )
+        last_position = first_position
+        while are_visible[(last_position + 1) % edges_count]:
+            last_position = (last_position + 1) % edges_count
+        stop_index = mesh.to_start_index(boundary_edges[first_position])
+        endpoint_index = mesh.push_endpoint(endpoint)
+        last_edge, edges = mesh.insert_into_open_face(
+            to_opposite_edge(boundary_edges[last_position]),
+            endpoint_index,
+            stop_index,
+        )
+        legalize(mesh, edges, self._orienteer)
+        self._update_sides(last_edge)
+
+    def _is_degenerate(self, /) -> bool:
+        return len(self.mesh.endpoints) < MIN_CONTOUR_VERTICES_COUNT or (
+            not is_triangle_face(self.mesh, self.left_side, self._orienteer)
+        )
+
+    def _rebuild(self, endpoints: list[hints.Point[hints.ScalarT]], /) -> None:
+        rebuilt = DelaunayTriangulation.from_points(endpoints, self._orienteer)
+        self._left_side, self._mesh, self._right_side = (
+            rebuilt.left_side,
+            rebuilt.mesh,
+            rebuilt.right_side,
+        )
+
+    def _to_boundary_edges(self, /) -> list[QuadEdge]:
+        result = [self.left_side]
+        edge = self.mesh.to_right_from_end(self.left_side)
+        while edge != self.left_side:
+            result.append(edge)
+            edge = self.mesh.to_right_from_end(edge)
+        return result
+
+    def _update_sides(self, boundary_edge: QuadEdge, /) -> None:
+        """
+        Sets sides to the counterclockwise boundary edge
+        from the minimal endpoint
+        and to the clockwise boundary edge from the maximal endpoint
+        by walking the boundary starting from the given edge.
+        """
+        mesh = self.mesh
+        left_side, right_side = boundary_edge, to_opposite_edge(boundary_edge)
+        edge = mesh.to_right_from_end(boundary_edge)
+        while edge != boundary_edge:
+            if mesh.to_start(edge) < mesh.to_start(left_side):
+                left_side = edge
+            if mesh.to_end(edge) > mesh.to_start(right_side):
+                right_side = to_opposite_edge(edge)
+            edge = mesh.to_right_from_end(edge)
+        self._left_side, self._right_side = left_side, right_side
+
     _left_side: QuadEdge
     _mesh: Mesh[hints.ScalarT]
     _orienteer: Orienteer[hints.ScalarT]
@@ -114,3 +301,9 @@ class DelaunayTriangulation(Generic[hints.ScalarT]):
             right_side,
         )
         return self
+
+    def __bool__(self, /) -> bool:
+        result = bool(self.mesh)
+        assert result is (self.left_side != UNDEFINED_EDGE)
+        assert result is (self.right_side != UNDEFINED_EDGE)
+        return result
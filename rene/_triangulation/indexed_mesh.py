@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from rene import hints
+
+from .quad_edge import to_opposite_edge, to_unique_edge
+
+if TYPE_CHECKING:
+    from collections.abc import Container, Sequence
+
+    from .mesh import Mesh
+    from .quad_edge import QuadEdge
+
+
+def to_indexed_mesh(
+    mesh: Mesh[hints.ScalarT],
+    triangles_base_edges: Sequence[QuadEdge],
+    constraints: Container[QuadEdge],
+    /,
+) -> tuple[
+    list[tuple[bool, bool, bool]],
+    list[tuple[int | None, int | None, int | None]],
+    list[tuple[int, int, int]],
+]:
+    """
+    Returns flags of triangles' edges
+    which lie on the border of the triangulation or are its constraints,
+    indices of triangles adjacent to triangles' edges
+    & indices of triangles' vertices.
+    """
+    triangles_indices = {
+        base_edge: triangle_index
+        for triangle_index, base_edge in enumerate(triangles_base_edges)
+    }
+    constrained_flags = []
+    neighbours = []
+    triangles = []
+    for base_edge in triangles_base_edges:
+        next_edge = mesh.to_left_from_end(base_edge)
+        last_edge = mesh.to_left_from_end(next_edge)
+        triangle_neighbours = (
+            _to_neighbour(mesh, base_edge, triangles_indices),
+            _to_neighbour(mesh, next_edge, triangles_indices),
+            _to_neighbour(mesh, last_edge, triangles_indices),
+        )
+        constrained_flags.append(
+            (
+                triangle_neighbours[0] is None
+                or to_unique_edge(base_edge) in constraints,
+                triangle_neighbours[1] is None
+                or to_unique_edge(next_edge) in constraints,
+                triangle_neighbours[2] is None
+                or to_unique_edge(last_edge) in constraints,
+            )
+        )
+        neighbours.append(triangle_neighbours)
+        triangles.append(
+            (
+                mesh.to_start_index(base_edge),
+                mesh.to_start_index(next_edge),
+                mesh.to_start_index(last_edge),
+            )
+        )
+    return constrained_flags, neighbours, triangles
+
+
+def _to_neighbour(
+    mesh: Mesh[hints.ScalarT],
+    edge: QuadEdge,
+    triangles_indices: dict[QuadEdge, int],
+    /,
+) -> int | None:
+    base_edge = _to_triangle_base_edge(mesh, to_opposite_edge(edge))
+    return None if base_edge is None else triangles_indices.get(base_edge)
+
+
+def _to_triangle_base_edge(
+    mesh: Mesh[hints.ScalarT], edge: QuadEdge, /
+) -> QuadEdge | None:
+    """
+    Returns the edge from the least vertex of the left face of the edge
+    if the face is a triangle.
+    """
+    next_edge = mesh.to_left_from_end(edge)
+    last_edge = mesh.to_left_from_end(next_edge)
+    if mesh.to_left_from_end(last_edge) != edge:
+        return None
+    result = edge
+    for candidate in (next_edge, last_edge):
+        if mesh.to_start(candidate) < mesh.to_start(result):
+            result = candidate
+    return result
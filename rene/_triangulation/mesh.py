@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import enum
+import sys
 from typing import Generic, TYPE_CHECKING
 
 from typing_extensions import Self
@@ -20,10 +22,19 @@ from .quad_edge import (
 )
 
 if TYPE_CHECKING:
-    from collections.abc import Iterable
+    from collections.abc import Callable, Iterable
 
     from rene._hints import Orienteer
 
+UNDEFINED_INDEX = -1
+
+
+class EndpointLocation(enum.Enum):
+    BOUNDARY = enum.auto()
+    EXTERIOR = enum.auto()
+    INTERIOR = enum.auto()
+    VERTEX = enum.auto()
+
 
 class Mesh(Generic[hints.ScalarT]):
     endpoints: list[hints.Point[hints.ScalarT]]
@@ -58,8 +69,8 @@ class Mesh(Generic[hints.ScalarT]):
         return edge
 
     def is_deleted_edge(self, edge: QuadEdge, /) -> bool:
-        result = self.to_left_from_start(edge) == edge
-        assert (self.to_right_from_start(edge) == edge) is result
+        result = self.to_start_index(edge) == UNDEFINED_INDEX
+        assert not result or self.to_left_from_start(edge) == edge
         return result
 
     def delete_edge(self, edge: QuadEdge, /) -> None:
@@ -68,6 +79,51 @@ class Mesh(Generic[hints.ScalarT]):
         self.splice_edges(
             opposite_edge, self.to_right_from_start(opposite_edge)
         )
+        self.starts_indices[edge // 2] = UNDEFINED_INDEX
+        self.starts_indices[opposite_edge // 2] = UNDEFINED_INDEX
+
+    def insert_into_face(
+        self, edge: QuadEdge, endpoint_index: int, /
+    ) -> list[QuadEdge]:
+        """
+        Connects the endpoint with vertices of the left face of the edge
+        which should be a star-shaped polygon containing the endpoint,
+        returns edges of the face.
+        """
+        first = self.create_edge(self.to_start_index(edge), endpoint_index)
+        self.splice_edges(first, edge)
+        base = first
+        result = []
+        while True:
+            base = self.connect_edges(edge, to_opposite_edge(base))
+            result.append(edge)
+            edge = self.to_right_from_start(base)
+            if self.to_left_from_end(edge) == first:
+                result.append(edge)
+                return result
+
+    def insert_into_open_face(
+        self, edge: QuadEdge, endpoint_index: int, stop_index: int, /
+    ) -> tuple[QuadEdge, list[QuadEdge]]:
+        """
+        Connects the endpoint with vertices of the left face of the edge
+        up to the vertex with the given index,
+        returns the last connecting edge along with passed edges of the face.
+        """
+        first = self.create_edge(self.to_start_index(edge), endpoint_index)
+        self.splice_edges(first, edge)
+        base = first
+        passed_edges = []
+        while True:
+            base = self.connect_edges(edge, to_opposite_edge(base))
+            passed_edges.append(edge)
+            if self.to_end_index(edge) == stop_index:
+                return base, passed_edges
+            edge = self.to_right_from_start(base)
+
+    def push_endpoint(self, endpoint: hints.Point[hints.ScalarT], /) -> int:
+        self.endpoints.append(endpoint)
+        return len(self.endpoints) - 1
 
     def splice_edges(self, first: QuadEdge, second: QuadEdge, /) -> None:
         alpha = to_rotated_edge(self.to_left_from_start(first))
@@ -98,6 +154,21 @@ class Mesh(Generic[hints.ScalarT]):
         assert self.to_start(edge) == self.to_end(side)
         assert self.to_end(edge) == self.to_end(opposite_side)
 
+    def swap_remove_endpoint(self, index: int, /) -> None:
+        """
+        Removes endpoint with the given index which has no edges
+        by replacing it with the last one.
+        """
+        last_index = len(self.endpoints) - 1
+        if index != last_index:
+            assert index not in self.starts_indices
+            self.starts_indices = [
+                index if start_index == last_index else start_index
+                for start_index in self.starts_indices
+            ]
+            self.endpoints[index] = self.endpoints[last_index]
+        self.endpoints.pop()
+
     def to_edges(self, /) -> Iterable[QuadEdge]:
         candidates = [
             QuadEdge(index) for index in range(0, len(self.left_from_start), 2)
@@ -108,6 +179,13 @@ class Mesh(Generic[hints.ScalarT]):
             if not self.is_deleted_edge(candidate)
         ]
 
+    def to_endpoints_neighbours(self, /) -> list[list[int]]:
+        result: list[list[int]] = [[] for _ in self.endpoints]
+        for edge in self.to_unique_edges():
+            result[self.to_start_index(edge)].append(self.to_end_index(edge))
+            result[self.to_end_index(edge)].append(self.to_start_index(edge))
+        return result
+
     def to_end(self, edge: QuadEdge, /) -> hints.Point[hints.ScalarT]:
         """
         aka "Dest" in L. Guibas and J. Stolfi notation.
@@ -288,6 +366,37 @@ def create_triangle(
     return first_edge, to_opposite_edge(second_edge)
 
 
+def fill_hole(
+    mesh: Mesh[hints.ScalarT],
+    edges: list[QuadEdge],
+    is_closed: bool,
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> list[QuadEdge]:
+    """
+    Triangulates hole bounded by the given edges
+    by cutting off ears with empty circumcircles,
+    returns remaining edges.
+    """
+    while not is_closed or len(edges) > 3:
+        candidates_count = len(edges) if is_closed else len(edges) - 1
+        position = next(
+            (
+                position
+                for position in range(candidates_count)
+                if is_delaunay_ear(mesh, edges, position, orienteer)
+            ),
+            None,
+        )
+        if position is None:
+            break
+        next_position = (position + 1) % len(edges)
+        diagonal = mesh.connect_edges(edges[next_position], edges[position])
+        edges[position] = to_opposite_edge(diagonal)
+        del edges[next_position]
+    return edges
+
+
 def find_left_candidate(
     mesh: Mesh[hints.ScalarT],
     base_edge: QuadEdge,
@@ -356,6 +465,148 @@ def find_right_candidate(
     return result
 
 
+def is_delaunay_ear(
+    mesh: Mesh[hints.ScalarT],
+    edges: list[QuadEdge],
+    position: int,
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> bool:
+    next_position = (position + 1) % len(edges)
+    first, second, third = (
+        mesh.to_start(edges[position]),
+        mesh.to_end(edges[position]),
+        mesh.to_end(edges[next_position]),
+    )
+    return orienteer(
+        first, second, third
+    ) is Orientation.COUNTERCLOCKWISE and all(
+        locate_point_in_point_point_point_circle(vertex, first, second, third)
+        is not Location.INTERIOR
+        for vertex in [
+            *[mesh.to_end(edge) for edge in edges],
+            mesh.to_start(edges[0]),
+        ]
+        if vertex != first and vertex != second and vertex != third
+    )
+
+
+def is_triangle_face(
+    mesh: Mesh[hints.ScalarT],
+    edge: QuadEdge,
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> bool:
+    """
+    Checks if the left face of the edge
+    is a triangle with vertices in counterclockwise order.
+    """
+    next_edge = mesh.to_left_from_end(edge)
+    return (
+        mesh.to_left_from_end(mesh.to_left_from_end(next_edge)) == edge
+        and orient_point_to_edge(
+            mesh, edge, mesh.to_end(next_edge), orienteer
+        )
+        is Orientation.COUNTERCLOCKWISE
+    )
+
+
+def legalize(
+    mesh: Mesh[hints.ScalarT],
+    edges: list[QuadEdge],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> None:
+    """
+    Restores the Delaunay property by flipping edges
+    starting from the given ones
+    which have inserted endpoint as the opposite vertex of the left face.
+    """
+    while edges:
+        edge = edges.pop()
+        opposite_edge = to_opposite_edge(edge)
+        if not is_triangle_face(mesh, opposite_edge, orienteer):
+            continue
+        side = mesh.to_left_from_end(opposite_edge)
+        other_side = mesh.to_left_from_end(side)
+        if (
+            locate_point_in_point_point_point_circle(
+                mesh.to_end(side),
+                mesh.to_start(edge),
+                mesh.to_end(edge),
+                mesh.to_end(mesh.to_left_from_end(edge)),
+            )
+            is Location.INTERIOR
+        ):
+            mesh.swap_diagonal(edge)
+            edges.append(side)
+            edges.append(other_side)
+
+
+def locate_endpoint(
+    mesh: Mesh[hints.ScalarT],
+    edge: QuadEdge,
+    endpoint: hints.Point[hints.ScalarT],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> tuple[EndpointLocation, QuadEdge]:
+    """
+    Locates the endpoint by walking over triangles
+    starting from the left face of the given edge,
+    which should be a triangle.
+    """
+    result = walk_to_endpoint(
+        mesh,
+        edge,
+        endpoint,
+        lambda candidate: is_triangle_face(mesh, candidate, orienteer),
+        sys.maxsize,
+        orienteer,
+    )
+    assert result is not None
+    return result
+
+
+def locate_point_by_scan(
+    mesh: Mesh[hints.ScalarT],
+    point: hints.Point[hints.ScalarT],
+    is_inner_face: Callable[[QuadEdge], bool],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> tuple[int, ...]:
+    """
+    Locates the point by checking all edges & inner faces.
+    """
+    try:
+        index = mesh.endpoints.index(point)
+    except ValueError:
+        pass
+    else:
+        return (index,)
+    for edge in mesh.to_unique_edges():
+        if (
+            orient_point_to_edge(mesh, edge, point, orienteer)
+            is Orientation.COLLINEAR
+            and (mesh.to_start(edge) < point) is (point < mesh.to_end(edge))
+        ):
+            return to_point_location(mesh, EndpointLocation.BOUNDARY, edge)
+    for edge in mesh.to_edges():
+        if not is_inner_face(edge):
+            continue
+        next_edge = mesh.to_left_from_end(edge)
+        if all(
+            orient_point_to_edge(mesh, triangle_edge, point, orienteer)
+            is Orientation.COUNTERCLOCKWISE
+            for triangle_edge in (
+                edge,
+                next_edge,
+                mesh.to_left_from_end(next_edge),
+            )
+        ):
+            return to_point_location(mesh, EndpointLocation.INTERIOR, edge)
+    return ()
+
+
 def merge(
     mesh: Mesh[hints.ScalarT],
     first_sides: tuple[QuadEdge, QuadEdge],
@@ -368,7 +619,6 @@ def merge(
     first_right_side, base_edge, second_left_side = build_base_edge(
         mesh, first_right_side, second_left_side, orienteer
     )
-    rise_bubble(mesh, base_edge, orienteer)
     left_side = (
         to_opposite_edge(base_edge)
         if mesh.to_start(first_left_side) == mesh.to_start(first_right_side)
@@ -381,6 +631,7 @@ def merge(
         )
         else second_right_side
     )
+    rise_bubble(mesh, base_edge, orienteer)
     return left_side, right_side
 
 
@@ -394,6 +645,46 @@ def orient_point_to_edge(
     return orienteer(mesh.to_start(base_edge), mesh.to_end(base_edge), point)
 
 
+def remove_vertex(
+    mesh: Mesh[hints.ScalarT],
+    edge: QuadEdge,
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> QuadEdge | None:
+    """
+    Deletes edges from the start of the given edge
+    and triangulates the resulting hole,
+    returns an edge of the remaining outer face
+    if the vertex was on the boundary.
+    """
+    star = [edge]
+    cursor = mesh.to_left_from_start(edge)
+    while cursor != edge:
+        star.append(cursor)
+        cursor = mesh.to_left_from_start(cursor)
+    gap_position = next(
+        (
+            position
+            for position, star_edge in enumerate(star)
+            if not is_triangle_face(mesh, star_edge, orienteer)
+        ),
+        None,
+    )
+    is_closed = gap_position is None
+    hole_edges = (
+        [mesh.to_left_from_end(star_edge) for star_edge in star]
+        if gap_position is None
+        else [
+            mesh.to_left_from_end(star[(gap_position + offset) % len(star)])
+            for offset in range(1, len(star))
+        ]
+    )
+    for star_edge in star:
+        mesh.delete_edge(star_edge)
+    hole_edges = fill_hole(mesh, hole_edges, is_closed, orienteer)
+    return None if is_closed else hole_edges[0]
+
+
 def rise_bubble(
     mesh: Mesh[hints.ScalarT],
     base_edge: QuadEdge,
@@ -450,3 +741,91 @@ def to_base_cases(points_count: int, /) -> tuple[int, int]:
     if rest_points == 1:
         return 2, triangles_count - 1
     return 1, triangles_count
+
+
+def to_point_location(
+    mesh: Mesh[hints.ScalarT],
+    location: EndpointLocation,
+    edge: QuadEdge,
+    /,
+) -> tuple[int, ...]:
+    """
+    Converts the endpoint location into indices of endpoints:
+    the edge's endpoints starting from the least one,
+    the triangle's vertices in counterclockwise order
+    starting from the least one,
+    the vertex' index or no indices for the exterior.
+    """
+    if location is EndpointLocation.BOUNDARY:
+        start_index, end_index = (
+            mesh.to_start_index(edge),
+            mesh.to_end_index(edge),
+        )
+        return (
+            (start_index, end_index)
+            if mesh.endpoints[start_index] < mesh.endpoints[end_index]
+            else (end_index, start_index)
+        )
+    if location is EndpointLocation.EXTERIOR:
+        return ()
+    if location is EndpointLocation.INTERIOR:
+        next_edge = mesh.to_left_from_end(edge)
+        first, second, third = (
+            mesh.to_start_index(edge),
+            mesh.to_start_index(next_edge),
+            mesh.to_end_index(next_edge),
+        )
+        endpoints = mesh.endpoints
+        if (
+            endpoints[first] < endpoints[second]
+            and endpoints[first] < endpoints[third]
+        ):
+            return first, second, third
+        if endpoints[second] < endpoints[third]:
+            return second, third, first
+        return third, first, second
+    assert location is EndpointLocation.VERTEX
+    return (mesh.to_start_index(edge),)
+
+
+def walk_to_endpoint(
+    mesh: Mesh[hints.ScalarT],
+    edge: QuadEdge,
+    endpoint: hints.Point[hints.ScalarT],
+    is_inner_face: Callable[[QuadEdge], bool],
+    steps_limit: int,
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> tuple[EndpointLocation, QuadEdge] | None:
+    """
+    Locates the endpoint by walking over inner faces
+    starting from the left face of the given edge,
+    which should be an inner triangle,
+    reports the endpoint as exterior if the walk leaves inner faces,
+    returns `None` if the walk has not finished in the given steps.
+    """
+    for _ in range(steps_limit):
+        next_edge = mesh.to_left_from_end(edge)
+        triangle_edges = (edge, next_edge, mesh.to_left_from_end(next_edge))
+        orientations = []
+        for triangle_edge in triangle_edges:
+            if mesh.to_start(triangle_edge) == endpoint:
+                return EndpointLocation.VERTEX, triangle_edge
+            orientations.append(
+                orient_point_to_edge(mesh, triangle_edge, endpoint, orienteer)
+            )
+        for triangle_edge, orientation in zip(triangle_edges, orientations):
+            if orientation is Orientation.CLOCKWISE:
+                opposite_edge = to_opposite_edge(triangle_edge)
+                if not is_inner_face(opposite_edge):
+                    return EndpointLocation.EXTERIOR, UNDEFINED_EDGE
+                edge = opposite_edge
+                break
+        else:
+            for triangle_edge, orientation in zip(
+                triangle_edges, orientations
+            ):
+                if orientation is Orientation.COLLINEAR:
+                    return EndpointLocation.BOUNDARY, triangle_edge
+            return EndpointLocation.INTERIOR, edge
+    return None
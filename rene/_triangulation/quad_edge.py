@@ -18,3 +18,7 @@ def to_rotated_edge(edge: QuadEdge, /) -> QuadEdge:
     aka "Rot" in L. Guibas and J. Stolfi notation.
     """
     return QuadEdge(((edge >> 2) << 2) + ((edge + 1) & 3))
+
+
+def to_unique_edge(edge: QuadEdge, /) -> QuadEdge:
+    return QuadEdge((edge >> 2) << 2)
@@ -0,0 +1,131 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from rene import hints
+from rene._utils import shrink_collinear_vertices
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from rene._hints import Orienteer
+
+    from .mesh import Mesh
+
+
+def to_voronoi_cells(
+    mesh: Mesh[hints.ScalarT],
+    box: hints.Box[hints.ScalarT],
+    point_cls: type[hints.Point[hints.ScalarT]],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> list[tuple[hints.Point[hints.ScalarT], list[hints.Point[hints.ScalarT]]]]:
+    sites = mesh.endpoints
+    result = []
+    for site, neighbours in zip(sites, mesh.to_endpoints_neighbours()):
+        vertices = [
+            point_cls(box.min_x, box.min_y),
+            point_cls(box.max_x, box.min_y),
+            point_cls(box.max_x, box.max_y),
+            point_cls(box.min_x, box.max_y),
+        ]
+        for neighbour_index in neighbours:
+            vertices = _clip_by_bisector(
+                vertices, site, sites[neighbour_index], point_cls
+            )
+            if not vertices:
+                break
+        if len(vertices) < MIN_CONTOUR_VERTICES_COUNT:
+            continue
+        vertices = shrink_collinear_vertices(vertices, orienteer)
+        if len(vertices) >= MIN_CONTOUR_VERTICES_COUNT:
+            result.append((site, vertices))
+    result.sort(key=lambda site_vertices: site_vertices[0])
+    return result
+
+
+def to_voronoi_vertices(
+    triangles_vertices: Sequence[
+        tuple[
+            hints.Point[hints.ScalarT],
+            hints.Point[hints.ScalarT],
+            hints.Point[hints.ScalarT],
+        ]
+    ],
+    point_cls: type[hints.Point[hints.ScalarT]],
+    /,
+) -> list[hints.Point[hints.ScalarT]]:
+    return sorted(
+        {
+            _to_circumcenter(first, second, third, point_cls)
+            for first, second, third in triangles_vertices
+        }
+    )
+
+
+def _clip_by_bisector(
+    vertices: Sequence[hints.Point[hints.ScalarT]],
+    site: hints.Point[hints.ScalarT],
+    other_site: hints.Point[hints.ScalarT],
+    point_cls: type[hints.Point[hints.ScalarT]],
+    /,
+) -> list[hints.Point[hints.ScalarT]]:
+    values = [
+        _to_bisector_value(vertex, site, other_site) for vertex in vertices
+    ]
+    result = []
+    for index, start in enumerate(vertices):
+        next_index = (index + 1) % len(vertices)
+        end = vertices[next_index]
+        start_value, end_value = values[index], values[next_index]
+        if start_value <= 0:
+            result.append(start)
+        if (start_value < 0 < end_value) or (end_value < 0 < start_value):
+            scale = start_value / (start_value - end_value)
+            result.append(
+                point_cls(
+                    start.x + (end.x - start.x) * scale,
+                    start.y + (end.y - start.y) * scale,
+                )
+            )
+    return result
+
+
+def _to_bisector_value(
+    point: hints.Point[hints.ScalarT],
+    site: hints.Point[hints.ScalarT],
+    other_site: hints.Point[hints.ScalarT],
+    /,
+) -> hints.ScalarT:
+    site_dx, site_dy = point.x - site.x, point.y - site.y
+    other_site_dx, other_site_dy = (
+        point.x - other_site.x,
+        point.y - other_site.y,
+    )
+    return (site_dx * site_dx + site_dy * site_dy) - (
+        other_site_dx * other_site_dx + other_site_dy * other_site_dy
+    )
+
+
+def _to_circumcenter(
+    first: hints.Point[hints.ScalarT],
+    second: hints.Point[hints.ScalarT],
+    third: hints.Point[hints.ScalarT],
+    point_cls: type[hints.Point[hints.ScalarT]],
+    /,
+) -> hints.Point[hints.ScalarT]:
+    second_dx, second_dy = second.x - first.x, second.y - first.y
+    third_dx, third_dy = third.x - first.x, third.y - first.y
+    second_squared_norm = second_dx * second_dx + second_dy * second_dy
+    third_squared_norm = third_dx * third_dx + third_dy * third_dy
+    half_denominator = second_dx * third_dy - second_dy * third_dx
+    denominator = half_denominator + half_denominator
+    return point_cls(
+        (third_dy * second_squared_norm - second_dy * third_squared_norm)
+        / denominator
+        + first.x,
+        (second_dx * third_squared_norm - third_dx * second_squared_norm)
+        / denominator
+        + first.y,
+    )
@@ -94,6 +94,25 @@ def ceil_log2(number: int, /) -> int:
     return number.bit_length() - (not (number & (number - 1)))
 
 
+def collect_convex_hull_vertices(
+    vertices: Sequence[hints.Point[hints.ScalarT]],
+    contour_cls: type[hints.Contour[hints.ScalarT]],
+    polygon_cls: type[hints.Polygon[hints.ScalarT]],
+    segment_cls: type[hints.Segment[hints.ScalarT]],
+    /,
+) -> (
+    hints.Point[hints.ScalarT]
+    | hints.Polygon[hints.ScalarT]
+    | hints.Segment[hints.ScalarT]
+):
+    assert len(vertices) >= 1
+    if len(vertices) == 1:
+        return vertices[0]
+    if len(vertices) == 2:
+        return segment_cls(vertices[0], vertices[1])
+    return polygon_cls(contour_cls(vertices), [])
+
+
 def collect_maybe_empty_polygons(
     polygons: Sequence[hints.Polygon[hints.ScalarT]],
     empty_cls: type[hints.Empty[hints.ScalarT]],
@@ -158,6 +177,18 @@ def cross_multiply(
     ) * (second_end.x - second_start.x)
 
 
+def dot_multiply(
+    first_start: hints.Point[hints.ScalarT],
+    first_end: hints.Point[hints.ScalarT],
+    second_start: hints.Point[hints.ScalarT],
+    second_end: hints.Point[hints.ScalarT],
+    /,
+) -> hints.ScalarT:
+    return (first_end.x - first_start.x) * (second_end.x - second_start.x) + (
+        first_end.y - first_start.y
+    ) * (second_end.y - second_start.y)
+
+
 def deduplicate(values: list[_T], /) -> list[_T]:
     return [value for value, _ in groupby(values)]
 
@@ -420,6 +451,35 @@ def to_boxes_ids_with_intersection(
     ]
 
 
+def to_contour_centroid(
+    vertices: Sequence[hints.Point[hints.ScalarT]],
+    point_cls: type[hints.Point[hints.ScalarT]],
+    /,
+) -> hints.Point[hints.ScalarT]:
+    first_vertex = vertices[0]
+    doubled_areas, x_numerators, y_numerators = [], [], []
+    for index in range(1, len(vertices) - 1):
+        vertex, next_vertex = vertices[index], vertices[index + 1]
+        triangle_doubled_area = cross_multiply(
+            first_vertex, vertex, first_vertex, next_vertex
+        )
+        doubled_areas.append(triangle_doubled_area)
+        x_numerators.append(
+            triangle_doubled_area * (first_vertex.x + vertex.x + next_vertex.x)
+        )
+        y_numerators.append(
+            triangle_doubled_area * (first_vertex.y + vertex.y + next_vertex.y)
+        )
+    doubled_area = sum(doubled_areas[1:], doubled_areas[0])
+    if not doubled_area:
+        return to_vertices_centroid(vertices, point_cls)
+    denominator = 3 * doubled_area
+    return point_cls(
+        sum(x_numerators[1:], x_numerators[0]) / denominator,
+        sum(y_numerators[1:], y_numerators[0]) / denominator,
+    )
+
+
 def to_contour_orientation(
     vertices: Sequence[hints.Point[hints.ScalarT]],
     min_vertex_index: int,
@@ -446,6 +506,19 @@ def to_contour_segments(
     return result
 
 
+def to_convex_hull(
+    points: Iterable[hints.Point[hints.ScalarT]],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> list[hints.Point[hints.ScalarT]]:
+    sorted_points = deduplicate(sorted(points))
+    if len(sorted_points) < 2:
+        return sorted_points
+    lower_hull = _to_convex_sub_hull(sorted_points, orienteer)
+    upper_hull = _to_convex_sub_hull(reversed(sorted_points), orienteer)
+    return lower_hull[:-1] + upper_hull[:-1]
+
+
 def to_oriented_segments(
     vertices: Sequence[hints.Point[hints.ScalarT]],
     target_orientation: Orientation,
@@ -478,6 +551,22 @@ def to_sorted_pair(
     return (first, second) if first < second else (second, first)
 
 
+def to_vertices_centroid(
+    vertices: Iterable[hints.Point[hints.ScalarT]],
+    point_cls: type[hints.Point[hints.ScalarT]],
+    /,
+) -> hints.Point[hints.ScalarT]:
+    vertices = iter(vertices)
+    first_vertex = next(vertices)
+    x_sum, y_sum = first_vertex.x, first_vertex.y
+    vertices_count = 1
+    for vertex in vertices:
+        x_sum += vertex.x
+        y_sum += vertex.y
+        vertices_count += 1
+    return point_cls(x_sum / vertices_count, y_sum / vertices_count)
+
+
 def validate_seed(
     seed: Any, _max_usize_value: int = (sys.maxsize << 1) + 1, /
 ) -> None:
@@ -512,3 +601,20 @@ def subtract_segments_overlap(
         if subtrahend_start < minuend_start < subtrahend_end
         else (minuend_start, subtrahend_start)
     )
+
+
+def _to_convex_sub_hull(
+    points: Iterable[hints.Point[hints.ScalarT]],
+    orienteer: Orienteer[hints.ScalarT],
+    /,
+) -> list[hints.Point[hints.ScalarT]]:
+    result: list[hints.Point[hints.ScalarT]] = []
+    for point in points:
+        while (
+            len(result) >= 2
+            and orienteer(result[-2], result[-1], point)
+            is not Orientation.COUNTERCLOCKWISE
+        ):
+            del result[-1]
+        result.append(point)
+    return result
@@ -0,0 +1,583 @@
+from __future__ import annotations
+
+from collections.abc import Callable, Iterable, Sequence
+from typing import Any, Generic, NoReturn, overload
+
+from typing_extensions import Self, final
+
+from rene import hints
+from rene._bentley_ottmann.base import Intersection, sweep
+from rene._context import Context
+from rene.constants import (
+    MIN_CONTOUR_VERTICES_COUNT,
+    MIN_MULTIPOLYGON_POLYGONS_COUNT,
+    MIN_MULTISEGMENT_SEGMENTS_COUNT,
+)
+from rene.enums import Orientation, Relation, ViolationKind
+
+
+@final
+class Violation(Generic[hints.ScalarT]):
+    @property
+    def count(self, /) -> int | None:
+        return self._count
+
+    @property
+    def end(self, /) -> hints.Point[hints.ScalarT] | None:
+        return self._end
+
+    @property
+    def first_hole_index(self, /) -> int | None:
+        return self._first_hole_index
+
+    @property
+    def first_polygon_index(self, /) -> int | None:
+        return self._first_polygon_index
+
+    @property
+    def first_segment_index(self, /) -> int | None:
+        return self._first_segment_index
+
+    @property
+    def hole_index(self, /) -> int | None:
+        return self._hole_index
+
+    @property
+    def kind(self, /) -> ViolationKind:
+        return self._kind
+
+    @property
+    def point(self, /) -> hints.Point[hints.ScalarT] | None:
+        return self._point
+
+    @property
+    def polygon_index(self, /) -> int | None:
+        return self._polygon_index
+
+    @property
+    def second_hole_index(self, /) -> int | None:
+        return self._second_hole_index
+
+    @property
+    def second_polygon_index(self, /) -> int | None:
+        return self._second_polygon_index
+
+    @property
+    def second_segment_index(self, /) -> int | None:
+        return self._second_segment_index
+
+    @property
+    def segment_index(self, /) -> int | None:
+        return self._segment_index
+
+    @property
+    def start(self, /) -> hints.Point[hints.ScalarT] | None:
+        return self._start
+
+    @property
+    def vertex_index(self, /) -> int | None:
+        return self._vertex_index
+
+    @property
+    def violation(self, /) -> Violation[hints.ScalarT] | None:
+        return self._violation
+
+    _count: int | None
+    _end: hints.Point[hints.ScalarT] | None
+    _first_hole_index: int | None
+    _first_polygon_index: int | None
+    _first_segment_index: int | None
+    _hole_index: int | None
+    _kind: ViolationKind
+    _point: hints.Point[hints.ScalarT] | None
+    _polygon_index: int | None
+    _second_hole_index: int | None
+    _second_polygon_index: int | None
+    _second_segment_index: int | None
+    _segment_index: int | None
+    _start: hints.Point[hints.ScalarT] | None
+    _vertex_index: int | None
+    _violation: Violation[hints.ScalarT] | None
+
+    __module__ = 'rene.exact'
+    __slots__ = (
+        '_count',
+        '_end',
+        '_first_hole_index',
+        '_first_polygon_index',
+        '_first_segment_index',
+        '_hole_index',
+        '_kind',
+        '_point',
+        '_polygon_index',
+        '_second_hole_index',
+        '_second_polygon_index',
+        '_second_segment_index',
+        '_segment_index',
+        '_start',
+        '_vertex_index',
+        '_violation',
+    )
+
+    def __init_subclass__(cls, /) -> NoReturn:
+        raise TypeError(
+            f'type {cls.__qualname__!r} is not an acceptable base type'
+        )
+
+    def __new__(
+        cls,
+        kind: ViolationKind,
+        /,
+        *,
+        count: int | None = None,
+        end: hints.Point[hints.ScalarT] | None = None,
+        first_hole_index: int | None = None,
+        first_polygon_index: int | None = None,
+        first_segment_index: int | None = None,
+        hole_index: int | None = None,
+        point: hints.Point[hints.ScalarT] | None = None,
+        polygon_index: int | None = None,
+        second_hole_index: int | None = None,
+        second_polygon_index: int | None = None,
+        second_segment_index: int | None = None,
+        segment_index: int | None = None,
+        start: hints.Point[hints.ScalarT] | None = None,
+        vertex_index: int | None = None,
+        violation: Violation[hints.ScalarT] | None = None,
+    ) -> Self:
+        self = super().__new__(cls)
+        self._count, self._end = count, end
+        self._first_hole_index = first_hole_index
+        self._first_polygon_index = first_polygon_index
+        self._first_segment_index = first_segment_index
+        self._hole_index, self._kind, self._point = hole_index, kind, point
+        self._polygon_index = polygon_index
+        self._second_hole_index = second_hole_index
+        self._second_polygon_index = second_polygon_index
+        self._second_segment_index = second_segment_index
+        self._segment_index, self._start = segment_index, start
+        self._vertex_index, self._violation = vertex_index, violation
+        return self
+
+    @overload
+    def __eq__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __eq__(self, other: Any, /) -> Any: ...
+
+    def __eq__(self, other: Any, /) -> Any:
+        return (
+            self._to_fields() == other._to_fields()
+            if isinstance(other, Violation)
+            else NotImplemented
+        )
+
+    def __repr__(self, /) -> str:
+        return (
+            f'{type(self).__qualname__}({self._kind!r}'
+            + ''.join(
+                f', {name}={value!r}'
+                for name, value in self._to_fields().items()
+                if value is not None and name != 'kind'
+            )
+            + ')'
+        )
+
+    def __str__(self, /) -> str:
+        return _VIOLATIONS_MESSAGES_TEMPLATES[self._kind].format(
+            **self._to_fields()
+        )
+
+    def _to_fields(self, /) -> dict[str, Any]:
+        return {
+            'count': self._count,
+            'end': self._end,
+            'first_hole_index': self._first_hole_index,
+            'first_polygon_index': self._first_polygon_index,
+            'first_segment_index': self._first_segment_index,
+            'hole_index': self._hole_index,
+            'kind': self._kind,
+            'point': self._point,
+            'polygon_index': self._polygon_index,
+            'second_hole_index': self._second_hole_index,
+            'second_polygon_index': self._second_polygon_index,
+            'second_segment_index': self._second_segment_index,
+            'segment_index': self._segment_index,
+            'start': self._start,
+            'vertex_index': self._vertex_index,
+            'violation': self._violation,
+        }
+
+
+def ensure_valid(
+    violations: list[Violation[hints.ScalarT]], geometry_name: str, /
+) -> None:
+    if violations:
+        raise ValueError(f'Invalid {geometry_name}: {violations[0]}.')
+
+
+def is_multipolygon_valid(
+    multipolygon: hints.Multipolygon[hints.ScalarT], /
+) -> bool:
+    return not validate_multipolygon(multipolygon)
+
+
+def is_polygon_valid(
+    polygon: hints.Polygon[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> bool:
+    return not validate_polygon(polygon, context=context)
+
+
+def validate_contour(
+    contour: hints.Contour[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[Violation[hints.ScalarT]]:
+    vertices = contour.vertices
+    if len(vertices) < MIN_CONTOUR_VERTICES_COUNT:
+        return [Violation(ViolationKind.TOO_FEW_VERTICES, count=len(vertices))]
+    result = [
+        Violation(ViolationKind.COLLINEAR_VERTICES, vertex_index=vertex_index)
+        for vertex_index in range(len(vertices))
+        if context.orient(
+            vertices[vertex_index - 1],
+            vertices[vertex_index],
+            vertices[(vertex_index + 1) % len(vertices)],
+        )
+        is Orientation.COLLINEAR
+    ]
+    segments = contour.segments
+    if _push_degenerate_segments_violations(segments, result):
+        last_segment_index = len(segments) - 1
+
+        def is_allowed(intersection: Intersection[hints.ScalarT], /) -> bool:
+            first_index, second_index = sorted(
+                (intersection.first_segment_id, intersection.second_segment_id)
+            )
+            return (
+                intersection.relation is Relation.TOUCH
+                and (
+                    second_index - first_index == 1
+                    or (
+                        first_index == 0 and second_index == last_segment_index
+                    )
+                )
+                and all(
+                    intersection.start in (segment.start, segment.end)
+                    for segment in (
+                        segments[first_index],
+                        segments[second_index],
+                    )
+                )
+            )
+
+        result.extend(
+            _to_intersections_violations(
+                sweep(
+                    segments,
+                    orienteer=context.orient,
+                    segments_intersector=context.intersect_segments,
+                ),
+                is_allowed,
+            )
+        )
+    return result
+
+
+def validate_multipolygon(
+    multipolygon: hints.Multipolygon[hints.ScalarT], /
+) -> list[Violation[hints.ScalarT]]:
+    polygons = multipolygon.polygons
+    result = (
+        [Violation(ViolationKind.TOO_FEW_POLYGONS, count=len(polygons))]
+        if len(polygons) < MIN_MULTIPOLYGON_POLYGONS_COUNT
+        else []
+    )
+    valid_polygons = []
+    for polygon_index, polygon in enumerate(polygons):
+        polygon_violations = polygon.validate()
+        if polygon_violations:
+            result.extend(
+                Violation(
+                    ViolationKind.INVALID_POLYGON,
+                    polygon_index=polygon_index,
+                    violation=violation,
+                )
+                for violation in polygon_violations
+            )
+        else:
+            valid_polygons.append((polygon_index, polygon))
+    for position, (first_polygon_index, first_polygon) in enumerate(
+        valid_polygons
+    ):
+        for second_polygon_index, second_polygon in valid_polygons[
+            position + 1 :
+        ]:
+            if first_polygon.relate_to(second_polygon) not in (
+                Relation.DISJOINT,
+                Relation.TOUCH,
+            ):
+                result.append(
+                    Violation(
+                        ViolationKind.POLYGONS_OVERLAP,
+                        first_polygon_index=first_polygon_index,
+                        second_polygon_index=second_polygon_index,
+                    )
+                )
+    return result
+
+
+def validate_multisegment(
+    multisegment: hints.Multisegment[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[Violation[hints.ScalarT]]:
+    segments = multisegment.segments
+    result = (
+        [Violation(ViolationKind.TOO_FEW_SEGMENTS, count=len(segments))]
+        if len(segments) < MIN_MULTISEGMENT_SEGMENTS_COUNT
+        else []
+    )
+    if segments and _push_degenerate_segments_violations(segments, result):
+        result.extend(
+            _to_intersections_violations(
+                sweep(
+                    segments,
+                    orienteer=context.orient,
+                    segments_intersector=context.intersect_segments,
+                ),
+                lambda intersection: intersection.relation is Relation.TOUCH,
+            )
+        )
+    return result
+
+
+def validate_polygon(
+    polygon: hints.Polygon[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> list[Violation[hints.ScalarT]]:
+    result: list[Violation[hints.ScalarT]] = []
+    border = polygon.border
+    border_violations = border.validate()
+    if border_violations:
+        result.extend(
+            Violation(ViolationKind.INVALID_BORDER, violation=violation)
+            for violation in border_violations
+        )
+    elif border.orientation is not Orientation.COUNTERCLOCKWISE:
+        result.append(Violation(ViolationKind.WRONG_BORDER_ORIENTATION))
+    valid_holes = []
+    for hole_index, hole in enumerate(polygon.holes):
+        hole_violations = hole.validate()
+        if hole_violations:
+            result.extend(
+                Violation(
+                    ViolationKind.INVALID_HOLE,
+                    hole_index=hole_index,
+                    violation=violation,
+                )
+                for violation in hole_violations
+            )
+        else:
+            if hole.orientation is not Orientation.CLOCKWISE:
+                result.append(
+                    Violation(
+                        ViolationKind.WRONG_HOLE_ORIENTATION,
+                        hole_index=hole_index,
+                    )
+                )
+            valid_holes.append(
+                (hole_index, hole, _to_region(hole, context=context))
+            )
+    if not border_violations:
+        border_region = _to_region(border, context=context)
+        for hole_index, hole, hole_region in valid_holes:
+            relation = hole_region.relate_to(border_region)
+            if relation is Relation.ENCLOSED:
+                if hole.relate_to(border) is not Relation.TOUCH:
+                    result.append(
+                        Violation(
+                            ViolationKind.HOLE_TOUCHES_BORDER,
+                            hole_index=hole_index,
+                        )
+                    )
+            elif relation is not Relation.WITHIN:
+                result.append(
+                    Violation(
+                        ViolationKind.HOLE_OUTSIDE_BORDER,
+                        hole_index=hole_index,
+                    )
+                )
+    for position, (first_hole_index, _, first_hole_region) in enumerate(
+        valid_holes
+    ):
+        for second_hole_index, _, second_hole_region in valid_holes[
+            position + 1 :
+        ]:
+            if first_hole_region.relate_to(second_hole_region) not in (
+                Relation.DISJOINT,
+                Relation.TOUCH,
+            ):
+                result.append(
+                    Violation(
+                        ViolationKind.HOLES_OVERLAP,
+                        first_hole_index=first_hole_index,
+                        second_hole_index=second_hole_index,
+                    )
+                )
+    return result
+
+
+def _push_degenerate_segments_violations(
+    segments: Sequence[hints.Segment[hints.ScalarT]],
+    result: list[Violation[hints.ScalarT]],
+    /,
+) -> bool:
+    are_non_degenerate = True
+    for segment_index, segment in enumerate(segments):
+        if segment.start == segment.end:
+            result.append(
+                Violation(
+                    ViolationKind.DEGENERATE_SEGMENT,
+                    segment_index=segment_index,
+                )
+            )
+            are_non_degenerate = False
+    return are_non_degenerate
+
+
+def _to_intersections_violations(
+    intersections: Iterable[Intersection[hints.ScalarT]],
+    is_allowed: Callable[[Intersection[hints.ScalarT]], bool],
+    /,
+) -> list[Violation[hints.ScalarT]]:
+    violations: dict[tuple[int, int], Violation[hints.ScalarT]] = {}
+    for intersection in intersections:
+        if (
+            intersection.first_segment_id == intersection.second_segment_id
+            or is_allowed(intersection)
+        ):
+            continue
+        first_segment_index, second_segment_index = sorted(
+            (intersection.first_segment_id, intersection.second_segment_id)
+        )
+        key = first_segment_index, second_segment_index
+        if key in violations:
+            continue
+        relation = intersection.relation
+        violations[key] = (
+            Violation(
+                ViolationKind.SEGMENTS_CROSS,
+                first_segment_index=first_segment_index,
+                second_segment_index=second_segment_index,
+                point=intersection.start,
+            )
+            if relation is Relation.CROSS
+            else (
+                Violation(
+                    ViolationKind.SEGMENTS_TOUCH,
+                    first_segment_index=first_segment_index,
+                    second_segment_index=second_segment_index,
+                    point=intersection.start,
+                )
+                if relation is Relation.TOUCH
+                else Violation(
+                    ViolationKind.SEGMENTS_OVERLAP,
+                    first_segment_index=first_segment_index,
+                    second_segment_index=second_segment_index,
+                    start=intersection.start,
+                    end=intersection.end,
+                )
+            )
+        )
+    return [violations[key] for key in sorted(violations)]
+
+
+def _to_oriented_contour(
+    contour: hints.Contour[hints.ScalarT],
+    orientation: Orientation,
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Contour[hints.ScalarT]:
+    vertices = contour.vertices
+    return context.contour_cls(
+        vertices[::-1] if contour.orientation is not orientation else vertices
+    )
+
+
+def _to_region(
+    contour: hints.Contour[hints.ScalarT],
+    /,
+    *,
+    context: Context[hints.ScalarT],
+) -> hints.Polygon[hints.ScalarT]:
+    return context.polygon_cls(
+        _to_oriented_contour(
+            contour, Orientation.COUNTERCLOCKWISE, context=context
+        ),
+        [],
+    )
+
+
+_VIOLATIONS_MESSAGES_TEMPLATES = {
+    ViolationKind.COLLINEAR_VERTICES: (
+        'vertex {vertex_index} is collinear with its neighbours'
+    ),
+    ViolationKind.DEGENERATE_SEGMENT: 'segment {segment_index} is degenerate',
+    ViolationKind.HOLE_OUTSIDE_BORDER: (
+        'hole {hole_index} lies not in the interior of the border'
+    ),
+    ViolationKind.HOLE_TOUCHES_BORDER: (
+        'hole {hole_index} touches the border along a segment'
+    ),
+    ViolationKind.HOLES_OVERLAP: (
+        'holes {first_hole_index} and {second_hole_index} overlap'
+    ),
+    ViolationKind.INVALID_BORDER: 'border is invalid: {violation}',
+    ViolationKind.INVALID_HOLE: 'hole {hole_index} is invalid: {violation}',
+    ViolationKind.INVALID_POLYGON: (
+        'polygon {polygon_index} is invalid: {violation}'
+    ),
+    ViolationKind.POLYGONS_OVERLAP: (
+        'polygons {first_polygon_index} and {second_polygon_index} overlap'
+    ),
+    ViolationKind.SEGMENTS_CROSS: (
+        'segments {first_segment_index} and {second_segment_index} '
+        'cross at {point}'
+    ),
+    ViolationKind.SEGMENTS_OVERLAP: (
+        'segments {first_segment_index} and {second_segment_index} '
+        'overlap from {start} to {end}'
+    ),
+    ViolationKind.SEGMENTS_TOUCH: (
+        'segments {first_segment_index} and {second_segment_index} '
+        'touch at {point}'
+    ),
+    ViolationKind.TOO_FEW_POLYGONS: (
+        f'expected at least {MIN_MULTIPOLYGON_POLYGONS_COUNT} polygons, '
+        'but found {count}'
+    ),
+    ViolationKind.TOO_FEW_SEGMENTS: (
+        f'expected at least {MIN_MULTISEGMENT_SEGMENTS_COUNT} segments, '
+        'but found {count}'
+    ),
+    ViolationKind.TOO_FEW_VERTICES: (
+        f'expected at least {MIN_CONTOUR_VERTICES_COUNT} vertices, '
+        'but found {count}'
+    ),
+    ViolationKind.WRONG_BORDER_ORIENTATION: (
+        'border is not oriented counterclockwise'
+    ),
+    ViolationKind.WRONG_HOLE_ORIENTATION: (
+        'hole {hole_index} is not oriented clockwise'
+    ),
+}
+
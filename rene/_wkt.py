@@ -0,0 +1,408 @@
+from __future__ import annotations
+
+import re
+from collections.abc import Callable, Iterable, Sequence
+from typing import TypeVar
+
+from rithm.fraction import Fraction
+
+from rene import hints
+
+_T = TypeVar('_T')
+_ContourT = TypeVar('_ContourT', bound=hints.Contour[Fraction])
+_EmptyT = TypeVar('_EmptyT', bound=hints.Empty[Fraction])
+_MultipolygonT = TypeVar('_MultipolygonT', bound=hints.Multipolygon[Fraction])
+_MultisegmentT = TypeVar('_MultisegmentT', bound=hints.Multisegment[Fraction])
+_PointT = TypeVar('_PointT', bound=hints.Point[Fraction])
+_PolygonT = TypeVar('_PolygonT', bound=hints.Polygon[Fraction])
+_SegmentT = TypeVar('_SegmentT', bound=hints.Segment[Fraction])
+
+_Coordinates = tuple[Fraction, Fraction]
+
+_DECIMAL_PATTERN = re.compile(
+    r'(?P<sign>[+-]?)'
+    r'(?P<integer>\d*)(?:\.(?P<fractional>\d*))?'
+    r'(?:[eE](?P<exponent>[+-]?\d+))?'
+)
+_MAX_EXPONENT_MAGNITUDE = 4096
+_DIMENSIONS = frozenset(('M', 'Z', 'ZM'))
+_COORDINATE_CHARACTERS = frozenset('0123456789+-.eE')
+_EMPTY_TAGS = (
+    'GEOMETRYCOLLECTION',
+    'LINEARRING',
+    'LINESTRING',
+    'MULTILINESTRING',
+    'MULTIPOINT',
+    'MULTIPOLYGON',
+    'POINT',
+    'POLYGON',
+)
+_MIN_LINE_STRING_POINTS_COUNT = 2
+_MIN_RING_POINTS_COUNT = 4
+
+
+def contour_from_wkt(
+    wkt: str,
+    contour_cls: type[_ContourT],
+    point_cls: type[hints.Point[Fraction]],
+    /,
+) -> _ContourT:
+    parser = _Parser(wkt)
+    parser.parse_tag('LINEARRING', 'LINESTRING')
+    result = contour_cls(
+        [point_cls(x, y) for x, y in parser.parse_ring_text()]
+    )
+    parser.finish()
+    return result
+
+
+def contour_to_wkt(contour: hints.Contour[Fraction], /) -> str:
+    return 'LINEARRING ' + _ring_to_wkt_text(contour.vertices)
+
+
+def empty_from_wkt(wkt: str, empty_cls: type[_EmptyT], /) -> _EmptyT:
+    parser = _Parser(wkt)
+    parser.parse_tag(*_EMPTY_TAGS)
+    parser.expect_empty()
+    parser.finish()
+    return empty_cls()
+
+
+def empty_to_wkt(_empty: hints.Empty[Fraction], /) -> str:
+    return 'GEOMETRYCOLLECTION EMPTY'
+
+
+def multipolygon_from_wkt(
+    wkt: str,
+    contour_cls: type[hints.Contour[Fraction]],
+    multipolygon_cls: type[_MultipolygonT],
+    point_cls: type[hints.Point[Fraction]],
+    polygon_cls: type[hints.Polygon[Fraction]],
+    /,
+) -> _MultipolygonT:
+    parser = _Parser(wkt)
+    parser.parse_tag('MULTIPOLYGON')
+    result = multipolygon_cls(
+        [
+            _to_polygon(rings, contour_cls, point_cls, polygon_cls)
+            for rings in parser.parse_list(_Parser.parse_polygon_text)
+        ]
+    )
+    parser.finish()
+    return result
+
+
+def multipolygon_to_wkt(multipolygon: hints.Multipolygon[Fraction], /) -> str:
+    return (
+        'MULTIPOLYGON ('
+        + ', '.join(
+            _polygon_to_wkt_text(polygon) for polygon in multipolygon.polygons
+        )
+        + ')'
+    )
+
+
+def multisegment_from_wkt(
+    wkt: str,
+    multisegment_cls: type[_MultisegmentT],
+    point_cls: type[hints.Point[Fraction]],
+    segment_cls: type[hints.Segment[Fraction]],
+    /,
+) -> _MultisegmentT:
+    parser = _Parser(wkt)
+    parser.parse_tag('MULTILINESTRING')
+    line_strings = parser.parse_list(_Parser.parse_line_string_text)
+    parser.finish()
+    return multisegment_cls(
+        [
+            segment_cls(point_cls(*start), point_cls(*end))
+            for points in line_strings
+            for start, end in zip(points, points[1:])
+        ]
+    )
+
+
+def multisegment_to_wkt(multisegment: hints.Multisegment[Fraction], /) -> str:
+    return (
+        'MULTILINESTRING ('
+        + ', '.join(
+            _points_to_wkt_text((segment.start, segment.end))
+            for segment in multisegment.segments
+        )
+        + ')'
+    )
+
+
+def point_from_wkt(wkt: str, point_cls: type[_PointT], /) -> _PointT:
+    parser = _Parser(wkt)
+    parser.parse_tag('POINT')
+    parser.expect_character('(')
+    x, y = parser.parse_coordinates()
+    parser.expect_character(')')
+    parser.finish()
+    return point_cls(x, y)
+
+
+def point_to_wkt(point: hints.Point[Fraction], /) -> str:
+    return 'POINT (' + _point_to_wkt(point) + ')'
+
+
+def polygon_from_wkt(
+    wkt: str,
+    contour_cls: type[hints.Contour[Fraction]],
+    point_cls: type[hints.Point[Fraction]],
+    polygon_cls: type[_PolygonT],
+    /,
+) -> _PolygonT:
+    parser = _Parser(wkt)
+    parser.parse_tag('POLYGON')
+    result = _to_polygon(
+        parser.parse_polygon_text(), contour_cls, point_cls, polygon_cls
+    )
+    parser.finish()
+    return result
+
+
+def polygon_to_wkt(polygon: hints.Polygon[Fraction], /) -> str:
+    return 'POLYGON ' + _polygon_to_wkt_text(polygon)
+
+
+def segment_from_wkt(
+    wkt: str,
+    point_cls: type[hints.Point[Fraction]],
+    segment_cls: type[_SegmentT],
+    /,
+) -> _SegmentT:
+    parser = _Parser(wkt)
+    parser.parse_tag('LINESTRING')
+    start, end = parser.parse_segment_text()
+    parser.finish()
+    return segment_cls(point_cls(*start), point_cls(*end))
+
+
+def segment_to_wkt(segment: hints.Segment[Fraction], /) -> str:
+    return 'LINESTRING ' + _points_to_wkt_text((segment.start, segment.end))
+
+
+class _Parser:
+    def __init__(self, text: str, /) -> None:
+        self._text, self._position = text, 0
+
+    def expect_character(self, character: str, /) -> None:
+        self._skip_whitespaces()
+        if not self._text.startswith(character, self._position):
+            raise self._to_unexpected_character_error()
+        self._position += len(character)
+
+    def expect_empty(self, /) -> None:
+        self._skip_whitespaces()
+        start = self._position
+        if self._parse_word().upper() != 'EMPTY':
+            self._position = start
+            raise self._to_unexpected_character_error()
+
+    def finish(self, /) -> None:
+        self._skip_whitespaces()
+        if self._position != len(self._text):
+            raise _to_unexpected_character_error(self._position)
+
+    def parse_coordinates(self, /) -> _Coordinates:
+        return self._parse_coordinate(), self._parse_coordinate()
+
+    def parse_line_string_text(self, /) -> list[_Coordinates]:
+        self._skip_whitespaces()
+        start = self._position
+        result = self.parse_list(_Parser.parse_coordinates)
+        if len(result) < _MIN_LINE_STRING_POINTS_COUNT:
+            raise _to_invalid_geometry_error(
+                start, 'line string should have at least 2 points'
+            )
+        return result
+
+    def parse_list(self, parse_item: Callable[[_Parser], _T], /) -> list[_T]:
+        self.expect_character('(')
+        result = [parse_item(self)]
+        while self._parse_separator():
+            result.append(parse_item(self))
+        return result
+
+    def parse_polygon_text(self, /) -> list[list[_Coordinates]]:
+        return self.parse_list(_Parser.parse_ring_text)
+
+    def parse_ring_text(self, /) -> list[_Coordinates]:
+        self._skip_whitespaces()
+        start = self._position
+        result = self.parse_list(_Parser.parse_coordinates)
+        if len(result) < _MIN_RING_POINTS_COUNT or result[0] != result[-1]:
+            raise _to_invalid_geometry_error(
+                start, 'ring should be closed and have at least 4 points'
+            )
+        del result[-1]
+        return result
+
+    def parse_segment_text(self, /) -> tuple[_Coordinates, _Coordinates]:
+        self._skip_whitespaces()
+        start = self._position
+        result = self.parse_list(_Parser.parse_coordinates)
+        if len(result) != 2:
+            raise _to_invalid_geometry_error(
+                start, 'segment should have exactly 2 points'
+            )
+        return result[0], result[1]
+
+    def parse_tag(self, *tags: str) -> int:
+        self._skip_whitespaces()
+        start = self._position
+        word = self._parse_word()
+        if not word:
+            raise self._to_unexpected_character_error()
+        try:
+            result = tags.index(word.upper())
+        except ValueError:
+            raise ValueError(
+                f'Unexpected geometry tag at position {start}.'
+            ) from None
+        self._skip_whitespaces()
+        dimension_start = self._position
+        if self._parse_word().upper() in _DIMENSIONS:
+            raise _to_invalid_geometry_error(
+                dimension_start,
+                'only two-dimensional coordinates are supported',
+            )
+        self._position = dimension_start
+        return result
+
+    def _parse_coordinate(self, /) -> Fraction:
+        self._skip_whitespaces()
+        start = end = self._position
+        while (
+            end < len(self._text) and self._text[end] in _COORDINATE_CHARACTERS
+        ):
+            end += 1
+        if end == start:
+            raise self._to_unexpected_character_error()
+        self._position = end
+        match = _DECIMAL_PATTERN.fullmatch(self._text, start, end)
+        if match is None or not (
+            match.group('integer') or match.group('fractional')
+        ):
+            raise ValueError(f'Invalid coordinate at position {start}.')
+        explicit_exponent = int(match.group('exponent') or 0)
+        if abs(explicit_exponent) > _MAX_EXPONENT_MAGNITUDE:
+            raise ValueError(f'Invalid coordinate at position {start}.')
+        fractional_digits = match.group('fractional') or ''
+        numerator = int((match.group('integer') or '') + fractional_digits)
+        if match.group('sign') == '-':
+            numerator = -numerator
+        exponent = explicit_exponent - len(fractional_digits)
+        return (
+            Fraction(numerator, 10**-exponent)
+            if exponent < 0
+            else Fraction(numerator * 10**exponent)
+        )
+
+    def _parse_separator(self, /) -> bool:
+        self._skip_whitespaces()
+        if self._text.startswith(',', self._position):
+            self._position += 1
+            return True
+        if self._text.startswith(')', self._position):
+            self._position += 1
+            return False
+        raise self._to_unexpected_character_error()
+
+    def _parse_word(self, /) -> str:
+        start = end = self._position
+        while (
+            end < len(self._text)
+            and self._text[end].isascii()
+            and self._text[end].isalpha()
+        ):
+            end += 1
+        self._position = end
+        return self._text[start:end]
+
+    def _skip_whitespaces(self, /) -> None:
+        self._position = len(self._text) - len(
+            self._text[self._position :].lstrip()
+        )
+
+    def _to_unexpected_character_error(self, /) -> ValueError:
+        return (
+            ValueError('Unexpected end of well-known text.')
+            if self._position == len(self._text)
+            else _to_unexpected_character_error(self._position)
+        )
+
+
+def _point_to_wkt(point: hints.Point[Fraction], /) -> str:
+    return f'{_scalar_to_decimal(point.x)} {_scalar_to_decimal(point.y)}'
+
+
+def _points_to_wkt_text(points: Iterable[hints.Point[Fraction]], /) -> str:
+    return '(' + ', '.join(_point_to_wkt(point) for point in points) + ')'
+
+
+def _polygon_to_wkt_text(polygon: hints.Polygon[Fraction], /) -> str:
+    return (
+        '('
+        + ', '.join(
+            _ring_to_wkt_text(contour.vertices)
+            for contour in (polygon.border, *polygon.holes)
+        )
+        + ')'
+    )
+
+
+def _ring_to_wkt_text(vertices: Sequence[hints.Point[Fraction]], /) -> str:
+    return _points_to_wkt_text((*vertices, vertices[0]))
+
+
+def _scalar_to_decimal(value: Fraction, /) -> str:
+    numerator, denominator = int(value.numerator), int(value.denominator)
+    twos_count = fives_count = 0
+    while denominator % 2 == 0:
+        denominator //= 2
+        twos_count += 1
+    while denominator % 5 == 0:
+        denominator //= 5
+        fives_count += 1
+    if denominator != 1:
+        raise ValueError('Coordinate has no finite decimal representation.')
+    fractional_digits_count = max(twos_count, fives_count)
+    digits = str(
+        abs(numerator)
+        * (
+            2 ** (fives_count - twos_count)
+            if twos_count < fives_count
+            else 5 ** (twos_count - fives_count)
+        )
+    ).rjust(fractional_digits_count + 1, '0')
+    integer_digits = digits[: len(digits) - fractional_digits_count]
+    fractional_digits = digits[len(digits) - fractional_digits_count :]
+    return (
+        ('-' if numerator < 0 else '')
+        + integer_digits
+        + ('.' + fractional_digits if fractional_digits else '')
+    )
+
+
+def _to_invalid_geometry_error(position: int, reason: str, /) -> ValueError:
+    return ValueError(f'Invalid geometry at position {position}: {reason}.')
+
+
+def _to_polygon(
+    rings: list[list[_Coordinates]],
+    contour_cls: type[hints.Contour[Fraction]],
+    point_cls: type[hints.Point[Fraction]],
+    polygon_cls: type[_PolygonT],
+    /,
+) -> _PolygonT:
+    border, *holes = [
+        contour_cls([point_cls(x, y) for x, y in ring]) for ring in rings
+    ]
+    return polygon_cls(border, holes)
+
+
+def _to_unexpected_character_error(position: int, /) -> ValueError:
+    return ValueError(f'Unexpected character at position {position}.')
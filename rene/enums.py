@@ -7,6 +7,23 @@ if TYPE_CHECKING:
 
     from typing_extensions import Self, final
 
+    @final
+    class FillRule:
+        EVEN_ODD: ClassVar[Self]
+        NON_ZERO: ClassVar[Self]
+
+        @overload
+        def __eq__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __repr__(self, /) -> str: ...
+
+        def __str__(self, /) -> str: ...
+
     @final
     class Location:
         BOUNDARY: ClassVar[Self]
@@ -72,12 +89,52 @@ if TYPE_CHECKING:
 
         def __str__(self, /) -> str: ...
 
+    @final
+    class ViolationKind:
+        COLLINEAR_VERTICES: ClassVar[Self]
+        DEGENERATE_SEGMENT: ClassVar[Self]
+        HOLE_OUTSIDE_BORDER: ClassVar[Self]
+        HOLE_TOUCHES_BORDER: ClassVar[Self]
+        HOLES_OVERLAP: ClassVar[Self]
+        INVALID_BORDER: ClassVar[Self]
+        INVALID_HOLE: ClassVar[Self]
+        INVALID_POLYGON: ClassVar[Self]
+        POLYGONS_OVERLAP: ClassVar[Self]
+        SEGMENTS_CROSS: ClassVar[Self]
+        SEGMENTS_OVERLAP: ClassVar[Self]
+        SEGMENTS_TOUCH: ClassVar[Self]
+        TOO_FEW_POLYGONS: ClassVar[Self]
+        TOO_FEW_SEGMENTS: ClassVar[Self]
+        TOO_FEW_VERTICES: ClassVar[Self]
+        WRONG_BORDER_ORIENTATION: ClassVar[Self]
+        WRONG_HOLE_ORIENTATION: ClassVar[Self]
+
+        @overload
+        def __eq__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __repr__(self, /) -> str: ...
+
+        def __str__(self, /) -> str: ...
+
 else:
     try:
         from . import _crene
     except ImportError:
-        from ._enums import Location, Orientation, Relation
+        from ._enums import (
+            FillRule,
+            Location,
+            Orientation,
+            Relation,
+            ViolationKind,
+        )
     else:
+        FillRule = _crene.FillRule
         Location = _crene.Location
         Orientation = _crene.Orientation
         Relation = _crene.Relation
+        ViolationKind = _crene.ViolationKind
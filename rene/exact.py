@@ -11,13 +11,18 @@ if TYPE_CHECKING:
     from typing_extensions import Self, final
 
     from .enums import (
+        FillRule as _FillRule,
         Location as _Location,
         Orientation as _Orientation,
         Relation as _Relation,
+        ViolationKind as _ViolationKind,
     )
     from .hints import Seeder as _Seeder
 
     _Scalar = _Fraction | _Rational | float | int
+    _AffineMatrix = tuple[
+        tuple[_Scalar, _Scalar, _Scalar], tuple[_Scalar, _Scalar, _Scalar]
+    ]
 
     class Box:
         @property
@@ -32,6 +37,8 @@ if TYPE_CHECKING:
         @property
         def min_y(self, /) -> _Fraction: ...
 
+        def convex_hull(self, /) -> Point | Polygon | Segment: ...
+
         def covers(self, other: Self, /) -> bool: ...
 
         def disjoint_with(self, other: Self, /) -> bool: ...
@@ -48,8 +55,14 @@ if TYPE_CHECKING:
 
         def relate_to(self, other: Self, /) -> _Relation: ...
 
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
         def touches(self, other: Self, /) -> bool: ...
 
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
         def within(self, other: Self, /) -> bool: ...
 
         def __new__(
@@ -76,25 +89,89 @@ if TYPE_CHECKING:
         def __str__(self, /) -> str: ...
 
     class Contour:
+        @property
+        def area(self, /) -> _Fraction: ...
+
         @property
         def bounding_box(self, /) -> Box: ...
 
+        @property
+        def centroid(self, /) -> Point: ...
+
         @property
         def orientation(self, /) -> _Orientation: ...
 
         @property
         def segments(self, /) -> Sequence[Segment]: ...
 
+        @property
+        def signed_area(self, /) -> _Fraction: ...
+
         @property
         def vertices(self, /) -> Sequence[Point]: ...
 
+        @property
+        def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+        @classmethod
+        def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Point | Polygon | Segment: ...
+
+        def discrete_hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[_Fraction, Point, Point]: ...
+
+        def distance_squared(self, other: _Metrizable, /) -> _Fraction: ...
+
+        def hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[
+            QuadraticSurd,
+            tuple[QuadraticSurd, QuadraticSurd],
+            tuple[QuadraticSurd, QuadraticSurd],
+        ]: ...
+
         def is_valid(self, /) -> bool: ...
 
         def locate(self, point: Point, /) -> _Location: ...
 
+        def make_valid(
+            self, fill_rule: _FillRule, /
+        ) -> Empty | Multipolygon | Polygon: ...
+
+        def nearest_points(
+            self, other: _Metrizable, /
+        ) -> tuple[Point, Point]: ...
+
+        def normalize(self, /) -> Self: ...
+
+        def relate_matrix(self, other: _Compound, /) -> str: ...
+
+        def relate_pattern(
+            self, other: _Compound, pattern: str, /
+        ) -> bool: ...
+
         def relate_to(self, other: _Compound, /) -> _Relation: ...
 
-        def __new__(cls, vertices: Sequence[Point], /) -> Self: ...
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def validate(self, /) -> list[Violation]: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
+        def __new__(
+            cls, vertices: Sequence[Point], /, *, validate: bool = False
+        ) -> Self: ...
 
         @overload
         def __and__(self, other: Empty, /) -> Empty: ...
@@ -167,10 +244,31 @@ if TYPE_CHECKING:
         ) -> Empty | Multisegment | Segment | Self: ...
 
     class Empty:
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Self: ...
+
         def locate(self, point: Point, /) -> _Location: ...
 
+        def relate_matrix(self, other: _Compound, /) -> str: ...
+
+        def relate_pattern(
+            self, other: _Compound, pattern: str, /
+        ) -> bool: ...
+
         def relate_to(self, other: _Compound, /) -> _Relation: ...
 
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __new__(cls) -> Self: ...
 
         def __and__(
@@ -282,17 +380,83 @@ if TYPE_CHECKING:
         ): ...
 
     class Multipolygon:
+        @property
+        def area(self, /) -> _Fraction: ...
+
         @property
         def bounding_box(self, /) -> Box: ...
 
+        @property
+        def centroid(self, /) -> Point: ...
+
         @property
         def polygons(self, /) -> Sequence[Polygon]: ...
 
+        @property
+        def signed_area(self, /) -> _Fraction: ...
+
+        @property
+        def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+        @classmethod
+        def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Point | Polygon | Segment: ...
+
+        def discrete_hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[_Fraction, Point, Point]: ...
+
+        def distance_squared(self, other: _Metrizable, /) -> _Fraction: ...
+
+        def hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[
+            QuadraticSurd,
+            tuple[QuadraticSurd, QuadraticSurd],
+            tuple[QuadraticSurd, QuadraticSurd],
+        ]: ...
+
+        def is_valid(self, /) -> bool: ...
+
         def locate(self, point: Point, /) -> _Location: ...
 
+        def make_valid(
+            self, fill_rule: _FillRule, /
+        ) -> Empty | Multipolygon | Polygon: ...
+
+        def nearest_points(
+            self, other: _Metrizable, /
+        ) -> tuple[Point, Point]: ...
+
+        def normalize(self, /) -> Self: ...
+
+        def relate_matrix(self, other: _Compound, /) -> str: ...
+
+        def relate_pattern(
+            self, other: _Compound, pattern: str, /
+        ) -> bool: ...
+
         def relate_to(self, other: _Compound, /) -> _Relation: ...
 
-        def __new__(cls, polygons: Sequence[Polygon], /) -> Self: ...
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def validate(self, /) -> list[Violation]: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
+        def __new__(
+            cls, polygons: Sequence[Polygon], /, *, validate: bool = False
+        ) -> Self: ...
 
         @overload
         def __and__(self, other: Empty, /) -> Empty: ...
@@ -367,16 +531,70 @@ if TYPE_CHECKING:
         @property
         def bounding_box(self, /) -> Box: ...
 
+        @property
+        def centroid(self, /) -> Point: ...
+
         @property
         def segments(self, /) -> Sequence[Segment]: ...
 
+        @property
+        def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+        @classmethod
+        def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Point | Polygon | Segment: ...
+
+        def discrete_hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[_Fraction, Point, Point]: ...
+
+        def distance_squared(self, other: _Metrizable, /) -> _Fraction: ...
+
+        def hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[
+            QuadraticSurd,
+            tuple[QuadraticSurd, QuadraticSurd],
+            tuple[QuadraticSurd, QuadraticSurd],
+        ]: ...
+
         def is_valid(self, /) -> bool: ...
 
         def locate(self, point: Point, /) -> _Location: ...
 
+        def nearest_points(
+            self, other: _Metrizable, /
+        ) -> tuple[Point, Point]: ...
+
+        def normalize(self, /) -> Self: ...
+
+        def relate_matrix(self, other: _Compound, /) -> str: ...
+
+        def relate_pattern(
+            self, other: _Compound, pattern: str, /
+        ) -> bool: ...
+
         def relate_to(self, other: _Compound, /) -> _Relation: ...
 
-        def __new__(cls, segments: Sequence[Segment], /) -> Self: ...
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def validate(self, /) -> list[Violation]: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
+        def __new__(
+            cls, segments: Sequence[Segment], /, *, validate: bool = False
+        ) -> Self: ...
 
         @overload
         def __and__(self, other: Empty, /) -> Empty: ...
@@ -451,6 +669,33 @@ if TYPE_CHECKING:
         @property
         def y(self, /) -> _Fraction: ...
 
+        @property
+        def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+        @classmethod
+        def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Self: ...
+
+        def distance_squared(self, other: _Metrizable, /) -> _Fraction: ...
+
+        def nearest_points(
+            self, other: _Metrizable, /
+        ) -> tuple[Point, Point]: ...
+
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __new__(cls, x: _Scalar, y: _Scalar, /) -> Self: ...
 
         @overload
@@ -476,21 +721,90 @@ if TYPE_CHECKING:
         def __str__(self, /) -> str: ...
 
     class Polygon:
+        @property
+        def area(self, /) -> _Fraction: ...
+
         @property
         def border(self, /) -> Contour: ...
 
         @property
         def bounding_box(self, /) -> Box: ...
 
+        @property
+        def centroid(self, /) -> Point: ...
+
         @property
         def holes(self, /) -> Sequence[Contour]: ...
 
+        @property
+        def signed_area(self, /) -> _Fraction: ...
+
+        @property
+        def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+        @classmethod
+        def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Point | Polygon | Segment: ...
+
+        def discrete_hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[_Fraction, Point, Point]: ...
+
+        def distance_squared(self, other: _Metrizable, /) -> _Fraction: ...
+
+        def hausdorff_distance_squared(
+            self, other: _HausdorffMetrizable, /
+        ) -> tuple[
+            QuadraticSurd,
+            tuple[QuadraticSurd, QuadraticSurd],
+            tuple[QuadraticSurd, QuadraticSurd],
+        ]: ...
+
+        def is_valid(self, /) -> bool: ...
+
         def locate(self, point: Point, /) -> _Location: ...
 
+        def make_valid(
+            self, fill_rule: _FillRule, /
+        ) -> Empty | Multipolygon | Polygon: ...
+
+        def nearest_points(
+            self, other: _Metrizable, /
+        ) -> tuple[Point, Point]: ...
+
+        def normalize(self, /) -> Self: ...
+
+        def relate_matrix(self, other: _Compound, /) -> str: ...
+
+        def relate_pattern(
+            self, other: _Compound, pattern: str, /
+        ) -> bool: ...
+
         def relate_to(self, other: _Compound, /) -> _Relation: ...
 
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def validate(self, /) -> list[Violation]: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __new__(
-            cls, border: Contour, holes: Sequence[Contour], /
+            cls,
+            border: Contour,
+            holes: Sequence[Contour],
+            /,
+            *,
+            validate: bool = False,
         ) -> Self: ...
 
         @overload
@@ -570,16 +884,52 @@ if TYPE_CHECKING:
         @property
         def bounding_box(self, /) -> Box: ...
 
+        @property
+        def centroid(self, /) -> Point: ...
+
         @property
         def end(self, /) -> Point: ...
 
         @property
         def start(self, /) -> Point: ...
 
+        @property
+        def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+        @classmethod
+        def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+        @classmethod
+        def from_wkt(cls, wkt: str, /) -> Self: ...
+
+        def convex_hull(self, /) -> Point | Polygon | Segment: ...
+
+        def distance_squared(self, other: _Metrizable, /) -> _Fraction: ...
+
         def locate(self, point: Point, /) -> _Location: ...
 
+        def nearest_points(
+            self, other: _Metrizable, /
+        ) -> tuple[Point, Point]: ...
+
+        def relate_matrix(self, other: _Compound, /) -> str: ...
+
+        def relate_pattern(
+            self, other: _Compound, pattern: str, /
+        ) -> bool: ...
+
         def relate_to(self, other: _Compound, /) -> _Relation: ...
 
+        def scale(self, factor_x: _Scalar, factor_y: _Scalar, /) -> Self: ...
+
+        def to_wkt(self, /) -> str: ...
+
+        def transform(self, matrix: _AffineMatrix, /) -> Self: ...
+
+        def translate(self, step_x: _Scalar, step_y: _Scalar, /) -> Self: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __new__(cls, start: Point, end: Point, /) -> Self: ...
 
         @overload
@@ -655,15 +1005,34 @@ if TYPE_CHECKING:
 
     @final
     class ConstrainedDelaunayTriangulation:
+        @classmethod
+        def from_multipolygon(cls, multipolygon: Multipolygon, /) -> Self: ...
+
+        @classmethod
+        def from_points_and_multisegment(
+            cls, points: Sequence[Point], multisegment: Multisegment, /
+        ) -> Self: ...
+
         @classmethod
         def from_polygon(cls, polygon: Polygon, /) -> Self: ...
 
         @property
         def border(self, /) -> Contour: ...
 
+        @property
+        def endpoints(self, /) -> Sequence[Point]: ...
+
         @property
         def triangles(self, /) -> Sequence[Contour]: ...
 
+        def locate_point(
+            self, point: Point, /
+        ) -> tuple[tuple[int, ...], Contour | Point | Segment | None]: ...
+
+        def to_indexed_mesh(self, /) -> IndexedMesh: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __bool__(self, /) -> bool: ...
 
     @final
@@ -674,11 +1043,111 @@ if TYPE_CHECKING:
         @property
         def border(self, /) -> Contour: ...
 
+        @property
+        def endpoints(self, /) -> Sequence[Point]: ...
+
         @property
         def triangles(self, /) -> Sequence[Contour]: ...
 
+        def insert(self, point: Point, /) -> bool: ...
+
+        def locate_point(
+            self, point: Point, /
+        ) -> tuple[tuple[int, ...], Contour | Point | Segment | None]: ...
+
+        def remove(self, point: Point, /) -> bool: ...
+
+        def to_indexed_mesh(self, /) -> IndexedMesh: ...
+
+        def to_voronoi(self, box: Box, /) -> VoronoiDiagram: ...
+
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __bool__(self, /) -> bool: ...
 
+    @final
+    class IndexedMesh:
+        @property
+        def constrained_flags(
+            self, /
+        ) -> Sequence[tuple[bool, bool, bool]]: ...
+
+        @property
+        def neighbours(
+            self, /
+        ) -> Sequence[tuple[int | None, int | None, int | None]]: ...
+
+        @property
+        def triangles(self, /) -> Sequence[tuple[int, int, int]]: ...
+
+        @property
+        def vertices(self, /) -> Sequence[Point]: ...
+
+    @final
+    class QuadraticSurd:
+        @property
+        def irrational(self, /) -> _Fraction: ...
+
+        @property
+        def radicand(self, /) -> _Fraction: ...
+
+        @property
+        def rational(self, /) -> _Fraction: ...
+
+        def __new__(
+            cls,
+            rational: _Scalar,
+            irrational: _Scalar,
+            radicand: _Scalar,
+            /,
+        ) -> Self: ...
+
+        @overload
+        def __eq__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __float__(self, /) -> float: ...
+
+        @overload
+        def __ge__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __ge__(self, other: Any, /) -> Any: ...
+
+        def __ge__(self, other: Any, /) -> Any: ...
+
+        @overload
+        def __gt__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __gt__(self, other: Any, /) -> Any: ...
+
+        def __gt__(self, other: Any, /) -> Any: ...
+
+        @overload
+        def __le__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __le__(self, other: Any, /) -> Any: ...
+
+        def __le__(self, other: Any, /) -> Any: ...
+
+        @overload
+        def __lt__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __lt__(self, other: Any, /) -> Any: ...
+
+        def __lt__(self, other: Any, /) -> Any: ...
+
+        def __repr__(self, /) -> str: ...
+
+        def __str__(self, /) -> str: ...
+
     @final
     class Trapezoidation:
         @classmethod
@@ -696,11 +1165,95 @@ if TYPE_CHECKING:
 
         def locate(self, point: Point, /) -> _Location: ...
 
+        def _repr_svg_(self, /) -> str | None: ...
+
         def __contains__(self, point: Point, /) -> bool: ...
 
+    @final
+    class Violation:
+        @property
+        def count(self, /) -> int | None: ...
+
+        @property
+        def end(self, /) -> Point | None: ...
+
+        @property
+        def first_hole_index(self, /) -> int | None: ...
+
+        @property
+        def first_polygon_index(self, /) -> int | None: ...
+
+        @property
+        def first_segment_index(self, /) -> int | None: ...
+
+        @property
+        def hole_index(self, /) -> int | None: ...
+
+        @property
+        def kind(self, /) -> _ViolationKind: ...
+
+        @property
+        def point(self, /) -> Point | None: ...
+
+        @property
+        def polygon_index(self, /) -> int | None: ...
+
+        @property
+        def second_hole_index(self, /) -> int | None: ...
+
+        @property
+        def second_polygon_index(self, /) -> int | None: ...
+
+        @property
+        def second_segment_index(self, /) -> int | None: ...
+
+        @property
+        def segment_index(self, /) -> int | None: ...
+
+        @property
+        def start(self, /) -> Point | None: ...
+
+        @property
+        def vertex_index(self, /) -> int | None: ...
+
+        @property
+        def violation(self, /) -> Self | None: ...
+
+        @overload
+        def __eq__(self, other: Self, /) -> bool: ...
+
+        @overload
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __eq__(self, other: Any, /) -> Any: ...
+
+        def __repr__(self, /) -> str: ...
+
+        def __str__(self, /) -> str: ...
+
+    @final
+    class VoronoiDiagram:
+        @property
+        def cells(self, /) -> Sequence[Polygon]: ...
+
+        @property
+        def sites(self, /) -> Sequence[Point]: ...
+
+        @property
+        def vertices(self, /) -> Sequence[Point]: ...
+
     _Compound = (
         Contour | Empty | Multisegment | Multipolygon | Polygon | Segment
     )
+    _HausdorffMetrizable = Contour | Multipolygon | Multisegment | Polygon
+    _Metrizable = (
+        Contour | Multipolygon | Multisegment | Point | Polygon | Segment
+    )
+
+    def nearest_points(
+        first: _Metrizable, second: _Metrizable, /
+    ) -> tuple[Point, Point]: ...
+
 else:
     try:
         from . import _cexact
@@ -715,9 +1268,14 @@ else:
             Multisegment,
             Point,
             Polygon,
+            QuadraticSurd,
             Segment,
             Trapezoidation,
+            nearest_points,
         )
+        from ._exact.indexed_mesh import IndexedMesh
+        from ._validation import Violation
+        from ._exact.voronoi_diagram import VoronoiDiagram
     else:
         import random as _random
         from typing import Any, NoReturn
@@ -736,12 +1294,17 @@ else:
         Contour = _cexact.Contour
         DelaunayTriangulation = _cexact.DelaunayTriangulation
         Empty = _cexact.Empty
+        IndexedMesh = _cexact.IndexedMesh
         Multipolygon = _cexact.Multipolygon
         Multisegment = _cexact.Multisegment
         Point = _cexact.Point
         Polygon = _cexact.Polygon
+        QuadraticSurd = _cexact.QuadraticSurd
         Segment = _cexact.Segment
+        Violation = _cexact.Violation
+        VoronoiDiagram = _cexact.VoronoiDiagram
         _RawTrapezoidation = _cexact.Trapezoidation
+        nearest_points = _cexact.nearest_points
 
         @final
         class Trapezoidation:
@@ -782,6 +1345,9 @@ else:
             def locate(self, point: Point, /) -> _Location:
                 return self._raw.locate(point)
 
+            def _repr_svg_(self, /) -> str | None:
+                return self._raw._repr_svg_()
+
             _raw: _RawTrapezoidation
 
             __slots__ = ('_raw',)
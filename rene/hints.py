@@ -9,9 +9,11 @@ if TYPE_CHECKING:
     from collections.abc import Sequence
 
     from rene.enums import (
+        FillRule as _FillRule,
         Location as _Location,
         Orientation as _Orientation,
         Relation as _Relation,
+        ViolationKind as _ViolationKind,
     )
 
 
@@ -86,6 +88,33 @@ class Point(_SelfComparable, Protocol[ScalarT_co]):
     @property
     def y(self, /) -> ScalarT_co: ...
 
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(self, /) -> Self: ...
+
+    def distance_squared(self, other: Metrizable[Scalar], /) -> ScalarT_co: ...
+
+    def nearest_points(
+        self, other: Metrizable[Scalar], /
+    ) -> tuple[Point[ScalarT_co], Point[Scalar]]: ...
+
+    def scale(self, factor_x: Scalar, factor_y: Scalar, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[Scalar], /) -> Self: ...
+
+    def translate(self, step_x: Scalar, step_y: Scalar, /) -> Self: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
     def __new__(cls, x: ScalarT_co, y: ScalarT_co, /) -> Self: ...
 
     def __ge__(self, other: Self, /) -> bool: ...
@@ -104,10 +133,31 @@ class Point(_SelfComparable, Protocol[ScalarT_co]):
 
 
 class Empty(_SelfComparable, Protocol[ScalarT]):
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(self, /) -> Self: ...
+
     def locate(self, point: Point[ScalarT], /) -> _Location: ...
 
+    def relate_matrix(self, other: Compound[ScalarT], /) -> str: ...
+
+    def relate_pattern(
+        self, other: Compound[ScalarT], pattern: str, /
+    ) -> bool: ...
+
     def relate_to(self, other: Compound[ScalarT], /) -> _Relation: ...
 
+    def scale(self, factor_x: ScalarT, factor_y: ScalarT, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[ScalarT], /) -> Self: ...
+
+    def translate(self, step_x: ScalarT, step_y: ScalarT, /) -> Self: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
     def __new__(cls, /) -> Self: ...
 
     def __and__(
@@ -202,6 +252,10 @@ class Box(_SelfComparable, Protocol[ScalarT_co]):
     @property
     def min_y(self, /) -> ScalarT_co: ...
 
+    def convex_hull(
+        self, /
+    ) -> Point[ScalarT_co] | Polygon[ScalarT_co] | Segment[ScalarT_co]: ...
+
     def covers(self, other: Self, /) -> bool: ...
 
     def disjoint_with(self, other: Self, /) -> bool: ...
@@ -218,8 +272,14 @@ class Box(_SelfComparable, Protocol[ScalarT_co]):
 
     def relate_to(self, other: Self, /) -> _Relation: ...
 
+    def scale(self, factor_x: Scalar, factor_y: Scalar, /) -> Self: ...
+
     def touches(self, other: Self, /) -> bool: ...
 
+    def transform(self, matrix: AffineMatrix[Scalar], /) -> Self: ...
+
+    def translate(self, step_x: Scalar, step_y: Scalar, /) -> Self: ...
+
     def within(self, other: Self, /) -> bool: ...
 
     def __new__(
@@ -242,16 +302,54 @@ class Segment(_SelfComparable, Protocol[ScalarT]):
     @property
     def bounding_box(self, /) -> Box[ScalarT]: ...
 
+    @property
+    def centroid(self, /) -> Point[ScalarT]: ...
+
     @property
     def end(self, /) -> Point[ScalarT]: ...
 
     @property
     def start(self, /) -> Point[ScalarT]: ...
 
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(
+        self, /
+    ) -> Point[ScalarT] | Polygon[ScalarT] | Segment[ScalarT]: ...
+
+    def distance_squared(self, other: Metrizable[ScalarT], /) -> ScalarT: ...
+
     def locate(self, point: Point[ScalarT], /) -> _Location: ...
 
+    def nearest_points(
+        self, other: Metrizable[ScalarT], /
+    ) -> tuple[Point[ScalarT], Point[ScalarT]]: ...
+
+    def relate_matrix(self, other: Compound[ScalarT], /) -> str: ...
+
+    def relate_pattern(
+        self, other: Compound[ScalarT], pattern: str, /
+    ) -> bool: ...
+
     def relate_to(self, other: Compound[ScalarT], /) -> _Relation: ...
 
+    def scale(self, factor_x: ScalarT, factor_y: ScalarT, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[ScalarT], /) -> Self: ...
+
+    def translate(self, step_x: ScalarT, step_y: ScalarT, /) -> Self: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
     def __new__(
         cls, start: Point[ScalarT], end: Point[ScalarT], /
     ) -> Self: ...
@@ -326,22 +424,92 @@ class Multisegmental(Protocol[_Segmental_co]):
 class Contour(
     _SelfComparable, Multisegmental[Segment[ScalarT]], Protocol[ScalarT]
 ):
+    @property
+    def area(self, /) -> ScalarT: ...
+
     @property
     def bounding_box(self, /) -> Box[ScalarT]: ...
 
+    @property
+    def centroid(self, /) -> Point[ScalarT]: ...
+
     @property
     def orientation(self, /) -> _Orientation: ...
 
+    @property
+    def signed_area(self, /) -> ScalarT: ...
+
     @property
     def vertices(self, /) -> Sequence[Point[ScalarT]]: ...
 
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(
+        self, /
+    ) -> Point[ScalarT] | Polygon[ScalarT] | Segment[ScalarT]: ...
+
+    def discrete_hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[ScalarT, Point[ScalarT], Point[ScalarT]]: ...
+
+    def distance_squared(self, other: Metrizable[ScalarT], /) -> ScalarT: ...
+
+    def hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[
+        QuadraticSurd[ScalarT],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+    ]: ...
+
     def is_valid(self, /) -> bool: ...
 
     def locate(self, point: Point[ScalarT], /) -> _Location: ...
 
+    def make_valid(
+        self, fill_rule: _FillRule, /
+    ) -> Empty[ScalarT] | Multipolygon[ScalarT] | Polygon[ScalarT]: ...
+
+    def nearest_points(
+        self, other: Metrizable[ScalarT], /
+    ) -> tuple[Point[ScalarT], Point[ScalarT]]: ...
+
+    def normalize(self, /) -> Self: ...
+
+    def relate_matrix(self, other: Compound[ScalarT], /) -> str: ...
+
+    def relate_pattern(
+        self, other: Compound[ScalarT], pattern: str, /
+    ) -> bool: ...
+
     def relate_to(self, other: Compound[ScalarT], /) -> _Relation: ...
 
-    def __new__(cls, vertices: Sequence[Point[ScalarT]], /) -> Self: ...
+    def scale(self, factor_x: ScalarT, factor_y: ScalarT, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[ScalarT], /) -> Self: ...
+
+    def translate(self, step_x: ScalarT, step_y: ScalarT, /) -> Self: ...
+
+    def validate(self, /) -> list[Violation[ScalarT]]: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
+    def __new__(
+        cls,
+        vertices: Sequence[Point[ScalarT]],
+        /,
+        *,
+        validate: bool = False,
+    ) -> Self: ...
 
     @overload
     def __and__(self, other: Empty[ScalarT], /) -> Empty[ScalarT]: ...
@@ -404,13 +572,73 @@ class Multisegment(
     @property
     def bounding_box(self, /) -> Box[ScalarT]: ...
 
+    @property
+    def centroid(self, /) -> Point[ScalarT]: ...
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(
+        self, /
+    ) -> Point[ScalarT] | Polygon[ScalarT] | Segment[ScalarT]: ...
+
+    def discrete_hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[ScalarT, Point[ScalarT], Point[ScalarT]]: ...
+
+    def distance_squared(self, other: Metrizable[ScalarT], /) -> ScalarT: ...
+
+    def hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[
+        QuadraticSurd[ScalarT],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+    ]: ...
+
     def is_valid(self, /) -> bool: ...
 
     def locate(self, point: Point[ScalarT], /) -> _Location: ...
 
+    def nearest_points(
+        self, other: Metrizable[ScalarT], /
+    ) -> tuple[Point[ScalarT], Point[ScalarT]]: ...
+
+    def normalize(self, /) -> Self: ...
+
+    def relate_matrix(self, other: Compound[ScalarT], /) -> str: ...
+
+    def relate_pattern(
+        self, other: Compound[ScalarT], pattern: str, /
+    ) -> bool: ...
+
     def relate_to(self, other: Compound[ScalarT], /) -> _Relation: ...
 
-    def __new__(cls, segments: Sequence[Segment[ScalarT]], /) -> Self: ...
+    def scale(self, factor_x: ScalarT, factor_y: ScalarT, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[ScalarT], /) -> Self: ...
+
+    def translate(self, step_x: ScalarT, step_y: ScalarT, /) -> Self: ...
+
+    def validate(self, /) -> list[Violation[ScalarT]]: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
+    def __new__(
+        cls,
+        segments: Sequence[Segment[ScalarT]],
+        /,
+        *,
+        validate: bool = False,
+    ) -> Self: ...
 
     @overload
     def __and__(self, other: Empty[ScalarT], /) -> Empty[ScalarT]: ...
@@ -462,21 +690,92 @@ class Multisegment(
 
 
 class Polygon(_SelfComparable, Protocol[ScalarT]):
+    @property
+    def area(self, /) -> ScalarT: ...
+
     @property
     def bounding_box(self, /) -> Box[ScalarT]: ...
 
+    @property
+    def centroid(self, /) -> Point[ScalarT]: ...
+
     @property
     def border(self, /) -> Contour[ScalarT]: ...
 
     @property
     def holes(self, /) -> Sequence[Contour[ScalarT]]: ...
 
+    @property
+    def signed_area(self, /) -> ScalarT: ...
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(
+        self, /
+    ) -> Point[ScalarT] | Polygon[ScalarT] | Segment[ScalarT]: ...
+
+    def discrete_hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[ScalarT, Point[ScalarT], Point[ScalarT]]: ...
+
+    def distance_squared(self, other: Metrizable[ScalarT], /) -> ScalarT: ...
+
+    def hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[
+        QuadraticSurd[ScalarT],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+    ]: ...
+
+    def is_valid(self, /) -> bool: ...
+
     def locate(self, point: Point[ScalarT], /) -> _Location: ...
 
+    def make_valid(
+        self, fill_rule: _FillRule, /
+    ) -> Empty[ScalarT] | Multipolygon[ScalarT] | Polygon[ScalarT]: ...
+
+    def nearest_points(
+        self, other: Metrizable[ScalarT], /
+    ) -> tuple[Point[ScalarT], Point[ScalarT]]: ...
+
+    def normalize(self, /) -> Self: ...
+
+    def relate_matrix(self, other: Compound[ScalarT], /) -> str: ...
+
+    def relate_pattern(
+        self, other: Compound[ScalarT], pattern: str, /
+    ) -> bool: ...
+
     def relate_to(self, other: Compound[ScalarT], /) -> _Relation: ...
 
+    def scale(self, factor_x: ScalarT, factor_y: ScalarT, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[ScalarT], /) -> Self: ...
+
+    def translate(self, step_x: ScalarT, step_y: ScalarT, /) -> Self: ...
+
+    def validate(self, /) -> list[Violation[ScalarT]]: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
     def __new__(
-        cls, border: Contour[ScalarT], holes: Sequence[Contour[ScalarT]], /
+        cls,
+        border: Contour[ScalarT],
+        holes: Sequence[Contour[ScalarT]],
+        /,
+        *,
+        validate: bool = False,
     ) -> Self: ...
 
     @overload
@@ -528,17 +827,89 @@ class Polygon(_SelfComparable, Protocol[ScalarT]):
 
 
 class Multipolygon(_SelfComparable, Protocol[ScalarT]):
+    @property
+    def area(self, /) -> ScalarT: ...
+
     @property
     def bounding_box(self, /) -> Box[ScalarT]: ...
 
+    @property
+    def centroid(self, /) -> Point[ScalarT]: ...
+
     @property
     def polygons(self, /) -> Sequence[Polygon[ScalarT]]: ...
 
+    @property
+    def signed_area(self, /) -> ScalarT: ...
+
+    @property
+    def __geo_interface__(self, /) -> dict[str, Any]: ...
+
+    @classmethod
+    def from_geo_interface(cls, value: Any, /) -> Self: ...
+
+    @classmethod
+    def from_wkt(cls, wkt: str, /) -> Self: ...
+
+    def convex_hull(
+        self, /
+    ) -> Point[ScalarT] | Polygon[ScalarT] | Segment[ScalarT]: ...
+
+    def discrete_hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[ScalarT, Point[ScalarT], Point[ScalarT]]: ...
+
+    def distance_squared(self, other: Metrizable[ScalarT], /) -> ScalarT: ...
+
+    def hausdorff_distance_squared(
+        self, other: HausdorffMetrizable[ScalarT], /
+    ) -> tuple[
+        QuadraticSurd[ScalarT],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+        tuple[QuadraticSurd[ScalarT], QuadraticSurd[ScalarT]],
+    ]: ...
+
+    def is_valid(self, /) -> bool: ...
+
     def locate(self, point: Point[ScalarT], /) -> _Location: ...
 
+    def make_valid(
+        self, fill_rule: _FillRule, /
+    ) -> Empty[ScalarT] | Multipolygon[ScalarT] | Polygon[ScalarT]: ...
+
+    def nearest_points(
+        self, other: Metrizable[ScalarT], /
+    ) -> tuple[Point[ScalarT], Point[ScalarT]]: ...
+
+    def normalize(self, /) -> Self: ...
+
+    def relate_matrix(self, other: Compound[ScalarT], /) -> str: ...
+
+    def relate_pattern(
+        self, other: Compound[ScalarT], pattern: str, /
+    ) -> bool: ...
+
     def relate_to(self, other: Compound[ScalarT], /) -> _Relation: ...
 
-    def __new__(cls, polygons: Sequence[Polygon[ScalarT]], /) -> Self: ...
+    def scale(self, factor_x: ScalarT, factor_y: ScalarT, /) -> Self: ...
+
+    def to_wkt(self, /) -> str: ...
+
+    def transform(self, matrix: AffineMatrix[ScalarT], /) -> Self: ...
+
+    def translate(self, step_x: ScalarT, step_y: ScalarT, /) -> Self: ...
+
+    def validate(self, /) -> list[Violation[ScalarT]]: ...
+
+    def _repr_svg_(self, /) -> str | None: ...
+
+    def __new__(
+        cls,
+        polygons: Sequence[Polygon[ScalarT]],
+        /,
+        *,
+        validate: bool = False,
+    ) -> Self: ...
 
     @overload
     def __and__(self, other: Empty[ScalarT], /) -> Empty[ScalarT]: ...
@@ -588,6 +959,109 @@ class Multipolygon(_SelfComparable, Protocol[ScalarT]):
     ) -> Empty[ScalarT] | Multipolygon[ScalarT] | Polygon[ScalarT]: ...
 
 
+class QuadraticSurd(_SelfComparable, Protocol[ScalarT]):
+    @property
+    def irrational(self, /) -> ScalarT: ...
+
+    @property
+    def radicand(self, /) -> ScalarT: ...
+
+    @property
+    def rational(self, /) -> ScalarT: ...
+
+    def __new__(
+        cls, rational: ScalarT, irrational: ScalarT, radicand: ScalarT, /
+    ) -> Self: ...
+
+    def __float__(self, /) -> float: ...
+
+    @overload
+    def __ge__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __ge__(self, other: Any, /) -> Any: ...
+
+    @overload
+    def __gt__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __gt__(self, other: Any, /) -> Any: ...
+
+    @overload
+    def __le__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __le__(self, other: Any, /) -> Any: ...
+
+    @overload
+    def __lt__(self, other: Self, /) -> bool: ...
+
+    @overload
+    def __lt__(self, other: Any, /) -> Any: ...
+
+    def __repr__(self, /) -> str: ...
+
+    def __str__(self, /) -> str: ...
+
+
+class Violation(_SelfComparable, Protocol[ScalarT]):
+    @property
+    def count(self, /) -> int | None: ...
+
+    @property
+    def end(self, /) -> Point[ScalarT] | None: ...
+
+    @property
+    def first_hole_index(self, /) -> int | None: ...
+
+    @property
+    def first_polygon_index(self, /) -> int | None: ...
+
+    @property
+    def first_segment_index(self, /) -> int | None: ...
+
+    @property
+    def hole_index(self, /) -> int | None: ...
+
+    @property
+    def kind(self, /) -> _ViolationKind: ...
+
+    @property
+    def point(self, /) -> Point[ScalarT] | None: ...
+
+    @property
+    def polygon_index(self, /) -> int | None: ...
+
+    @property
+    def second_hole_index(self, /) -> int | None: ...
+
+    @property
+    def second_polygon_index(self, /) -> int | None: ...
+
+    @property
+    def second_segment_index(self, /) -> int | None: ...
+
+    @property
+    def segment_index(self, /) -> int | None: ...
+
+    @property
+    def start(self, /) -> Point[ScalarT] | None: ...
+
+    @property
+    def vertex_index(self, /) -> int | None: ...
+
+    @property
+    def violation(self, /) -> Self | None: ...
+
+    def __repr__(self, /) -> str: ...
+
+    def __str__(self, /) -> str: ...
+
+
+AffineMatrix: TypeAlias = tuple[
+    tuple[ScalarT, ScalarT, ScalarT], tuple[ScalarT, ScalarT, ScalarT]
+]
+
 Seeder = Callable[[], int]
 
 Compound: TypeAlias = (
@@ -598,3 +1072,19 @@ Compound: TypeAlias = (
     | Polygon[ScalarT]
     | Segment[ScalarT]
 )
+
+HausdorffMetrizable: TypeAlias = (
+    Contour[ScalarT]
+    | Multipolygon[ScalarT]
+    | Multisegment[ScalarT]
+    | Polygon[ScalarT]
+)
+
+Metrizable: TypeAlias = (
+    Contour[ScalarT]
+    | Multipolygon[ScalarT]
+    | Multisegment[ScalarT]
+    | Point[ScalarT]
+    | Polygon[ScalarT]
+    | Segment[ScalarT]
+)
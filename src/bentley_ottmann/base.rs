@@ -1,16 +1,23 @@
 use core::convert::From;
+use std::collections::BTreeMap;
 
-use crate::constants::MIN_MULTISEGMENT_SEGMENTS_COUNT;
+use crate::constants::{
+    MIN_CONTOUR_VERTICES_COUNT, MIN_MULTISEGMENT_SEGMENTS_COUNT,
+};
+#[cfg(feature = "python")]
 use crate::contracts::are_contour_vertices_non_degenerate;
-use crate::operations::Orient;
+use crate::operations::{to_sorted_pair, Orient};
+use crate::oriented::Orientation;
 use crate::relatable::Relation;
 use crate::traits::{
     Contoural, Elemental, Iterable, Lengthsome, Multisegmental, Multivertexal,
     Segmental,
 };
+use crate::validation::Violation;
 
 use super::sweep::{Intersection, Sweep};
 
+#[cfg(feature = "python")]
 pub(crate) fn is_contour_valid<Contour, Point: Ord, Scalar, Segment>(
     contour: &Contour,
 ) -> bool
@@ -68,6 +75,7 @@ where
     }
 }
 
+#[cfg(feature = "python")]
 pub(crate) fn is_multisegment_valid<
     'a,
     Multisegment,
@@ -91,3 +99,249 @@ where
         && Sweep::from(&segments)
             .all(|intersection| intersection.relation == Relation::Touch)
 }
+
+/// Returns points each segment is split into by intersections
+/// with the other segments, ordered from its start to its end.
+pub(crate) fn split_segments<Point: Clone + Ord, Segment, Segments>(
+    segments: &Segments,
+) -> Vec<Vec<Point>>
+where
+    Sweep<Point>:
+        for<'a> From<&'a Segments> + Iterator<Item = Intersection<Point>>,
+    Segments: Iterable<Item = Segment>,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let mut result = segments
+        .iter()
+        .map(|segment| {
+            let (start, end) = segment.endpoints();
+            vec![start.clone(), end.clone()]
+        })
+        .collect::<Vec<_>>();
+    let mut overlaps_roots = (0..result.len()).collect::<Vec<_>>();
+    for intersection in Sweep::from(segments) {
+        if intersection.first_segment_id == intersection.second_segment_id {
+            continue;
+        }
+        for &segment_id in &[
+            intersection.first_segment_id,
+            intersection.second_segment_id,
+        ] {
+            result[segment_id].push(intersection.start.clone());
+            if intersection.end != intersection.start {
+                result[segment_id].push(intersection.end.clone());
+            }
+        }
+        if intersection.end != intersection.start {
+            let first_root = to_overlap_root(
+                &mut overlaps_roots,
+                intersection.first_segment_id,
+            );
+            let second_root = to_overlap_root(
+                &mut overlaps_roots,
+                intersection.second_segment_id,
+            );
+            overlaps_roots[first_root] = second_root;
+        }
+    }
+    // sweep does not report each intersection for all overlapping segments,
+    // so collinear overlapping segments share their split points
+    let mut overlaps = BTreeMap::<usize, Vec<usize>>::new();
+    for segment_id in 0..result.len() {
+        overlaps
+            .entry(to_overlap_root(&mut overlaps_roots, segment_id))
+            .or_default()
+            .push(segment_id);
+    }
+    for segments_ids in overlaps.values().filter(|ids| ids.len() > 1) {
+        let points = segments_ids
+            .iter()
+            .flat_map(|&segment_id| result[segment_id].iter().cloned())
+            .collect::<Vec<_>>();
+        for &segment_id in segments_ids {
+            let (min_point, max_point) = to_sorted_pair((
+                result[segment_id][0].clone(),
+                result[segment_id][1].clone(),
+            ));
+            result[segment_id].extend(
+                points
+                    .iter()
+                    .filter(|&point| {
+                        &min_point <= point && point <= &max_point
+                    })
+                    .cloned(),
+            );
+        }
+    }
+    for points in &mut result {
+        let is_reversed = points[0] > points[1];
+        points.sort();
+        points.dedup();
+        if is_reversed {
+            points.reverse();
+        }
+    }
+    result
+}
+
+pub(crate) fn to_contour_violations<
+    Contour,
+    Point: Clone + Ord,
+    Scalar,
+    Segment,
+>(
+    contour: &Contour,
+) -> Vec<Violation<Point>>
+where
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a, 'b> From<&'a <&'b Contour as Multisegmental>::Segments>,
+    for<'a> &'a Contour:
+        Contoural<IndexVertex = Point, IndexSegment = Segment>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar> + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let contour_vertices = contour.vertices();
+    let vertices = contour_vertices.iter().collect::<Vec<_>>();
+    if vertices.len() < MIN_CONTOUR_VERTICES_COUNT {
+        return vec![Violation::TooFewVertices {
+            count: vertices.len(),
+        }];
+    }
+    let mut result = Vec::new();
+    for (vertex_index, vertex) in vertices.iter().enumerate() {
+        let previous_vertex =
+            vertices[(vertex_index + vertices.len() - 1) % vertices.len()];
+        let next_vertex = vertices[(vertex_index + 1) % vertices.len()];
+        if previous_vertex.orient(vertex, next_vertex)
+            == Orientation::Collinear
+        {
+            result.push(Violation::CollinearVertices { vertex_index });
+        }
+    }
+    let segments = contour.segments();
+    if push_degenerate_segments_violations(&segments, &mut result) {
+        let last_segment_index = segments.len() - 1;
+        result.extend(to_intersections_violations(
+            Sweep::from(&segments),
+            |sweep, intersection| {
+                let (first_index, second_index) = to_sorted_pair((
+                    intersection.first_segment_id,
+                    intersection.second_segment_id,
+                ));
+                intersection.relation == Relation::Touch
+                    && (second_index - first_index == 1
+                        || (first_index == 0
+                            && second_index == last_segment_index))
+                    && [first_index, second_index].iter().all(|&index| {
+                        intersection.start.eq(sweep.get_segment_start(index))
+                            || intersection
+                                .start
+                                .eq(sweep.get_segment_end(index))
+                    })
+            },
+        ));
+    }
+    result
+}
+
+pub(crate) fn to_multisegment_violations<
+    Multisegment,
+    Point: Clone + Ord,
+    Segment,
+>(
+    multisegment: &Multisegment,
+) -> Vec<Violation<Point>>
+where
+    Sweep<Point>: for<'b, 'c> From<&'b <&'c Multisegment as Multisegmental>::Segments>
+        + Iterator<Item = Intersection<Point>>,
+    for<'b> &'b Multisegment: Multisegmental<IndexSegment = Segment>,
+    for<'b> &'b Segment: Segmental<Endpoint = &'b Point>,
+{
+    let segments = multisegment.segments();
+    let mut result = Vec::new();
+    if segments.len() < MIN_MULTISEGMENT_SEGMENTS_COUNT {
+        result.push(Violation::TooFewSegments {
+            count: segments.len(),
+        });
+    }
+    if push_degenerate_segments_violations(&segments, &mut result) {
+        result.extend(to_intersections_violations(
+            Sweep::from(&segments),
+            |_, intersection| intersection.relation == Relation::Touch,
+        ));
+    }
+    result
+}
+
+/// Pushes violations of degenerate segments
+/// and returns whether there were none.
+fn push_degenerate_segments_violations<Point: PartialEq, Segment, Segments>(
+    segments: &Segments,
+    result: &mut Vec<Violation<Point>>,
+) -> bool
+where
+    Segments: Iterable<Item = Segment>,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let mut are_non_degenerate = true;
+    for (segment_index, segment) in segments.iter().enumerate() {
+        let (start, end) = segment.endpoints();
+        if start == end {
+            result.push(Violation::DegenerateSegment { segment_index });
+            are_non_degenerate = false;
+        }
+    }
+    are_non_degenerate
+}
+
+/// Returns violations of disallowed intersections
+/// ordered by indices of segments, one per pair of segments.
+fn to_intersections_violations<Point: Clone + Ord>(
+    mut sweep: Sweep<Point>,
+    is_allowed: impl Fn(&Sweep<Point>, &Intersection<Point>) -> bool,
+) -> impl Iterator<Item = Violation<Point>>
+where
+    Sweep<Point>: Iterator<Item = Intersection<Point>>,
+{
+    let mut violations = BTreeMap::new();
+    while let Some(intersection) = sweep.next() {
+        if intersection.first_segment_id == intersection.second_segment_id
+            || is_allowed(&sweep, &intersection)
+        {
+            continue;
+        }
+        let (first_segment_index, second_segment_index) = to_sorted_pair((
+            intersection.first_segment_id,
+            intersection.second_segment_id,
+        ));
+        violations
+            .entry((first_segment_index, second_segment_index))
+            .or_insert_with(|| match intersection.relation {
+                Relation::Cross => Violation::SegmentsCross {
+                    first_segment_index,
+                    second_segment_index,
+                    point: intersection.start,
+                },
+                Relation::Touch => Violation::SegmentsTouch {
+                    first_segment_index,
+                    second_segment_index,
+                    point: intersection.start,
+                },
+                _ => Violation::SegmentsOverlap {
+                    first_segment_index,
+                    second_segment_index,
+                    start: intersection.start,
+                    end: intersection.end,
+                },
+            });
+    }
+    violations.into_values()
+}
+
+fn to_overlap_root(roots: &mut [usize], mut segment_id: usize) -> usize {
+    while roots[segment_id] != segment_id {
+        roots[segment_id] = roots[roots[segment_id]];
+        segment_id = roots[segment_id];
+    }
+    segment_id
+}
@@ -1,4 +1,9 @@
+#[cfg(feature = "python")]
 pub(crate) use base::{is_contour_valid, is_multisegment_valid};
+pub(crate) use base::{
+    split_segments, to_contour_violations, to_multisegment_violations,
+};
+pub(crate) use sweep::{Intersection, Sweep};
 
 mod base;
 mod event;
@@ -0,0 +1,24 @@
+use crate::geometries::{Hull, Point};
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::ConvexHull;
+
+use super::types::Box;
+
+impl<Scalar: Clone + Ord> ConvexHull for &Box<Scalar>
+where
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    /// Returns the box as a polygon,
+    /// which degenerates to a segment or a point for zero sizes.
+    fn convex_hull(self) -> Self::Output {
+        let corners = [
+            Point::new(self.min_x.clone(), self.min_y.clone()),
+            Point::new(self.max_x.clone(), self.min_y.clone()),
+            Point::new(self.max_x.clone(), self.max_y.clone()),
+            Point::new(self.min_x.clone(), self.max_y.clone()),
+        ];
+        Hull::from_vertices(to_convex_hull(corners.iter().collect()))
+    }
+}
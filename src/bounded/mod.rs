@@ -1,9 +1,11 @@
 pub use self::traits::Bounded;
 pub use self::types::Box;
 
+mod convex_hull;
 mod eq;
 mod hash;
 mod partial_eq;
 mod relatable;
 mod traits;
+mod transform;
 mod types;
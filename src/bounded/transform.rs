@@ -0,0 +1,38 @@
+use crate::geometries::Point;
+use crate::traits::Elemental;
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Box;
+
+impl<Scalar> Transform<Scalar> for &Box<Scalar>
+where
+    Scalar: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + Transform<Scalar, Output = Point<Scalar>>,
+{
+    type Output = Box<Scalar>;
+
+    /// Returns the bounding box of the transformed box,
+    /// which coincides with it for translations & scalings.
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        let corners = [
+            Point::new(self.min_x.clone(), self.min_y.clone()),
+            Point::new(self.max_x.clone(), self.min_y.clone()),
+            Point::new(self.max_x.clone(), self.max_y.clone()),
+            Point::new(self.min_x.clone(), self.max_y.clone()),
+        ]
+        .iter()
+        .map(|corner| corner.transform(matrix))
+        .collect::<Vec<_>>();
+        let xs = corners.iter().map(Elemental::x);
+        let ys = corners.iter().map(Elemental::y);
+        unsafe {
+            Box::new(
+                xs.clone().min().unwrap_unchecked().clone(),
+                xs.max().unwrap_unchecked().clone(),
+                ys.clone().min().unwrap_unchecked().clone(),
+                ys.max().unwrap_unchecked().clone(),
+            )
+        }
+    }
+}
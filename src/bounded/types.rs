@@ -63,6 +63,7 @@ impl<Scalar> Box<Scalar> {
 }
 
 impl<Scalar: Clone> Box<&Scalar> {
+    #[cfg(feature = "python")]
     pub(crate) fn cloned(&self) -> Box<Scalar> {
         Box::new(
             self.min_x.clone(),
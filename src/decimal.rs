@@ -0,0 +1,179 @@
+//! Exact conversions between scalars and decimal strings.
+
+use std::convert::TryFrom;
+use std::ops::{Div, Mul, Neg};
+
+use rithm::fraction::Fraction;
+use traiter::numbers::{DivRem, FromStrRadix, One, Signed, Unitary, Zeroable};
+
+/// Maximum magnitude of an explicit decimal exponent,
+/// bounds the size of scales built while parsing.
+const MAX_EXPONENT_MAGNITUDE: u64 = 4096;
+
+/// Exactly parses scalar from a decimal string
+/// like `-12`, `0.125` or `1.5e-3`,
+/// returns `None` for exponents with magnitude above 4096.
+///
+/// ```
+/// use rene::decimal::FromDecimal;
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+/// assert!(Scalar::from_decimal("1e4096").is_some());
+/// assert!(Scalar::from_decimal("1e-4096").is_some());
+/// assert!(Scalar::from_decimal("1e40000000").is_none());
+/// assert!(Scalar::from_decimal("1e-40000000").is_none());
+/// ```
+pub trait FromDecimal: Sized {
+    fn from_decimal(string: &str) -> Option<Self>;
+}
+
+/// Exactly formats scalar as a decimal string,
+/// returns `None` for values with infinite decimal expansion.
+pub trait ToDecimal {
+    fn to_decimal(&self) -> Option<String>;
+}
+
+impl<Component> FromDecimal for Fraction<Component>
+where
+    Component: Clone
+        + From<u8>
+        + FromStrRadix
+        + Mul<Output = Component>
+        + Neg<Output = Component>
+        + One,
+    Fraction<Component>: From<Component> + Div<Output = Fraction<Component>>,
+{
+    fn from_decimal(string: &str) -> Option<Self> {
+        let (is_negative, unsigned) = match string.as_bytes().first()? {
+            b'-' => (true, &string[1..]),
+            b'+' => (false, &string[1..]),
+            _ => (false, string),
+        };
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(position) => (
+                &unsigned[..position],
+                unsigned[position + 1..].parse::<i64>().ok()?,
+            ),
+            None => (unsigned, 0),
+        };
+        if exponent.unsigned_abs() > MAX_EXPONENT_MAGNITUDE {
+            return None;
+        }
+        let (integer_digits, fractional_digits) = match mantissa.find('.') {
+            Some(position) => {
+                (&mantissa[..position], &mantissa[position + 1..])
+            }
+            None => (mantissa, ""),
+        };
+        if (integer_digits.is_empty() && fractional_digits.is_empty())
+            || !integer_digits
+                .bytes()
+                .chain(fractional_digits.bytes())
+                .all(|byte| byte.is_ascii_digit())
+        {
+            return None;
+        }
+        let digits = [integer_digits, fractional_digits].concat();
+        let mut numerator =
+            Component::from_str_radix(digits.trim_start_matches('0'), 10)
+                .unwrap_or_else(|_| Component::from(0u8));
+        if is_negative {
+            numerator = -numerator;
+        }
+        let exponent = exponent
+            .checked_sub(i64::try_from(fractional_digits.len()).ok()?)?;
+        let scale = to_power(
+            Component::from(10u8),
+            u32::try_from(exponent.unsigned_abs()).ok()?,
+        );
+        Some(if exponent < 0 {
+            Fraction::from(numerator) / Fraction::from(scale)
+        } else {
+            Fraction::from(numerator * scale)
+        })
+    }
+}
+
+impl<Component> ToDecimal for Fraction<Component>
+where
+    Component: Clone
+        + DivRem<Output = (Component, Component)>
+        + From<u8>
+        + Mul<Output = Component>
+        + Neg<Output = Component>
+        + One
+        + ToString,
+    for<'a> &'a Component: Signed + Unitary + Zeroable,
+{
+    fn to_decimal(&self) -> Option<String> {
+        let (mut denominator, mut twos_count, mut fives_count) =
+            (self.denominator().clone(), 0u32, 0u32);
+        for (factor, count) in
+            [(2u8, &mut twos_count), (5u8, &mut fives_count)]
+        {
+            loop {
+                let (quotient, remainder) =
+                    denominator.clone().div_rem(Component::from(factor));
+                if !remainder.is_zero() {
+                    break;
+                }
+                denominator = quotient;
+                *count += 1;
+            }
+        }
+        if !denominator.is_one() {
+            return None;
+        }
+        let scale = if twos_count < fives_count {
+            to_power(Component::from(2u8), fives_count - twos_count)
+        } else {
+            to_power(Component::from(5u8), twos_count - fives_count)
+        };
+        let numerator = self.numerator();
+        let is_negative = numerator.is_negative();
+        let magnitude = if is_negative {
+            -numerator.clone()
+        } else {
+            numerator.clone()
+        };
+        let digits = (magnitude * scale).to_string();
+        let fractional_digits_count =
+            usize::try_from(twos_count.max(fives_count)).ok()?;
+        let digits = if digits.len() <= fractional_digits_count {
+            "0".repeat(fractional_digits_count + 1 - digits.len()) + &digits
+        } else {
+            digits
+        };
+        let (integer_digits, fractional_digits) =
+            digits.split_at(digits.len() - fractional_digits_count);
+        let mut result = String::with_capacity(digits.len() + 2);
+        if is_negative {
+            result.push('-');
+        }
+        result.push_str(integer_digits);
+        if !fractional_digits.is_empty() {
+            result.push('.');
+            result.push_str(fractional_digits);
+        }
+        Some(result)
+    }
+}
+
+fn to_power<Value: Clone + Mul<Output = Value> + One>(
+    mut base: Value,
+    mut exponent: u32,
+) -> Value {
+    let mut result = Value::one();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base.clone();
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.clone() * base;
+        }
+    }
+    result
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// Error of reading or writing GeoJSON.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Text ended before the object was complete.
+    UnexpectedEnd,
+    /// Unexpected character at the given byte offset.
+    UnexpectedCharacter(usize),
+    /// Object lacks the member with the given name.
+    MissingMember(&'static str),
+    /// Geometry type at the given byte offset
+    /// does not correspond to the target geometry.
+    UnexpectedType(usize),
+    /// Coordinate at the given byte offset is not a valid number.
+    InvalidCoordinate(usize),
+    /// Coordinates at the given byte offset
+    /// do not form the target geometry.
+    InvalidGeometry(usize, &'static str),
+    /// Coordinate has no finite decimal representation.
+    NonDecimalCoordinate,
+    /// Array or object at the given byte offset is nested too deeply.
+    TooDeepNesting(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => {
+                formatter.write_str("Unexpected end of GeoJSON.")
+            }
+            Self::UnexpectedCharacter(position) => formatter.write_fmt(
+                format_args!("Unexpected character at position {}.", position),
+            ),
+            Self::MissingMember(name) => formatter
+                .write_fmt(format_args!("Missing \"{}\" member.", name)),
+            Self::UnexpectedType(position) => {
+                formatter.write_fmt(format_args!(
+                    "Unexpected geometry type at position {}.",
+                    position
+                ))
+            }
+            Self::InvalidCoordinate(position) => formatter.write_fmt(
+                format_args!("Invalid coordinate at position {}.", position),
+            ),
+            Self::InvalidGeometry(position, reason) => {
+                formatter.write_fmt(format_args!(
+                    "Invalid geometry at position {}: {}.",
+                    position, reason
+                ))
+            }
+            Self::NonDecimalCoordinate => formatter
+                .write_str("Coordinate has no finite decimal representation."),
+            Self::TooDeepNesting(position) => formatter.write_fmt(
+                format_args!("Too deep nesting at position {}.", position),
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
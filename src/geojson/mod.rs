@@ -0,0 +1,47 @@
+//! GeoJSON representation of geometries.
+//!
+//! Coordinates are read & written as exact decimals,
+//! e.g. `Point::<Fraction>::from_geojson(r#"{"type": "Point", "coordinates": [1, 0.5]}"#)`.
+//! Contours are represented as closed `LineString`s
+//! & multisegments as `MultiLineString`s of two-point line strings.
+//! Arrays & objects nested deeper than geometries need are rejected:
+//!
+//! ```
+//! use rene::geojson::{Error, FromGeoJson};
+//! use rene::geometries::{Multipolygon, Point};
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//! let multipolygon = Multipolygon::<Scalar>::from_geojson(
+//!     r#"{"type": "MultiPolygon", "coordinates": [
+//!         [[[0, 0], [1, 0], [0, 1], [0, 0]]],
+//!         [[[2, 0], [3, 0], [2, 1], [2, 0]]]
+//!     ]}"#,
+//! );
+//! assert!(multipolygon.is_ok());
+//! let text = format!(
+//!     r#"{{"type": "Point", "coordinates": {}"#,
+//!     "[".repeat(200_000)
+//! );
+//! assert!(matches!(
+//!     Point::<Scalar>::from_geojson(&text),
+//!     Err(Error::TooDeepNesting(_))
+//! ));
+//! ```
+
+pub use self::error::Error;
+pub(crate) use self::parsing::{parse_coordinates, Node};
+pub use self::traits::{FromGeoJson, ToGeoJson};
+#[cfg(feature = "python")]
+pub(crate) use self::validation::to_point;
+pub(crate) use self::writing::{
+    write_geometry, write_polygon_coordinates, write_position,
+    write_positions, write_ring_coordinates,
+};
+
+mod error;
+mod parsing;
+mod traits;
+mod validation;
+mod writing;
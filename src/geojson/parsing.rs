@@ -0,0 +1,311 @@
+use crate::decimal::FromDecimal;
+use crate::geometries::{
+    to_contour, to_line_string, to_polygon, to_segment, Contour, Point,
+    Polygon, Segment,
+};
+
+use super::error::Error;
+use super::validation::to_point;
+
+const LITERALS: [&str; 3] = ["false", "null", "true"];
+/// Maximum nesting of arrays & objects,
+/// geometries need at most 5 (object with 4 levels of coordinates arrays),
+/// the rest is left for nested foreign members.
+const MAX_NESTING_DEPTH: usize = 16;
+
+/// Parses GeoJSON geometry object of the given type
+/// and returns its coordinates.
+pub(crate) fn parse_coordinates<'a>(
+    text: &'a str,
+    geometry_type: &str,
+) -> Result<Node<'a>, Error> {
+    let mut parser = Parser {
+        text,
+        position: 0,
+        depth: 0,
+    };
+    let root = parser.parse_value()?;
+    parser.finish()?;
+    let members = match root.value {
+        Value::Object(members) => members,
+        _ => return Err(Error::UnexpectedCharacter(root.position)),
+    };
+    let (mut type_node, mut coordinates) = (None, None);
+    for (name, node) in members {
+        match name.as_str() {
+            "coordinates" => coordinates = Some(node),
+            "type" => type_node = Some(node),
+            _ => {}
+        }
+    }
+    let type_node = type_node.ok_or(Error::MissingMember("type"))?;
+    match &type_node.value {
+        Value::String(value) if value == geometry_type => {}
+        _ => return Err(Error::UnexpectedType(type_node.position)),
+    }
+    coordinates.ok_or(Error::MissingMember("coordinates"))
+}
+
+/// JSON value with its byte offset.
+pub(crate) struct Node<'a> {
+    position: usize,
+    value: Value<'a>,
+}
+
+enum Value<'a> {
+    Array(Vec<Node<'a>>),
+    Literal,
+    Number(&'a str),
+    Object(Vec<(String, Node<'a>)>),
+    String(String),
+}
+
+impl<'a> Node<'a> {
+    pub(crate) fn into_contour<Scalar: Clone + FromDecimal + PartialEq>(
+        self,
+    ) -> Result<Contour<Scalar>, Error> {
+        let position = self.position;
+        to_contour(self.into_points()?)
+            .map_err(|reason| Error::InvalidGeometry(position, reason))
+    }
+
+    pub(crate) fn into_line_string<Scalar: FromDecimal>(
+        self,
+    ) -> Result<Vec<Point<Scalar>>, Error> {
+        let position = self.position;
+        to_line_string(self.into_points()?)
+            .map_err(|reason| Error::InvalidGeometry(position, reason))
+    }
+
+    pub(crate) fn into_list<Item>(
+        self,
+        convert: impl FnMut(Self) -> Result<Item, Error>,
+    ) -> Result<Vec<Item>, Error> {
+        match self.value {
+            Value::Array(items) => items.into_iter().map(convert).collect(),
+            _ => Err(Error::InvalidGeometry(
+                self.position,
+                "coordinates should be an array",
+            )),
+        }
+    }
+
+    pub(crate) fn into_point<Scalar: FromDecimal>(
+        self,
+    ) -> Result<Point<Scalar>, Error> {
+        let position = self.position;
+        to_point(self.into_list(Self::into_coordinate)?)
+            .map_err(|reason| Error::InvalidGeometry(position, reason))
+    }
+
+    pub(crate) fn into_points<Scalar: FromDecimal>(
+        self,
+    ) -> Result<Vec<Point<Scalar>>, Error> {
+        self.into_list(Self::into_point)
+    }
+
+    pub(crate) fn into_polygon<Scalar: Clone + FromDecimal + PartialEq>(
+        self,
+    ) -> Result<Polygon<Scalar>, Error> {
+        let position = self.position;
+        to_polygon(self.into_list(Self::into_contour)?)
+            .map_err(|reason| Error::InvalidGeometry(position, reason))
+    }
+
+    pub(crate) fn into_segment<Scalar: FromDecimal>(
+        self,
+    ) -> Result<Segment<Scalar>, Error> {
+        let position = self.position;
+        to_segment(self.into_points()?)
+            .map_err(|reason| Error::InvalidGeometry(position, reason))
+    }
+
+    fn into_coordinate<Scalar: FromDecimal>(self) -> Result<Scalar, Error> {
+        match self.value {
+            Value::Number(text) => Scalar::from_decimal(text),
+            _ => None,
+        }
+        .ok_or(Error::InvalidCoordinate(self.position))
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn finish(mut self) -> Result<(), Error> {
+        self.skip_whitespaces();
+        if self.position == self.text.len() {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedCharacter(self.position))
+        }
+    }
+
+    fn expect_character(&mut self, character: char) -> Result<(), Error> {
+        self.skip_whitespaces();
+        if self.text[self.position..].starts_with(character) {
+            self.position += character.len_utf8();
+            Ok(())
+        } else {
+            Err(self.to_unexpected_character_error())
+        }
+    }
+
+    fn next_character(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn parse_items<Item>(
+        &mut self,
+        closing: char,
+        mut parse_item: impl FnMut(&mut Self) -> Result<Item, Error>,
+    ) -> Result<Vec<Item>, Error> {
+        self.skip_whitespaces();
+        let mut result = Vec::new();
+        if self.next_character() == Some(closing) {
+            self.position += 1;
+            return Ok(result);
+        }
+        loop {
+            result.push(parse_item(self)?);
+            self.skip_whitespaces();
+            match self.next_character() {
+                Some(',') => self.position += 1,
+                Some(character) if character == closing => {
+                    self.position += 1;
+                    return Ok(result);
+                }
+                _ => return Err(self.to_unexpected_character_error()),
+            }
+        }
+    }
+
+    fn parse_member(&mut self) -> Result<(String, Node<'a>), Error> {
+        self.expect_character('"')?;
+        let name = self.parse_string_rest()?;
+        self.expect_character(':')?;
+        Ok((name, self.parse_value()?))
+    }
+
+    fn parse_string_rest(&mut self) -> Result<String, Error> {
+        let mut result = String::new();
+        let mut characters = self.text[self.position..].char_indices();
+        while let Some((offset, character)) = characters.next() {
+            match character {
+                '"' => {
+                    self.position += offset + 1;
+                    return Ok(result);
+                }
+                '\\' => {
+                    let escaped = match characters.next() {
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'u')) => {
+                            let start = self.position + offset + 2;
+                            let code = self
+                                .text
+                                .get(start..start + 4)
+                                .and_then(|digits| {
+                                    u32::from_str_radix(digits, 16).ok()
+                                })
+                                .ok_or(Error::UnexpectedCharacter(start))?;
+                            for _ in 0..4 {
+                                characters.next();
+                            }
+                            char::from_u32(code)
+                                .unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        Some((_, character @ ('"' | '\\' | '/'))) => character,
+                        Some((offset, _)) => {
+                            return Err(Error::UnexpectedCharacter(
+                                self.position + offset,
+                            ))
+                        }
+                        None => return Err(Error::UnexpectedEnd),
+                    };
+                    result.push(escaped);
+                }
+                character if character.is_control() => {
+                    return Err(Error::UnexpectedCharacter(
+                        self.position + offset,
+                    ))
+                }
+                character => result.push(character),
+            }
+        }
+        Err(Error::UnexpectedEnd)
+    }
+
+    fn parse_value(&mut self) -> Result<Node<'a>, Error> {
+        self.skip_whitespaces();
+        let position = self.position;
+        let value = match self.next_character() {
+            Some(opening @ ('[' | '{')) => {
+                if self.depth == MAX_NESTING_DEPTH {
+                    return Err(Error::TooDeepNesting(position));
+                }
+                self.position += 1;
+                self.depth += 1;
+                let result = if opening == '[' {
+                    self.parse_items(']', Self::parse_value).map(Value::Array)
+                } else {
+                    self.parse_items('}', Self::parse_member)
+                        .map(Value::Object)
+                };
+                self.depth -= 1;
+                result?
+            }
+            Some('"') => {
+                self.position += 1;
+                Value::String(self.parse_string_rest()?)
+            }
+            Some(character)
+                if character.is_ascii_digit() || character == '-' =>
+            {
+                let length = self.text[position..]
+                    .find(|character: char| {
+                        !(character.is_ascii_digit()
+                            || matches!(
+                                character,
+                                '+' | '-' | '.' | 'e' | 'E'
+                            ))
+                    })
+                    .unwrap_or(self.text.len() - position);
+                self.position += length;
+                Value::Number(&self.text[position..self.position])
+            }
+            Some(_) => {
+                let literal = LITERALS
+                    .iter()
+                    .find(|literal| {
+                        self.text[position..].starts_with(*literal)
+                    })
+                    .ok_or(Error::UnexpectedCharacter(position))?;
+                self.position += literal.len();
+                Value::Literal
+            }
+            None => return Err(Error::UnexpectedEnd),
+        };
+        Ok(Node { position, value })
+    }
+
+    fn skip_whitespaces(&mut self) {
+        self.position =
+            self.text.len() - self.text[self.position..].trim_start().len();
+    }
+
+    fn to_unexpected_character_error(&self) -> Error {
+        if self.position == self.text.len() {
+            Error::UnexpectedEnd
+        } else {
+            Error::UnexpectedCharacter(self.position)
+        }
+    }
+}
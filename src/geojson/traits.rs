@@ -0,0 +1,11 @@
+use super::error::Error;
+
+pub trait FromGeoJson: Sized {
+    /// Parses geometry from its GeoJSON geometry object.
+    fn from_geojson(text: &str) -> Result<Self, Error>;
+}
+
+pub trait ToGeoJson {
+    /// Returns GeoJSON geometry object of the geometry.
+    fn to_geojson(self) -> Result<String, Error>;
+}
@@ -0,0 +1,10 @@
+use crate::geometries::Point;
+
+pub(crate) fn to_point<Scalar>(
+    mut coordinates: Vec<Scalar>,
+) -> Result<Point<Scalar>, &'static str> {
+    match (coordinates.pop(), coordinates.pop()) {
+        (Some(y), Some(x)) if coordinates.is_empty() => Ok(Point::new(x, y)),
+        _ => Err("position should have exactly 2 coordinates"),
+    }
+}
@@ -0,0 +1,67 @@
+use crate::decimal::ToDecimal;
+use crate::geometries::{Contour, Point, Polygon};
+use crate::traits::{Elemental, Iterable, Multivertexal, Polygonal};
+
+use super::error::Error;
+
+pub(crate) fn write_geometry(
+    geometry_type: &str,
+    write_coordinates: impl FnOnce(&mut String) -> Result<(), Error>,
+) -> Result<String, Error> {
+    let mut result = String::from("{\"type\": \"");
+    result.push_str(geometry_type);
+    result.push_str("\", \"coordinates\": ");
+    write_coordinates(&mut result)?;
+    result.push('}');
+    Ok(result)
+}
+
+pub(crate) fn write_polygon_coordinates<Scalar: ToDecimal>(
+    polygon: &Polygon<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    output.push('[');
+    write_ring_coordinates(polygon.border(), output)?;
+    for hole in polygon.holes().iter() {
+        output.push_str(", ");
+        write_ring_coordinates(hole, output)?;
+    }
+    output.push(']');
+    Ok(())
+}
+
+pub(crate) fn write_position<Scalar: ToDecimal>(
+    point: &Point<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    let (x, y) = point.coordinates();
+    output.push('[');
+    output.push_str(&x.to_decimal().ok_or(Error::NonDecimalCoordinate)?);
+    output.push_str(", ");
+    output.push_str(&y.to_decimal().ok_or(Error::NonDecimalCoordinate)?);
+    output.push(']');
+    Ok(())
+}
+
+pub(crate) fn write_positions<'a, Scalar: 'a + ToDecimal>(
+    points: impl Iterator<Item = &'a Point<Scalar>>,
+    output: &mut String,
+) -> Result<(), Error> {
+    output.push('[');
+    for (index, point) in points.enumerate() {
+        if index > 0 {
+            output.push_str(", ");
+        }
+        write_position(point, output)?;
+    }
+    output.push(']');
+    Ok(())
+}
+
+pub(crate) fn write_ring_coordinates<Scalar: ToDecimal>(
+    contour: &Contour<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    let vertices = contour.vertices();
+    write_positions(vertices.iter().chain(vertices.iter().take(1)), output)
+}
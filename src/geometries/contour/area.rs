@@ -0,0 +1,55 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::{Abs, One, Zero};
+
+use crate::geometries::Point;
+use crate::operations::CrossMultiply;
+use crate::traits::Area;
+
+use super::types::Contour;
+
+impl<'a, Scalar> Area for &'a Contour<Scalar>
+where
+    Scalar: Abs<Output = Scalar>
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + One
+        + Zero,
+    &'a Point<Scalar>: CrossMultiply<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn area(self) -> Self::Output {
+        self.signed_area().abs()
+    }
+
+    fn signed_area(self) -> Self::Output {
+        let first_vertex = &self.vertices[0];
+        let mut result = Scalar::zero();
+        for index in 1..self.vertices.len() - 1 {
+            result = result
+                + CrossMultiply::cross_multiply(
+                    first_vertex,
+                    &self.vertices[index],
+                    first_vertex,
+                    &self.vertices[index + 1],
+                );
+        }
+        result / (Scalar::one() + Scalar::one())
+    }
+}
+
+impl<Scalar> Area for Contour<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: Area<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn area(self) -> Self::Output {
+        (&self).area()
+    }
+
+    fn signed_area(self) -> Self::Output {
+        (&self).signed_area()
+    }
+}
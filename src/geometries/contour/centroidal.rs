@@ -0,0 +1,85 @@
+use std::ops::{Add, Div, Mul};
+
+use traiter::numbers::{One, Zero, Zeroable};
+
+use crate::geometries::Point;
+use crate::operations::CrossMultiply;
+use crate::traits::{Centroidal, Elemental};
+
+use super::types::Contour;
+
+impl<'a, Scalar> Centroidal for &'a Contour<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + Clone
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + One
+        + Zero
+        + Zeroable,
+    for<'b> &'b Scalar: Add<Output = Scalar> + Add<Scalar, Output = Scalar>,
+    &'a Point<Scalar>: CrossMultiply<Output = Scalar>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        let first_vertex = &self.vertices[0];
+        let mut x_numerator = Scalar::zero();
+        let mut y_numerator = Scalar::zero();
+        let mut doubled_area = Scalar::zero();
+        for index in 1..self.vertices.len() - 1 {
+            let (vertex, next_vertex) =
+                (&self.vertices[index], &self.vertices[index + 1]);
+            let triangle_doubled_area = CrossMultiply::cross_multiply(
+                first_vertex,
+                vertex,
+                first_vertex,
+                next_vertex,
+            );
+            x_numerator = x_numerator
+                + triangle_doubled_area.clone()
+                    * (first_vertex.x() + (vertex.x() + next_vertex.x()));
+            y_numerator = y_numerator
+                + triangle_doubled_area.clone()
+                    * (first_vertex.y() + (vertex.y() + next_vertex.y()));
+            doubled_area = doubled_area + triangle_doubled_area;
+        }
+        if doubled_area.clone().is_zero() {
+            self.to_vertices_centroid()
+        } else {
+            let denominator =
+                (Scalar::one() + Scalar::one() + Scalar::one()) * doubled_area;
+            Point::new(
+                x_numerator / denominator.clone(),
+                y_numerator / denominator,
+            )
+        }
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        let mut x_sum = Scalar::zero();
+        let mut y_sum = Scalar::zero();
+        let mut vertices_count = Scalar::zero();
+        for vertex in &self.vertices {
+            x_sum = vertex.x() + x_sum;
+            y_sum = vertex.y() + y_sum;
+            vertices_count = vertices_count + Scalar::one();
+        }
+        Point::new(x_sum / vertices_count.clone(), y_sum / vertices_count)
+    }
+}
+
+impl<Scalar> Centroidal for Contour<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        (&self).to_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        (&self).to_vertices_centroid()
+    }
+}
@@ -0,0 +1,28 @@
+use crate::geometries::{Hull, Point};
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::ConvexHull;
+
+use super::types::Contour;
+
+impl<Scalar: Ord> ConvexHull for &Contour<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::from_vertices(to_convex_hull(self.vertices.iter().collect()))
+    }
+}
+
+impl<Scalar> ConvexHull for Contour<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: ConvexHull<Output = Hull<Scalar>>,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        (&self).convex_hull()
+    }
+}
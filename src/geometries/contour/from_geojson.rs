@@ -0,0 +1,10 @@
+use crate::decimal::FromDecimal;
+use crate::geojson::{parse_coordinates, Error, FromGeoJson};
+
+use super::types::Contour;
+
+impl<Scalar: Clone + FromDecimal + PartialEq> FromGeoJson for Contour<Scalar> {
+    fn from_geojson(text: &str) -> Result<Self, Error> {
+        parse_coordinates(text, "LineString")?.into_contour()
+    }
+}
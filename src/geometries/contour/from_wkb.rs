@@ -0,0 +1,17 @@
+use crate::wkb::{
+    Error, FromWkb, FromWkbCoordinate, Reader, LINE_STRING_TYPE,
+};
+
+use super::types::Contour;
+
+impl<Scalar: Clone + FromWkbCoordinate + PartialEq> FromWkb
+    for Contour<Scalar>
+{
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, _) = reader.read_header(&[LINE_STRING_TYPE])?;
+        let result = reader.read_ring_body(format)?;
+        reader.finish()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::decimal::FromDecimal;
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Contour;
+
+impl<Scalar: Clone + FromDecimal + PartialEq> FromWkt for Contour<Scalar> {
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&["LINEARRING", "LINESTRING"])?;
+        let result = parser.parse_ring_text()?;
+        parser.finish()?;
+        Ok(result)
+    }
+}
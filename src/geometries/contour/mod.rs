@@ -1,19 +1,41 @@
 pub use self::types::Contour;
 
+mod area;
 mod bounded;
+mod centroidal;
 mod contoural;
+mod convex_hull;
 mod difference;
+mod discrete_hausdorff_metric;
 mod eq;
 mod from;
+mod from_geojson;
+mod from_wkb;
+mod from_wkt;
 mod hash;
+mod hausdorff_metric;
 mod intersection;
 mod locatable;
+mod make_valid;
+mod matrix_relatable;
 mod multisegmental;
 mod multivertexal;
+mod nearest_points;
+mod normalize;
 mod oriented;
 mod partial_eq;
 mod relatable;
+mod squared_metric;
 mod symmetric_difference;
+mod to_boundary_segments;
+mod to_boundary_vertices;
+mod to_dimension;
+mod to_geojson;
 mod to_reversed_segments;
+mod to_svg;
+mod to_wkb;
+mod to_wkt;
+mod transform;
 mod types;
 mod union;
+mod validate;
@@ -0,0 +1,142 @@
+use crate::geometries::{Multipolygon, Multisegment, Point, Polygon, Segment};
+use crate::metric::{NearestPoints, SquaredMetric};
+
+use super::types::Contour;
+
+impl<Scalar: Ord> NearestPoints for &Contour<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Contour<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: Self) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Multipolygon<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Multipolygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multipolygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Multisegment<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Multisegment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multisegment<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Point<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Point<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Polygon<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Polygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Polygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Segment<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Segment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Segment<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
+use crate::geometries::Point;
+use crate::operations::{shrink_collinear_vertices, Orient};
+use crate::oriented::{Orientation, Oriented};
+use crate::traits::Normalize;
+
+use super::types::Contour;
+
+impl<Scalar: Ord> Normalize for &Contour<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Contour<Scalar>;
+
+    fn normalize(self) -> Self::Output {
+        let mut vertices = self.vertices.iter().collect::<Vec<_>>();
+        vertices.rotate_left(self.to_min_vertex_index());
+        if self.to_orientation() == Orientation::Clockwise {
+            vertices[1..].reverse();
+        }
+        let shrunk_vertices = shrink_collinear_vertices(&vertices);
+        Contour::new(
+            // degenerate contours are kept as is
+            if shrunk_vertices.len() < MIN_CONTOUR_VERTICES_COUNT {
+                vertices
+            } else {
+                shrunk_vertices
+            }
+            .into_iter()
+            .cloned()
+            .collect(),
+        )
+    }
+}
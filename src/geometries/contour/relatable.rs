@@ -8,9 +8,9 @@ use traiter::numbers::Signed;
 use crate::geometries::{
     Empty, Multipolygon, Multisegment, Point, Polygon, Segment,
 };
+use crate::metric::SquaredMetric;
 use crate::operations::{
     CrossMultiply, DotMultiply, IntersectCrossingSegments, Orient, Square,
-    SquaredMetric,
 };
 use crate::relatable::{Relatable, Relation};
 use crate::relating::{contour, linear, mixed, Event};
@@ -0,0 +1,118 @@
+use crate::geometries::{Multipolygon, Multisegment, Point, Polygon, Segment};
+use crate::metric::SquaredMetric;
+
+use super::types::Contour;
+
+impl<Scalar: Ord> SquaredMetric for &Contour<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Contour<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: Self) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Multipolygon<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Multipolygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multipolygon<Scalar>,
+    ) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Multisegment<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Multisegment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multisegment<Scalar>,
+    ) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Point<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Point<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Polygon<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Polygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Polygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Segment<Scalar>> for &Contour<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Segment<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::geometries::Point;
+use crate::operations::ToBoundaryVertices;
+
+use super::types::Contour;
+
+impl<'a, Scalar> ToBoundaryVertices for &'a Contour<Scalar> {
+    type Output = Vec<&'a Point<Scalar>>;
+
+    fn to_boundary_vertices(self) -> Self::Output {
+        self.vertices.iter().collect()
+    }
+}
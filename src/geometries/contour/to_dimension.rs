@@ -0,0 +1,10 @@
+use crate::operations::ToDimension;
+use crate::relatable::Dimension;
+
+use super::types::Contour;
+
+impl<Scalar> ToDimension for &Contour<Scalar> {
+    fn to_dimension(self) -> Dimension {
+        Dimension::One
+    }
+}
@@ -0,0 +1,14 @@
+use crate::decimal::ToDecimal;
+use crate::geojson::{
+    write_geometry, write_ring_coordinates, Error, ToGeoJson,
+};
+
+use super::types::Contour;
+
+impl<Scalar: ToDecimal> ToGeoJson for &Contour<Scalar> {
+    fn to_geojson(self) -> Result<String, Error> {
+        write_geometry("LineString", |output| {
+            write_ring_coordinates(self, output)
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use std::convert::TryFrom;
+use std::iter::once;
+
+use crate::bounded::Bounded;
+use crate::svg::{
+    contour_to_polyline, to_double_bounding_box, to_path_data, Canvas, Error,
+    Style, ToSvg,
+};
+
+use super::types::Contour;
+
+impl<Scalar> ToSvg for &Contour<Scalar>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    for<'a> &'a Contour<Scalar>: Bounded<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let mut canvas = Canvas::new(
+            Some(&to_double_bounding_box(&self.to_bounding_box())?),
+            style,
+        );
+        canvas
+            .add_path(&to_path_data(once(contour_to_polyline(self)?)), false);
+        Ok(canvas.finish())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::wkb::{
+    Encoding, Error, ToWkb, ToWkbCoordinate, Writer, LINE_STRING_TYPE,
+};
+
+use super::types::Contour;
+
+impl<Scalar: ToWkbCoordinate> ToWkb for &Contour<Scalar> {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(LINE_STRING_TYPE);
+        writer.write_ring_body(self)?;
+        Ok(writer.finish())
+    }
+}
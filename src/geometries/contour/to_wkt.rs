@@ -0,0 +1,12 @@
+use crate::decimal::ToDecimal;
+use crate::wkt::{write_ring_text, Error, ToWkt};
+
+use super::types::Contour;
+
+impl<Scalar: ToDecimal> ToWkt for &Contour<Scalar> {
+    fn to_wkt(self) -> Result<String, Error> {
+        let mut result = String::from("LINEARRING ");
+        write_ring_text(self, &mut result)?;
+        Ok(result)
+    }
+}
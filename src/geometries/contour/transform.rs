@@ -0,0 +1,31 @@
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::Signed;
+
+use crate::geometries::Point;
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Contour;
+
+impl<Scalar> Transform<Scalar> for &Contour<Scalar>
+where
+    Point<Scalar>: Clone,
+    Scalar: Add<Output = Scalar> + Clone,
+    for<'a> &'a Point<Scalar>: Transform<Scalar, Output = Point<Scalar>>,
+    for<'a> &'a Scalar: Mul<Output = Scalar> + Signed + Sub<Output = Scalar>,
+{
+    type Output = Contour<Scalar>;
+
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        let mut vertices = self
+            .vertices
+            .iter()
+            .map(|vertex| vertex.transform(matrix))
+            .collect::<Vec<_>>();
+        // reflections flip the orientation, so it is restored
+        if (&matrix.determinant()).is_negative() {
+            vertices[1..].reverse();
+        }
+        Contour::new(vertices)
+    }
+}
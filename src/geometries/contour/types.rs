@@ -1,5 +1,7 @@
+use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
 use crate::geometries::{Point, Segment};
 use crate::operations::to_arg_min;
+use crate::validation::{Validatable, Violation};
 
 #[derive(Clone)]
 pub struct Contour<Scalar> {
@@ -34,3 +36,23 @@ impl<Scalar: Ord> Contour<Scalar> {
         unsafe { to_arg_min(&self.vertices).unwrap_unchecked() }
     }
 }
+
+impl<Scalar> Contour<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Self: Validatable<Point = Point<Scalar>>,
+{
+    /// Constructs contour if it is valid,
+    /// otherwise returns the first found violation.
+    pub fn try_new(
+        vertices: Vec<Point<Scalar>>,
+    ) -> Result<Self, Violation<Point<Scalar>>> {
+        if vertices.len() < MIN_CONTOUR_VERTICES_COUNT {
+            return Err(Violation::TooFewVertices {
+                count: vertices.len(),
+            });
+        }
+        let result = Self::new(vertices);
+        result.validate().into_iter().next().map_or(Ok(result), Err)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::bentley_ottmann::{to_contour_violations, Intersection, Sweep};
+use crate::geometries::{Point, Segment};
+use crate::operations::{IntersectCrossingSegments, Orient};
+use crate::traits::{Elemental, Multisegmental, Segmental};
+use crate::validation::{Validatable, Violation};
+
+use super::types::Contour;
+
+impl<Scalar: Clone + Ord> Validatable for &Contour<Scalar>
+where
+    Sweep<Point<Scalar>>: Iterator<Item = Intersection<Point<Scalar>>>
+        + for<'a, 'b> From<&'a <&'b Contour<Scalar> as Multisegmental>::Segments>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Point = Point<Scalar>;
+
+    fn validate(self) -> Vec<Violation<Self::Point>> {
+        to_contour_violations(self)
+    }
+}
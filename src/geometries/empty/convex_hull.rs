@@ -0,0 +1,11 @@
+use crate::traits::ConvexHull;
+
+use super::types::Empty;
+
+impl ConvexHull for &Empty {
+    type Output = Empty;
+
+    fn convex_hull(self) -> Self::Output {
+        *self
+    }
+}
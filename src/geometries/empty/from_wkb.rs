@@ -0,0 +1,25 @@
+use crate::wkb::{
+    Error, FromWkb, Reader, GEOMETRY_COLLECTION_TYPE, LINE_STRING_TYPE,
+    MULTI_LINE_STRING_TYPE, MULTI_POINT_TYPE, MULTI_POLYGON_TYPE, POINT_TYPE,
+    POLYGON_TYPE,
+};
+
+use super::types::Empty;
+
+impl FromWkb for Empty {
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, geometry_type) = reader.read_header(&[
+            POINT_TYPE,
+            LINE_STRING_TYPE,
+            POLYGON_TYPE,
+            MULTI_POINT_TYPE,
+            MULTI_LINE_STRING_TYPE,
+            MULTI_POLYGON_TYPE,
+            GEOMETRY_COLLECTION_TYPE,
+        ])?;
+        reader.expect_empty(geometry_type, format)?;
+        reader.finish()?;
+        Ok(Empty::new())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Empty;
+
+impl FromWkt for Empty {
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&[
+            "GEOMETRYCOLLECTION",
+            "LINEARRING",
+            "LINESTRING",
+            "MULTILINESTRING",
+            "MULTIPOINT",
+            "MULTIPOLYGON",
+            "POINT",
+            "POLYGON",
+        ])?;
+        parser.expect_empty()?;
+        parser.finish()?;
+        Ok(Empty::new())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::geometries::{Point, Segment};
+use crate::operations::{ToBoundarySegments, ToDimension};
+use crate::relatable::{IntersectionMatrix, MatrixRelatable};
+use crate::relating::matrix::relate_to_empty;
+use crate::traits::Segmental;
+
+use super::types::Empty;
+
+impl MatrixRelatable for &Empty {
+    fn relate_matrix(self, _other: Self) -> IntersectionMatrix {
+        IntersectionMatrix::new()
+    }
+}
+
+impl<Scalar, Other> MatrixRelatable<&Other> for &Empty
+where
+    Point<Scalar>: Ord,
+    for<'a> &'a Other:
+        ToBoundarySegments<Output = Vec<&'a Segment<Scalar>>> + ToDimension,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn relate_matrix(self, other: &Other) -> IntersectionMatrix {
+        relate_to_empty(other.to_dimension(), &other.to_boundary_segments())
+            .to_transposed()
+    }
+}
@@ -1,11 +1,20 @@
 pub use self::types::Empty;
 
+mod convex_hull;
 mod default;
 mod difference;
 mod from;
+mod from_wkb;
+mod from_wkt;
 mod intersection;
 mod locatable;
+mod matrix_relatable;
 mod relatable;
 mod symmetric_difference;
+mod to_dimension;
+mod to_svg;
+mod to_wkb;
+mod to_wkt;
+mod transform;
 mod types;
 mod union;
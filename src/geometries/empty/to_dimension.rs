@@ -0,0 +1,10 @@
+use crate::operations::ToDimension;
+use crate::relatable::Dimension;
+
+use super::types::Empty;
+
+impl ToDimension for &Empty {
+    fn to_dimension(self) -> Dimension {
+        Dimension::Empty
+    }
+}
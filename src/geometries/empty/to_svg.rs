@@ -0,0 +1,9 @@
+use crate::svg::{Canvas, Error, Style, ToSvg};
+
+use super::types::Empty;
+
+impl ToSvg for &Empty {
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        Ok(Canvas::new(None, style).finish())
+    }
+}
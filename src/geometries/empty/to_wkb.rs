@@ -0,0 +1,12 @@
+use crate::wkb::{Encoding, Error, ToWkb, Writer, GEOMETRY_COLLECTION_TYPE};
+
+use super::types::Empty;
+
+impl ToWkb for &Empty {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(GEOMETRY_COLLECTION_TYPE);
+        writer.write_count(0)?;
+        Ok(writer.finish())
+    }
+}
@@ -0,0 +1,9 @@
+use crate::wkt::{Error, ToWkt};
+
+use super::types::Empty;
+
+impl ToWkt for &Empty {
+    fn to_wkt(self) -> Result<String, Error> {
+        Ok(String::from("GEOMETRYCOLLECTION EMPTY"))
+    }
+}
@@ -0,0 +1,11 @@
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Empty;
+
+impl<Scalar> Transform<Scalar> for &Empty {
+    type Output = Empty;
+
+    fn transform(self, _matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        *self
+    }
+}
@@ -0,0 +1,3 @@
+pub use self::types::Hull;
+
+mod types;
@@ -0,0 +1,34 @@
+use crate::geometries::{Contour, Empty, Point, Polygon, Segment};
+
+/// Convex hull of a geometry,
+/// which degenerates to a segment if all its points are collinear,
+/// to a point if they coincide & is empty if there are no points.
+#[derive(Clone)]
+pub enum Hull<Scalar> {
+    Empty(Empty),
+    Point(Point<Scalar>),
+    Polygon(Polygon<Scalar>),
+    Segment(Segment<Scalar>),
+}
+
+impl<Scalar> Hull<Scalar>
+where
+    Point<Scalar>: Clone,
+{
+    /// Packs vertices of a convex hull
+    /// given in counterclockwise order without collinear ones.
+    pub(crate) fn from_vertices(vertices: Vec<&Point<Scalar>>) -> Self {
+        match vertices.len() {
+            0 => Self::Empty(Empty::new()),
+            1 => Self::Point(vertices[0].clone()),
+            2 => Self::Segment(Segment::new(
+                vertices[0].clone(),
+                vertices[1].clone(),
+            )),
+            _ => Self::Polygon(Polygon::new(
+                Contour::new(vertices.into_iter().cloned().collect()),
+                vec![],
+            )),
+        }
+    }
+}
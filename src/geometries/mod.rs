@@ -1,14 +1,19 @@
 pub use self::contour::Contour;
 pub use self::empty::Empty;
+pub use self::hull::Hull;
 pub use self::multipolygon::Multipolygon;
 pub use self::multisegment::Multisegment;
 pub use self::point::Point;
 pub use self::polygon::Polygon;
 pub use self::segment::Segment;
+pub(crate) use self::utils::{
+    to_contour, to_line_string, to_multisegment, to_polygon, to_segment,
+};
 
 mod contour;
 mod contracts;
 mod empty;
+mod hull;
 mod multipolygon;
 mod multisegment;
 mod point;
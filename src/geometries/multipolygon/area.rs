@@ -0,0 +1,47 @@
+use std::ops::Add;
+
+use traiter::numbers::Zero;
+
+use crate::geometries::Polygon;
+use crate::traits::Area;
+
+use super::types::Multipolygon;
+
+impl<'a, Scalar> Area for &'a Multipolygon<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Zero,
+    &'a Polygon<Scalar>: Area<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn area(self) -> Self::Output {
+        let mut result = Scalar::zero();
+        for polygon in &self.polygons {
+            result = result + polygon.area();
+        }
+        result
+    }
+
+    fn signed_area(self) -> Self::Output {
+        let mut result = Scalar::zero();
+        for polygon in &self.polygons {
+            result = result + polygon.signed_area();
+        }
+        result
+    }
+}
+
+impl<Scalar> Area for Multipolygon<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>: Area<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn area(self) -> Self::Output {
+        (&self).area()
+    }
+
+    fn signed_area(self) -> Self::Output {
+        (&self).signed_area()
+    }
+}
@@ -0,0 +1,77 @@
+use std::ops::{Add, Div, Mul};
+
+use traiter::numbers::{One, Zero, Zeroable};
+
+use crate::geometries::{Point, Polygon};
+use crate::traits::{Area, Centroidal, Elemental, Multivertexal, Polygonal};
+
+use super::types::Multipolygon;
+
+impl<'a, Scalar> Centroidal for &'a Multipolygon<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + Clone
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + One
+        + Zero
+        + Zeroable,
+    for<'b> &'b Scalar: Add<Scalar, Output = Scalar>,
+    &'a Polygon<Scalar>:
+        Area<Output = Scalar> + Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        let mut x_numerator = Scalar::zero();
+        let mut y_numerator = Scalar::zero();
+        let mut area = Scalar::zero();
+        for polygon in &self.polygons {
+            let polygon_area = polygon.area();
+            let (polygon_centroid_x, polygon_centroid_y) =
+                polygon.to_centroid().coordinates();
+            x_numerator =
+                x_numerator + polygon_area.clone() * polygon_centroid_x;
+            y_numerator =
+                y_numerator + polygon_area.clone() * polygon_centroid_y;
+            area = area + polygon_area;
+        }
+        if area.clone().is_zero() {
+            self.to_vertices_centroid()
+        } else {
+            Point::new(x_numerator / area.clone(), y_numerator / area)
+        }
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        let mut x_sum = Scalar::zero();
+        let mut y_sum = Scalar::zero();
+        let mut vertices_count = Scalar::zero();
+        for polygon in &self.polygons {
+            let (border, holes) = polygon.components();
+            for contour in std::iter::once(border).chain(holes) {
+                for vertex in contour.vertices() {
+                    x_sum = vertex.x() + x_sum;
+                    y_sum = vertex.y() + y_sum;
+                    vertices_count = vertices_count + Scalar::one();
+                }
+            }
+        }
+        Point::new(x_sum / vertices_count.clone(), y_sum / vertices_count)
+    }
+}
+
+impl<Scalar> Centroidal for Multipolygon<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>: Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        (&self).to_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        (&self).to_vertices_centroid()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::geometries::{Hull, Point};
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::{ConvexHull, Multivertexal, Polygonal};
+
+use super::types::Multipolygon;
+
+impl<Scalar: Ord> ConvexHull for &Multipolygon<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::from_vertices(to_convex_hull(
+            self.polygons
+                .iter()
+                .flat_map(|polygon| polygon.border().vertices())
+                .collect(),
+        ))
+    }
+}
+
+impl<Scalar> ConvexHull for Multipolygon<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>: ConvexHull<Output = Hull<Scalar>>,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        (&self).convex_hull()
+    }
+}
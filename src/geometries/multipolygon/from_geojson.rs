@@ -0,0 +1,15 @@
+use crate::decimal::FromDecimal;
+use crate::geojson::{parse_coordinates, Error, FromGeoJson, Node};
+
+use super::types::Multipolygon;
+
+impl<Scalar: Clone + FromDecimal + PartialEq> FromGeoJson
+    for Multipolygon<Scalar>
+{
+    fn from_geojson(text: &str) -> Result<Self, Error> {
+        Ok(Multipolygon::new(
+            parse_coordinates(text, "MultiPolygon")?
+                .into_list(Node::into_polygon)?,
+        ))
+    }
+}
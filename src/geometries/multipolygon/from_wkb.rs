@@ -0,0 +1,22 @@
+use crate::wkb::{
+    Error, FromWkb, FromWkbCoordinate, Reader, MULTI_POLYGON_TYPE,
+    POLYGON_TYPE,
+};
+
+use super::types::Multipolygon;
+
+impl<Scalar: Clone + FromWkbCoordinate + PartialEq> FromWkb
+    for Multipolygon<Scalar>
+{
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, _) = reader.read_header(&[MULTI_POLYGON_TYPE])?;
+        let polygons = reader.read_nested_list(
+            format,
+            POLYGON_TYPE,
+            Reader::read_polygon_body,
+        )?;
+        reader.finish()?;
+        Ok(Multipolygon::new(polygons))
+    }
+}
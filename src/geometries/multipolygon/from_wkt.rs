@@ -0,0 +1,16 @@
+use crate::decimal::FromDecimal;
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Multipolygon;
+
+impl<Scalar: Clone + FromDecimal + PartialEq> FromWkt
+    for Multipolygon<Scalar>
+{
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&["MULTIPOLYGON"])?;
+        let polygons = parser.parse_list(Parser::parse_polygon_text)?;
+        parser.finish()?;
+        Ok(Multipolygon::new(polygons))
+    }
+}
@@ -0,0 +1,38 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::{One, Signed, Zero};
+
+use crate::geometries::{Point, Segment};
+use crate::metric::{
+    to_squared_hausdorff_distance, HausdorffMetric, QuadraticSurd,
+};
+use crate::operations::ToBoundarySegments;
+use crate::traits::{Elemental, Segmental};
+
+use super::types::Multipolygon;
+
+impl<Scalar, Other> HausdorffMetric<&Other> for &Multipolygon<Scalar>
+where
+    Scalar: Clone + One + Ord + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Other: ToBoundarySegments<Output = Vec<&'a Segment<Scalar>>>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output = (
+        QuadraticSurd<Scalar>,
+        Point<QuadraticSurd<Scalar>>,
+        Point<QuadraticSurd<Scalar>>,
+    );
+
+    fn squared_hausdorff_distance_to(self, other: &Other) -> Self::Output {
+        to_squared_hausdorff_distance(
+            &self.to_boundary_segments(),
+            &other.to_boundary_segments(),
+        )
+    }
+}
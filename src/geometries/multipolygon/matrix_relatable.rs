@@ -0,0 +1,49 @@
+use crate::geometries::{Empty, Point, Segment};
+use crate::operations::{
+    IntersectCrossingSegments, Orient, ToBoundarySegments, ToDimension,
+};
+use crate::relatable::{IntersectionMatrix, MatrixRelatable};
+use crate::relating::matrix::{relate_segments, relate_to_empty};
+use crate::relating::{linear, mixed, shaped, Event};
+use crate::sweeping::traits::{EventsQueue, SweepLine};
+use crate::traits::{Elemental, Segmental};
+
+use super::types::Multipolygon;
+
+impl<Scalar> MatrixRelatable<&Empty> for &Multipolygon<Scalar>
+where
+    Point<Scalar>: Ord,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn relate_matrix(self, _other: &Empty) -> IntersectionMatrix {
+        relate_to_empty(self.to_dimension(), &self.to_boundary_segments())
+    }
+}
+
+impl<Scalar, Other> MatrixRelatable<&Other> for &Multipolygon<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Segment<Scalar>: Clone + Segmental<Endpoint = Point<Scalar>>,
+    linear::Operation<Point<Scalar>>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    mixed::Operation<false, Point<Scalar>>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    mixed::Operation<true, Point<Scalar>>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    shaped::Operation<Point<Scalar>>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    for<'a> &'a Other:
+        ToBoundarySegments<Output = Vec<&'a Segment<Scalar>>> + ToDimension,
+    for<'a> &'a Point<Scalar>:
+        Elemental + IntersectCrossingSegments<Output = Point<Scalar>> + Orient,
+    for<'a> <&'a Point<Scalar> as Elemental>::Coordinate: PartialEq,
+{
+    fn relate_matrix(self, other: &Other) -> IntersectionMatrix {
+        relate_segments(
+            self.to_dimension(),
+            &self.to_boundary_segments(),
+            other.to_dimension(),
+            &other.to_boundary_segments(),
+        )
+    }
+}
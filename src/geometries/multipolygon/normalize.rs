@@ -0,0 +1,23 @@
+use crate::geometries::utils::compare_polygons;
+use crate::geometries::{Point, Polygon};
+use crate::traits::Normalize;
+
+use super::types::Multipolygon;
+
+impl<Scalar> Normalize for &Multipolygon<Scalar>
+where
+    Point<Scalar>: Ord,
+    for<'a> &'a Polygon<Scalar>: Normalize<Output = Polygon<Scalar>>,
+{
+    type Output = Multipolygon<Scalar>;
+
+    fn normalize(self) -> Self::Output {
+        let mut polygons = self
+            .polygons
+            .iter()
+            .map(Normalize::normalize)
+            .collect::<Vec<_>>();
+        polygons.sort_by(compare_polygons);
+        Multipolygon::new(polygons)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::geometries::{Contour, Multisegment, Point, Polygon, Segment};
+use crate::metric::SquaredMetric;
+
+use super::types::Multipolygon;
+
+impl<Scalar: Ord> SquaredMetric<&Contour<Scalar>> for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Contour<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Contour<Scalar>) -> Self::Output {
+        unsafe {
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Multipolygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: Self) -> Self::Output {
+        unsafe {
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Multisegment<Scalar>>
+    for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Multisegment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multisegment<Scalar>,
+    ) -> Self::Output {
+        unsafe {
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Point<Scalar>> for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Point<Scalar>) -> Self::Output {
+        unsafe {
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Polygon<Scalar>> for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Polygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Polygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Segment<Scalar>> for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Segment<Scalar>) -> Self::Output {
+        unsafe {
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
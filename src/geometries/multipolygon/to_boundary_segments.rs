@@ -0,0 +1,15 @@
+use crate::geometries::Segment;
+use crate::operations::ToBoundarySegments;
+
+use super::types::Multipolygon;
+
+impl<'a, Scalar> ToBoundarySegments for &'a Multipolygon<Scalar> {
+    type Output = Vec<&'a Segment<Scalar>>;
+
+    fn to_boundary_segments(self) -> Self::Output {
+        self.polygons
+            .iter()
+            .flat_map(|polygon| polygon.to_boundary_segments())
+            .collect()
+    }
+}
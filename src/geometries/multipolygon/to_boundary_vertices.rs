@@ -0,0 +1,15 @@
+use crate::geometries::Point;
+use crate::operations::ToBoundaryVertices;
+
+use super::types::Multipolygon;
+
+impl<'a, Scalar> ToBoundaryVertices for &'a Multipolygon<Scalar> {
+    type Output = Vec<&'a Point<Scalar>>;
+
+    fn to_boundary_vertices(self) -> Self::Output {
+        self.polygons
+            .iter()
+            .flat_map(|polygon| polygon.to_boundary_vertices())
+            .collect()
+    }
+}
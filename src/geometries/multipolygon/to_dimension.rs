@@ -0,0 +1,10 @@
+use crate::operations::ToDimension;
+use crate::relatable::Dimension;
+
+use super::types::Multipolygon;
+
+impl<Scalar> ToDimension for &Multipolygon<Scalar> {
+    fn to_dimension(self) -> Dimension {
+        Dimension::Two
+    }
+}
@@ -0,0 +1,23 @@
+use crate::decimal::ToDecimal;
+use crate::geojson::{
+    write_geometry, write_polygon_coordinates, Error, ToGeoJson,
+};
+use crate::traits::{Iterable, Multipolygonal};
+
+use super::types::Multipolygon;
+
+impl<Scalar: ToDecimal> ToGeoJson for &Multipolygon<Scalar> {
+    fn to_geojson(self) -> Result<String, Error> {
+        write_geometry("MultiPolygon", |output| {
+            output.push('[');
+            for (index, polygon) in self.polygons().iter().enumerate() {
+                if index > 0 {
+                    output.push_str(", ");
+                }
+                write_polygon_coordinates(polygon, output)?;
+            }
+            output.push(']');
+            Ok(())
+        })
+    }
+}
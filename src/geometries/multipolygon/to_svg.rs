@@ -0,0 +1,28 @@
+use std::convert::TryFrom;
+
+use crate::bounded::Bounded;
+use crate::svg::{
+    polygon_to_polylines, to_double_bounding_box, to_path_data, Canvas, Error,
+    Style, ToSvg,
+};
+
+use super::types::Multipolygon;
+
+impl<Scalar> ToSvg for &Multipolygon<Scalar>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    for<'a> &'a Multipolygon<Scalar>: Bounded<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let mut canvas = Canvas::new(
+            Some(&to_double_bounding_box(&self.to_bounding_box())?),
+            style,
+        );
+        let mut polylines = Vec::new();
+        for polygon in &self.polygons {
+            polylines.extend(polygon_to_polylines(polygon)?);
+        }
+        canvas.add_path(&to_path_data(polylines.into_iter()), true);
+        Ok(canvas.finish())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::traits::{Iterable, Lengthsome, Multipolygonal};
+use crate::wkb::{
+    Encoding, Error, ToWkb, ToWkbCoordinate, Writer, MULTI_POLYGON_TYPE,
+    POLYGON_TYPE,
+};
+
+use super::types::Multipolygon;
+
+impl<Scalar: ToWkbCoordinate> ToWkb for &Multipolygon<Scalar> {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(MULTI_POLYGON_TYPE);
+        let polygons = self.polygons();
+        writer.write_count(polygons.len())?;
+        for polygon in polygons.iter() {
+            writer.write_header(POLYGON_TYPE);
+            writer.write_polygon_body(polygon)?;
+        }
+        Ok(writer.finish())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::decimal::ToDecimal;
+use crate::traits::{Iterable, Multipolygonal};
+use crate::wkt::{write_polygon_text, Error, ToWkt};
+
+use super::types::Multipolygon;
+
+impl<Scalar: ToDecimal> ToWkt for &Multipolygon<Scalar> {
+    fn to_wkt(self) -> Result<String, Error> {
+        let mut result = String::from("MULTIPOLYGON (");
+        for (index, polygon) in self.polygons().iter().enumerate() {
+            if index > 0 {
+                result.push_str(", ");
+            }
+            write_polygon_text(polygon, &mut result)?;
+        }
+        result.push(')');
+        Ok(result)
+    }
+}
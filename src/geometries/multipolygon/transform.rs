@@ -0,0 +1,21 @@
+use crate::geometries::Polygon;
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Multipolygon;
+
+impl<Scalar> Transform<Scalar> for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        Transform<Scalar, Output = Option<Polygon<Scalar>>>,
+{
+    type Output = Option<Multipolygon<Scalar>>;
+
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        Some(Multipolygon::new(
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.transform(matrix))
+                .collect::<Option<Vec<_>>>()?,
+        ))
+    }
+}
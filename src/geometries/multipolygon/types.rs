@@ -1,4 +1,5 @@
-use crate::geometries::Polygon;
+use crate::geometries::{Point, Polygon};
+use crate::validation::{Validatable, Violation};
 
 #[derive(Clone)]
 pub struct Multipolygon<Scalar> {
@@ -11,3 +12,18 @@ impl<Scalar> Multipolygon<Scalar> {
         Self { polygons }
     }
 }
+
+impl<Scalar> Multipolygon<Scalar>
+where
+    for<'a> &'a Self: Validatable<Point = Point<Scalar>>,
+{
+    /// Constructs multipolygon if it is valid (including orientation
+    /// of its contours, same as `Validatable::is_valid`),
+    /// otherwise returns the first found violation.
+    pub fn try_new(
+        polygons: Vec<Polygon<Scalar>>,
+    ) -> Result<Self, Violation<Point<Scalar>>> {
+        let result = Self::new(polygons);
+        result.validate().into_iter().next().map_or(Ok(result), Err)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::constants::MIN_MULTIPOLYGON_POLYGONS_COUNT;
+use crate::geometries::{Point, Polygon};
+use crate::relatable::{Relatable, Relation};
+use crate::validation::{Validatable, Violation};
+
+use super::types::Multipolygon;
+
+impl<Scalar> Validatable for &Multipolygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        Relatable<&'a Polygon<Scalar>> + Validatable<Point = Point<Scalar>>,
+{
+    type Point = Point<Scalar>;
+
+    fn validate(self) -> Vec<Violation<Self::Point>> {
+        let mut result = Vec::new();
+        if self.polygons.len() < MIN_MULTIPOLYGON_POLYGONS_COUNT {
+            result.push(Violation::TooFewPolygons {
+                count: self.polygons.len(),
+            });
+        }
+        let mut valid_polygons = Vec::with_capacity(self.polygons.len());
+        for (polygon_index, polygon) in self.polygons.iter().enumerate() {
+            let polygon_violations = polygon.validate();
+            if polygon_violations.is_empty() {
+                valid_polygons.push((polygon_index, polygon));
+            } else {
+                result.extend(polygon_violations.into_iter().map(
+                    |violation| Violation::InvalidPolygon {
+                        polygon_index,
+                        violation: Box::new(violation),
+                    },
+                ));
+            }
+        }
+        for (position, &(first_polygon_index, first_polygon)) in
+            valid_polygons.iter().enumerate()
+        {
+            for &(second_polygon_index, second_polygon) in
+                &valid_polygons[position + 1..]
+            {
+                if !matches!(
+                    first_polygon.relate_to(second_polygon),
+                    Relation::Disjoint | Relation::Touch
+                ) {
+                    result.push(Violation::PolygonsOverlap {
+                        first_polygon_index,
+                        second_polygon_index,
+                    });
+                }
+            }
+        }
+        result
+    }
+}
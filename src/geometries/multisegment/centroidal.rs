@@ -0,0 +1,54 @@
+use std::ops::{Add, Div, Mul};
+
+use traiter::numbers::{One, Zero};
+
+use crate::geometries::Point;
+use crate::traits::{Centroidal, Elemental, Segmental};
+
+use super::types::Multisegment;
+
+impl<Scalar> Centroidal for &Multisegment<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + Clone
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + One
+        + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        self.to_vertices_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        let mut x_sum = Scalar::zero();
+        let mut y_sum = Scalar::zero();
+        let mut segments_count = Scalar::zero();
+        for segment in &self.segments {
+            let (start, end) = segment.endpoints();
+            x_sum = x_sum + (start.x() + end.x());
+            y_sum = y_sum + (start.y() + end.y());
+            segments_count = segments_count + Scalar::one();
+        }
+        let endpoints_count = (Scalar::one() + Scalar::one()) * segments_count;
+        Point::new(x_sum / endpoints_count.clone(), y_sum / endpoints_count)
+    }
+}
+
+impl<Scalar> Centroidal for Multisegment<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>: Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        (&self).to_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        (&self).to_vertices_centroid()
+    }
+}
@@ -0,0 +1,36 @@
+use crate::geometries::{Hull, Point};
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::{ConvexHull, Segmental};
+
+use super::types::Multisegment;
+
+impl<Scalar: Ord> ConvexHull for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::from_vertices(to_convex_hull(
+            self.segments
+                .iter()
+                .flat_map(|segment| {
+                    let (start, end) = segment.endpoints();
+                    [start, end]
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl<Scalar> ConvexHull for Multisegment<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>: ConvexHull<Output = Hull<Scalar>>,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        (&self).convex_hull()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::geometries::Point;
+use crate::metric::{DiscreteHausdorffMetric, SquaredMetric};
+use crate::operations::{
+    to_squared_discrete_hausdorff_distance, ToBoundaryVertices,
+};
+
+use super::types::Multisegment;
+
+impl<Scalar, Other> DiscreteHausdorffMetric<&Other> for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone,
+    Scalar: Ord,
+    for<'a> &'a Other: ToBoundaryVertices<Output = Vec<&'a Point<Scalar>>>,
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+{
+    type Output = (Scalar, Point<Scalar>, Point<Scalar>);
+
+    fn squared_discrete_hausdorff_distance_to(
+        self,
+        other: &Other,
+    ) -> Self::Output {
+        to_squared_discrete_hausdorff_distance(
+            &self.to_boundary_vertices(),
+            &other.to_boundary_vertices(),
+        )
+    }
+}
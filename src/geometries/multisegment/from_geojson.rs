@@ -0,0 +1,14 @@
+use crate::decimal::FromDecimal;
+use crate::geojson::{parse_coordinates, Error, FromGeoJson, Node};
+use crate::geometries::to_multisegment;
+
+use super::types::Multisegment;
+
+impl<Scalar: Clone + FromDecimal> FromGeoJson for Multisegment<Scalar> {
+    fn from_geojson(text: &str) -> Result<Self, Error> {
+        Ok(to_multisegment(
+            parse_coordinates(text, "MultiLineString")?
+                .into_list(Node::into_line_string)?,
+        ))
+    }
+}
@@ -0,0 +1,21 @@
+use crate::geometries::to_multisegment;
+use crate::wkb::{
+    Error, FromWkb, FromWkbCoordinate, Reader, LINE_STRING_TYPE,
+    MULTI_LINE_STRING_TYPE,
+};
+
+use super::types::Multisegment;
+
+impl<Scalar: Clone + FromWkbCoordinate> FromWkb for Multisegment<Scalar> {
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, _) = reader.read_header(&[MULTI_LINE_STRING_TYPE])?;
+        let line_strings = reader.read_nested_list(
+            format,
+            LINE_STRING_TYPE,
+            Reader::read_line_string_body,
+        )?;
+        reader.finish()?;
+        Ok(to_multisegment(line_strings))
+    }
+}
@@ -0,0 +1,16 @@
+use crate::decimal::FromDecimal;
+use crate::geometries::to_multisegment;
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Multisegment;
+
+impl<Scalar: Clone + FromDecimal> FromWkt for Multisegment<Scalar> {
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&["MULTILINESTRING"])?;
+        let line_strings =
+            parser.parse_list(Parser::parse_line_string_text)?;
+        parser.finish()?;
+        Ok(to_multisegment(line_strings))
+    }
+}
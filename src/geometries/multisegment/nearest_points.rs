@@ -0,0 +1,143 @@
+use crate::geometries::{Contour, Multipolygon, Point, Polygon, Segment};
+use crate::metric::{NearestPoints, SquaredMetric};
+
+use super::types::Multisegment;
+
+impl<Scalar: Ord> NearestPoints<&Contour<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Contour<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Contour<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Multipolygon<Scalar>>
+    for &Multisegment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Multipolygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multipolygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints for &Multisegment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Multisegment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: Self) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Point<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Point<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Polygon<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Polygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Polygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> NearestPoints<&Segment<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Segment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Segment<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.nearest_points(other))
+                .min_by_key(|(point, other_point)| {
+                    point.squared_distance_to(other_point)
+                })
+                .unwrap_unchecked()
+        }
+    }
+}
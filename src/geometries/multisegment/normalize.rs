@@ -0,0 +1,28 @@
+use crate::geometries::{Point, Segment};
+use crate::operations::to_sorted_pair;
+use crate::traits::{Normalize, Segmental};
+
+use super::types::Multisegment;
+
+impl<Scalar> Normalize for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output = Multisegment<Scalar>;
+
+    fn normalize(self) -> Self::Output {
+        let mut endpoints = self
+            .segments
+            .iter()
+            .map(|segment| to_sorted_pair(segment.endpoints()))
+            .collect::<Vec<_>>();
+        endpoints.sort();
+        Multisegment::new(
+            endpoints
+                .into_iter()
+                .map(|(start, end)| Segment::new(start.clone(), end.clone()))
+                .collect(),
+        )
+    }
+}
@@ -8,9 +8,9 @@ use crate::bounded::Bounded;
 use crate::geometries::{
     Contour, Empty, Multipolygon, Point, Polygon, Segment,
 };
+use crate::metric::SquaredMetric;
 use crate::operations::{
     CrossMultiply, DotMultiply, IntersectCrossingSegments, Orient, Square,
-    SquaredMetric,
 };
 use crate::relatable::{Relatable, Relation};
 use crate::relating::{linear, mixed, multisegment, Event};
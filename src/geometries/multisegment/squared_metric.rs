@@ -0,0 +1,116 @@
+use crate::geometries::{Contour, Multipolygon, Point, Polygon, Segment};
+use crate::metric::SquaredMetric;
+
+use super::types::Multisegment;
+
+impl<Scalar: Ord> SquaredMetric<&Contour<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Contour<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Contour<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Multipolygon<Scalar>>
+    for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Multipolygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multipolygon<Scalar>,
+    ) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Multisegment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: Self) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Point<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Point<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Polygon<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Polygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Polygon<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar: Ord> SquaredMetric<&Segment<Scalar>> for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Segment<Scalar>) -> Self::Output {
+        unsafe {
+            self.segments
+                .iter()
+                .map(|segment| segment.squared_distance_to(other))
+                .min()
+                .unwrap_unchecked()
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::geometries::Segment;
+use crate::operations::ToBoundarySegments;
+
+use super::types::Multisegment;
+
+impl<'a, Scalar> ToBoundarySegments for &'a Multisegment<Scalar> {
+    type Output = Vec<&'a Segment<Scalar>>;
+
+    fn to_boundary_segments(self) -> Self::Output {
+        self.segments.iter().collect()
+    }
+}
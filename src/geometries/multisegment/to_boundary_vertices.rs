@@ -0,0 +1,19 @@
+use crate::geometries::Point;
+use crate::operations::ToBoundaryVertices;
+use crate::traits::Segmental;
+
+use super::types::Multisegment;
+
+impl<'a, Scalar> ToBoundaryVertices for &'a Multisegment<Scalar> {
+    type Output = Vec<&'a Point<Scalar>>;
+
+    fn to_boundary_vertices(self) -> Self::Output {
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                let (start, end) = segment.endpoints();
+                [start, end]
+            })
+            .collect()
+    }
+}
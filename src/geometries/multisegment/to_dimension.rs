@@ -0,0 +1,10 @@
+use crate::operations::ToDimension;
+use crate::relatable::Dimension;
+
+use super::types::Multisegment;
+
+impl<Scalar> ToDimension for &Multisegment<Scalar> {
+    fn to_dimension(self) -> Dimension {
+        Dimension::One
+    }
+}
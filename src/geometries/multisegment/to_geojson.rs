@@ -0,0 +1,22 @@
+use crate::decimal::ToDecimal;
+use crate::geojson::{write_geometry, write_positions, Error, ToGeoJson};
+use crate::traits::{Iterable, Multisegmental, Segmental};
+
+use super::types::Multisegment;
+
+impl<Scalar: ToDecimal> ToGeoJson for &Multisegment<Scalar> {
+    fn to_geojson(self) -> Result<String, Error> {
+        write_geometry("MultiLineString", |output| {
+            output.push('[');
+            for (index, segment) in self.segments().iter().enumerate() {
+                if index > 0 {
+                    output.push_str(", ");
+                }
+                let (start, end) = segment.endpoints();
+                write_positions([start, end].iter().copied(), output)?;
+            }
+            output.push(']');
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use std::convert::TryFrom;
+
+use crate::bounded::Bounded;
+use crate::svg::{
+    to_coordinates, to_double_bounding_box, to_path_data, Canvas, Error,
+    Style, ToSvg,
+};
+use crate::traits::Segmental;
+
+use super::types::Multisegment;
+
+impl<Scalar> ToSvg for &Multisegment<Scalar>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    for<'a> &'a Multisegment<Scalar>: Bounded<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let mut canvas = Canvas::new(
+            Some(&to_double_bounding_box(&self.to_bounding_box())?),
+            style,
+        );
+        let polylines = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let (start, end) = segment.endpoints();
+                Ok((vec![to_coordinates(start)?, to_coordinates(end)?], false))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        canvas.add_path(&to_path_data(polylines.into_iter()), false);
+        Ok(canvas.finish())
+    }
+}
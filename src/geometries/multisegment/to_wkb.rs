@@ -0,0 +1,22 @@
+use crate::traits::{Iterable, Lengthsome, Multisegmental, Segmental};
+use crate::wkb::{
+    Encoding, Error, ToWkb, ToWkbCoordinate, Writer, LINE_STRING_TYPE,
+    MULTI_LINE_STRING_TYPE,
+};
+
+use super::types::Multisegment;
+
+impl<Scalar: ToWkbCoordinate> ToWkb for &Multisegment<Scalar> {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(MULTI_LINE_STRING_TYPE);
+        let segments = self.segments();
+        writer.write_count(segments.len())?;
+        for segment in segments.iter() {
+            writer.write_header(LINE_STRING_TYPE);
+            let (start, end) = segment.endpoints();
+            writer.write_points_body([start, end].iter().copied())?;
+        }
+        Ok(writer.finish())
+    }
+}
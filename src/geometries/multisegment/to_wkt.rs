@@ -0,0 +1,20 @@
+use crate::decimal::ToDecimal;
+use crate::traits::{Iterable, Multisegmental, Segmental};
+use crate::wkt::{write_points_text, Error, ToWkt};
+
+use super::types::Multisegment;
+
+impl<Scalar: ToDecimal> ToWkt for &Multisegment<Scalar> {
+    fn to_wkt(self) -> Result<String, Error> {
+        let mut result = String::from("MULTILINESTRING (");
+        for (index, segment) in self.segments().iter().enumerate() {
+            if index > 0 {
+                result.push_str(", ");
+            }
+            let (start, end) = segment.endpoints();
+            write_points_text([start, end].iter().copied(), &mut result)?;
+        }
+        result.push(')');
+        Ok(result)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::geometries::Segment;
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Multisegment;
+
+impl<Scalar> Transform<Scalar> for &Multisegment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>: Transform<Scalar, Output = Segment<Scalar>>,
+{
+    type Output = Multisegment<Scalar>;
+
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        Multisegment::new(
+            self.segments
+                .iter()
+                .map(|segment| segment.transform(matrix))
+                .collect(),
+        )
+    }
+}
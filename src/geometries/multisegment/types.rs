@@ -1,4 +1,5 @@
-use crate::geometries::Segment;
+use crate::geometries::{Point, Segment};
+use crate::validation::{Validatable, Violation};
 
 #[derive(Clone)]
 pub struct Multisegment<Scalar> {
@@ -11,3 +12,17 @@ impl<Scalar> Multisegment<Scalar> {
         Self { segments }
     }
 }
+
+impl<Scalar> Multisegment<Scalar>
+where
+    for<'a> &'a Self: Validatable<Point = Point<Scalar>>,
+{
+    /// Constructs multisegment if it is valid,
+    /// otherwise returns the first found violation.
+    pub fn try_new(
+        segments: Vec<Segment<Scalar>>,
+    ) -> Result<Self, Violation<Point<Scalar>>> {
+        let result = Self::new(segments);
+        result.validate().into_iter().next().map_or(Ok(result), Err)
+    }
+}
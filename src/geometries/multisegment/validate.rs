@@ -0,0 +1,26 @@
+use crate::bentley_ottmann::{
+    to_multisegment_violations, Intersection, Sweep,
+};
+use crate::geometries::{Point, Segment};
+use crate::operations::{IntersectCrossingSegments, Orient};
+use crate::traits::{Multisegmental, Segmental};
+use crate::validation::{Validatable, Violation};
+
+use super::types::Multisegment;
+
+impl<Scalar: Clone + Ord> Validatable for &Multisegment<Scalar>
+where
+    Sweep<Point<Scalar>>: Iterator<Item = Intersection<Point<Scalar>>>
+        + for<'a, 'b> From<
+            &'a <&'b Multisegment<Scalar> as Multisegmental>::Segments,
+        >,
+    for<'a> &'a Point<Scalar>:
+        IntersectCrossingSegments<Output = Point<Scalar>> + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Point = Point<Scalar>;
+
+    fn validate(self) -> Vec<Violation<Self::Point>> {
+        to_multisegment_violations(self)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::geometries::Hull;
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::ConvexHull;
+
+use super::types::Point;
+
+impl<Scalar: Ord> ConvexHull for &[Point<Scalar>]
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::from_vertices(to_convex_hull(self.iter().collect()))
+    }
+}
+
+impl<Scalar> ConvexHull for &Point<Scalar>
+where
+    Point<Scalar>: Clone,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::Point(self.clone())
+    }
+}
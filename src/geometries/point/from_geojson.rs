@@ -0,0 +1,10 @@
+use crate::decimal::FromDecimal;
+use crate::geojson::{parse_coordinates, Error, FromGeoJson};
+
+use super::types::Point;
+
+impl<Scalar: FromDecimal> FromGeoJson for Point<Scalar> {
+    fn from_geojson(text: &str) -> Result<Self, Error> {
+        parse_coordinates(text, "Point")?.into_point()
+    }
+}
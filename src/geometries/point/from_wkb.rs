@@ -0,0 +1,13 @@
+use crate::wkb::{Error, FromWkb, FromWkbCoordinate, Reader, POINT_TYPE};
+
+use super::types::Point;
+
+impl<Scalar: FromWkbCoordinate> FromWkb for Point<Scalar> {
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, _) = reader.read_header(&[POINT_TYPE])?;
+        let result = reader.read_point_body(format)?;
+        reader.finish()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::decimal::FromDecimal;
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Point;
+
+impl<Scalar: FromDecimal> FromWkt for Point<Scalar> {
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&["POINT"])?;
+        let result = parser.parse_point_text()?;
+        parser.finish()?;
+        Ok(result)
+    }
+}
@@ -1,11 +1,22 @@
 pub use self::types::Point;
 
+mod convex_hull;
 mod display;
 mod elemental;
 mod eq;
 mod from;
+mod from_geojson;
+mod from_wkb;
+mod from_wkt;
 mod hash;
+mod nearest_points;
 mod ord;
 mod partial_eq;
 mod partial_ord;
+mod squared_metric;
+mod to_geojson;
+mod to_svg;
+mod to_wkb;
+mod to_wkt;
+mod transform;
 mod types;
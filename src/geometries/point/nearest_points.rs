@@ -0,0 +1,92 @@
+use crate::geometries::{
+    Contour, Multipolygon, Multisegment, Polygon, Segment,
+};
+use crate::metric::NearestPoints;
+
+use super::types::Point;
+
+impl<Scalar> NearestPoints<&Contour<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Contour<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Multipolygon<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multipolygon<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Multisegment<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multisegment<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints for &Point<Scalar>
+where
+    Point<Scalar>: Clone,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: Self) -> Self::Output {
+        (self.clone(), other.clone())
+    }
+}
+
+impl<Scalar> NearestPoints<&Polygon<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Polygon<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Segment<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Segment<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::geometries::{
+    Contour, Multipolygon, Multisegment, Polygon, Segment,
+};
+use crate::metric::SquaredMetric;
+
+use super::types::Point;
+
+impl<Scalar> SquaredMetric<&Contour<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Contour<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Contour<Scalar>) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Multipolygon<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multipolygon<Scalar>,
+    ) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Multisegment<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multisegment<Scalar>,
+    ) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Polygon<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Polygon<Scalar>) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Segment<Scalar>> for &Point<Scalar>
+where
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Segment<Scalar>) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::decimal::ToDecimal;
+use crate::geojson::{write_geometry, write_position, Error, ToGeoJson};
+
+use super::types::Point;
+
+impl<Scalar: ToDecimal> ToGeoJson for &Point<Scalar> {
+    fn to_geojson(self) -> Result<String, Error> {
+        write_geometry("Point", |output| write_position(self, output))
+    }
+}
@@ -0,0 +1,24 @@
+use std::convert::TryFrom;
+
+use crate::bounded;
+use crate::svg::{
+    to_coordinates, to_double_bounding_box, Canvas, Error, Style, ToSvg,
+};
+use crate::traits::Elemental;
+
+use super::types::Point;
+
+impl<Scalar> ToSvg for &Point<Scalar>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let (x, y) = self.coordinates();
+        let mut canvas = Canvas::new(
+            Some(&to_double_bounding_box(&bounded::Box::new(x, x, y, y))?),
+            style,
+        );
+        canvas.add_point(to_coordinates(self)?);
+        Ok(canvas.finish())
+    }
+}
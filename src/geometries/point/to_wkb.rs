@@ -0,0 +1,14 @@
+use crate::wkb::{
+    Encoding, Error, ToWkb, ToWkbCoordinate, Writer, POINT_TYPE,
+};
+
+use super::types::Point;
+
+impl<Scalar: ToWkbCoordinate> ToWkb for &Point<Scalar> {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(POINT_TYPE);
+        writer.write_point_body(self)?;
+        Ok(writer.finish())
+    }
+}
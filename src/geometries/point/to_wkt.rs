@@ -0,0 +1,12 @@
+use crate::decimal::ToDecimal;
+use crate::wkt::{write_point_text, Error, ToWkt};
+
+use super::types::Point;
+
+impl<Scalar: ToDecimal> ToWkt for &Point<Scalar> {
+    fn to_wkt(self) -> Result<String, Error> {
+        let mut result = String::from("POINT ");
+        write_point_text(self, &mut result)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,24 @@
+use std::ops::{Add, Mul};
+
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Point;
+
+impl<Scalar> Transform<Scalar> for &Point<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Clone,
+    for<'a> &'a Scalar: Mul<Output = Scalar>,
+{
+    type Output = Point<Scalar>;
+
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        Point::new(
+            matrix.get_xx() * &self.x
+                + matrix.get_xy() * &self.y
+                + matrix.get_x_offset().clone(),
+            matrix.get_yx() * &self.x
+                + matrix.get_yy() * &self.y
+                + matrix.get_y_offset().clone(),
+        )
+    }
+}
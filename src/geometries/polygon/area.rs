@@ -0,0 +1,52 @@
+use std::ops::{Add, Neg, Sub};
+
+use traiter::numbers::{Signed, Zero};
+
+use crate::geometries::Contour;
+use crate::traits::Area;
+
+use super::types::Polygon;
+
+impl<'a, Scalar> Area for &'a Polygon<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + Neg<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>
+        + Zero,
+    &'a Contour<Scalar>: Area<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn area(self) -> Self::Output {
+        let mut holes_area = Scalar::zero();
+        for hole in &self.holes {
+            holes_area = holes_area + hole.area();
+        }
+        (&self.border).area() - holes_area
+    }
+
+    fn signed_area(self) -> Self::Output {
+        let area = self.area();
+        if (&self.border).signed_area().is_negative() {
+            -area
+        } else {
+            area
+        }
+    }
+}
+
+impl<Scalar> Area for Polygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>: Area<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn area(self) -> Self::Output {
+        (&self).area()
+    }
+
+    fn signed_area(self) -> Self::Output {
+        (&self).signed_area()
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::{One, Zero, Zeroable};
+
+use crate::geometries::{Contour, Point};
+use crate::traits::{Area, Centroidal, Elemental, Multivertexal};
+
+use super::types::Polygon;
+
+impl<'a, Scalar> Centroidal for &'a Polygon<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + Clone
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + One
+        + Sub<Output = Scalar>
+        + Zero
+        + Zeroable,
+    for<'b> &'b Scalar: Add<Scalar, Output = Scalar>,
+    &'a Contour<Scalar>:
+        Area<Output = Scalar> + Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        let mut area = (&self.border).area();
+        let (border_centroid_x, border_centroid_y) =
+            (&self.border).to_centroid().coordinates();
+        let mut x_numerator = area.clone() * border_centroid_x;
+        let mut y_numerator = area.clone() * border_centroid_y;
+        for hole in &self.holes {
+            let hole_area = hole.area();
+            let (hole_centroid_x, hole_centroid_y) =
+                hole.to_centroid().coordinates();
+            x_numerator = x_numerator - hole_area.clone() * hole_centroid_x;
+            y_numerator = y_numerator - hole_area.clone() * hole_centroid_y;
+            area = area - hole_area;
+        }
+        if area.clone().is_zero() {
+            self.to_vertices_centroid()
+        } else {
+            Point::new(x_numerator / area.clone(), y_numerator / area)
+        }
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        let mut x_sum = Scalar::zero();
+        let mut y_sum = Scalar::zero();
+        let mut vertices_count = Scalar::zero();
+        for contour in std::iter::once(&self.border).chain(&self.holes) {
+            for vertex in contour.vertices() {
+                x_sum = vertex.x() + x_sum;
+                y_sum = vertex.y() + y_sum;
+                vertices_count = vertices_count + Scalar::one();
+            }
+        }
+        Point::new(x_sum / vertices_count.clone(), y_sum / vertices_count)
+    }
+}
+
+impl<Scalar> Centroidal for Polygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>: Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        (&self).to_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        (&self).to_vertices_centroid()
+    }
+}
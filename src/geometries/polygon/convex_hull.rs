@@ -0,0 +1,30 @@
+use crate::geometries::{Hull, Point};
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::{ConvexHull, Multivertexal};
+
+use super::types::Polygon;
+
+impl<Scalar: Ord> ConvexHull for &Polygon<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::from_vertices(to_convex_hull(
+            (&self.border).vertices().into_iter().collect(),
+        ))
+    }
+}
+
+impl<Scalar> ConvexHull for Polygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>: ConvexHull<Output = Hull<Scalar>>,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        (&self).convex_hull()
+    }
+}
@@ -0,0 +1,10 @@
+use crate::decimal::FromDecimal;
+use crate::geojson::{parse_coordinates, Error, FromGeoJson};
+
+use super::types::Polygon;
+
+impl<Scalar: Clone + FromDecimal + PartialEq> FromGeoJson for Polygon<Scalar> {
+    fn from_geojson(text: &str) -> Result<Self, Error> {
+        parse_coordinates(text, "Polygon")?.into_polygon()
+    }
+}
@@ -0,0 +1,15 @@
+use crate::wkb::{Error, FromWkb, FromWkbCoordinate, Reader, POLYGON_TYPE};
+
+use super::types::Polygon;
+
+impl<Scalar: Clone + FromWkbCoordinate + PartialEq> FromWkb
+    for Polygon<Scalar>
+{
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, _) = reader.read_header(&[POLYGON_TYPE])?;
+        let result = reader.read_polygon_body(format)?;
+        reader.finish()?;
+        Ok(result)
+    }
+}
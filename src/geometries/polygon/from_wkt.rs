@@ -0,0 +1,14 @@
+use crate::decimal::FromDecimal;
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Polygon;
+
+impl<Scalar: Clone + FromDecimal + PartialEq> FromWkt for Polygon<Scalar> {
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&["POLYGON"])?;
+        let result = parser.parse_polygon_text()?;
+        parser.finish()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,35 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::One;
+
+use crate::bentley_ottmann::{Intersection, Sweep};
+use crate::geometries::{Contour, Point, Segment};
+use crate::operations::{CrossMultiply, Orient};
+use crate::traits::{Elemental, Multisegmental, Segmental};
+use crate::validation::{to_valid_polygons, FillRule, MakeValid};
+
+use super::types::Polygon;
+
+impl<Scalar> MakeValid for &Polygon<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Clone + Div<Output = Scalar> + One + Ord,
+    Segment<Scalar>: Clone,
+    Sweep<Point<Scalar>>: for<'a> From<&'a Vec<Segment<Scalar>>>
+        + Iterator<Item = Intersection<Point<Scalar>>>,
+    for<'a> &'a Contour<Scalar>:
+        Multisegmental<IndexSegment = Segment<Scalar>>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + Orient,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output = Vec<Polygon<Scalar>>;
+
+    fn make_valid(self, fill_rule: FillRule) -> Self::Output {
+        to_valid_polygons(
+            std::iter::once(&self.border).chain(&self.holes),
+            fill_rule,
+        )
+    }
+}
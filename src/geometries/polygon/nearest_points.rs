@@ -0,0 +1,148 @@
+use crate::geometries::{Contour, Multipolygon, Multisegment, Point, Segment};
+use crate::locatable::{Locatable, Location};
+use crate::metric::{NearestPoints, SquaredMetric};
+use crate::operations::ToBoundarySegments;
+use crate::traits::Segmental;
+
+use super::types::Polygon;
+
+impl<Scalar> NearestPoints<&Contour<Scalar>> for &Polygon<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: NearestPoints<
+        &'a Polygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Contour<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Multipolygon<Scalar>> for &Polygon<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>: NearestPoints<
+        &'a Polygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multipolygon<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Multisegment<Scalar>> for &Polygon<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>: NearestPoints<
+        &'a Polygon<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multisegment<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Point<Scalar>> for &Polygon<Scalar>
+where
+    Point<Scalar>: Clone,
+    Scalar: Ord,
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<
+        &'a Point<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Point<Scalar>) -> Self::Output {
+        if self.locate(other) == Location::Exterior {
+            unsafe {
+                self.to_boundary_segments()
+                    .into_iter()
+                    .map(|segment| segment.nearest_points(other))
+                    .min_by_key(|(point, other_point)| {
+                        point.squared_distance_to(other_point)
+                    })
+                    .unwrap_unchecked()
+            }
+        } else {
+            (other.clone(), other.clone())
+        }
+    }
+}
+
+impl<Scalar> NearestPoints<&Segment<Scalar>> for &Polygon<Scalar>
+where
+    Point<Scalar>: Clone,
+    Scalar: Ord,
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+    for<'a> &'a Segment<Scalar>: NearestPoints<Output = (Point<Scalar>, Point<Scalar>)>
+        + Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Segment<Scalar>) -> Self::Output {
+        let other_start = other.start();
+        if self.locate(other_start) == Location::Exterior {
+            unsafe {
+                self.to_boundary_segments()
+                    .into_iter()
+                    .map(|segment| segment.nearest_points(other))
+                    .min_by_key(|(point, other_point)| {
+                        point.squared_distance_to(other_point)
+                    })
+                    .unwrap_unchecked()
+            }
+        } else {
+            (other_start.clone(), other_start.clone())
+        }
+    }
+}
+
+impl<Scalar> NearestPoints for &Polygon<Scalar>
+where
+    Point<Scalar>: Clone,
+    Scalar: Ord,
+    for<'a> &'a Point<Scalar>: SquaredMetric<Output = Scalar>,
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+    for<'a> &'a Segment<Scalar>:
+        NearestPoints<Output = (Point<Scalar>, Point<Scalar>)>,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: Self) -> Self::Output {
+        let (first_vertex, other_first_vertex) =
+            (self.to_first_vertex(), other.to_first_vertex());
+        if self.locate(other_first_vertex) != Location::Exterior {
+            (other_first_vertex.clone(), other_first_vertex.clone())
+        } else if other.locate(first_vertex) != Location::Exterior {
+            (first_vertex.clone(), first_vertex.clone())
+        } else {
+            let other_segments = other.to_boundary_segments();
+            unsafe {
+                self.to_boundary_segments()
+                    .into_iter()
+                    .flat_map(|segment| {
+                        other_segments.iter().map(move |other_segment| {
+                            segment.nearest_points(other_segment)
+                        })
+                    })
+                    .min_by_key(|(point, other_point)| {
+                        point.squared_distance_to(other_point)
+                    })
+                    .unwrap_unchecked()
+            }
+        }
+    }
+}
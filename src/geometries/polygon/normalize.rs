@@ -0,0 +1,28 @@
+use crate::geometries::utils::compare_contours;
+use crate::geometries::{Contour, Point};
+use crate::traits::{Multivertexal, Normalize};
+
+use super::types::Polygon;
+
+impl<Scalar> Normalize for &Polygon<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Contour<Scalar>: Normalize<Output = Contour<Scalar>>,
+{
+    type Output = Polygon<Scalar>;
+
+    fn normalize(self) -> Self::Output {
+        let mut holes = self
+            .holes
+            .iter()
+            .map(|hole| {
+                let mut vertices = hole.normalize().vertices();
+                // normalized contours are counterclockwise
+                vertices[1..].reverse();
+                Contour::new(vertices)
+            })
+            .collect::<Vec<_>>();
+        holes.sort_by(compare_contours);
+        Polygon::new(self.border.normalize(), holes)
+    }
+}
@@ -8,9 +8,9 @@ use crate::bounded::Bounded;
 use crate::geometries::{
     Contour, Empty, Multipolygon, Multisegment, Point, Segment,
 };
+use crate::metric::SquaredMetric;
 use crate::operations::{
     CrossMultiply, DotMultiply, IntersectCrossingSegments, Orient, Square,
-    SquaredMetric,
 };
 use crate::oriented::Oriented;
 use crate::relatable::{Relatable, Relation};
@@ -0,0 +1,129 @@
+use traiter::numbers::Zero;
+
+use crate::geometries::{Contour, Multipolygon, Multisegment, Point, Segment};
+use crate::locatable::{Locatable, Location};
+use crate::metric::SquaredMetric;
+use crate::operations::ToBoundarySegments;
+use crate::traits::Segmental;
+
+use super::types::Polygon;
+
+impl<Scalar> SquaredMetric<&Contour<Scalar>> for &Polygon<Scalar>
+where
+    for<'a> &'a Contour<Scalar>:
+        SquaredMetric<&'a Polygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Contour<Scalar>) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Multipolygon<Scalar>> for &Polygon<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>:
+        SquaredMetric<&'a Polygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multipolygon<Scalar>,
+    ) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Multisegment<Scalar>> for &Polygon<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>:
+        SquaredMetric<&'a Polygon<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multisegment<Scalar>,
+    ) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Point<Scalar>> for &Polygon<Scalar>
+where
+    Scalar: Ord + Zero,
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+    for<'a> &'a Segment<Scalar>:
+        SquaredMetric<&'a Point<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Point<Scalar>) -> Self::Output {
+        if self.locate(other) == Location::Exterior {
+            unsafe {
+                self.to_boundary_segments()
+                    .into_iter()
+                    .map(|segment| segment.squared_distance_to(other))
+                    .min()
+                    .unwrap_unchecked()
+            }
+        } else {
+            Scalar::zero()
+        }
+    }
+}
+
+impl<Scalar> SquaredMetric<&Segment<Scalar>> for &Polygon<Scalar>
+where
+    Scalar: Ord + Zero,
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Segment<Scalar>) -> Self::Output {
+        if self.locate(other.start()) == Location::Exterior {
+            unsafe {
+                self.to_boundary_segments()
+                    .into_iter()
+                    .map(|segment| segment.squared_distance_to(other))
+                    .min()
+                    .unwrap_unchecked()
+            }
+        } else {
+            Scalar::zero()
+        }
+    }
+}
+
+impl<Scalar> SquaredMetric for &Polygon<Scalar>
+where
+    Scalar: Ord + Zero,
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+    for<'a> &'a Segment<Scalar>: SquaredMetric<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: Self) -> Self::Output {
+        if self.locate(other.to_first_vertex()) == Location::Exterior
+            && other.locate(self.to_first_vertex()) == Location::Exterior
+        {
+            let other_segments = other.to_boundary_segments();
+            unsafe {
+                self.to_boundary_segments()
+                    .into_iter()
+                    .flat_map(|segment| {
+                        other_segments.iter().map(move |other_segment| {
+                            segment.squared_distance_to(other_segment)
+                        })
+                    })
+                    .min()
+                    .unwrap_unchecked()
+            }
+        } else {
+            Scalar::zero()
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use std::iter;
+
+use crate::geometries::Segment;
+use crate::operations::ToBoundarySegments;
+
+use super::types::Polygon;
+
+impl<'a, Scalar> ToBoundarySegments for &'a Polygon<Scalar> {
+    type Output = Vec<&'a Segment<Scalar>>;
+
+    fn to_boundary_segments(self) -> Self::Output {
+        iter::once(&self.border)
+            .chain(&self.holes)
+            .flat_map(|contour| contour.to_boundary_segments())
+            .collect()
+    }
+}
@@ -0,0 +1,17 @@
+use std::iter;
+
+use crate::geometries::Point;
+use crate::operations::ToBoundaryVertices;
+
+use super::types::Polygon;
+
+impl<'a, Scalar> ToBoundaryVertices for &'a Polygon<Scalar> {
+    type Output = Vec<&'a Point<Scalar>>;
+
+    fn to_boundary_vertices(self) -> Self::Output {
+        iter::once(&self.border)
+            .chain(&self.holes)
+            .flat_map(|contour| contour.to_boundary_vertices())
+            .collect()
+    }
+}
@@ -0,0 +1,10 @@
+use crate::operations::ToDimension;
+use crate::relatable::Dimension;
+
+use super::types::Polygon;
+
+impl<Scalar> ToDimension for &Polygon<Scalar> {
+    fn to_dimension(self) -> Dimension {
+        Dimension::Two
+    }
+}
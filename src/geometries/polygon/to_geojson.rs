@@ -0,0 +1,14 @@
+use crate::decimal::ToDecimal;
+use crate::geojson::{
+    write_geometry, write_polygon_coordinates, Error, ToGeoJson,
+};
+
+use super::types::Polygon;
+
+impl<Scalar: ToDecimal> ToGeoJson for &Polygon<Scalar> {
+    fn to_geojson(self) -> Result<String, Error> {
+        write_geometry("Polygon", |output| {
+            write_polygon_coordinates(self, output)
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use std::convert::TryFrom;
+
+use crate::bounded::Bounded;
+use crate::svg::{
+    polygon_to_polylines, to_double_bounding_box, to_path_data, Canvas, Error,
+    Style, ToSvg,
+};
+
+use super::types::Polygon;
+
+impl<Scalar> ToSvg for &Polygon<Scalar>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    for<'a> &'a Polygon<Scalar>: Bounded<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let mut canvas = Canvas::new(
+            Some(&to_double_bounding_box(&self.to_bounding_box())?),
+            style,
+        );
+        canvas.add_path(
+            &to_path_data(polygon_to_polylines(self)?.into_iter()),
+            true,
+        );
+        Ok(canvas.finish())
+    }
+}
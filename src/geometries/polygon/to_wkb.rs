@@ -0,0 +1,14 @@
+use crate::wkb::{
+    Encoding, Error, ToWkb, ToWkbCoordinate, Writer, POLYGON_TYPE,
+};
+
+use super::types::Polygon;
+
+impl<Scalar: ToWkbCoordinate> ToWkb for &Polygon<Scalar> {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(POLYGON_TYPE);
+        writer.write_polygon_body(self)?;
+        Ok(writer.finish())
+    }
+}
@@ -0,0 +1,12 @@
+use crate::decimal::ToDecimal;
+use crate::wkt::{write_polygon_text, Error, ToWkt};
+
+use super::types::Polygon;
+
+impl<Scalar: ToDecimal> ToWkt for &Polygon<Scalar> {
+    fn to_wkt(self) -> Result<String, Error> {
+        let mut result = String::from("POLYGON ");
+        write_polygon_text(self, &mut result)?;
+        Ok(result)
+    }
+}
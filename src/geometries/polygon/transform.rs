@@ -0,0 +1,31 @@
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::Zeroable;
+
+use crate::geometries::Contour;
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Polygon;
+
+impl<Scalar> Transform<Scalar> for &Polygon<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Clone,
+    for<'a> &'a Contour<Scalar>: Transform<Scalar, Output = Contour<Scalar>>,
+    for<'a> &'a Scalar: Mul<Output = Scalar> + Sub<Output = Scalar> + Zeroable,
+{
+    type Output = Option<Polygon<Scalar>>;
+
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        // singular matrices collapse polygons into line segments
+        if (&matrix.determinant()).is_zero() {
+            return None;
+        }
+        Some(Polygon::new(
+            self.border.transform(matrix),
+            self.holes
+                .iter()
+                .map(|hole| hole.transform(matrix))
+                .collect(),
+        ))
+    }
+}
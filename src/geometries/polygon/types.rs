@@ -1,4 +1,6 @@
-use crate::geometries::Contour;
+use crate::geometries::{Contour, Point};
+use crate::traits::Multivertexal;
+use crate::validation::{Validatable, Violation};
 
 #[derive(Clone)]
 pub struct Polygon<Scalar> {
@@ -11,4 +13,30 @@ impl<Scalar> Polygon<Scalar> {
     pub fn new(border: Contour<Scalar>, holes: Vec<Contour<Scalar>>) -> Self {
         Self { border, holes }
     }
+
+    pub(super) fn to_first_vertex(&self) -> &Point<Scalar> {
+        unsafe {
+            (&self.border)
+                .vertices()
+                .into_iter()
+                .next()
+                .unwrap_unchecked()
+        }
+    }
+}
+
+impl<Scalar> Polygon<Scalar>
+where
+    for<'a> &'a Self: Validatable<Point = Point<Scalar>>,
+{
+    /// Constructs polygon if it is valid (including orientation
+    /// of its contours, same as `Validatable::is_valid`),
+    /// otherwise returns the first found violation.
+    pub fn try_new(
+        border: Contour<Scalar>,
+        holes: Vec<Contour<Scalar>>,
+    ) -> Result<Self, Violation<Point<Scalar>>> {
+        let result = Self::new(border, holes);
+        result.validate().into_iter().next().map_or(Ok(result), Err)
+    }
 }
@@ -0,0 +1,89 @@
+use crate::geometries::{Contour, Point};
+use crate::oriented::{Orientation, Oriented};
+use crate::relatable::{Relatable, Relation};
+use crate::validation::{to_region, Validatable, Violation};
+
+use super::types::Polygon;
+
+impl<Scalar: Clone> Validatable for &Polygon<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: Oriented
+        + Relatable<&'a Contour<Scalar>>
+        + Validatable<Point = Point<Scalar>>,
+    for<'a> &'a Polygon<Scalar>: Relatable<&'a Polygon<Scalar>>,
+{
+    type Point = Point<Scalar>;
+
+    fn validate(self) -> Vec<Violation<Self::Point>> {
+        let mut result = Vec::new();
+        let border_violations = self.border.validate();
+        let is_border_valid = border_violations.is_empty();
+        if is_border_valid {
+            if self.border.to_orientation() != Orientation::Counterclockwise {
+                result.push(Violation::WrongBorderOrientation);
+            }
+        } else {
+            result.extend(border_violations.into_iter().map(|violation| {
+                Violation::InvalidBorder {
+                    violation: Box::new(violation),
+                }
+            }));
+        }
+        let mut valid_holes = Vec::with_capacity(self.holes.len());
+        for (hole_index, hole) in self.holes.iter().enumerate() {
+            let hole_violations = hole.validate();
+            if hole_violations.is_empty() {
+                if hole.to_orientation() != Orientation::Clockwise {
+                    result
+                        .push(Violation::WrongHoleOrientation { hole_index });
+                }
+                valid_holes.push((hole_index, hole, to_region(hole)));
+            } else {
+                result.extend(hole_violations.into_iter().map(|violation| {
+                    Violation::InvalidHole {
+                        hole_index,
+                        violation: Box::new(violation),
+                    }
+                }));
+            }
+        }
+        if is_border_valid {
+            let border_region = to_region(&self.border);
+            for (hole_index, hole, hole_region) in &valid_holes {
+                match hole_region.relate_to(&border_region) {
+                    Relation::Within => {}
+                    Relation::Enclosed => {
+                        if hole.relate_to(&self.border) != Relation::Touch {
+                            result.push(Violation::HoleTouchesBorder {
+                                hole_index: *hole_index,
+                            });
+                        }
+                    }
+                    _ => {
+                        result.push(Violation::HoleOutsideBorder {
+                            hole_index: *hole_index,
+                        });
+                    }
+                }
+            }
+        }
+        for (position, (first_hole_index, _, first_hole_region)) in
+            valid_holes.iter().enumerate()
+        {
+            for (second_hole_index, _, second_hole_region) in
+                &valid_holes[position + 1..]
+            {
+                if !matches!(
+                    first_hole_region.relate_to(second_hole_region),
+                    Relation::Disjoint | Relation::Touch
+                ) {
+                    result.push(Violation::HolesOverlap {
+                        first_hole_index: *first_hole_index,
+                        second_hole_index: *second_hole_index,
+                    });
+                }
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,42 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::One;
+
+use crate::geometries::Point;
+use crate::traits::{Centroidal, Elemental};
+
+use super::types::Segment;
+
+impl<Scalar> Centroidal for &Segment<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Clone + Div<Output = Scalar> + One,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        self.to_vertices_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        let (start_x, start_y) = (&self.start).coordinates();
+        let (end_x, end_y) = (&self.end).coordinates();
+        let two = Scalar::one() + Scalar::one();
+        Point::new((start_x + end_x) / two.clone(), (start_y + end_y) / two)
+    }
+}
+
+impl<Scalar> Centroidal for Segment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>: Centroidal<Output = Point<Scalar>>,
+{
+    type Output = Point<Scalar>;
+
+    fn to_centroid(self) -> Self::Output {
+        (&self).to_centroid()
+    }
+
+    fn to_vertices_centroid(self) -> Self::Output {
+        (&self).to_vertices_centroid()
+    }
+}
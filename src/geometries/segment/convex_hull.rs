@@ -0,0 +1,28 @@
+use crate::geometries::{Hull, Point};
+use crate::operations::{to_convex_hull, Orient};
+use crate::traits::ConvexHull;
+
+use super::types::Segment;
+
+impl<Scalar: Ord> ConvexHull for &Segment<Scalar>
+where
+    Point<Scalar>: Clone,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        Hull::from_vertices(to_convex_hull(vec![&self.start, &self.end]))
+    }
+}
+
+impl<Scalar> ConvexHull for Segment<Scalar>
+where
+    for<'a> &'a Segment<Scalar>: ConvexHull<Output = Hull<Scalar>>,
+{
+    type Output = Hull<Scalar>;
+
+    fn convex_hull(self) -> Self::Output {
+        (&self).convex_hull()
+    }
+}
@@ -0,0 +1,15 @@
+use crate::wkb::{
+    Error, FromWkb, FromWkbCoordinate, Reader, LINE_STRING_TYPE,
+};
+
+use super::types::Segment;
+
+impl<Scalar: FromWkbCoordinate> FromWkb for Segment<Scalar> {
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+        let (format, _) = reader.read_header(&[LINE_STRING_TYPE])?;
+        let result = reader.read_segment_body(format)?;
+        reader.finish()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::decimal::FromDecimal;
+use crate::wkt::{Error, FromWkt, Parser};
+
+use super::types::Segment;
+
+impl<Scalar: FromDecimal> FromWkt for Segment<Scalar> {
+    fn from_wkt(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser::new(text);
+        parser.parse_tag(&["LINESTRING"])?;
+        let result = parser.parse_segment_text()?;
+        parser.finish()?;
+        Ok(result)
+    }
+}
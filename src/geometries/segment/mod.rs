@@ -1,15 +1,30 @@
 pub use types::Segment;
 
 mod bounded;
+mod centroidal;
+mod convex_hull;
 mod difference;
 mod eq;
 mod from;
+mod from_geojson;
+mod from_wkb;
+mod from_wkt;
 mod hash;
 mod intersection;
 mod locatable;
+mod matrix_relatable;
+mod nearest_points;
 mod partial_eq;
 mod relatable;
 mod segmental;
+mod squared_metric;
 mod symmetric_difference;
+mod to_boundary_segments;
+mod to_dimension;
+mod to_geojson;
+mod to_svg;
+mod to_wkb;
+mod to_wkt;
+mod transform;
 mod types;
 mod union;
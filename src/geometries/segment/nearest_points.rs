@@ -0,0 +1,124 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::Zero;
+
+use crate::geometries::{Contour, Multipolygon, Multisegment, Point, Polygon};
+use crate::metric::{NearestPoints, SquaredMetric};
+use crate::operations::{
+    to_point_segment_nearest_point, to_segments_nearest_points, CrossMultiply,
+    DotMultiply, IntersectCrossingSegments, Orient,
+};
+use crate::traits::Elemental;
+
+use super::types::Segment;
+
+impl<Scalar> NearestPoints<&Contour<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: NearestPoints<
+        &'a Segment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Contour<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Multipolygon<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>: NearestPoints<
+        &'a Segment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multipolygon<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Multisegment<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>: NearestPoints<
+        &'a Segment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Multisegment<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints<&Point<Scalar>> for &Segment<Scalar>
+where
+    Point<Scalar>: Clone + From<(Scalar, Scalar)>,
+    Scalar: Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + PartialOrd
+        + Zero
+        + for<'a> Mul<&'a Scalar, Output = Scalar>,
+    for<'a> &'a Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Point<Scalar>) -> Self::Output {
+        (
+            to_point_segment_nearest_point(other, &self.start, &self.end),
+            other.clone(),
+        )
+    }
+}
+
+impl<Scalar> NearestPoints<&Polygon<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>: NearestPoints<
+        &'a Segment<Scalar>,
+        Output = (Point<Scalar>, Point<Scalar>),
+    >,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &Polygon<Scalar>) -> Self::Output {
+        let (other_point, point) = other.nearest_points(self);
+        (point, other_point)
+    }
+}
+
+impl<Scalar> NearestPoints for &Segment<Scalar>
+where
+    Point<Scalar>: Clone + From<(Scalar, Scalar)>,
+    Scalar: Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Ord
+        + Zero
+        + for<'a> Mul<&'a Scalar, Output = Scalar>,
+    for<'a> &'a Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: Self) -> Self::Output {
+        to_segments_nearest_points(
+            &self.start,
+            &self.end,
+            &other.start,
+            &other.end,
+        )
+    }
+}
@@ -0,0 +1,100 @@
+use std::ops::Div;
+
+use traiter::numbers::Zero;
+
+use crate::geometries::{Contour, Multipolygon, Multisegment, Point, Polygon};
+use crate::metric::SquaredMetric;
+use crate::operations::{
+    to_point_segment_squared_distance, to_segments_squared_distance,
+    CrossMultiply, DotMultiply, Orient, Square,
+};
+
+use super::types::Segment;
+
+impl<Scalar> SquaredMetric<&Contour<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Contour<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Contour<Scalar>) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Multipolygon<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Multipolygon<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multipolygon<Scalar>,
+    ) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Multisegment<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Multisegment<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(
+        self,
+        other: &Multisegment<Scalar>,
+    ) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Point<Scalar>> for &Segment<Scalar>
+where
+    Scalar: Div<Output = Scalar> + PartialOrd + Square<Output = Scalar> + Zero,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Point<Scalar>) -> Self::Output {
+        to_point_segment_squared_distance(other, &self.start, &self.end)
+    }
+}
+
+impl<Scalar> SquaredMetric<&Polygon<Scalar>> for &Segment<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>:
+        SquaredMetric<&'a Segment<Scalar>, Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &Polygon<Scalar>) -> Self::Output {
+        other.squared_distance_to(self)
+    }
+}
+
+impl<Scalar> SquaredMetric for &Segment<Scalar>
+where
+    Scalar: Div<Output = Scalar> + Ord + Square<Output = Scalar> + Zero,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: Self) -> Self::Output {
+        to_segments_squared_distance(
+            &self.start,
+            &self.end,
+            &other.start,
+            &other.end,
+        )
+    }
+}
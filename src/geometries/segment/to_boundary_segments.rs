@@ -0,0 +1,11 @@
+use crate::operations::ToBoundarySegments;
+
+use super::types::Segment;
+
+impl<'a, Scalar> ToBoundarySegments for &'a Segment<Scalar> {
+    type Output = Vec<&'a Segment<Scalar>>;
+
+    fn to_boundary_segments(self) -> Self::Output {
+        vec![self]
+    }
+}
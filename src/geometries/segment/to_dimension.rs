@@ -0,0 +1,10 @@
+use crate::operations::ToDimension;
+use crate::relatable::Dimension;
+
+use super::types::Segment;
+
+impl<Scalar> ToDimension for &Segment<Scalar> {
+    fn to_dimension(self) -> Dimension {
+        Dimension::One
+    }
+}
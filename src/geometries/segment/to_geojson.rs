@@ -0,0 +1,14 @@
+use crate::decimal::ToDecimal;
+use crate::geojson::{write_geometry, write_positions, Error, ToGeoJson};
+use crate::traits::Segmental;
+
+use super::types::Segment;
+
+impl<Scalar: ToDecimal> ToGeoJson for &Segment<Scalar> {
+    fn to_geojson(self) -> Result<String, Error> {
+        write_geometry("LineString", |output| {
+            let (start, end) = self.endpoints();
+            write_positions([start, end].iter().copied(), output)
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use std::convert::TryFrom;
+use std::iter::once;
+
+use crate::bounded::Bounded;
+use crate::svg::{
+    to_coordinates, to_double_bounding_box, to_path_data, Canvas, Error,
+    Style, ToSvg,
+};
+use crate::traits::Segmental;
+
+use super::types::Segment;
+
+impl<Scalar> ToSvg for &Segment<Scalar>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    for<'a> &'a Segment<Scalar>: Bounded<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let mut canvas = Canvas::new(
+            Some(&to_double_bounding_box(&self.to_bounding_box())?),
+            style,
+        );
+        let (start, end) = self.endpoints();
+        canvas.add_path(
+            &to_path_data(once((
+                vec![to_coordinates(start)?, to_coordinates(end)?],
+                false,
+            ))),
+            false,
+        );
+        Ok(canvas.finish())
+    }
+}
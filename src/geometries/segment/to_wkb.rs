@@ -0,0 +1,16 @@
+use crate::traits::Segmental;
+use crate::wkb::{
+    Encoding, Error, ToWkb, ToWkbCoordinate, Writer, LINE_STRING_TYPE,
+};
+
+use super::types::Segment;
+
+impl<Scalar: ToWkbCoordinate> ToWkb for &Segment<Scalar> {
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(encoding);
+        writer.write_header(LINE_STRING_TYPE);
+        let (start, end) = self.endpoints();
+        writer.write_points_body([start, end].iter().copied())?;
+        Ok(writer.finish())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::decimal::ToDecimal;
+use crate::traits::Segmental;
+use crate::wkt::{write_points_text, Error, ToWkt};
+
+use super::types::Segment;
+
+impl<Scalar: ToDecimal> ToWkt for &Segment<Scalar> {
+    fn to_wkt(self) -> Result<String, Error> {
+        let (start, end) = self.endpoints();
+        let mut result = String::from("LINESTRING ");
+        write_points_text([start, end].iter().copied(), &mut result)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::geometries::Point;
+use crate::transformation::{AffineMatrix, Transform};
+
+use super::types::Segment;
+
+impl<Scalar> Transform<Scalar> for &Segment<Scalar>
+where
+    for<'a> &'a Point<Scalar>: Transform<Scalar, Output = Point<Scalar>>,
+{
+    type Output = Segment<Scalar>;
+
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output {
+        Segment::new(self.start.transform(matrix), self.end.transform(matrix))
+    }
+}
@@ -1,5 +1,46 @@
+use std::cmp::Ordering;
 use std::hash::{BuildHasher, Hash, Hasher};
 
+use crate::traits::{Iterable, Lengthsome, Multivertexal, Polygonal};
+
+use super::{Contour, Multisegment, Point, Polygon, Segment};
+
+const MIN_LINE_STRING_POINTS_COUNT: usize = 2;
+const MIN_RING_POINTS_COUNT: usize = 4;
+
+/// Compares contours by their vertices lexicographically.
+pub(super) fn compare_contours<Scalar>(
+    first: &Contour<Scalar>,
+    second: &Contour<Scalar>,
+) -> Ordering
+where
+    Point<Scalar>: Ord,
+{
+    first.vertices().iter().cmp(second.vertices().iter())
+}
+
+/// Compares polygons by their borders & then by their holes
+/// lexicographically.
+pub(super) fn compare_polygons<Scalar>(
+    first: &Polygon<Scalar>,
+    second: &Polygon<Scalar>,
+) -> Ordering
+where
+    Point<Scalar>: Ord,
+{
+    let (first_holes, second_holes) = (first.holes(), second.holes());
+    compare_contours(first.border(), second.border()).then_with(|| {
+        first_holes
+            .iter()
+            .zip(second_holes.iter())
+            .map(|(first_hole, second_hole)| {
+                compare_contours(first_hole, second_hole)
+            })
+            .find(|&ordering| ordering != Ordering::Equal)
+            .unwrap_or_else(|| first_holes.len().cmp(&second_holes.len()))
+    })
+}
+
 pub(super) fn hash_slice_unordered<
     Value: Hash,
     H: Hasher,
@@ -16,6 +57,70 @@ pub(super) fn hash_slice_unordered<
     state.write_u64(hash);
 }
 
+/// Converts points of a closed ring into a contour.
+pub(crate) fn to_contour<Scalar: Clone + PartialEq>(
+    mut points: Vec<Point<Scalar>>,
+) -> Result<Contour<Scalar>, &'static str> {
+    if points.len() < MIN_RING_POINTS_COUNT || points.first() != points.last()
+    {
+        Err("ring should be closed and have at least 4 points")
+    } else {
+        points.pop();
+        Ok(Contour::new(points))
+    }
+}
+
+/// Checks that a line string has enough points.
+pub(crate) fn to_line_string<Scalar>(
+    points: Vec<Point<Scalar>>,
+) -> Result<Vec<Point<Scalar>>, &'static str> {
+    if points.len() < MIN_LINE_STRING_POINTS_COUNT {
+        Err("line string should have at least 2 points")
+    } else {
+        Ok(points)
+    }
+}
+
+/// Joins segments of line strings into a multisegment.
+pub(crate) fn to_multisegment<Scalar: Clone>(
+    line_strings: Vec<Vec<Point<Scalar>>>,
+) -> Multisegment<Scalar> {
+    Multisegment::new(
+        line_strings
+            .iter()
+            .flat_map(|points| {
+                points.windows(2).map(|endpoints| {
+                    Segment::new(endpoints[0].clone(), endpoints[1].clone())
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Converts rings into a polygon with the first one as its border.
+pub(crate) fn to_polygon<Scalar>(
+    mut contours: Vec<Contour<Scalar>>,
+) -> Result<Polygon<Scalar>, &'static str> {
+    if contours.is_empty() {
+        Err("polygon should have a border")
+    } else {
+        let border = contours.remove(0);
+        Ok(Polygon::new(border, contours))
+    }
+}
+
+/// Converts exactly two points into a segment.
+pub(crate) fn to_segment<Scalar>(
+    mut points: Vec<Point<Scalar>>,
+) -> Result<Segment<Scalar>, &'static str> {
+    match (points.pop(), points.pop()) {
+        (Some(end), Some(start)) if points.is_empty() => {
+            Ok(Segment::new(start, end))
+        }
+        _ => Err("segment should have exactly 2 points"),
+    }
+}
+
 fn shuffle_bits(hash: u64) -> u64 {
     ((hash ^ 89_869_747) ^ (hash.wrapping_shl(16))).wrapping_mul(3_644_798_167)
 }
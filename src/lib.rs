@@ -2,17 +2,27 @@ mod bentley_ottmann;
 pub mod bounded;
 mod clipping;
 mod constants;
+#[cfg(feature = "python")]
 mod contracts;
+pub mod decimal;
+pub mod geojson;
 pub mod geometries;
 mod iteration;
 pub mod locatable;
+pub mod metric;
 mod operations;
 pub mod oriented;
+#[cfg(feature = "python")]
 mod python_binding;
 pub mod relatable;
 mod relating;
-mod seidel;
+pub mod seidel;
 mod slice_sequence;
+pub mod svg;
 mod sweeping;
 pub mod traits;
-mod triangulation;
+pub mod transformation;
+pub mod triangulation;
+pub mod validation;
+pub mod wkb;
+pub mod wkt;
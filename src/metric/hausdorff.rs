@@ -0,0 +1,501 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::{One, Sign, Signed, Zero, Zeroable};
+
+use crate::geometries::{Point, Segment};
+use crate::traits::{Elemental, Segmental};
+
+use super::quadratic_surd::{multiply_signs, QuadraticSurd};
+
+/// Squared distance from the point `start + t * direction`
+/// of the segment being measured to its nearest point `origin + t * shift`
+/// on a single segment of the other boundary,
+/// the distance is a quadratic polynomial in `t`.
+struct Piece<Scalar> {
+    quadratic: Scalar,
+    linear: Scalar,
+    constant: Scalar,
+    origin: (Scalar, Scalar),
+    shift: (Scalar, Scalar),
+}
+
+/// Subintervals of `[0, 1]` in ascending order
+/// with indices of pieces attaining the smallest distance on them.
+type Envelope<Scalar> =
+    Vec<(QuadraticSurd<Scalar>, QuadraticSurd<Scalar>, usize)>;
+
+type Witnessed<Scalar> = (
+    QuadraticSurd<Scalar>,
+    Point<QuadraticSurd<Scalar>>,
+    Point<QuadraticSurd<Scalar>>,
+);
+
+pub(crate) fn to_squared_hausdorff_distance<Scalar>(
+    first_segments: &[&Segment<Scalar>],
+    second_segments: &[&Segment<Scalar>],
+) -> Witnessed<Scalar>
+where
+    Scalar: Clone + One + Ord + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let (first_squared_distance, first_point, second_nearest_point) =
+        to_directed_squared_hausdorff_distance(
+            first_segments,
+            second_segments,
+        );
+    let (second_squared_distance, second_point, first_nearest_point) =
+        to_directed_squared_hausdorff_distance(
+            second_segments,
+            first_segments,
+        );
+    if second_squared_distance > first_squared_distance {
+        (second_squared_distance, first_nearest_point, second_point)
+    } else {
+        (first_squared_distance, first_point, second_nearest_point)
+    }
+}
+
+/// Finds the largest distance from points of the segments
+/// to the other segments' union, which for each segment
+/// is attained at an endpoint of its lower envelope's subintervals,
+/// since distances to points & lines are convex along the segment.
+fn to_directed_squared_hausdorff_distance<Scalar>(
+    segments: &[&Segment<Scalar>],
+    other_segments: &[&Segment<Scalar>],
+) -> Witnessed<Scalar>
+where
+    Scalar: Clone + One + Ord + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let mut result: Option<Witnessed<Scalar>> = None;
+    for segment in segments {
+        let (start, end) = segment.endpoints();
+        let start = (start.x().clone(), start.y().clone());
+        let direction = (end.x() - &start.0, end.y() - &start.1);
+        let mut pieces = Vec::new();
+        let envelopes = other_segments
+            .iter()
+            .map(|other_segment| {
+                to_segment_envelope(
+                    &start,
+                    &direction,
+                    other_segment,
+                    &mut pieces,
+                )
+            })
+            .collect::<Vec<_>>();
+        for (start_parameter, end_parameter, piece_index) in
+            merge_envelopes(envelopes, &pieces)
+        {
+            let piece = &pieces[piece_index];
+            for parameter in [start_parameter, end_parameter] {
+                let squared_distance = to_piece_value(piece, &parameter);
+                if result.as_ref().map_or(
+                    true,
+                    |(max_squared_distance, _, _)| {
+                        squared_distance.gt(max_squared_distance)
+                    },
+                ) {
+                    result = Some((
+                        squared_distance,
+                        to_parameter_point(&start, &direction, &parameter),
+                        to_parameter_point(
+                            &piece.origin,
+                            &piece.shift,
+                            &parameter,
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    unsafe { result.unwrap_unchecked() }
+}
+
+fn merge_envelopes<Scalar>(
+    mut envelopes: Vec<Envelope<Scalar>>,
+    pieces: &[Piece<Scalar>],
+) -> Envelope<Scalar>
+where
+    Scalar: Clone + One + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    while envelopes.len() > 1 {
+        let mut merged = Vec::with_capacity(envelopes.len().div_ceil(2));
+        let mut envelopes_iterator = envelopes.into_iter();
+        while let Some(first) = envelopes_iterator.next() {
+            merged.push(match envelopes_iterator.next() {
+                Some(second) => merge_envelopes_pair(&first, &second, pieces),
+                None => first,
+            });
+        }
+        envelopes = merged;
+    }
+    unsafe { envelopes.pop().unwrap_unchecked() }
+}
+
+fn merge_envelopes_pair<Scalar>(
+    first: &Envelope<Scalar>,
+    second: &Envelope<Scalar>,
+    pieces: &[Piece<Scalar>],
+) -> Envelope<Scalar>
+where
+    Scalar: Clone + One + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    let mut result = Envelope::with_capacity(first.len() + second.len());
+    let (mut first_index, mut second_index) = (0, 0);
+    let mut start = QuadraticSurd::from(Scalar::zero());
+    while first_index < first.len() && second_index < second.len() {
+        let (_, first_end, first_piece_index) = &first[first_index];
+        let (_, second_end, second_piece_index) = &second[second_index];
+        let end = if first_end < second_end {
+            first_end
+        } else {
+            second_end
+        };
+        push_lower_pieces(
+            &start,
+            end,
+            *first_piece_index,
+            *second_piece_index,
+            pieces,
+            &mut result,
+        );
+        start = end.clone();
+        if first_end.eq(&start) {
+            first_index += 1;
+        }
+        if second_end.eq(&start) {
+            second_index += 1;
+        }
+    }
+    result
+}
+
+/// Splits the interval by crossings of the pieces
+/// & pushes the lower piece for each part.
+fn push_lower_pieces<Scalar>(
+    start: &QuadraticSurd<Scalar>,
+    end: &QuadraticSurd<Scalar>,
+    first_piece_index: usize,
+    second_piece_index: usize,
+    pieces: &[Piece<Scalar>],
+    envelope: &mut Envelope<Scalar>,
+) where
+    Scalar: Clone + One + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    let (first_piece, second_piece) =
+        (&pieces[first_piece_index], &pieces[second_piece_index]);
+    let quadratic = &first_piece.quadratic - &second_piece.quadratic;
+    let linear = &first_piece.linear - &second_piece.linear;
+    let constant = &first_piece.constant - &second_piece.constant;
+    // roots of the difference where it changes sign in ascending order
+    // along with the sign of the difference before them
+    let (roots, initial_sign) = if quadratic.is_zero() {
+        if linear.is_zero() {
+            (vec![], constant.sign())
+        } else {
+            (
+                vec![QuadraticSurd::from(
+                    &(&Scalar::zero() - &constant) / &linear,
+                )],
+                multiply_signs(linear.sign(), Sign::Negative),
+            )
+        }
+    } else {
+        let discriminant = &(&linear * &linear)
+            - &(&(&quadratic + &quadratic) * &(&constant + &constant));
+        if discriminant.is_positive() {
+            let doubled_quadratic = &quadratic + &quadratic;
+            let center = &(&Scalar::zero() - &linear) / &doubled_quadratic;
+            let scale = &Scalar::one() / &doubled_quadratic;
+            let scale = if scale.is_negative() {
+                &Scalar::zero() - &scale
+            } else {
+                scale
+            };
+            (
+                vec![
+                    unsafe {
+                        QuadraticSurd::new(
+                            center.clone(),
+                            &Scalar::zero() - &scale,
+                            discriminant.clone(),
+                        )
+                        .unwrap_unchecked()
+                    },
+                    unsafe {
+                        QuadraticSurd::new(center, scale, discriminant)
+                            .unwrap_unchecked()
+                    },
+                ],
+                quadratic.sign(),
+            )
+        } else {
+            (vec![], quadratic.sign())
+        }
+    };
+    let mut part_start = start.clone();
+    let cuts = roots
+        .iter()
+        .filter(|&root| root > start && root < end)
+        .cloned()
+        .chain(std::iter::once(end.clone()));
+    for part_end in cuts {
+        let passed_roots_count =
+            roots.iter().filter(|&root| root <= &part_start).count();
+        let sign = if passed_roots_count % 2 == 0 {
+            initial_sign
+        } else {
+            multiply_signs(initial_sign, Sign::Negative)
+        };
+        let piece_index = if sign == Sign::Positive {
+            second_piece_index
+        } else {
+            first_piece_index
+        };
+        match envelope.last_mut() {
+            Some((_, last_end, last_piece_index))
+                if *last_piece_index == piece_index =>
+            {
+                *last_end = part_end.clone();
+            }
+            _ => envelope.push((part_start, part_end.clone(), piece_index)),
+        }
+        part_start = part_end;
+    }
+}
+
+/// Builds pieces of the squared distance
+/// to the other segment along the segment
+/// for the parts of the latter closest to the other segment's start,
+/// to its interior & to its end.
+fn to_segment_envelope<Scalar>(
+    start: &(Scalar, Scalar),
+    direction: &(Scalar, Scalar),
+    other_segment: &Segment<Scalar>,
+    pieces: &mut Vec<Piece<Scalar>>,
+) -> Envelope<Scalar>
+where
+    Scalar: Clone + One + Ord + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let (other_start, other_end) = other_segment.endpoints();
+    let other_start = (other_start.x().clone(), other_start.y().clone());
+    let other_end = (other_end.x().clone(), other_end.y().clone());
+    let other_direction =
+        (&other_end.0 - &other_start.0, &other_end.1 - &other_start.1);
+    let other_squared_length =
+        dot_multiply(&other_direction, &other_direction);
+    let (zero, one) = (Scalar::zero(), Scalar::one());
+    let mut result = Envelope::new();
+    if other_squared_length.is_zero() {
+        result.push((
+            QuadraticSurd::from(zero),
+            QuadraticSurd::from(one),
+            pieces.len(),
+        ));
+        pieces.push(to_point_piece(start, direction, other_start));
+        return result;
+    }
+    let offset = (&start.0 - &other_start.0, &start.1 - &other_start.1);
+    // projection of the point onto the other segment's line has scale
+    // `(projection_numerator + projection_slope * t) / squared_length`
+    let projection_numerator = dot_multiply(&offset, &other_direction);
+    let projection_slope = dot_multiply(direction, &other_direction);
+    let regions = if projection_slope.is_zero() {
+        let region_index = if !projection_numerator.is_positive() {
+            0
+        } else if projection_numerator < other_squared_length {
+            1
+        } else {
+            2
+        };
+        vec![(zero, one, region_index)]
+    } else {
+        let start_crossing =
+            &(&zero - &projection_numerator) / &projection_slope;
+        let end_crossing = &(&other_squared_length - &projection_numerator)
+            / &projection_slope;
+        let (first_crossing, second_crossing, region_indices) =
+            if projection_slope.is_positive() {
+                (start_crossing, end_crossing, [0, 1, 2])
+            } else {
+                (end_crossing, start_crossing, [2, 1, 0])
+            };
+        let clip =
+            |value: &Scalar| value.clone().max(zero.clone()).min(one.clone());
+        let (first_crossing, second_crossing) =
+            (clip(&first_crossing), clip(&second_crossing));
+        vec![
+            (zero.clone(), first_crossing.clone(), region_indices[0]),
+            (first_crossing, second_crossing.clone(), region_indices[1]),
+            (second_crossing, one.clone(), region_indices[2]),
+        ]
+    };
+    for (region_start, region_end, region_index) in regions {
+        if region_start >= region_end {
+            continue;
+        }
+        result.push((
+            QuadraticSurd::from(region_start),
+            QuadraticSurd::from(region_end),
+            pieces.len(),
+        ));
+        pieces.push(match region_index {
+            0 => to_point_piece(start, direction, other_start.clone()),
+            1 => {
+                let cross_offset = cross_multiply(&other_direction, &offset);
+                let cross_slope = cross_multiply(&other_direction, direction);
+                let scale_start =
+                    &projection_numerator / &other_squared_length;
+                let scale_slope = &projection_slope / &other_squared_length;
+                Piece {
+                    quadratic: &(&cross_slope * &cross_slope)
+                        / &other_squared_length,
+                    linear: &(&(&cross_offset + &cross_offset) * &cross_slope)
+                        / &other_squared_length,
+                    constant: &(&cross_offset * &cross_offset)
+                        / &other_squared_length,
+                    origin: (
+                        &other_start.0 + &(&other_direction.0 * &scale_start),
+                        &other_start.1 + &(&other_direction.1 * &scale_start),
+                    ),
+                    shift: (
+                        &other_direction.0 * &scale_slope,
+                        &other_direction.1 * &scale_slope,
+                    ),
+                }
+            }
+            _ => to_point_piece(start, direction, other_end.clone()),
+        });
+    }
+    result
+}
+
+fn to_point_piece<Scalar>(
+    start: &(Scalar, Scalar),
+    direction: &(Scalar, Scalar),
+    point: (Scalar, Scalar),
+) -> Piece<Scalar>
+where
+    Scalar: Zero,
+    for<'a> &'a Scalar:
+        Add<Output = Scalar> + Mul<Output = Scalar> + Sub<Output = Scalar>,
+{
+    let offset = (&start.0 - &point.0, &start.1 - &point.1);
+    let doubled_offset_projection = dot_multiply(&offset, direction);
+    Piece {
+        quadratic: dot_multiply(direction, direction),
+        linear: &doubled_offset_projection + &doubled_offset_projection,
+        constant: dot_multiply(&offset, &offset),
+        origin: point,
+        shift: (Scalar::zero(), Scalar::zero()),
+    }
+}
+
+fn to_piece_value<Scalar>(
+    piece: &Piece<Scalar>,
+    parameter: &QuadraticSurd<Scalar>,
+) -> QuadraticSurd<Scalar>
+where
+    Scalar: Clone + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    let (rational, irrational, radicand) = (
+        parameter.get_rational(),
+        parameter.get_irrational(),
+        parameter.get_radicand(),
+    );
+    let doubled_quadratic = &piece.quadratic + &piece.quadratic;
+    unsafe {
+        QuadraticSurd::new(
+            &(&(&piece.quadratic
+                * &(&(rational * rational)
+                    + &(&(irrational * irrational) * radicand)))
+                + &(&piece.linear * rational))
+                + &piece.constant,
+            &(&(&doubled_quadratic * rational) + &piece.linear) * irrational,
+            radicand.clone(),
+        )
+        .unwrap_unchecked()
+    }
+}
+
+fn to_parameter_point<Scalar>(
+    origin: &(Scalar, Scalar),
+    shift: &(Scalar, Scalar),
+    parameter: &QuadraticSurd<Scalar>,
+) -> Point<QuadraticSurd<Scalar>>
+where
+    Scalar: Clone + Zero,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Mul<Output = Scalar> + Signed,
+{
+    let to_coordinate = |origin: &Scalar, shift: &Scalar| unsafe {
+        QuadraticSurd::new(
+            origin + &(shift * parameter.get_rational()),
+            shift * parameter.get_irrational(),
+            parameter.get_radicand().clone(),
+        )
+        .unwrap_unchecked()
+    };
+    Point::new(
+        to_coordinate(&origin.0, &shift.0),
+        to_coordinate(&origin.1, &shift.1),
+    )
+}
+
+fn cross_multiply<Scalar>(
+    first: &(Scalar, Scalar),
+    second: &(Scalar, Scalar),
+) -> Scalar
+where
+    for<'a> &'a Scalar: Mul<Output = Scalar> + Sub<Output = Scalar>,
+{
+    &(&first.0 * &second.1) - &(&first.1 * &second.0)
+}
+
+fn dot_multiply<Scalar>(
+    first: &(Scalar, Scalar),
+    second: &(Scalar, Scalar),
+) -> Scalar
+where
+    for<'a> &'a Scalar: Add<Output = Scalar> + Mul<Output = Scalar>,
+{
+    &(&first.0 * &second.0) + &(&first.1 * &second.1)
+}
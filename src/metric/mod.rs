@@ -0,0 +1,9 @@
+pub(crate) use self::hausdorff::to_squared_hausdorff_distance;
+pub use self::quadratic_surd::QuadraticSurd;
+pub use self::traits::{
+    DiscreteHausdorffMetric, HausdorffMetric, NearestPoints, SquaredMetric,
+};
+
+mod hausdorff;
+mod quadratic_surd;
+mod traits;
@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::{Sign, Signed, Zero, Zeroable};
+
+/// Exact real number of the form
+/// ```text
+/// rational + irrational * sqrt(radicand)
+/// ```
+/// with rational components & non-negative radicand.
+#[derive(Clone, Debug)]
+pub struct QuadraticSurd<Scalar> {
+    rational: Scalar,
+    irrational: Scalar,
+    radicand: Scalar,
+}
+
+impl<Scalar> QuadraticSurd<Scalar> {
+    pub fn get_irrational(&self) -> &Scalar {
+        &self.irrational
+    }
+
+    pub fn get_radicand(&self) -> &Scalar {
+        &self.radicand
+    }
+
+    pub fn get_rational(&self) -> &Scalar {
+        &self.rational
+    }
+}
+
+impl<Scalar: Zero> QuadraticSurd<Scalar>
+where
+    for<'a> &'a Scalar: Signed,
+{
+    /// Returns `None` for negative radicand,
+    /// zero irrational part or radicand are normalized to zeros both.
+    pub fn new(
+        rational: Scalar,
+        irrational: Scalar,
+        radicand: Scalar,
+    ) -> Option<Self> {
+        if radicand.is_negative() {
+            None
+        } else if irrational.is_zero() || radicand.is_zero() {
+            Some(Self::from(rational))
+        } else {
+            Some(Self {
+                rational,
+                irrational,
+                radicand,
+            })
+        }
+    }
+}
+
+impl<Scalar: Zero> From<Scalar> for QuadraticSurd<Scalar> {
+    fn from(rational: Scalar) -> Self {
+        Self {
+            rational,
+            irrational: Scalar::zero(),
+            radicand: Scalar::zero(),
+        }
+    }
+}
+
+impl<Scalar: fmt::Display> fmt::Display for QuadraticSurd<Scalar> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_fmt(format_args!(
+            "QuadraticSurd({}, {}, {})",
+            self.rational, self.irrational, self.radicand
+        ))
+    }
+}
+
+impl<Scalar> Eq for QuadraticSurd<Scalar> where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>
+{
+}
+
+impl<Scalar> Ord for QuadraticSurd<Scalar>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match to_difference_sign(self, other) {
+            Sign::Negative => Ordering::Less,
+            Sign::Positive => Ordering::Greater,
+            Sign::Zero => Ordering::Equal,
+        }
+    }
+}
+
+impl<Scalar> PartialEq for QuadraticSurd<Scalar>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        to_difference_sign(self, other) == Sign::Zero
+    }
+}
+
+impl<Scalar> PartialOrd for QuadraticSurd<Scalar>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns sign of `first - second`,
+/// surds with different radicands are compared
+/// by squaring the difference's parts when they have the same sign.
+fn to_difference_sign<Scalar>(
+    first: &QuadraticSurd<Scalar>,
+    second: &QuadraticSurd<Scalar>,
+) -> Sign
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    let rational_difference = &first.rational - &second.rational;
+    let minuend_sign =
+        to_sign(&rational_difference, &first.irrational, &first.radicand);
+    let subtrahend_sign = second.irrational.sign();
+    match (minuend_sign, subtrahend_sign) {
+        (_, Sign::Zero) => minuend_sign,
+        (Sign::Zero, Sign::Positive) => Sign::Negative,
+        (Sign::Zero, Sign::Negative) => Sign::Positive,
+        _ if minuend_sign != subtrahend_sign => minuend_sign,
+        _ => {
+            let doubled_rational_difference =
+                &rational_difference + &rational_difference;
+            let squares_difference_sign = to_sign(
+                &(&(&(&rational_difference * &rational_difference)
+                    + &(&(&first.irrational * &first.irrational)
+                        * &first.radicand))
+                    - &(&(&second.irrational * &second.irrational)
+                        * &second.radicand)),
+                &(&doubled_rational_difference * &first.irrational),
+                &first.radicand,
+            );
+            multiply_signs(minuend_sign, squares_difference_sign)
+        }
+    }
+}
+
+pub(crate) fn multiply_signs(first: Sign, second: Sign) -> Sign {
+    match (first, second) {
+        (Sign::Zero, _) | (_, Sign::Zero) => Sign::Zero,
+        _ if first == second => Sign::Positive,
+        _ => Sign::Negative,
+    }
+}
+
+/// Returns sign of `rational + irrational * sqrt(radicand)`.
+fn to_sign<Scalar>(
+    rational: &Scalar,
+    irrational: &Scalar,
+    radicand: &Scalar,
+) -> Sign
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+{
+    let (rational_sign, irrational_sign) =
+        (rational.sign(), irrational.sign());
+    if irrational_sign == Sign::Zero || radicand.is_zero() {
+        rational_sign
+    } else if rational_sign == Sign::Zero || rational_sign == irrational_sign {
+        irrational_sign
+    } else {
+        multiply_signs(
+            rational_sign,
+            (&(rational * rational)
+                - &(&(irrational * irrational) * radicand))
+                .sign(),
+        )
+    }
+}
@@ -0,0 +1,99 @@
+pub trait DiscreteHausdorffMetric<Other = Self> {
+    type Output;
+
+    /// Returns squared discrete Hausdorff distance
+    /// between vertices of geometries' boundaries
+    /// along with the witness points
+    /// (the first one lies on `self`, the second one -- on `other`).
+    fn squared_discrete_hausdorff_distance_to(
+        self,
+        other: Other,
+    ) -> Self::Output;
+}
+
+pub trait HausdorffMetric<Other = Self> {
+    type Output;
+
+    /// Returns squared Hausdorff distance between geometries' boundaries
+    /// along with the witness points
+    /// (the first one lies on `self`, the second one -- on `other`).
+    ///
+    /// Both the distance & the witnesses' coordinates
+    /// are exact quadratic surds, since the largest distance
+    /// from one boundary to the other can be attained inside segments
+    /// & generally at irrational coordinates:
+    ///
+    /// ```
+    /// use rene::geometries::{Multisegment, Point, Segment};
+    /// use rene::metric::{HausdorffMetric, QuadraticSurd};
+    /// use rithm::big_int::BigInt;
+    /// use rithm::fraction::Fraction;
+    ///
+    /// type Scalar = Fraction<BigInt<u32, 31>>;
+    /// let to_scalar =
+    ///     |value: i32| Scalar::from(BigInt::<u32, 31>::from(value));
+    /// let to_point = |x: i32, y: i32| Point::new(to_scalar(x), to_scalar(y));
+    /// let to_segment = |start: (i32, i32), end: (i32, i32)| {
+    ///     Segment::new(to_point(start.0, start.1), to_point(end.0, end.1))
+    /// };
+    /// let to_surd = |value: i32| QuadraticSurd::from(to_scalar(value));
+    /// let first = Multisegment::new(vec![
+    ///     to_segment((0, 0), (4, 0)),
+    ///     to_segment((4, 0), (4, -1)),
+    /// ]);
+    /// let second = Multisegment::new(vec![
+    ///     to_segment((0, 0), (0, 1)),
+    ///     to_segment((4, 0), (4, 1)),
+    /// ]);
+    /// // the point (2, 0) of the first boundary
+    /// // is at squared distance 4 from the second one
+    /// let (squared_distance, point, other_point) =
+    ///     (&first).squared_hausdorff_distance_to(&second);
+    /// assert!(squared_distance == to_surd(4));
+    /// assert!(point == Point::new(to_surd(2), to_surd(0)));
+    /// assert!(
+    ///     other_point == Point::new(to_surd(0), to_surd(0))
+    ///         || other_point == Point::new(to_surd(4), to_surd(0))
+    /// );
+    /// // the farthest point lies at irrational coordinates
+    /// let third = Multisegment::new(vec![
+    ///     to_segment((0, 0), (3, 0)),
+    ///     to_segment((0, 0), (0, 1)),
+    /// ]);
+    /// let fourth = Multisegment::new(vec![
+    ///     to_segment((0, 0), (0, 1)),
+    ///     to_segment((2, 1), (3, 0)),
+    /// ]);
+    /// let to_irrational_surd = |rational: i32, irrational: i32| {
+    ///     QuadraticSurd::new(
+    ///         to_scalar(rational),
+    ///         to_scalar(irrational),
+    ///         to_scalar(2),
+    ///     )
+    ///     .unwrap()
+    /// };
+    /// let (squared_distance, point, _) =
+    ///     (&third).squared_hausdorff_distance_to(&fourth);
+    /// // i.e. 27 - 18 * sqrt(2)
+    /// assert!(squared_distance == to_irrational_surd(27, -18));
+    /// assert!(point == Point::new(to_irrational_surd(-3, 3), to_surd(0)));
+    /// ```
+    fn squared_hausdorff_distance_to(self, other: Other) -> Self::Output;
+}
+
+pub trait NearestPoints<Other = Self> {
+    type Output;
+
+    /// Returns pair of the closest points of geometries
+    /// (the first one lies on `self`, the second one -- on `other`),
+    /// which coincide if geometries have common points.
+    fn nearest_points(self, other: Other) -> Self::Output;
+}
+
+pub trait SquaredMetric<Other = Self> {
+    type Output;
+
+    /// Returns squared Euclidean distance between geometries,
+    /// which is zero if they have common points.
+    fn squared_distance_to(self, other: Other) -> Self::Output;
+}
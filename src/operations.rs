@@ -1,12 +1,13 @@
 use std::ops::{Add, Div, Mul, Sub};
 
-use traiter::numbers::{BitLength, IsPowerOfTwo, One, Sign, Signed};
+use traiter::numbers::{BitLength, IsPowerOfTwo, One, Sign, Signed, Zero};
 
 use crate::bounded;
 use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
 use crate::locatable::Location;
+use crate::metric::SquaredMetric;
 use crate::oriented::Orientation;
-use crate::relatable::Relatable;
+use crate::relatable::{Dimension, Relatable};
 use crate::traits::{
     Contoural, Elemental, Iterable, Lengthsome, Multisegmental,
     MultisegmentalIndexSegment, MultivertexalIndexVertex, Polygonal,
@@ -25,6 +26,29 @@ pub(crate) trait CrossMultiply {
     ) -> Self::Output;
 }
 
+impl<Point, Scalar> CrossMultiply for &Point
+where
+    Scalar: Mul<Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Scalar: Sub<Output = Scalar>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>,
+{
+    type Output = Scalar;
+
+    fn cross_multiply(
+        first_start: Self,
+        first_end: Self,
+        second_start: Self,
+        second_end: Self,
+    ) -> Self::Output {
+        let (first_start_x, first_start_y) = first_start.coordinates();
+        let (first_end_x, first_end_y) = first_end.coordinates();
+        let (second_start_x, second_start_y) = second_start.coordinates();
+        let (second_end_x, second_end_y) = second_end.coordinates();
+        (first_end_x - first_start_x) * (second_end_y - second_start_y)
+            - (first_end_y - first_start_y) * (second_end_x - second_start_x)
+    }
+}
+
 pub(crate) trait DotMultiply {
     type Output;
 
@@ -36,6 +60,29 @@ pub(crate) trait DotMultiply {
     ) -> Self::Output;
 }
 
+impl<Point, Scalar> DotMultiply for &Point
+where
+    Scalar: Add<Output = Scalar> + Mul<Output = Scalar>,
+    for<'a> &'a Scalar: Sub<Output = Scalar>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>,
+{
+    type Output = Scalar;
+
+    fn dot_multiply(
+        first_start: Self,
+        first_end: Self,
+        second_start: Self,
+        second_end: Self,
+    ) -> Self::Output {
+        let (first_start_x, first_start_y) = first_start.coordinates();
+        let (first_end_x, first_end_y) = first_end.coordinates();
+        let (second_start_x, second_start_y) = second_start.coordinates();
+        let (second_end_x, second_end_y) = second_end.coordinates();
+        (first_end_x - first_start_x) * (second_end_x - second_start_x)
+            + (first_end_y - first_start_y) * (second_end_y - second_start_y)
+    }
+}
+
 pub(crate) trait IntersectCrossingSegments {
     type Output;
 
@@ -80,7 +127,9 @@ where
     }
 }
 
-pub(crate) trait LocatePointInPointPointPointCircle {
+/// Locates a point relative to the circle
+/// passing through three other points given in counterclockwise order.
+pub trait LocatePointInPointPointPointCircle {
     fn locate_point_in_point_point_point_circle(
         self,
         first: Self,
@@ -89,7 +138,42 @@ pub(crate) trait LocatePointInPointPointPointCircle {
     ) -> Location;
 }
 
-pub(crate) trait Orient {
+impl<'a, Point, Scalar: 'a> LocatePointInPointPointPointCircle for &'a Point
+where
+    &'a Point: Elemental<Coordinate = &'a Scalar>,
+    Scalar: Add<Output = Scalar> + Mul<Output = Scalar> + Sub<Output = Scalar>,
+    for<'b> &'b Scalar: Mul<Output = Scalar> + Signed + Sub<Output = Scalar>,
+{
+    fn locate_point_in_point_point_point_circle(
+        self,
+        first: Self,
+        second: Self,
+        third: Self,
+    ) -> Location {
+        let (first_dx, first_dy) =
+            (first.x() - self.x(), first.y() - self.y());
+        let (second_dx, second_dy) =
+            (second.x() - self.x(), second.y() - self.y());
+        let (third_dx, third_dy) =
+            (third.x() - self.x(), third.y() - self.y());
+        match ((&first_dx * &first_dx + &first_dy * &first_dy)
+            * (&second_dx * &third_dy - &second_dy * &third_dx)
+            - (&second_dx * &second_dx + &second_dy * &second_dy)
+                * (&first_dx * &third_dy - &first_dy * &third_dx)
+            + (&third_dx * &third_dx + &third_dy * &third_dy)
+                * (first_dx * second_dy - first_dy * second_dx))
+            .sign()
+        {
+            Sign::Negative => Location::Exterior,
+            Sign::Positive => Location::Interior,
+            Sign::Zero => Location::Boundary,
+        }
+    }
+}
+
+/// Determines orientation of the second ray point
+/// relative to the ray from the point through the first ray point.
+pub trait Orient {
     fn orient(
         self,
         first_ray_point: Self,
@@ -122,12 +206,28 @@ where
     }
 }
 
+pub(crate) trait ToBoundarySegments {
+    type Output;
+
+    fn to_boundary_segments(self) -> Self::Output;
+}
+
+pub(crate) trait ToBoundaryVertices {
+    type Output;
+
+    fn to_boundary_vertices(self) -> Self::Output;
+}
+
 pub(crate) trait ToCorrectlyOrientedSegments {
     type Output;
 
     fn to_correctly_oriented_segments(self) -> Self::Output;
 }
 
+pub(crate) trait ToDimension {
+    fn to_dimension(self) -> Dimension;
+}
+
 pub(crate) trait ToReversedSegments {
     type Output;
 
@@ -140,10 +240,30 @@ pub(crate) trait Square {
     fn square(self) -> Self::Output;
 }
 
-pub(crate) trait SquaredMetric<Other = Self> {
-    type Output;
+impl<Scalar> Square for Scalar
+where
+    Scalar: Clone + Mul<Output = Scalar>,
+{
+    type Output = Self;
 
-    fn squared_distance_to(self, other: Other) -> Self::Output;
+    fn square(self) -> Self::Output {
+        self.clone() * self
+    }
+}
+
+impl<Point, Scalar> SquaredMetric for &Point
+where
+    Scalar: Add<Output = Scalar> + Square<Output = Scalar>,
+    for<'a> &'a Scalar: Sub<Output = Scalar>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: Self) -> Self::Output {
+        let (start_x, start_y) = self.coordinates();
+        let (other_start_x, other_start_y) = other.coordinates();
+        (start_x - other_start_x).square() + (start_y - other_start_y).square()
+    }
 }
 
 pub(crate) fn ceil_log2<
@@ -209,6 +329,27 @@ where
                 || second.get_min_x() == first.get_max_x()))
 }
 
+pub(crate) fn do_segments_cross<Point>(
+    first_start: &Point,
+    first_end: &Point,
+    second_start: &Point,
+    second_end: &Point,
+) -> bool
+where
+    for<'a> &'a Point: Orient,
+{
+    let first_start_orientation = second_start.orient(second_end, first_start);
+    let first_end_orientation = second_start.orient(second_end, first_end);
+    let second_start_orientation = first_start.orient(first_end, second_start);
+    let second_end_orientation = first_start.orient(first_end, second_end);
+    first_start_orientation != Orientation::Collinear
+        && first_end_orientation != Orientation::Collinear
+        && first_start_orientation != first_end_orientation
+        && second_start_orientation != Orientation::Collinear
+        && second_end_orientation != Orientation::Collinear
+        && second_start_orientation != second_end_orientation
+}
+
 pub(crate) fn flags_to_false_indices(flags: &[bool]) -> Vec<usize> {
     flags
         .iter()
@@ -450,6 +591,45 @@ pub(crate) fn to_arg_min<Value: Ord>(values: &[Value]) -> Option<usize> {
     (0..values.len()).min_by_key(|index| &values[*index])
 }
 
+pub(crate) fn to_convex_hull<'a, Point: Ord>(
+    mut points: Vec<&'a Point>,
+) -> Vec<&'a Point>
+where
+    for<'b> &'b Point: Orient,
+{
+    points.sort();
+    points.dedup();
+    if points.len() < 2 {
+        points
+    } else {
+        let mut result = to_convex_sub_hull(points.iter().copied());
+        let mut upper_hull = to_convex_sub_hull(points.into_iter().rev());
+        result.pop();
+        upper_hull.pop();
+        result.append(&mut upper_hull);
+        result
+    }
+}
+
+fn to_convex_sub_hull<'a, Point>(
+    points: impl Iterator<Item = &'a Point>,
+) -> Vec<&'a Point>
+where
+    for<'b> &'b Point: Orient,
+{
+    let mut result = Vec::<&'a Point>::new();
+    for point in points {
+        while result.len() >= 2
+            && result[result.len() - 2].orient(result[result.len() - 1], point)
+                != Orientation::Counterclockwise
+        {
+            result.pop();
+        }
+        result.push(point);
+    }
+    result
+}
+
 pub(crate) fn to_boxes_ids_with_common_area<Scalar>(
     boxes: &[bounded::Box<Scalar>],
     target_box: &bounded::Box<Scalar>,
@@ -543,6 +723,243 @@ where
     )
 }
 
+pub(crate) fn to_squared_discrete_hausdorff_distance<Point, Scalar>(
+    first_vertices: &[&Point],
+    second_vertices: &[&Point],
+) -> (Scalar, Point, Point)
+where
+    Point: Clone,
+    Scalar: Ord,
+    for<'a> &'a Point: SquaredMetric<Output = Scalar>,
+{
+    let (first_squared_distance, first_vertex, second_nearest_vertex) =
+        to_directed_squared_discrete_hausdorff_distance(
+            first_vertices,
+            second_vertices,
+        );
+    let (second_squared_distance, second_vertex, first_nearest_vertex) =
+        to_directed_squared_discrete_hausdorff_distance(
+            second_vertices,
+            first_vertices,
+        );
+    if second_squared_distance > first_squared_distance {
+        (
+            second_squared_distance,
+            first_nearest_vertex.clone(),
+            second_vertex.clone(),
+        )
+    } else {
+        (
+            first_squared_distance,
+            first_vertex.clone(),
+            second_nearest_vertex.clone(),
+        )
+    }
+}
+
+fn to_directed_squared_discrete_hausdorff_distance<'a, Point, Scalar>(
+    vertices: &[&'a Point],
+    other_vertices: &[&'a Point],
+) -> (Scalar, &'a Point, &'a Point)
+where
+    Scalar: Ord,
+    for<'b> &'b Point: SquaredMetric<Output = Scalar>,
+{
+    let mut result: Option<(Scalar, &Point, &Point)> = None;
+    for &vertex in vertices {
+        let (squared_distance, nearest_vertex) = unsafe {
+            other_vertices
+                .iter()
+                .map(|&other_vertex| {
+                    (vertex.squared_distance_to(other_vertex), other_vertex)
+                })
+                .min_by(
+                    |(squared_distance, _), (other_squared_distance, _)| {
+                        squared_distance.cmp(other_squared_distance)
+                    },
+                )
+                .unwrap_unchecked()
+        };
+        if result
+            .as_ref()
+            .map_or(true, |(max_squared_distance, _, _)| {
+                squared_distance.gt(max_squared_distance)
+            })
+        {
+            result = Some((squared_distance, vertex, nearest_vertex));
+        }
+    }
+    unsafe { result.unwrap_unchecked() }
+}
+
+pub(crate) fn to_point_segment_nearest_point<Point, Scalar>(
+    point: &Point,
+    start: &Point,
+    end: &Point,
+) -> Point
+where
+    Point: Clone + From<(Scalar, Scalar)>,
+    Scalar: Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + PartialOrd
+        + Zero
+        + for<'a> Mul<&'a Scalar, Output = Scalar>,
+    for<'a> &'a Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point: DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + SquaredMetric<Output = Scalar>,
+{
+    let projection_scale = DotMultiply::dot_multiply(start, point, start, end);
+    if projection_scale <= Scalar::zero() {
+        return start.clone();
+    }
+    let segment_squared_length = start.squared_distance_to(end);
+    if projection_scale >= segment_squared_length {
+        end.clone()
+    } else {
+        let scale = projection_scale / segment_squared_length;
+        Point::from((
+            start.x() + (end.x() - start.x()) * &scale,
+            start.y() + (end.y() - start.y()) * scale,
+        ))
+    }
+}
+
+pub(crate) fn to_point_segment_squared_distance<Point, Scalar>(
+    point: &Point,
+    start: &Point,
+    end: &Point,
+) -> Scalar
+where
+    Scalar: Div<Output = Scalar> + PartialOrd + Square<Output = Scalar> + Zero,
+    for<'a> &'a Point: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + SquaredMetric<Output = Scalar>,
+{
+    let projection_scale = DotMultiply::dot_multiply(start, point, start, end);
+    if projection_scale <= Scalar::zero() {
+        return point.squared_distance_to(start);
+    }
+    let segment_squared_length = start.squared_distance_to(end);
+    if projection_scale >= segment_squared_length {
+        point.squared_distance_to(end)
+    } else {
+        CrossMultiply::cross_multiply(start, end, start, point).square()
+            / segment_squared_length
+    }
+}
+
+pub(crate) fn to_segments_nearest_points<Point, Scalar>(
+    first_start: &Point,
+    first_end: &Point,
+    second_start: &Point,
+    second_end: &Point,
+) -> (Point, Point)
+where
+    Point: Clone + From<(Scalar, Scalar)>,
+    Scalar: Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Ord
+        + Zero
+        + for<'a> Mul<&'a Scalar, Output = Scalar>,
+    for<'a> &'a Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    if do_segments_cross(first_start, first_end, second_start, second_end) {
+        let cross_point =
+            IntersectCrossingSegments::intersect_crossing_segments(
+                first_start,
+                first_end,
+                second_start,
+                second_end,
+            );
+        (cross_point.clone(), cross_point)
+    } else {
+        unsafe {
+            IntoIterator::into_iter([
+                (
+                    first_start.clone(),
+                    to_point_segment_nearest_point(
+                        first_start,
+                        second_start,
+                        second_end,
+                    ),
+                ),
+                (
+                    first_end.clone(),
+                    to_point_segment_nearest_point(
+                        first_end,
+                        second_start,
+                        second_end,
+                    ),
+                ),
+                (
+                    to_point_segment_nearest_point(
+                        second_start,
+                        first_start,
+                        first_end,
+                    ),
+                    second_start.clone(),
+                ),
+                (
+                    to_point_segment_nearest_point(
+                        second_end,
+                        first_start,
+                        first_end,
+                    ),
+                    second_end.clone(),
+                ),
+            ])
+            .min_by_key(|(first, second)| first.squared_distance_to(second))
+            .unwrap_unchecked()
+        }
+    }
+}
+
+pub(crate) fn to_segments_squared_distance<Point, Scalar>(
+    first_start: &Point,
+    first_end: &Point,
+    second_start: &Point,
+    second_end: &Point,
+) -> Scalar
+where
+    Scalar: Div<Output = Scalar> + Ord + Square<Output = Scalar> + Zero,
+    for<'a> &'a Point: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    if do_segments_cross(first_start, first_end, second_start, second_end) {
+        Scalar::zero()
+    } else {
+        to_point_segment_squared_distance(
+            first_start,
+            second_start,
+            second_end,
+        )
+        .min(to_point_segment_squared_distance(
+            first_end,
+            second_start,
+            second_end,
+        ))
+        .min(to_point_segment_squared_distance(
+            second_start,
+            first_start,
+            first_end,
+        ))
+        .min(to_point_segment_squared_distance(
+            second_end,
+            first_start,
+            first_end,
+        ))
+    }
+}
+
 pub(crate) fn to_sorted_pair<Value: PartialOrd>(
     (left, right): (Value, Value),
 ) -> (Value, Value) {
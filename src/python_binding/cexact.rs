@@ -1,16 +1,10 @@
 use rithm::{big_int, fraction};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
-use std::ops::{Add, Mul, Sub};
-use traiter::numbers::{Endianness, FromBytes, Sign, Signed, ToBytes, Zero};
+use traiter::numbers::{Endianness, FromBytes, ToBytes, Zero};
 
-use crate::locatable::Location;
-use crate::operations::{
-    CrossMultiply, DotMultiply, LocatePointInPointPointPointCircle, Square,
-    SquaredMetric,
-};
 use crate::traits::{
-    Elemental, Multipolygonal, Multisegmental, Multivertexal, Polygonal,
+    Multipolygonal, Multisegmental, Multivertexal, Polygonal,
 };
 
 use super::impl_box_wrapper::impl_box_wrapper;
@@ -18,15 +12,19 @@ use super::impl_constrained_delaunay_triangulation_wrapper::impl_constrained_del
 use super::impl_contour_wrapper::impl_contour_wrapper;
 use super::impl_delaunay_triangulation_wrapper::impl_delaunay_triangulation_wrapper;
 use super::impl_empty_wrapper::impl_empty_wrapper;
+use super::impl_indexed_mesh_wrapper::impl_indexed_mesh_wrapper;
 use super::impl_multipolygon_wrapper::impl_multipolygon_wrapper;
 use super::impl_multisegment_wrapper::impl_multisegment_wrapper;
 use super::impl_point_wrapper::impl_point_wrapper;
 use super::impl_polygon_wrapper::impl_polygon_wrapper;
 use super::impl_py_sequence::impl_py_sequence;
+use super::impl_quadratic_surd_wrapper::impl_quadratic_surd_wrapper;
 use super::impl_segment_wrapper::impl_segment_wrapper;
 use super::impl_trapezoidation_wrapper::impl_trapezoidation_wrapper;
+use super::impl_violation_wrapper::impl_violation_wrapper;
+use super::impl_voronoi_diagram_wrapper::impl_voronoi_diagram_wrapper;
 use super::reference;
-use super::traits::{TryFromPyAny, TryToPyAny};
+use super::traits::{TryFromPyAny, TryToPyAny, TryToPyFloat};
 
 #[pyo3::pymodule]
 fn _cexact(
@@ -39,12 +37,17 @@ fn _cexact(
     module.add_class::<PyContour>()?;
     module.add_class::<PyDelaunayTriangulation>()?;
     module.add_class::<PyEmpty>()?;
+    module.add_class::<PyIndexedMesh>()?;
     module.add_class::<PyTrapezoidation>()?;
     module.add_class::<PyMultipolygon>()?;
     module.add_class::<PyMultisegment>()?;
     module.add_class::<PyPoint>()?;
     module.add_class::<PyPolygon>()?;
+    module.add_class::<PyQuadraticSurd>()?;
     module.add_class::<PySegment>()?;
+    module.add_class::<PyViolation>()?;
+    module.add_class::<PyVoronoiDiagram>()?;
+    module.add_function(pyo3::wrap_pyfunction!(nearest_points, module)?)?;
     pyo3::types::PySequence::register::<PyContourSegments>(py)?;
     pyo3::types::PySequence::register::<PyContourVertices>(py)?;
     pyo3::types::PySequence::register::<PyMultipolygonPolygons>(py)?;
@@ -53,6 +56,29 @@ fn _cexact(
     Ok(())
 }
 
+#[pyo3::pyfunction]
+#[pyo3(signature = (first, second, /))]
+fn nearest_points(
+    first: &pyo3::Bound<'_, pyo3::PyAny>,
+    second: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> pyo3::PyResult<(PyPoint, PyPoint)> {
+    let (first_point, second_point) = super::dispatch_geometry::dispatch_geometry!(
+        first,
+        |first_geometry| {
+            super::dispatch_geometry::dispatch_geometry!(
+                second,
+                |second_geometry| {
+                    Ok(crate::metric::NearestPoints::nearest_points(
+                        first_geometry,
+                        second_geometry,
+                    ))
+                }
+            )
+        }
+    )?;
+    Ok((PyPoint(first_point), PyPoint(second_point)))
+}
+
 #[cfg(target_arch = "x86")]
 type Digit = u16;
 #[cfg(not(target_arch = "x86"))]
@@ -113,114 +139,6 @@ impl From<Segment> for PySegment {
     }
 }
 
-impl<Point> CrossMultiply for &Point
-where
-    Fraction: Mul<Output = Fraction> + Sub<Output = Fraction>,
-    for<'a> &'a Fraction: Sub<Output = Fraction>,
-    for<'a> &'a Point: Elemental<Coordinate = &'a Fraction>,
-{
-    type Output = Fraction;
-
-    fn cross_multiply(
-        first_start: Self,
-        first_end: Self,
-        second_start: Self,
-        second_end: Self,
-    ) -> Self::Output {
-        let (first_start_x, first_start_y) = first_start.coordinates();
-        let (first_end_x, first_end_y) = first_end.coordinates();
-        let (second_start_x, second_start_y) = second_start.coordinates();
-        let (second_end_x, second_end_y) = second_end.coordinates();
-        (first_end_x - first_start_x) * (second_end_y - second_start_y)
-            - (first_end_y - first_start_y) * (second_end_x - second_start_x)
-    }
-}
-
-impl<Point> DotMultiply for &Point
-where
-    Fraction: Add<Output = Fraction> + Mul<Output = Fraction>,
-    for<'a> &'a Fraction: Sub<Output = Fraction>,
-    for<'a> &'a Point: Elemental<Coordinate = &'a Fraction>,
-{
-    type Output = Fraction;
-
-    fn dot_multiply(
-        first_start: Self,
-        first_end: Self,
-        second_start: Self,
-        second_end: Self,
-    ) -> Self::Output {
-        let (first_start_x, first_start_y) = first_start.coordinates();
-        let (first_end_x, first_end_y) = first_end.coordinates();
-        let (second_start_x, second_start_y) = second_start.coordinates();
-        let (second_end_x, second_end_y) = second_end.coordinates();
-        (first_end_x - first_start_x) * (second_end_x - second_start_x)
-            + (first_end_y - first_start_y) * (second_end_y - second_start_y)
-    }
-}
-
-impl<'a, Point> LocatePointInPointPointPointCircle for &'a Point
-where
-    &'a Point: Elemental<Coordinate = &'a Fraction>,
-    Fraction: Add<Output = Fraction>
-        + Mul<Output = Fraction>
-        + Sub<Output = Fraction>,
-    for<'b> &'b Fraction:
-        Mul<Output = Fraction> + Signed + Sub<Output = Fraction>,
-{
-    fn locate_point_in_point_point_point_circle(
-        self,
-        first: Self,
-        second: Self,
-        third: Self,
-    ) -> Location {
-        let (first_dx, first_dy) =
-            (first.x() - self.x(), first.y() - self.y());
-        let (second_dx, second_dy) =
-            (second.x() - self.x(), second.y() - self.y());
-        let (third_dx, third_dy) =
-            (third.x() - self.x(), third.y() - self.y());
-        match ((&first_dx * &first_dx + &first_dy * &first_dy)
-            * (&second_dx * &third_dy - &second_dy * &third_dx)
-            - (&second_dx * &second_dx + &second_dy * &second_dy)
-                * (&first_dx * &third_dy - &first_dy * &third_dx)
-            + (&third_dx * &third_dx + &third_dy * &third_dy)
-                * (first_dx * second_dy - first_dy * second_dx))
-            .sign()
-        {
-            Sign::Negative => Location::Exterior,
-            Sign::Positive => Location::Interior,
-            Sign::Zero => Location::Boundary,
-        }
-    }
-}
-
-impl Square for Fraction
-where
-    Fraction: Clone + Mul<Output = Fraction>,
-{
-    type Output = Self;
-
-    fn square(self) -> Self::Output {
-        self.clone() * self
-    }
-}
-
-impl<Point> SquaredMetric for &Point
-where
-    Fraction: Add<Output = Fraction> + Square<Output = Fraction>,
-    for<'a> &'a Fraction: Sub<Output = Fraction>,
-    for<'a> &'a Point: Elemental<Coordinate = &'a Fraction>,
-{
-    type Output = Fraction;
-
-    fn squared_distance_to(self, other: Self) -> Self::Output {
-        let (start_x, start_y) = self.coordinates();
-        let (other_start_x, other_start_y) = other.coordinates();
-        (start_x - other_start_x).square() + (start_y - other_start_y).square()
-    }
-}
-
 const INVALID_SCALAR_TYPE_ERROR_MESSAGE: &str =
     "Scalar should be a rational number.";
 const UNDEFINED_DIVISION_ERROR_MESSAGE: &str =
@@ -301,6 +219,14 @@ impl TryToPyAny for &Fraction {
     }
 }
 
+impl TryToPyFloat for &Fraction {
+    fn try_to_py_float(self) -> pyo3::PyResult<f64> {
+        f64::try_from(self).map_err(|error| {
+            pyo3::exceptions::PyOverflowError::new_err(error.to_string())
+        })
+    }
+}
+
 impl From<PyContour> for Contour {
     fn from(value: PyContour) -> Self {
         value.0
@@ -351,6 +277,45 @@ impl<'py> pyo3::IntoPyObject<'py> for Contour {
     }
 }
 
+impl<'py> pyo3::IntoPyObject<'py> for Empty {
+    type Target = <PyEmpty as pyo3::IntoPyObject<'py>>::Target;
+    type Output = <PyEmpty as pyo3::IntoPyObject<'py>>::Output;
+    type Error = <PyEmpty as pyo3::IntoPyObject<'py>>::Error;
+
+    fn into_pyobject(
+        self,
+        py: pyo3::Python<'py>,
+    ) -> Result<Self::Output, Self::Error> {
+        pyo3::IntoPyObject::into_pyobject(PyEmpty(self), py)
+    }
+}
+
+impl<'py> pyo3::IntoPyObject<'py> for Hull {
+    type Target = pyo3::PyAny;
+    type Output = pyo3::Bound<'py, Self::Target>;
+    type Error = pyo3::PyErr;
+
+    fn into_pyobject(
+        self,
+        py: pyo3::Python<'py>,
+    ) -> Result<Self::Output, Self::Error> {
+        match self {
+            Hull::Empty(empty) => pyo3::IntoPyObject::into_pyobject(empty, py)
+                .map(pyo3::Bound::into_any),
+            Hull::Point(point) => pyo3::IntoPyObject::into_pyobject(point, py)
+                .map(pyo3::Bound::into_any),
+            Hull::Polygon(polygon) => {
+                pyo3::IntoPyObject::into_pyobject(polygon, py)
+                    .map(pyo3::Bound::into_any)
+            }
+            Hull::Segment(segment) => {
+                pyo3::IntoPyObject::into_pyobject(segment, py)
+                    .map(pyo3::Bound::into_any)
+            }
+        }
+    }
+}
+
 impl<'py> pyo3::IntoPyObject<'py> for Multipolygon {
     type Target = <PyMultipolygon as pyo3::IntoPyObject<'py>>::Target;
     type Output = <PyMultipolygon as pyo3::IntoPyObject<'py>>::Output;
@@ -410,12 +375,17 @@ type Contour = crate::geometries::Contour<Fraction>;
 type DelaunayTriangulation =
     crate::triangulation::DelaunayTriangulation<Point>;
 type Empty = crate::geometries::Empty;
+type Hull = crate::geometries::Hull<Fraction>;
+type IndexedMesh = crate::triangulation::IndexedMesh<Point>;
 type Multipolygon = crate::geometries::Multipolygon<Fraction>;
 type Multisegment = crate::geometries::Multisegment<Fraction>;
 type Point = crate::geometries::Point<Fraction>;
 type Polygon = crate::geometries::Polygon<Fraction>;
+type QuadraticSurd = crate::metric::QuadraticSurd<Fraction>;
 type Segment = crate::geometries::Segment<Fraction>;
 type Trapezoidation = crate::seidel::Trapezoidation<Point>;
+type Violation = crate::validation::Violation<Point>;
+type VoronoiDiagram = crate::triangulation::VoronoiDiagram<Fraction>;
 
 #[pyo3::pyclass(name = "Box", module = "rene.exact", skip_from_py_object)]
 #[derive(Clone)]
@@ -443,7 +413,15 @@ struct PyDelaunayTriangulation(DelaunayTriangulation);
 
 #[pyo3::pyclass(name = "Empty", module = "rene.exact", skip_from_py_object)]
 #[derive(Clone, Default)]
-struct PyEmpty(Empty);
+pub struct PyEmpty(Empty);
+
+#[pyo3::pyclass(
+    name = "IndexedMesh",
+    module = "rene.exact",
+    skip_from_py_object
+)]
+#[derive(Clone)]
+struct PyIndexedMesh(IndexedMesh);
 
 #[pyo3::pyclass(
     name = "Multipolygon",
@@ -469,6 +447,14 @@ pub struct PyPolygon(Polygon);
 #[derive(Clone)]
 pub struct PyPoint(Point);
 
+#[pyo3::pyclass(
+    name = "QuadraticSurd",
+    module = "rene.exact",
+    skip_from_py_object
+)]
+#[derive(Clone)]
+struct PyQuadraticSurd(QuadraticSurd);
+
 #[pyo3::pyclass(name = "Segment", module = "rene.exact", from_py_object)]
 #[derive(Clone)]
 pub struct PySegment(Segment);
@@ -481,17 +467,37 @@ pub struct PySegment(Segment);
 #[derive(Clone)]
 struct PyTrapezoidation(Trapezoidation);
 
+#[pyo3::pyclass(
+    name = "Violation",
+    module = "rene.exact",
+    skip_from_py_object
+)]
+#[derive(Clone)]
+struct PyViolation(Violation);
+
+#[pyo3::pyclass(
+    name = "VoronoiDiagram",
+    module = "rene.exact",
+    skip_from_py_object
+)]
+#[derive(Clone)]
+struct PyVoronoiDiagram(VoronoiDiagram);
+
 impl_box_wrapper!();
 impl_constrained_delaunay_triangulation_wrapper!();
 impl_contour_wrapper!();
 impl_delaunay_triangulation_wrapper!();
 impl_empty_wrapper!();
+impl_indexed_mesh_wrapper!();
 impl_multipolygon_wrapper!();
 impl_multisegment_wrapper!();
 impl_point_wrapper!();
 impl_polygon_wrapper!();
+impl_quadratic_surd_wrapper!();
 impl_segment_wrapper!();
 impl_trapezoidation_wrapper!();
+impl_violation_wrapper!();
+impl_voronoi_diagram_wrapper!();
 
 type PyContourReference = reference::Reference<PyContour>;
 type PyMultisegmentReference = reference::Reference<PyMultisegment>;
@@ -595,6 +601,14 @@ fn big_int_to_py_long<'py>(
     }
 }
 
+fn to_quadratic_surd_coordinates(
+    point: crate::geometries::Point<QuadraticSurd>,
+) -> (PyQuadraticSurd, PyQuadraticSurd) {
+    use crate::traits::Elemental;
+    let (x, y) = point.coordinates();
+    (PyQuadraticSurd(x), PyQuadraticSurd(y))
+}
+
 fn try_py_integral_to_big_int<'py>(
     value: pyo3::Bound<'py, pyo3::PyAny>,
 ) -> pyo3::PyResult<BigInt> {
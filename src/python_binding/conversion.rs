@@ -12,3 +12,15 @@ pub(super) fn extract_from_py_sequence<
     }
     Ok(result)
 }
+
+impl From<crate::relatable::PatternError> for pyo3::PyErr {
+    fn from(error: crate::relatable::PatternError) -> Self {
+        pyo3::exceptions::PyValueError::new_err(error.to_string())
+    }
+}
+
+impl From<crate::wkt::Error> for pyo3::PyErr {
+    fn from(error: crate::wkt::Error) -> Self {
+        pyo3::exceptions::PyValueError::new_err(error.to_string())
+    }
+}
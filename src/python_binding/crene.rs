@@ -1,4 +1,4 @@
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::sync::PyOnceLock;
 use pyo3::type_object::PyTypeInfo;
 use pyo3::types::{PyModule, PyTuple, PyTypeMethods};
@@ -14,15 +14,18 @@ use crate::constants::{
 use crate::locatable::Location;
 use crate::oriented::Orientation;
 use crate::relatable::Relation;
+use crate::validation::{FillRule, ViolationKind};
 
-use super::traits::TryToPyAny;
+use super::traits::{TryFromPyAny, TryToPyAny};
 
 #[pymodule]
 fn _crene(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     use pyo3::types::PyModuleMethods;
+    module.add_class::<PyFillRule>()?;
     module.add_class::<PyLocation>()?;
     module.add_class::<PyOrientation>()?;
     module.add_class::<PyRelation>()?;
+    module.add_class::<PyViolationKind>()?;
     module.add("MIN_CONTOUR_VERTICES_COUNT", MIN_CONTOUR_VERTICES_COUNT)?;
     module.add(
         "MIN_MULTIPOLYGON_POLYGONS_COUNT",
@@ -35,6 +38,32 @@ fn _crene(_py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+impl TryFromPyAny for FillRule {
+    fn try_from_py_any(
+        value: &Bound<'_, PyAny>,
+        py: Python<'_>,
+    ) -> PyResult<Self> {
+        use pyo3::types::PyAnyMethods;
+        static FILL_RULE_CLS: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+        let fill_rule_cls = FILL_RULE_CLS.get_or_try_init(py, || {
+            py.import("rene.enums")?
+                .getattr(intern!(py, "FillRule"))
+                .map(|value| value.into_pyobject(py).unwrap().unbind())
+        })?;
+        if value.is(fill_rule_cls.getattr(py, intern!(py, "EVEN_ODD"))?) {
+            Ok(FillRule::EvenOdd)
+        } else if value.is(fill_rule_cls.getattr(py, intern!(py, "NON_ZERO"))?)
+        {
+            Ok(FillRule::NonZero)
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Expected fill rule, but got {}.",
+                value.repr()?
+            )))
+        }
+    }
+}
+
 impl TryToPyAny for Location {
     fn try_to_py_any(self, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
         use pyo3::types::PyAnyMethods;
@@ -116,6 +145,77 @@ impl TryToPyAny for Relation {
     }
 }
 
+impl TryToPyAny for ViolationKind {
+    fn try_to_py_any(self, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+        use pyo3::types::PyAnyMethods;
+        static VIOLATION_KIND_CLS: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+        VIOLATION_KIND_CLS
+            .get_or_try_init(py, || {
+                py.import("rene.enums")?
+                    .getattr(intern!(py, "ViolationKind"))
+                    .map(|value| value.into_pyobject(py).unwrap().unbind())
+            })?
+            .getattr(
+                py,
+                match self {
+                    ViolationKind::CollinearVertices => {
+                        intern!(py, "COLLINEAR_VERTICES")
+                    }
+                    ViolationKind::DegenerateSegment => {
+                        intern!(py, "DEGENERATE_SEGMENT")
+                    }
+                    ViolationKind::HoleOutsideBorder => {
+                        intern!(py, "HOLE_OUTSIDE_BORDER")
+                    }
+                    ViolationKind::HoleTouchesBorder => {
+                        intern!(py, "HOLE_TOUCHES_BORDER")
+                    }
+                    ViolationKind::HolesOverlap => {
+                        intern!(py, "HOLES_OVERLAP")
+                    }
+                    ViolationKind::InvalidBorder => {
+                        intern!(py, "INVALID_BORDER")
+                    }
+                    ViolationKind::InvalidHole => intern!(py, "INVALID_HOLE"),
+                    ViolationKind::InvalidPolygon => {
+                        intern!(py, "INVALID_POLYGON")
+                    }
+                    ViolationKind::PolygonsOverlap => {
+                        intern!(py, "POLYGONS_OVERLAP")
+                    }
+                    ViolationKind::SegmentsCross => {
+                        intern!(py, "SEGMENTS_CROSS")
+                    }
+                    ViolationKind::SegmentsOverlap => {
+                        intern!(py, "SEGMENTS_OVERLAP")
+                    }
+                    ViolationKind::SegmentsTouch => {
+                        intern!(py, "SEGMENTS_TOUCH")
+                    }
+                    ViolationKind::TooFewPolygons => {
+                        intern!(py, "TOO_FEW_POLYGONS")
+                    }
+                    ViolationKind::TooFewSegments => {
+                        intern!(py, "TOO_FEW_SEGMENTS")
+                    }
+                    ViolationKind::TooFewVertices => {
+                        intern!(py, "TOO_FEW_VERTICES")
+                    }
+                    ViolationKind::WrongBorderOrientation => {
+                        intern!(py, "WRONG_BORDER_ORIENTATION")
+                    }
+                    ViolationKind::WrongHoleOrientation => {
+                        intern!(py, "WRONG_HOLE_ORIENTATION")
+                    }
+                },
+            )
+            .map(|value| value.into_bound(py))
+    }
+}
+
+#[pyclass(name = "FillRule", module = "rene.enums")]
+struct PyFillRule(FillRule);
+
 #[pyclass(name = "Location", module = "rene.enums")]
 struct PyLocation(Location);
 
@@ -125,6 +225,29 @@ struct PyOrientation(Orientation);
 #[pyclass(name = "Relation", module = "rene.enums")]
 struct PyRelation(Relation);
 
+#[pyclass(name = "ViolationKind", module = "rene.enums")]
+struct PyViolationKind(ViolationKind);
+
+#[pymethods]
+impl PyFillRule {
+    #[classattr]
+    const EVEN_ODD: PyFillRule = PyFillRule(FillRule::EvenOdd);
+
+    #[classattr]
+    const NON_ZERO: PyFillRule = PyFillRule(FillRule::NonZero);
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "{}.{}",
+            Self::type_object(py).name()?,
+            match self.0 {
+                FillRule::EvenOdd => "EVEN_ODD",
+                FillRule::NonZero => "NON_ZERO",
+            }
+        ))
+    }
+}
+
 #[pymethods]
 impl PyLocation {
     #[classattr]
@@ -311,6 +434,105 @@ impl PyRelation {
     }
 }
 
+#[pymethods]
+impl PyViolationKind {
+    #[classattr]
+    const COLLINEAR_VERTICES: PyViolationKind =
+        PyViolationKind(ViolationKind::CollinearVertices);
+
+    #[classattr]
+    const DEGENERATE_SEGMENT: PyViolationKind =
+        PyViolationKind(ViolationKind::DegenerateSegment);
+
+    #[classattr]
+    const HOLE_OUTSIDE_BORDER: PyViolationKind =
+        PyViolationKind(ViolationKind::HoleOutsideBorder);
+
+    #[classattr]
+    const HOLE_TOUCHES_BORDER: PyViolationKind =
+        PyViolationKind(ViolationKind::HoleTouchesBorder);
+
+    #[classattr]
+    const HOLES_OVERLAP: PyViolationKind =
+        PyViolationKind(ViolationKind::HolesOverlap);
+
+    #[classattr]
+    const INVALID_BORDER: PyViolationKind =
+        PyViolationKind(ViolationKind::InvalidBorder);
+
+    #[classattr]
+    const INVALID_HOLE: PyViolationKind =
+        PyViolationKind(ViolationKind::InvalidHole);
+
+    #[classattr]
+    const INVALID_POLYGON: PyViolationKind =
+        PyViolationKind(ViolationKind::InvalidPolygon);
+
+    #[classattr]
+    const POLYGONS_OVERLAP: PyViolationKind =
+        PyViolationKind(ViolationKind::PolygonsOverlap);
+
+    #[classattr]
+    const SEGMENTS_CROSS: PyViolationKind =
+        PyViolationKind(ViolationKind::SegmentsCross);
+
+    #[classattr]
+    const SEGMENTS_OVERLAP: PyViolationKind =
+        PyViolationKind(ViolationKind::SegmentsOverlap);
+
+    #[classattr]
+    const SEGMENTS_TOUCH: PyViolationKind =
+        PyViolationKind(ViolationKind::SegmentsTouch);
+
+    #[classattr]
+    const TOO_FEW_POLYGONS: PyViolationKind =
+        PyViolationKind(ViolationKind::TooFewPolygons);
+
+    #[classattr]
+    const TOO_FEW_SEGMENTS: PyViolationKind =
+        PyViolationKind(ViolationKind::TooFewSegments);
+
+    #[classattr]
+    const TOO_FEW_VERTICES: PyViolationKind =
+        PyViolationKind(ViolationKind::TooFewVertices);
+
+    #[classattr]
+    const WRONG_BORDER_ORIENTATION: PyViolationKind =
+        PyViolationKind(ViolationKind::WrongBorderOrientation);
+
+    #[classattr]
+    const WRONG_HOLE_ORIENTATION: PyViolationKind =
+        PyViolationKind(ViolationKind::WrongHoleOrientation);
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "{}.{}",
+            Self::type_object(py).name()?,
+            match self.0 {
+                ViolationKind::CollinearVertices => "COLLINEAR_VERTICES",
+                ViolationKind::DegenerateSegment => "DEGENERATE_SEGMENT",
+                ViolationKind::HoleOutsideBorder => "HOLE_OUTSIDE_BORDER",
+                ViolationKind::HoleTouchesBorder => "HOLE_TOUCHES_BORDER",
+                ViolationKind::HolesOverlap => "HOLES_OVERLAP",
+                ViolationKind::InvalidBorder => "INVALID_BORDER",
+                ViolationKind::InvalidHole => "INVALID_HOLE",
+                ViolationKind::InvalidPolygon => "INVALID_POLYGON",
+                ViolationKind::PolygonsOverlap => "POLYGONS_OVERLAP",
+                ViolationKind::SegmentsCross => "SEGMENTS_CROSS",
+                ViolationKind::SegmentsOverlap => "SEGMENTS_OVERLAP",
+                ViolationKind::SegmentsTouch => "SEGMENTS_TOUCH",
+                ViolationKind::TooFewPolygons => "TOO_FEW_POLYGONS",
+                ViolationKind::TooFewSegments => "TOO_FEW_SEGMENTS",
+                ViolationKind::TooFewVertices => "TOO_FEW_VERTICES",
+                ViolationKind::WrongBorderOrientation =>
+                    "WRONG_BORDER_ORIENTATION",
+                ViolationKind::WrongHoleOrientation =>
+                    "WRONG_HOLE_ORIENTATION",
+            }
+        ))
+    }
+}
+
 fn to_py_relation_values(py: Python<'_>) -> &[Py<PyRelation>; 11] {
     static VALUES: PyOnceLock<[Py<PyRelation>; 11]> = PyOnceLock::new();
     VALUES.get_or_init(py, || {
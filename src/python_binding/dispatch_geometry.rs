@@ -0,0 +1,43 @@
+/// Evaluates the body with the geometry wrapped by the Python object
+/// bound to the name, trying the listed wrapper types in order,
+/// defaults to all the wrappers of geometries with a metric.
+macro_rules! dispatch_geometry {
+    ($other: ident, |$geometry: ident| $body: expr) => {
+        $crate::python_binding::dispatch_geometry::dispatch_geometry!(
+            $other,
+            |$geometry| $body,
+            "geometry",
+            [
+                PyContour,
+                PyMultipolygon,
+                PyMultisegment,
+                PyPoint,
+                PyPolygon,
+                PySegment,
+            ]
+        )
+    };
+    (
+        $other: ident,
+        |$geometry: ident| $body: expr,
+        $expected: literal,
+        [$($py_geometry: ty),+ $(,)?]
+    ) => {{
+        use pyo3::types::PyAnyMethods;
+        $(
+            if $other.is_instance_of::<$py_geometry>() {
+                let other = $other.extract::<pyo3::Bound<'_, $py_geometry>>()?;
+                let other = other.borrow();
+                let $geometry = &other.0;
+                $body
+            }
+        ) else + else {
+            Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                concat!("Expected ", $expected, ", but got {}."),
+                $other.get_type().repr()?
+            )))
+        }
+    }};
+}
+
+pub(super) use dispatch_geometry;
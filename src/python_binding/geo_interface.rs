@@ -0,0 +1,178 @@
+use pyo3::types::{PyAnyMethods, PyDictMethods};
+
+use crate::geometries::{Contour, Point, Polygon, Segment};
+use crate::traits::{Elemental, Iterable, Multivertexal, Polygonal};
+
+use super::traits::{TryFromPyAny, TryToPyFloat};
+
+const COORDINATES_MEMBER_NAME: &str = "coordinates";
+const TYPE_MEMBER_NAME: &str = "type";
+
+pub(super) fn extract_coordinates<'py>(
+    value: &pyo3::Bound<'py, pyo3::PyAny>,
+    geometry_type: &str,
+    py: pyo3::Python<'py>,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+    let value = value
+        .getattr(pyo3::intern!(py, "__geo_interface__"))
+        .unwrap_or_else(|_| value.clone());
+    let actual_type = extract_member(&value, TYPE_MEMBER_NAME)?;
+    if !actual_type.eq(geometry_type)? {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unexpected geometry type {}.",
+            actual_type.repr()?
+        )));
+    }
+    extract_member(&value, COORDINATES_MEMBER_NAME)
+}
+
+pub(super) fn extract_list<Item>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    mut extract_item: impl FnMut(
+        &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Item>,
+) -> pyo3::PyResult<Vec<Item>> {
+    let mut result = Vec::new();
+    for item in value.try_iter()? {
+        result.push(extract_item(&item?)?);
+    }
+    Ok(result)
+}
+
+pub(super) fn to_geo_interface<'py>(
+    geometry_type: &str,
+    coordinates: pyo3::Bound<'py, pyo3::types::PyTuple>,
+    py: pyo3::Python<'py>,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+    let result = pyo3::types::PyDict::new(py);
+    result.set_item(TYPE_MEMBER_NAME, geometry_type)?;
+    result.set_item(COORDINATES_MEMBER_NAME, coordinates)?;
+    Ok(result)
+}
+
+pub(super) fn try_point_to_position<'py, Scalar>(
+    point: &Point<Scalar>,
+    py: pyo3::Python<'py>,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyTuple>>
+where
+    for<'a> &'a Scalar: TryToPyFloat,
+{
+    let (x, y) = point.coordinates();
+    pyo3::types::PyTuple::new(py, [x.try_to_py_float()?, y.try_to_py_float()?])
+}
+
+pub(super) fn try_points_to_positions<'a, 'py, Scalar: 'a>(
+    points: impl Iterator<Item = &'a Point<Scalar>>,
+    py: pyo3::Python<'py>,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyTuple>>
+where
+    for<'b> &'b Scalar: TryToPyFloat,
+{
+    pyo3::types::PyTuple::new(
+        py,
+        points
+            .map(|point| try_point_to_position(point, py))
+            .collect::<pyo3::PyResult<Vec<_>>>()?,
+    )
+}
+
+pub(super) fn try_polygon_to_positions<'py, Scalar>(
+    polygon: &Polygon<Scalar>,
+    py: pyo3::Python<'py>,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyTuple>>
+where
+    for<'a> &'a Scalar: TryToPyFloat,
+{
+    pyo3::types::PyTuple::new(
+        py,
+        std::iter::once(polygon.border())
+            .chain(polygon.holes().iter())
+            .map(|contour| try_ring_to_positions(contour, py))
+            .collect::<pyo3::PyResult<Vec<_>>>()?,
+    )
+}
+
+pub(super) fn try_position_to_point<Scalar: TryFromPyAny>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<Point<Scalar>> {
+    crate::geojson::to_point(extract_list(value, |coordinate| {
+        Scalar::try_from_py_any(coordinate, py)
+    })?)
+    .map_err(to_invalid_geometry_error)
+}
+
+pub(super) fn try_positions_to_contour<
+    Scalar: Clone + PartialEq + TryFromPyAny,
+>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<Contour<Scalar>> {
+    crate::geometries::to_contour(try_positions_to_points(value, py)?)
+        .map_err(to_invalid_geometry_error)
+}
+
+pub(super) fn try_positions_to_line_string<Scalar: TryFromPyAny>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<Vec<Point<Scalar>>> {
+    crate::geometries::to_line_string(try_positions_to_points(value, py)?)
+        .map_err(to_invalid_geometry_error)
+}
+
+pub(super) fn try_positions_to_polygon<
+    Scalar: Clone + PartialEq + TryFromPyAny,
+>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<Polygon<Scalar>> {
+    crate::geometries::to_polygon(extract_list(value, |ring| {
+        try_positions_to_contour(ring, py)
+    })?)
+    .map_err(to_invalid_geometry_error)
+}
+
+pub(super) fn try_positions_to_segment<Scalar: TryFromPyAny>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<Segment<Scalar>> {
+    crate::geometries::to_segment(try_positions_to_points(value, py)?)
+        .map_err(to_invalid_geometry_error)
+}
+
+pub(super) fn try_ring_to_positions<'py, Scalar>(
+    contour: &Contour<Scalar>,
+    py: pyo3::Python<'py>,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyTuple>>
+where
+    for<'a> &'a Scalar: TryToPyFloat,
+{
+    let vertices = contour.vertices();
+    try_points_to_positions(vertices.iter().chain(vertices.iter().take(1)), py)
+}
+
+fn extract_member<'py>(
+    value: &pyo3::Bound<'py, pyo3::PyAny>,
+    name: &str,
+) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+    value.get_item(name).map_err(|_| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Missing \"{}\" member.",
+            name
+        ))
+    })
+}
+
+fn to_invalid_geometry_error(reason: &str) -> pyo3::PyErr {
+    pyo3::exceptions::PyValueError::new_err(format!(
+        "Invalid geometry: {}.",
+        reason
+    ))
+}
+
+fn try_positions_to_points<Scalar: TryFromPyAny>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<Vec<Point<Scalar>>> {
+    extract_list(value, |position| try_position_to_point(position, py))
+}
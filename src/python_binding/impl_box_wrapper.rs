@@ -63,6 +63,16 @@ macro_rules! impl_box_wrapper {
                 )
             }
 
+            fn convex_hull<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                pyo3::IntoPyObject::into_pyobject(
+                    crate::traits::ConvexHull::convex_hull(&self.0),
+                    py,
+                )
+            }
+
             #[pyo3(signature = (other, /))]
             fn covers(&self, other: &Self) -> bool {
                 crate::relatable::Relatable::covers(&self.0, &other.0)
@@ -110,11 +120,53 @@ macro_rules! impl_box_wrapper {
                 )
             }
 
+            #[pyo3(signature = (factor_x, factor_y, /))]
+            fn scale(
+                &self,
+                factor_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                factor_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyBox> {
+                Ok(PyBox(crate::transformation::Transform::transform(
+                    &self.0,
+                    &super::transformation::try_to_scaling_matrix(
+                        factor_x, factor_y, py,
+                    )?,
+                )))
+            }
+
             #[pyo3(signature = (other, /))]
             fn touches(&self, other: &Self) -> bool {
                 crate::relatable::Relatable::touches(&self.0, &other.0)
             }
 
+            #[pyo3(signature = (matrix, /))]
+            fn transform(
+                &self,
+                matrix: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyBox> {
+                Ok(PyBox(crate::transformation::Transform::transform(
+                    &self.0,
+                    &super::transformation::try_to_affine_matrix(matrix, py)?,
+                )))
+            }
+
+            #[pyo3(signature = (step_x, step_y, /))]
+            fn translate(
+                &self,
+                step_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                step_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyBox> {
+                Ok(PyBox(crate::transformation::Transform::transform(
+                    &self.0,
+                    &super::transformation::try_to_translation_matrix(
+                        step_x, step_y, py,
+                    )?,
+                )))
+            }
+
             #[pyo3(signature = (other, /))]
             fn within(&self, other: &Self) -> bool {
                 crate::relatable::Relatable::within(&self.0, &other.0)
@@ -2,6 +2,35 @@ macro_rules! impl_constrained_delaunay_triangulation_wrapper {
     () => {
         #[pyo3::pymethods]
         impl PyConstrainedDelaunayTriangulation {
+            #[classmethod]
+            #[pyo3(signature = (multipolygon, /))]
+            fn from_multipolygon(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                multipolygon: &PyMultipolygon,
+            ) -> Self {
+                PyConstrainedDelaunayTriangulation(
+                    ConstrainedDelaunayTriangulation::from(&multipolygon.0),
+                )
+            }
+
+            #[classmethod]
+            #[pyo3(signature = (points, multisegment, /))]
+            fn from_points_and_multisegment(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                points: &pyo3::Bound<'_, pyo3::types::PySequence>,
+                multisegment: &PyMultisegment,
+            ) -> pyo3::PyResult<Self> {
+                Ok(PyConstrainedDelaunayTriangulation(
+                    crate::triangulation::FromPointsAndMultisegment::from_points_and_multisegment(
+                        super::conversion::extract_from_py_sequence::<
+                            Point,
+                            PyPoint,
+                        >(points)?,
+                        &multisegment.0,
+                    ),
+                ))
+            }
+
             #[classmethod]
             #[pyo3(signature = (polygon, /))]
             fn from_polygon(
@@ -25,10 +54,15 @@ macro_rules! impl_constrained_delaunay_triangulation_wrapper {
         )
             }
 
+            #[getter]
+            fn endpoints(&self) -> Vec<Point> {
+                self.0.get_endpoints().to_vec()
+            }
+
             #[getter]
             fn triangles(&self) -> Vec<Contour> {
                 self.0
-                    .to_triangles_vertices()
+                    .iter_triangles_vertices()
                     .map(|(first, second, third)| {
                         Contour::from([
                             first.clone(),
@@ -39,6 +73,39 @@ macro_rules! impl_constrained_delaunay_triangulation_wrapper {
                     .collect()
             }
 
+            #[pyo3(signature = (point, /))]
+            fn locate_point(
+                &self,
+                point: &pyo3::Bound<'_, PyPoint>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<(
+                pyo3::Py<pyo3::types::PyTuple>,
+                pyo3::Py<pyo3::PyAny>,
+            )> {
+                super::unpacking::try_unpack_point_location::<
+                    Contour,
+                    Point,
+                    Segment,
+                    _,
+                >(
+                    self.0.locate_point(&point.borrow().0),
+                    self.0.get_endpoints(),
+                    py,
+                )
+            }
+
+            fn to_indexed_mesh(&self) -> PyIndexedMesh {
+                PyIndexedMesh(self.0.to_indexed_mesh())
+            }
+
+            fn _repr_svg_(&self) -> Option<String> {
+                crate::svg::ToSvg::to_svg(
+                    &self.0,
+                    &crate::svg::Style::default(),
+                )
+                .ok()
+            }
+
             fn __bool__(&self) -> bool {
                 !self.0.is_empty()
             }
@@ -28,6 +28,11 @@ macro_rules! impl_delaunay_triangulation_wrapper {
         )
             }
 
+            #[getter]
+            fn endpoints(&self) -> Vec<Point> {
+                self.0.get_endpoints().to_vec()
+            }
+
             #[getter]
             fn triangles(&self) -> Vec<Contour> {
                 self.0
@@ -42,6 +47,54 @@ macro_rules! impl_delaunay_triangulation_wrapper {
                     .collect()
             }
 
+            #[pyo3(signature = (point, /))]
+            fn insert(&mut self, point: &pyo3::Bound<'_, PyPoint>) -> bool {
+                self.0.insert(point.borrow().0.clone())
+            }
+
+            #[pyo3(signature = (point, /))]
+            fn locate_point(
+                &self,
+                point: &pyo3::Bound<'_, PyPoint>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<(
+                pyo3::Py<pyo3::types::PyTuple>,
+                pyo3::Py<pyo3::PyAny>,
+            )> {
+                super::unpacking::try_unpack_point_location::<
+                    Contour,
+                    Point,
+                    Segment,
+                    _,
+                >(
+                    self.0.locate_point(&point.borrow().0),
+                    self.0.get_endpoints(),
+                    py,
+                )
+            }
+
+            #[pyo3(signature = (point, /))]
+            fn remove(&mut self, point: &pyo3::Bound<'_, PyPoint>) -> bool {
+                self.0.remove(&point.borrow().0)
+            }
+
+            fn to_indexed_mesh(&self) -> PyIndexedMesh {
+                PyIndexedMesh(self.0.to_indexed_mesh())
+            }
+
+            #[pyo3(signature = (bounding_box, /))]
+            fn to_voronoi(&self, bounding_box: &PyBox) -> PyVoronoiDiagram {
+                PyVoronoiDiagram(self.0.to_voronoi(&bounding_box.0))
+            }
+
+            fn _repr_svg_(&self) -> Option<String> {
+                crate::svg::ToSvg::to_svg(
+                    &self.0,
+                    &crate::svg::Style::default(),
+                )
+                .ok()
+            }
+
             fn __bool__(&self) -> bool {
                 !self.0.is_empty()
             }
@@ -7,6 +7,19 @@ macro_rules! impl_empty_wrapper {
                 PyEmpty(Empty::new())
             }
 
+            #[classmethod]
+            #[pyo3(signature = (wkt, /))]
+            fn from_wkt(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                wkt: &str,
+            ) -> pyo3::PyResult<Self> {
+                Ok(Self(<Empty as crate::wkt::FromWkt>::from_wkt(wkt)?))
+            }
+
+            fn convex_hull(&self) -> Self {
+                PyEmpty(crate::traits::ConvexHull::convex_hull(&self.0))
+            }
+
             #[pyo3(signature = (point, /))]
             fn locate<'py>(
                 &self,
@@ -16,6 +29,23 @@ macro_rules! impl_empty_wrapper {
                 crate::locatable::Location::Exterior.try_to_py_any(py)
             }
 
+            #[pyo3(signature = (other, /))]
+            fn relate_matrix(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<String> {
+                Ok(self.to_intersection_matrix(other)?.to_string())
+            }
+
+            #[pyo3(signature = (other, pattern, /))]
+            fn relate_pattern(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                pattern: &str,
+            ) -> pyo3::PyResult<bool> {
+                Ok(self.to_intersection_matrix(other)?.matches(pattern)?)
+            }
+
             #[pyo3(signature = (other, /))]
             fn relate_to<'py>(
                 &self,
@@ -79,6 +109,56 @@ macro_rules! impl_empty_wrapper {
                 }
             }
 
+            #[pyo3(signature = (factor_x, factor_y, /))]
+            fn scale(
+                &self,
+                factor_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                factor_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyEmpty> {
+                Ok(PyEmpty(crate::transformation::Transform::<Fraction>::transform(
+                    &self.0,
+                    &super::transformation::try_to_scaling_matrix(factor_x, factor_y, py)?,
+                )))
+            }
+
+            fn to_wkt(&self) -> pyo3::PyResult<String> {
+                Ok(crate::wkt::ToWkt::to_wkt(&self.0)?)
+            }
+
+            #[pyo3(signature = (matrix, /))]
+            fn transform(
+                &self,
+                matrix: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyEmpty> {
+                Ok(PyEmpty(crate::transformation::Transform::<Fraction>::transform(
+                    &self.0,
+                    &super::transformation::try_to_affine_matrix(matrix, py)?,
+                )))
+            }
+
+            #[pyo3(signature = (step_x, step_y, /))]
+            fn translate(
+                &self,
+                step_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                step_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyEmpty> {
+                Ok(PyEmpty(crate::transformation::Transform::<Fraction>::transform(
+                    &self.0,
+                    &super::transformation::try_to_translation_matrix(step_x, step_y, py)?,
+                )))
+            }
+
+            fn _repr_svg_(&self) -> Option<String> {
+                crate::svg::ToSvg::to_svg(
+                    &self.0,
+                    &crate::svg::Style::default(),
+                )
+                .ok()
+            }
+
             fn __and__(
                 &self,
                 other: &pyo3::Bound<'_, pyo3::PyAny>,
@@ -456,6 +536,69 @@ macro_rules! impl_empty_wrapper {
                 }
             }
         }
+
+        impl PyEmpty {
+            fn to_intersection_matrix(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<crate::relatable::IntersectionMatrix> {
+                use pyo3::types::PyAnyMethods;
+                if other.is_instance_of::<PyContour>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyContour>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyEmpty>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyEmpty>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyMultipolygon>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyMultipolygon>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyMultisegment>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyMultisegment>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyPolygon>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyPolygon>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PySegment>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PySegment>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else {
+                    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                        "Expected compound geometry, but got {}.",
+                        other.get_type().repr()?
+                    )))
+                }
+            }
+        }
     };
 }
 
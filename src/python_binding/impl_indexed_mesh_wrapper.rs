@@ -0,0 +1,42 @@
+macro_rules! impl_indexed_mesh_wrapper {
+    () => {
+        #[pyo3::pymethods]
+        impl PyIndexedMesh {
+            #[getter]
+            fn constrained_flags(&self) -> Vec<(bool, bool, bool)> {
+                self.0
+                    .get_constrained_flags()
+                    .iter()
+                    .map(|&[first, second, third]| (first, second, third))
+                    .collect()
+            }
+
+            #[getter]
+            fn neighbours(
+                &self,
+            ) -> Vec<(Option<usize>, Option<usize>, Option<usize>)> {
+                self.0
+                    .get_neighbours()
+                    .iter()
+                    .map(|&[first, second, third]| (first, second, third))
+                    .collect()
+            }
+
+            #[getter]
+            fn triangles(&self) -> Vec<(usize, usize, usize)> {
+                self.0
+                    .get_triangles()
+                    .iter()
+                    .map(|&[first, second, third]| (first, second, third))
+                    .collect()
+            }
+
+            #[getter]
+            fn vertices(&self) -> Vec<Point> {
+                self.0.get_vertices().to_vec()
+            }
+        }
+    };
+}
+
+pub(super) use impl_indexed_mesh_wrapper;
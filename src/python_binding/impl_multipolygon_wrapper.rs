@@ -3,15 +3,87 @@ macro_rules! impl_multipolygon_wrapper {
         #[pyo3::pymethods]
         impl PyMultipolygon {
             #[new]
-            #[pyo3(signature = (polygons, /))]
+            #[pyo3(signature = (polygons, /, *, validate = false))]
             fn new(
                 polygons: &pyo3::Bound<'_, pyo3::types::PySequence>,
+                validate: bool,
             ) -> pyo3::PyResult<Self> {
-                super::packing::try_pack_polygons(
+                let result: Self = super::packing::try_pack_polygons(
                     super::conversion::extract_from_py_sequence::<
                         Polygon,
                         PyPolygon,
                     >(polygons)?,
+                )?;
+                if validate {
+                    super::validation::try_ensure_valid(
+                        &result.0,
+                        "multipolygon",
+                    )?;
+                }
+                Ok(result)
+            }
+
+            #[classmethod]
+            #[pyo3(signature = (value, /))]
+            fn from_geo_interface(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                value: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<Self> {
+                let coordinates = super::geo_interface::extract_coordinates(
+                    value,
+                    "MultiPolygon",
+                    py,
+                )?;
+                Ok(Self(Multipolygon::new(
+                    super::geo_interface::extract_list(&coordinates, |polygon| {
+                        super::geo_interface::try_positions_to_polygon(
+                            polygon, py,
+                        )
+                    })?,
+                )))
+            }
+
+            #[classmethod]
+            #[pyo3(signature = (wkt, /))]
+            fn from_wkt(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                wkt: &str,
+            ) -> pyo3::PyResult<Self> {
+                Ok(Self(<Multipolygon as crate::wkt::FromWkt>::from_wkt(wkt)?))
+            }
+
+            #[getter]
+            fn __geo_interface__<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                super::geo_interface::to_geo_interface(
+                    "MultiPolygon",
+                    pyo3::types::PyTuple::new(
+                        py,
+                        (&self.0)
+                            .polygons()
+                            .into_iter()
+                            .map(|polygon| {
+                                super::geo_interface::try_polygon_to_positions(
+                                    polygon, py,
+                                )
+                            })
+                            .collect::<pyo3::PyResult<Vec<_>>>()?,
+                    )?,
+                    py,
+                )
+            }
+
+            #[getter]
+            fn area<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                    &crate::traits::Area::area(&self.0),
+                    py,
                 )
             }
 
@@ -20,6 +92,11 @@ macro_rules! impl_multipolygon_wrapper {
                 crate::bounded::Bounded::to_bounding_box(&self.0).cloned()
             }
 
+            #[getter]
+            fn centroid(&self) -> PyPoint {
+                PyPoint(crate::traits::Centroidal::to_centroid(&self.0))
+            }
+
             #[getter]
             fn polygons(slf: pyo3::PyRef<'_, Self>) -> PyMultipolygonPolygons {
                 let polygons_count =
@@ -32,6 +109,108 @@ macro_rules! impl_multipolygon_wrapper {
                 }
             }
 
+            #[getter]
+            fn signed_area<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                    &crate::traits::Area::signed_area(&self.0),
+                    py,
+                )
+            }
+
+            fn convex_hull<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                pyo3::IntoPyObject::into_pyobject(
+                    crate::traits::ConvexHull::convex_hull(&self.0),
+                    py,
+                )
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn discrete_hausdorff_distance_squared<'py>(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<(
+                pyo3::Bound<'py, pyo3::PyAny>,
+                PyPoint,
+                PyPoint,
+            )> {
+                let (squared_distance, point, other_point) =
+                    super::dispatch_geometry::dispatch_geometry!(
+                        other,
+                        |other_geometry| {
+                            Ok(crate::metric::DiscreteHausdorffMetric::squared_discrete_hausdorff_distance_to(
+                                &self.0,
+                                other_geometry,
+                            ))
+                        },
+                        "contour, multipolygon, multisegment or polygon",
+                        [PyContour, PyMultipolygon, PyMultisegment, PyPolygon]
+                    )?;
+                Ok((
+                    crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                        &squared_distance,
+                        py,
+                    )?,
+                    PyPoint(point),
+                    PyPoint(other_point),
+                ))
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn distance_squared<'py>(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                super::dispatch_geometry::dispatch_geometry!(other, |other_geometry| {
+                    crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                        &crate::metric::SquaredMetric::squared_distance_to(
+                            &self.0,
+                            other_geometry,
+                        ),
+                        py,
+                    )
+                })
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn hausdorff_distance_squared(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<(
+                PyQuadraticSurd,
+                (PyQuadraticSurd, PyQuadraticSurd),
+                (PyQuadraticSurd, PyQuadraticSurd),
+            )> {
+                let (squared_distance, point, other_point) =
+                    super::dispatch_geometry::dispatch_geometry!(
+                        other,
+                        |other_geometry| {
+                            Ok(crate::metric::HausdorffMetric::squared_hausdorff_distance_to(
+                                &self.0,
+                                other_geometry,
+                            ))
+                        },
+                        "contour, multipolygon, multisegment or polygon",
+                        [PyContour, PyMultipolygon, PyMultisegment, PyPolygon]
+                    )?;
+                Ok((
+                    PyQuadraticSurd(squared_distance),
+                    to_quadratic_surd_coordinates(point),
+                    to_quadratic_surd_coordinates(other_point),
+                ))
+            }
+
+            fn is_valid(&self) -> bool {
+                crate::validation::Validatable::is_valid(&self.0)
+            }
+
             #[pyo3(signature = (point, /))]
             fn locate<'py>(
                 &self,
@@ -44,6 +223,60 @@ macro_rules! impl_multipolygon_wrapper {
                 )
             }
 
+            #[pyo3(signature = (fill_rule, /))]
+            fn make_valid(
+                &self,
+                fill_rule: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+                let polygons = crate::validation::MakeValid::make_valid(
+                    &self.0,
+                    <crate::validation::FillRule as crate::python_binding::traits::TryFromPyAny>::try_from_py_any(
+                        fill_rule, py,
+                    )?,
+                );
+                super::unpacking::try_unpack_maybe_empty_polygons::<PyEmpty, PyMultipolygon, Polygon, _>(
+                    polygons,
+                    py,
+                )
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<(PyPoint, PyPoint)> {
+                let (point, other_point) =
+                    super::dispatch_geometry::dispatch_geometry!(other, |other_geometry| {
+                        Ok(crate::metric::NearestPoints::nearest_points(
+                            &self.0,
+                            other_geometry,
+                        ))
+                    })?;
+                Ok((PyPoint(point), PyPoint(other_point)))
+            }
+
+            fn normalize(&self) -> PyMultipolygon {
+                PyMultipolygon(crate::traits::Normalize::normalize(&self.0))
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn relate_matrix(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<String> {
+                Ok(self.to_intersection_matrix(other)?.to_string())
+            }
+
+            #[pyo3(signature = (other, pattern, /))]
+            fn relate_pattern(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                pattern: &str,
+            ) -> pyo3::PyResult<bool> {
+                Ok(self.to_intersection_matrix(other)?.matches(pattern)?)
+            }
+
             #[pyo3(signature = (other, /))]
             fn relate_to<'py>(
                 &self,
@@ -107,6 +340,69 @@ macro_rules! impl_multipolygon_wrapper {
                 }
             }
 
+            #[pyo3(signature = (factor_x, factor_y, /))]
+            fn scale(
+                &self,
+                factor_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                factor_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyMultipolygon> {
+                Ok(PyMultipolygon(super::transformation::try_to_non_singular_image(
+                    crate::transformation::Transform::transform(
+                        &self.0,
+                        &super::transformation::try_to_scaling_matrix(factor_x, factor_y, py)?,
+                    ),
+                )?))
+            }
+
+            fn to_wkt(&self) -> pyo3::PyResult<String> {
+                Ok(crate::wkt::ToWkt::to_wkt(&self.0)?)
+            }
+
+            #[pyo3(signature = (matrix, /))]
+            fn transform(
+                &self,
+                matrix: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyMultipolygon> {
+                Ok(PyMultipolygon(super::transformation::try_to_non_singular_image(
+                    crate::transformation::Transform::transform(
+                        &self.0,
+                        &super::transformation::try_to_affine_matrix(matrix, py)?,
+                    ),
+                )?))
+            }
+
+            #[pyo3(signature = (step_x, step_y, /))]
+            fn translate(
+                &self,
+                step_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                step_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyMultipolygon> {
+                Ok(PyMultipolygon(super::transformation::try_to_non_singular_image(
+                    crate::transformation::Transform::transform(
+                        &self.0,
+                        &super::transformation::try_to_translation_matrix(step_x, step_y, py)?,
+                    ),
+                )?))
+            }
+
+            fn validate(&self) -> Vec<PyViolation> {
+                crate::validation::Validatable::validate(&self.0)
+                    .into_iter()
+                    .map(PyViolation)
+                    .collect()
+            }
+
+            fn _repr_svg_(&self) -> Option<String> {
+                crate::svg::ToSvg::to_svg(
+                    &self.0,
+                    &crate::svg::Style::default(),
+                )
+                .ok()
+            }
+
             fn __and__(
                 &self,
                 other: &pyo3::Bound<'_, pyo3::PyAny>,
@@ -391,6 +687,69 @@ macro_rules! impl_multipolygon_wrapper {
                 }
             }
         }
+
+        impl PyMultipolygon {
+            fn to_intersection_matrix(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<crate::relatable::IntersectionMatrix> {
+                use pyo3::types::PyAnyMethods;
+                if other.is_instance_of::<PyContour>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyContour>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyEmpty>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyEmpty>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyMultipolygon>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyMultipolygon>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyMultisegment>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyMultisegment>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PyPolygon>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PyPolygon>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else if other.is_instance_of::<PySegment>() {
+                    Ok(crate::relatable::MatrixRelatable::relate_matrix(
+                        &self.0,
+                        &other
+                            .extract::<pyo3::Bound<'_, PySegment>>()?
+                            .borrow()
+                            .0,
+                    ))
+                } else {
+                    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                        "Expected compound geometry, but got {}.",
+                        other.get_type().repr()?
+                    )))
+                }
+            }
+        }
     };
 }
 
@@ -15,6 +15,43 @@ macro_rules! impl_point_wrapper {
                 )))
             }
 
+            #[classmethod]
+            #[pyo3(signature = (value, /))]
+            fn from_geo_interface(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                value: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<Self> {
+                let coordinates = super::geo_interface::extract_coordinates(
+                    value, "Point", py,
+                )?;
+                Ok(Self(super::geo_interface::try_position_to_point(
+                    &coordinates,
+                    py,
+                )?))
+            }
+
+            #[classmethod]
+            #[pyo3(signature = (wkt, /))]
+            fn from_wkt(
+                _: &pyo3::Bound<'_, pyo3::types::PyType>,
+                wkt: &str,
+            ) -> pyo3::PyResult<Self> {
+                Ok(Self(<Point as crate::wkt::FromWkt>::from_wkt(wkt)?))
+            }
+
+            #[getter]
+            fn __geo_interface__<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                super::geo_interface::to_geo_interface(
+                    "Point",
+                    super::geo_interface::try_point_to_position(&self.0, py)?,
+                    py,
+                )
+            }
+
             #[getter]
             fn x<'py>(
                 &self,
@@ -37,6 +74,102 @@ macro_rules! impl_point_wrapper {
                 )
             }
 
+            fn convex_hull<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                pyo3::IntoPyObject::into_pyobject(
+                    crate::traits::ConvexHull::convex_hull(&self.0),
+                    py,
+                )
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn distance_squared<'py>(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                super::dispatch_geometry::dispatch_geometry!(other, |other_geometry| {
+                    crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                        &crate::metric::SquaredMetric::squared_distance_to(
+                            &self.0,
+                            other_geometry,
+                        ),
+                        py,
+                    )
+                })
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<(PyPoint, PyPoint)> {
+                let (point, other_point) =
+                    super::dispatch_geometry::dispatch_geometry!(other, |other_geometry| {
+                        Ok(crate::metric::NearestPoints::nearest_points(
+                            &self.0,
+                            other_geometry,
+                        ))
+                    })?;
+                Ok((PyPoint(point), PyPoint(other_point)))
+            }
+
+            #[pyo3(signature = (factor_x, factor_y, /))]
+            fn scale(
+                &self,
+                factor_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                factor_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyPoint> {
+                Ok(PyPoint(crate::transformation::Transform::transform(
+                    &self.0,
+                    &super::transformation::try_to_scaling_matrix(
+                        factor_x, factor_y, py,
+                    )?,
+                )))
+            }
+
+            fn to_wkt(&self) -> pyo3::PyResult<String> {
+                Ok(crate::wkt::ToWkt::to_wkt(&self.0)?)
+            }
+
+            #[pyo3(signature = (matrix, /))]
+            fn transform(
+                &self,
+                matrix: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyPoint> {
+                Ok(PyPoint(crate::transformation::Transform::transform(
+                    &self.0,
+                    &super::transformation::try_to_affine_matrix(matrix, py)?,
+                )))
+            }
+
+            #[pyo3(signature = (step_x, step_y, /))]
+            fn translate(
+                &self,
+                step_x: &pyo3::Bound<'_, pyo3::PyAny>,
+                step_y: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<PyPoint> {
+                Ok(PyPoint(crate::transformation::Transform::transform(
+                    &self.0,
+                    &super::transformation::try_to_translation_matrix(
+                        step_x, step_y, py,
+                    )?,
+                )))
+            }
+
+            fn _repr_svg_(&self) -> Option<String> {
+                crate::svg::ToSvg::to_svg(
+                    &self.0,
+                    &crate::svg::Style::default(),
+                )
+                .ok()
+            }
+
             fn __hash__(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<isize> {
                 pyo3::types::PyAnyMethods::hash(
                     pyo3::types::PyTuple::new(py, [self.x(py)?, self.y(py)?])?
@@ -0,0 +1,139 @@
+macro_rules! impl_quadratic_surd_wrapper {
+    () => {
+        #[pyo3::pymethods]
+        impl PyQuadraticSurd {
+            #[new]
+            #[pyo3(signature = (rational, irrational, radicand, /))]
+            fn new(
+                rational: &pyo3::Bound<'_, pyo3::PyAny>,
+                irrational: &pyo3::Bound<'_, pyo3::PyAny>,
+                radicand: &pyo3::Bound<'_, pyo3::PyAny>,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<Self> {
+                let radicand = Fraction::try_from_py_any(radicand, py)?;
+                match QuadraticSurd::new(
+                    TryFromPyAny::try_from_py_any(rational, py)?,
+                    TryFromPyAny::try_from_py_any(irrational, py)?,
+                    radicand.clone(),
+                ) {
+                    Some(value) => Ok(Self(value)),
+                    None => {
+                        Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "Radicand should be non-negative, but found {}.",
+                            radicand
+                        )))
+                    }
+                }
+            }
+
+            #[getter]
+            fn irrational<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                    self.0.get_irrational(),
+                    py,
+                )
+            }
+
+            #[getter]
+            fn radicand<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                    self.0.get_radicand(),
+                    py,
+                )
+            }
+
+            #[getter]
+            fn rational<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                crate::python_binding::traits::TryToPyAny::try_to_py_any(
+                    self.0.get_rational(),
+                    py,
+                )
+            }
+
+            fn __float__(&self) -> pyo3::PyResult<f64> {
+                Ok(self.0.get_rational().try_to_py_float()?
+                    + self.0.get_irrational().try_to_py_float()?
+                        * self.0.get_radicand().try_to_py_float()?.sqrt())
+            }
+
+            fn __repr__(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<String> {
+                use pyo3::types::{PyAnyMethods, PyTypeMethods};
+                Ok(format!(
+                    "{}({}, {}, {})",
+                    <Self as pyo3::type_object::PyTypeInfo>::type_object(py)
+                        .name()?,
+                    self.rational(py)?.repr()?.extract::<String>()?,
+                    self.irrational(py)?.repr()?.extract::<String>()?,
+                    self.radicand(py)?.repr()?.extract::<String>()?,
+                ))
+            }
+
+            fn __richcmp__(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                op: pyo3::basic::CompareOp,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+                use pyo3::types::PyAnyMethods;
+                let py = other.py();
+                if other.is_instance(
+                    &<Self as pyo3::type_object::PyTypeInfo>::type_object(py),
+                )? {
+                    let other =
+                        other.extract::<pyo3::Bound<'_, Self>>()?.borrow();
+                    Ok(pyo3::BoundObject::into_bound(
+                        pyo3::IntoPyObject::into_pyobject(
+                            match op {
+                                pyo3::basic::CompareOp::Eq => {
+                                    self.0 == other.0
+                                }
+                                pyo3::basic::CompareOp::Ge => {
+                                    self.0 >= other.0
+                                }
+                                pyo3::basic::CompareOp::Gt => self.0 > other.0,
+                                pyo3::basic::CompareOp::Le => {
+                                    self.0 <= other.0
+                                }
+                                pyo3::basic::CompareOp::Lt => self.0 < other.0,
+                                pyo3::basic::CompareOp::Ne => {
+                                    self.0 != other.0
+                                }
+                            },
+                            py,
+                        )
+                        .unwrap(),
+                    )
+                    .into_any()
+                    .unbind())
+                } else {
+                    Ok(py.NotImplemented())
+                }
+            }
+
+            fn __str__(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                use pyo3::types::{PyAnyMethods, PyTypeMethods};
+                Ok(format!(
+                    "{}({}, {}, {})",
+                    <Self as pyo3::type_object::PyTypeInfo>::type_object(py)
+                        .name()?,
+                    self.rational(py)?.str()?.extract::<String>()?,
+                    self.irrational(py)?.str()?.extract::<String>()?,
+                    self.radicand(py)?.str()?.extract::<String>()?,
+                ))
+            }
+        }
+    };
+}
+
+pub(super) use impl_quadratic_surd_wrapper;
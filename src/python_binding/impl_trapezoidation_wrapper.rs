@@ -9,9 +9,9 @@ macro_rules! impl_trapezoidation_wrapper {
                 multisegment: &PyMultisegment,
                 seed: usize,
             ) -> Self {
-                PyTrapezoidation(Trapezoidation::from_multisegment(
+                PyTrapezoidation(Trapezoidation::from_multisegment_with_seed(
                     &multisegment.0,
-                    |values| crate::operations::permute(values, seed),
+                    seed,
                 ))
             }
 
@@ -22,11 +22,8 @@ macro_rules! impl_trapezoidation_wrapper {
                 polygon: &PyPolygon,
                 seed: usize,
             ) -> Self {
-                PyTrapezoidation(Trapezoidation::from_polygon(
-                    &polygon.0,
-                    |values| {
-                        crate::operations::permute(values, seed);
-                    },
+                PyTrapezoidation(Trapezoidation::from_polygon_with_seed(
+                    &polygon.0, seed,
                 ))
             }
 
@@ -50,6 +47,14 @@ macro_rules! impl_trapezoidation_wrapper {
                 )
             }
 
+            fn _repr_svg_(&self) -> Option<String> {
+                crate::svg::ToSvg::to_svg(
+                    &self.0,
+                    &crate::svg::Style::default(),
+                )
+                .ok()
+            }
+
             fn __contains__(&self, point: &pyo3::Bound<'_, PyPoint>) -> bool {
                 crate::locatable::Locatable::locate(&self.0, &point.borrow().0)
                     != crate::locatable::Location::Exterior
@@ -0,0 +1,319 @@
+macro_rules! impl_violation_wrapper {
+    () => {
+        #[pyo3::pymethods]
+        impl PyViolation {
+            #[getter]
+            fn count(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::TooFewPolygons { count, .. }
+                    | Violation::TooFewSegments { count, .. }
+                    | Violation::TooFewVertices { count, .. } => Some(count),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn end(&self) -> Option<Point> {
+                match &self.0 {
+                    Violation::SegmentsOverlap { end, .. } => {
+                        Some(end.clone())
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn first_hole_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::HolesOverlap {
+                        first_hole_index, ..
+                    } => Some(first_hole_index),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn first_polygon_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::PolygonsOverlap {
+                        first_polygon_index,
+                        ..
+                    } => Some(first_polygon_index),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn first_segment_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::SegmentsCross {
+                        first_segment_index,
+                        ..
+                    }
+                    | Violation::SegmentsOverlap {
+                        first_segment_index,
+                        ..
+                    }
+                    | Violation::SegmentsTouch {
+                        first_segment_index,
+                        ..
+                    } => Some(first_segment_index),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn hole_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::HoleOutsideBorder { hole_index, .. }
+                    | Violation::HoleTouchesBorder { hole_index, .. }
+                    | Violation::InvalidHole { hole_index, .. }
+                    | Violation::WrongHoleOrientation { hole_index, .. } => {
+                        Some(hole_index)
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn kind<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                self.0.kind().try_to_py_any(py)
+            }
+
+            #[getter]
+            fn point(&self) -> Option<Point> {
+                match &self.0 {
+                    Violation::SegmentsCross { point, .. }
+                    | Violation::SegmentsTouch { point, .. } => {
+                        Some(point.clone())
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn polygon_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::InvalidPolygon { polygon_index, .. } => {
+                        Some(polygon_index)
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn second_hole_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::HolesOverlap {
+                        second_hole_index, ..
+                    } => Some(second_hole_index),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn second_polygon_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::PolygonsOverlap {
+                        second_polygon_index,
+                        ..
+                    } => Some(second_polygon_index),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn second_segment_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::SegmentsCross {
+                        second_segment_index,
+                        ..
+                    }
+                    | Violation::SegmentsOverlap {
+                        second_segment_index,
+                        ..
+                    }
+                    | Violation::SegmentsTouch {
+                        second_segment_index,
+                        ..
+                    } => Some(second_segment_index),
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn segment_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::DegenerateSegment { segment_index, .. } => {
+                        Some(segment_index)
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn start(&self) -> Option<Point> {
+                match &self.0 {
+                    Violation::SegmentsOverlap { start, .. } => {
+                        Some(start.clone())
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn vertex_index(&self) -> Option<usize> {
+                match self.0 {
+                    Violation::CollinearVertices { vertex_index, .. } => {
+                        Some(vertex_index)
+                    }
+                    _ => None,
+                }
+            }
+
+            #[getter]
+            fn violation(&self) -> Option<Self> {
+                match &self.0 {
+                    Violation::InvalidBorder { violation }
+                    | Violation::InvalidHole { violation, .. }
+                    | Violation::InvalidPolygon { violation, .. } => {
+                        Some(Self((**violation).clone()))
+                    }
+                    _ => None,
+                }
+            }
+
+            fn __repr__(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<String> {
+                use pyo3::types::{PyAnyMethods, PyTypeMethods};
+                let to_point_repr = |point: Option<Point>| {
+                    point.map(|point| PyPoint(point).__repr__(py)).transpose()
+                };
+                let fields = [
+                    ("count", self.count().map(|value| value.to_string())),
+                    ("end", to_point_repr(self.end())?),
+                    (
+                        "first_hole_index",
+                        self.first_hole_index().map(|value| value.to_string()),
+                    ),
+                    (
+                        "first_polygon_index",
+                        self.first_polygon_index()
+                            .map(|value| value.to_string()),
+                    ),
+                    (
+                        "first_segment_index",
+                        self.first_segment_index()
+                            .map(|value| value.to_string()),
+                    ),
+                    (
+                        "hole_index",
+                        self.hole_index().map(|value| value.to_string()),
+                    ),
+                    ("point", to_point_repr(self.point())?),
+                    (
+                        "polygon_index",
+                        self.polygon_index().map(|value| value.to_string()),
+                    ),
+                    (
+                        "second_hole_index",
+                        self.second_hole_index()
+                            .map(|value| value.to_string()),
+                    ),
+                    (
+                        "second_polygon_index",
+                        self.second_polygon_index()
+                            .map(|value| value.to_string()),
+                    ),
+                    (
+                        "second_segment_index",
+                        self.second_segment_index()
+                            .map(|value| value.to_string()),
+                    ),
+                    (
+                        "segment_index",
+                        self.segment_index().map(|value| value.to_string()),
+                    ),
+                    ("start", to_point_repr(self.start())?),
+                    (
+                        "vertex_index",
+                        self.vertex_index().map(|value| value.to_string()),
+                    ),
+                    (
+                        "violation",
+                        self.violation()
+                            .map(|violation| violation.__repr__(py))
+                            .transpose()?,
+                    ),
+                ];
+                Ok(format!(
+                    "{}({}{})",
+                    <Self as pyo3::type_object::PyTypeInfo>::type_object(py)
+                        .name()?,
+                    self.kind(py)?.repr()?.extract::<String>()?,
+                    fields
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            value
+                                .as_ref()
+                                .map(|value| format!(", {}={}", name, value))
+                        })
+                        .collect::<String>()
+                ))
+            }
+
+            fn __richcmp__(
+                &self,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+                op: pyo3::basic::CompareOp,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+                use pyo3::types::PyAnyMethods;
+                let py = other.py();
+                if other.is_instance(
+                    &<Self as pyo3::type_object::PyTypeInfo>::type_object(py),
+                )? {
+                    let other =
+                        other.extract::<pyo3::Bound<'_, Self>>()?.borrow();
+                    match op {
+                        pyo3::basic::CompareOp::Eq => {
+                            Ok(pyo3::BoundObject::into_bound(
+                                pyo3::IntoPyObject::into_pyobject(
+                                    self.0 == other.0,
+                                    py,
+                                )
+                                .unwrap(),
+                            )
+                            .into_any()
+                            .unbind())
+                        }
+                        pyo3::basic::CompareOp::Ne => {
+                            Ok(pyo3::BoundObject::into_bound(
+                                pyo3::IntoPyObject::into_pyobject(
+                                    self.0 != other.0,
+                                    py,
+                                )
+                                .unwrap(),
+                            )
+                            .into_any()
+                            .unbind())
+                        }
+                        _ => Ok(py.NotImplemented()),
+                    }
+                } else {
+                    Ok(py.NotImplemented())
+                }
+            }
+
+            fn __str__(&self) -> String {
+                self.0.to_string()
+            }
+        }
+    };
+}
+
+pub(super) use impl_violation_wrapper;
@@ -0,0 +1,23 @@
+macro_rules! impl_voronoi_diagram_wrapper {
+    () => {
+        #[pyo3::pymethods]
+        impl PyVoronoiDiagram {
+            #[getter]
+            fn cells(&self) -> Vec<Polygon> {
+                self.0.get_cells().to_vec()
+            }
+
+            #[getter]
+            fn sites(&self) -> Vec<Point> {
+                self.0.get_sites().to_vec()
+            }
+
+            #[getter]
+            fn vertices(&self) -> Vec<Point> {
+                self.0.get_vertices().to_vec()
+            }
+        }
+    };
+}
+
+pub(super) use impl_voronoi_diagram_wrapper;
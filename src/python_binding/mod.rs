@@ -1,21 +1,29 @@
 mod cexact;
 mod conversion;
 mod crene;
+mod dispatch_geometry;
 mod generic_iterator;
+mod geo_interface;
 mod impl_box_wrapper;
 mod impl_constrained_delaunay_triangulation_wrapper;
 mod impl_contour_wrapper;
 mod impl_delaunay_triangulation_wrapper;
 mod impl_empty_wrapper;
+mod impl_indexed_mesh_wrapper;
 mod impl_multipolygon_wrapper;
 mod impl_multisegment_wrapper;
 mod impl_point_wrapper;
 mod impl_polygon_wrapper;
 mod impl_py_sequence;
+mod impl_quadratic_surd_wrapper;
 mod impl_segment_wrapper;
 mod impl_trapezoidation_wrapper;
+mod impl_violation_wrapper;
+mod impl_voronoi_diagram_wrapper;
 mod packing;
 mod reference;
 mod slicing;
 mod traits;
+mod transformation;
 mod unpacking;
+mod validation;
@@ -14,3 +14,7 @@ pub(super) trait TryToPyAny {
         py: pyo3::Python<'_>,
     ) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::PyAny>>;
 }
+
+pub(super) trait TryToPyFloat {
+    fn try_to_py_float(self) -> pyo3::PyResult<f64>;
+}
@@ -0,0 +1,84 @@
+use pyo3::types::{PyAnyMethods, PyTupleMethods};
+use traiter::numbers::{One, Zero};
+
+use crate::transformation::AffineMatrix;
+
+use super::traits::TryFromPyAny;
+
+const AFFINE_MATRIX_ROW_SIZE: usize = 3;
+const AFFINE_MATRIX_ROWS_COUNT: usize = 2;
+
+pub(super) fn try_to_affine_matrix<Scalar: TryFromPyAny>(
+    value: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<AffineMatrix<Scalar>> {
+    let rows = value
+        .cast::<pyo3::types::PyTuple>()
+        .ok()
+        .filter(|rows| rows.len() == AFFINE_MATRIX_ROWS_COUNT)
+        .and_then(|rows| {
+            rows.iter()
+                .map(|row| {
+                    row.cast_into::<pyo3::types::PyTuple>()
+                        .ok()
+                        .filter(|row| row.len() == AFFINE_MATRIX_ROW_SIZE)
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| -> pyo3::PyErr {
+            match value.repr() {
+                Ok(repr) => pyo3::exceptions::PyTypeError::new_err(format!(
+                    "Expected affine matrix, but got {}.",
+                    repr
+                )),
+                Err(error) => error,
+            }
+        })?;
+    let to_coefficient = |row: usize, column: usize| {
+        Scalar::try_from_py_any(&rows[row].get_item(column)?, py)
+    };
+    Ok(AffineMatrix::new(
+        to_coefficient(0, 0)?,
+        to_coefficient(0, 1)?,
+        to_coefficient(0, 2)?,
+        to_coefficient(1, 0)?,
+        to_coefficient(1, 1)?,
+        to_coefficient(1, 2)?,
+    ))
+}
+
+pub(super) fn try_to_non_singular_image<Image>(
+    image: Option<Image>,
+) -> pyo3::PyResult<Image> {
+    image.ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(
+            "Affine matrix should be non-singular for areal geometries.",
+        )
+    })
+}
+
+pub(super) fn try_to_scaling_matrix<Scalar: TryFromPyAny + Zero>(
+    factor_x: &pyo3::Bound<'_, pyo3::PyAny>,
+    factor_y: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<AffineMatrix<Scalar>> {
+    Ok(AffineMatrix::new(
+        Scalar::try_from_py_any(factor_x, py)?,
+        Scalar::zero(),
+        Scalar::zero(),
+        Scalar::zero(),
+        Scalar::try_from_py_any(factor_y, py)?,
+        Scalar::zero(),
+    ))
+}
+
+pub(super) fn try_to_translation_matrix<Scalar: One + TryFromPyAny + Zero>(
+    step_x: &pyo3::Bound<'_, pyo3::PyAny>,
+    step_y: &pyo3::Bound<'_, pyo3::PyAny>,
+    py: pyo3::Python<'_>,
+) -> pyo3::PyResult<AffineMatrix<Scalar>> {
+    Ok(AffineMatrix::translation(
+        Scalar::try_from_py_any(step_x, py)?,
+        Scalar::try_from_py_any(step_y, py)?,
+    ))
+}
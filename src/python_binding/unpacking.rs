@@ -107,3 +107,55 @@ pub(super) fn try_unpack_non_empty_segments<
     }
     .map(pyo3::Bound::unbind)
 }
+
+pub(super) fn try_unpack_point_location<
+    'py,
+    Contour: From<Vec<Point>> + pyo3::IntoPyObject<'py, Error = Error>,
+    Point: Clone + pyo3::IntoPyObject<'py, Error = Error>,
+    Segment: From<(Point, Point)> + pyo3::IntoPyObject<'py, Error = Error>,
+    Error: From<pyo3::PyErr>,
+>(
+    location: crate::triangulation::PointLocation,
+    endpoints: &[Point],
+    py: pyo3::Python<'py>,
+) -> Result<(pyo3::Py<pyo3::types::PyTuple>, pyo3::Py<pyo3::PyAny>), Error> {
+    use crate::triangulation::PointLocation;
+    let (indices, geometry) = match location {
+        PointLocation::Edge(start_index, end_index) => (
+            vec![start_index, end_index],
+            pyo3::IntoPyObject::into_pyobject(
+                Segment::from((
+                    endpoints[start_index].clone(),
+                    endpoints[end_index].clone(),
+                )),
+                py,
+            )
+            .map(pyo3::BoundObject::into_bound)
+            .map(pyo3::Bound::into_any)
+            .map(pyo3::Bound::unbind)?,
+        ),
+        PointLocation::Exterior => (vec![], py.None()),
+        PointLocation::Triangle(first_index, second_index, third_index) => (
+            vec![first_index, second_index, third_index],
+            pyo3::IntoPyObject::into_pyobject(
+                Contour::from(vec![
+                    endpoints[first_index].clone(),
+                    endpoints[second_index].clone(),
+                    endpoints[third_index].clone(),
+                ]),
+                py,
+            )
+            .map(pyo3::BoundObject::into_bound)
+            .map(pyo3::Bound::into_any)
+            .map(pyo3::Bound::unbind)?,
+        ),
+        PointLocation::Vertex(index) => (
+            vec![index],
+            pyo3::IntoPyObject::into_pyobject(endpoints[index].clone(), py)
+                .map(pyo3::BoundObject::into_bound)
+                .map(pyo3::Bound::into_any)
+                .map(pyo3::Bound::unbind)?,
+        ),
+    };
+    Ok((pyo3::types::PyTuple::new(py, indices)?.unbind(), geometry))
+}
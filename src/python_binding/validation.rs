@@ -0,0 +1,16 @@
+use crate::validation::Validatable;
+
+pub(super) fn try_ensure_valid<Geometry: Validatable>(
+    geometry: Geometry,
+    geometry_name: &str,
+) -> pyo3::PyResult<()>
+where
+    Geometry::Point: std::fmt::Display,
+{
+    match geometry.validate().into_iter().next() {
+        Some(violation) => Err(pyo3::exceptions::PyValueError::new_err(
+            format!("Invalid {}: {}.", geometry_name, violation),
+        )),
+        None => Ok(()),
+    }
+}
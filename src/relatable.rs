@@ -1,3 +1,137 @@
+use std::fmt;
+
+use crate::locatable::Location;
+
+/// Dimension of an intersection between parts of two geometries.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dimension {
+    /// intersection is empty
+    Empty,
+    /// intersection consists of isolated points
+    Zero,
+    /// intersection has curves, but no regions
+    One,
+    /// intersection has regions
+    Two,
+}
+
+/// Dimensionally extended nine-intersection model (DE-9IM) matrix
+/// of two geometries.
+///
+/// Rows correspond to the interior, boundary & exterior
+/// of the first geometry, columns -- to the ones of the second geometry.
+/// Boundary of a linear geometry consists of its segments' endpoints
+/// which are shared by an odd number of segments ("mod-2" rule),
+/// so contours have empty boundary.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IntersectionMatrix([Dimension; 9]);
+
+impl IntersectionMatrix {
+    pub(crate) fn new() -> Self {
+        let mut result = Self([Dimension::Empty; 9]);
+        result.include(Location::Exterior, Location::Exterior, Dimension::Two);
+        result
+    }
+
+    /// Returns dimension of the intersection
+    /// between given parts of the first & the second geometries.
+    pub fn get(&self, first: Location, second: Location) -> Dimension {
+        self.0[to_entry_index(first, second)]
+    }
+
+    /// Checks if the matrix satisfies the pattern,
+    /// e.g. `"T*F**F***"` for the first geometry lying within the second.
+    ///
+    /// Pattern consists of 9 characters in row-major order:
+    /// `'T'` matches non-empty intersection, `'F'` -- empty one,
+    /// `'*'` -- any, `'0'`, `'1'` & `'2'` -- intersection of that dimension.
+    pub fn matches(&self, pattern: &str) -> Result<bool, PatternError> {
+        let characters_count = pattern.chars().count();
+        if characters_count != self.0.len() {
+            return Err(PatternError::InvalidLength(characters_count));
+        }
+        let mut result = true;
+        for (position, (character, &dimension)) in
+            pattern.chars().zip(self.0.iter()).enumerate()
+        {
+            let entry_matches = match character {
+                '*' => true,
+                'T' => dimension != Dimension::Empty,
+                'F' => dimension == Dimension::Empty,
+                '0' => dimension == Dimension::Zero,
+                '1' => dimension == Dimension::One,
+                '2' => dimension == Dimension::Two,
+                _ => return Err(PatternError::InvalidCharacter(position)),
+            };
+            result = result && entry_matches;
+        }
+        Ok(result)
+    }
+
+    /// Returns matrix of the geometries taken in the reverse order.
+    pub fn to_transposed(self) -> Self {
+        let mut result = self;
+        for (index, dimension) in result.0.iter_mut().enumerate() {
+            *dimension = self.0[(index % 3) * 3 + index / 3];
+        }
+        result
+    }
+
+    pub(crate) fn include(
+        &mut self,
+        first: Location,
+        second: Location,
+        dimension: Dimension,
+    ) {
+        let entry = &mut self.0[to_entry_index(first, second)];
+        if *entry < dimension {
+            *entry = dimension;
+        }
+    }
+}
+
+impl fmt::Display for IntersectionMatrix {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for dimension in self.0.iter() {
+            formatter.write_str(match dimension {
+                Dimension::Empty => "F",
+                Dimension::Zero => "0",
+                Dimension::One => "1",
+                Dimension::Two => "2",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Error of matching an intersection matrix against a malformed pattern.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PatternError {
+    /// Pattern has the given number of characters instead of 9.
+    InvalidLength(usize),
+    /// Pattern has unsupported character at the given position.
+    InvalidCharacter(usize),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(length) => formatter.write_fmt(format_args!(
+                "Pattern should have 9 characters, but got {}.",
+                length
+            )),
+            Self::InvalidCharacter(position) => {
+                formatter.write_fmt(format_args!(
+                    "Invalid pattern character at position {}.",
+                    position
+                ))
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Relation {
     /// geometry is a strict subset of the other
@@ -134,3 +268,19 @@ pub trait Relatable<Other = Self> {
 
     fn relate_to(self, other: Other) -> Relation;
 }
+
+pub trait MatrixRelatable<Other = Self> {
+    fn relate_matrix(self, other: Other) -> IntersectionMatrix;
+}
+
+fn to_entry_index(first: Location, second: Location) -> usize {
+    to_location_index(first) * 3 + to_location_index(second)
+}
+
+fn to_location_index(location: Location) -> usize {
+    match location {
+        Location::Interior => 0,
+        Location::Boundary => 1,
+        Location::Exterior => 2,
+    }
+}
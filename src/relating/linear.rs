@@ -6,12 +6,13 @@ use std::ops::{Div, Neg};
 
 use traiter::numbers::Signed;
 
+use crate::locatable::Location;
+use crate::metric::SquaredMetric;
 use crate::operations::{
     to_sorted_pair, DotMultiply, IntersectCrossingSegments, Orient, Square,
-    SquaredMetric,
 };
 use crate::oriented::Orientation;
-use crate::relatable::Relation;
+use crate::relatable::{Dimension, IntersectionMatrix, Relation};
 use crate::sweeping::traits::{EventsContainer, EventsQueue, SweepLine};
 use crate::traits::{Elemental, Segmental};
 
@@ -21,7 +22,7 @@ use super::event::{
 };
 use super::events_queue_key::EventsQueueKey;
 use super::sweep_line_key::SweepLineKey;
-use super::utils::all_equal;
+use super::utils::{all_equal, to_linear_location};
 
 pub(crate) struct Operation<Point> {
     first_segments_count: usize,
@@ -258,6 +259,27 @@ where
         Elemental + IntersectCrossingSegments<Output = Point> + Orient,
     for<'a> <&'a Point as Elemental>::Coordinate: PartialEq,
 {
+    pub(super) fn into_matrix(mut self) -> IntersectionMatrix {
+        let mut result = IntersectionMatrix::new();
+        let mut first_same_start_event =
+            unsafe { self.pop().unwrap_unchecked() };
+        let mut same_start_events = vec![first_same_start_event];
+        self.process_event(first_same_start_event);
+        while let Some(event) = self.pop() {
+            if self.get_event_start(event)
+                != self.get_event_start(first_same_start_event)
+            {
+                self.update_matrix(&same_start_events, &mut result);
+                same_start_events.clear();
+                first_same_start_event = event;
+            }
+            same_start_events.push(event);
+            self.process_event(event);
+        }
+        self.update_matrix(&same_start_events, &mut result);
+        result
+    }
+
     fn process_event(&mut self, event: Event) {
         if is_event_right(event) {
             let opposite_event = self.to_opposite_event(event);
@@ -379,6 +401,58 @@ impl<Point> Operation<Point> {
         }))
     }
 
+    fn update_matrix(
+        &self,
+        same_start_events: &[Event],
+        matrix: &mut IntersectionMatrix,
+    ) where
+        Point: PartialEq,
+    {
+        debug_assert!(!same_start_events.is_empty());
+        let from_first_operand_events_count = same_start_events
+            .iter()
+            .filter(|&&event| self.is_event_from_first_operand(event))
+            .count();
+        matrix.include(
+            to_linear_location(from_first_operand_events_count),
+            to_linear_location(
+                same_start_events.len() - from_first_operand_events_count,
+            ),
+            Dimension::Zero,
+        );
+        let mut left_events = same_start_events
+            .iter()
+            .copied()
+            .filter(|&event| is_event_left(event))
+            .peekable();
+        while let Some(event) = left_events.next() {
+            if left_events
+                .next_if(|&next_event| {
+                    self.get_event_end(next_event) == self.get_event_end(event)
+                })
+                .is_some()
+            {
+                matrix.include(
+                    Location::Interior,
+                    Location::Interior,
+                    Dimension::One,
+                );
+            } else if self.is_event_from_first_operand(event) {
+                matrix.include(
+                    Location::Interior,
+                    Location::Exterior,
+                    Dimension::One,
+                );
+            } else {
+                matrix.include(
+                    Location::Exterior,
+                    Location::Interior,
+                    Dimension::One,
+                );
+            }
+        }
+    }
+
     fn has_intersection(&self, same_start_events: &[Event]) -> bool {
         debug_assert!(!same_start_events.is_empty());
         !all_equal(
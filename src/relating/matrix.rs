@@ -0,0 +1,118 @@
+use crate::locatable::Location;
+use crate::operations::{IntersectCrossingSegments, Orient};
+use crate::relatable::{Dimension, IntersectionMatrix};
+use crate::sweeping::traits::{EventsQueue, SweepLine};
+use crate::traits::{Elemental, Segmental};
+
+use super::event::Event;
+use super::{linear, mixed, shaped};
+
+pub(crate) fn relate_segments<Point, Segment>(
+    first_dimension: Dimension,
+    first_segments: &[&Segment],
+    second_dimension: Dimension,
+    second_segments: &[&Segment],
+) -> IntersectionMatrix
+where
+    Point: Clone + Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+    linear::Operation<Point>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    mixed::Operation<false, Point>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    mixed::Operation<true, Point>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    shaped::Operation<Point>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    for<'a> &'a Point:
+        Elemental + IntersectCrossingSegments<Output = Point> + Orient,
+    for<'a> <&'a Point as Elemental>::Coordinate: PartialEq,
+{
+    debug_assert!(!first_segments.is_empty());
+    debug_assert!(!second_segments.is_empty());
+    let first = (
+        first_segments.len(),
+        first_segments.iter().copied().cloned(),
+    );
+    let second = (
+        second_segments.len(),
+        second_segments.iter().copied().cloned(),
+    );
+    match (first_dimension, second_dimension) {
+        (Dimension::One, Dimension::One) => {
+            linear::Operation::from((first_segments, second_segments))
+                .into_matrix()
+        }
+        (Dimension::One, Dimension::Two) => {
+            mixed::Operation::<true, Point>::from_segments_iterators(
+                first, second,
+            )
+            .into_matrix()
+        }
+        (Dimension::Two, Dimension::One) => {
+            mixed::Operation::<false, Point>::from_segments_iterators(
+                first, second,
+            )
+            .into_matrix()
+        }
+        (Dimension::Two, Dimension::Two) => {
+            shaped::Operation::from_segments_iterators(first, second)
+                .into_matrix()
+        }
+        _ => unreachable!("Only linear & shaped geometries have segments."),
+    }
+}
+
+pub(crate) fn relate_to_empty<Point: Ord, Segment>(
+    dimension: Dimension,
+    segments: &[&Segment],
+) -> IntersectionMatrix
+where
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let mut result = IntersectionMatrix::new();
+    result.include(Location::Interior, Location::Exterior, dimension);
+    result.include(
+        Location::Boundary,
+        Location::Exterior,
+        match dimension {
+            Dimension::One => {
+                if has_odd_endpoint(segments) {
+                    Dimension::Zero
+                } else {
+                    Dimension::Empty
+                }
+            }
+            Dimension::Two => Dimension::One,
+            _ => Dimension::Empty,
+        },
+    );
+    result
+}
+
+fn has_odd_endpoint<Point: Ord, Segment>(segments: &[&Segment]) -> bool
+where
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let mut endpoints = segments
+        .iter()
+        .flat_map(|segment| {
+            let (start, end) = segment.endpoints();
+            [start, end]
+        })
+        .collect::<Vec<_>>();
+    endpoints.sort_unstable();
+    let mut start_index = 0;
+    while start_index < endpoints.len() {
+        let end_index = start_index
+            + endpoints[start_index..]
+                .iter()
+                .take_while(|&&endpoint| endpoint == endpoints[start_index])
+                .count();
+        if (end_index - start_index) % 2 == 1 {
+            return true;
+        }
+        start_index = end_index;
+    }
+    false
+}
@@ -2,9 +2,10 @@ use std::cmp::Reverse;
 use std::collections::{BTreeSet, BinaryHeap};
 use std::ops::Bound::{Excluded, Unbounded};
 
+use crate::locatable::Location;
 use crate::operations::{IntersectCrossingSegments, Orient};
 use crate::oriented::Orientation;
-use crate::relatable::Relation;
+use crate::relatable::{Dimension, IntersectionMatrix, Relation};
 use crate::sweeping::traits::{EventsContainer, EventsQueue, SweepLine};
 use crate::traits::{Elemental, Segmental};
 
@@ -15,7 +16,7 @@ use super::event::{
 };
 use super::events_queue_key::EventsQueueKey;
 use super::sweep_line_key::SweepLineKey;
-use super::utils::all_equal;
+use super::utils::{all_equal, to_linear_location};
 
 pub(crate) struct Operation<const FIRST_IS_LINEAR: bool, Point> {
     first_segments_count: usize,
@@ -159,6 +160,39 @@ impl<Point, const FIRST_IS_LINEAR: bool> EventsContainer
 }
 
 impl<Point, const FIRST_IS_LINEAR: bool> Operation<FIRST_IS_LINEAR, Point> {
+    pub(super) fn into_matrix(mut self) -> IntersectionMatrix
+    where
+        Self: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+        Point: Clone + PartialOrd,
+        for<'a> &'a Point:
+            Elemental + IntersectCrossingSegments<Output = Point> + Orient,
+        for<'a> <&'a Point as Elemental>::Coordinate: PartialEq,
+    {
+        let mut result = IntersectionMatrix::new();
+        result.include(Location::Exterior, Location::Interior, Dimension::Two);
+        let mut first_same_start_event =
+            unsafe { self.pop().unwrap_unchecked() };
+        let mut same_start_events = vec![first_same_start_event];
+        self.process_event(first_same_start_event);
+        while let Some(event) = self.pop() {
+            if self.get_event_start(event)
+                != self.get_event_start(first_same_start_event)
+            {
+                self.update_matrix(&same_start_events, &mut result);
+                same_start_events.clear();
+                first_same_start_event = event;
+            }
+            same_start_events.push(event);
+            self.process_event(event);
+        }
+        self.update_matrix(&same_start_events, &mut result);
+        if FIRST_IS_LINEAR {
+            result
+        } else {
+            result.to_transposed()
+        }
+    }
+
     pub(super) fn into_relation<Scalar: PartialOrd>(
         mut self,
         linear_is_subset_of_shaped: bool,
@@ -279,6 +313,68 @@ impl<Point, const FIRST_IS_LINEAR: bool> Operation<FIRST_IS_LINEAR, Point> {
         }
     }
 
+    fn update_matrix(
+        &self,
+        same_start_events: &[Event],
+        matrix: &mut IntersectionMatrix,
+    ) where
+        Point: PartialEq,
+    {
+        debug_assert!(!same_start_events.is_empty());
+        let linear_events_count = same_start_events
+            .iter()
+            .filter(|&&event| self.is_event_from_linear(event))
+            .count();
+        let shaped_location = if linear_events_count < same_start_events.len()
+        {
+            Location::Boundary
+        } else if self.is_event_inside(same_start_events[0]) {
+            Location::Interior
+        } else {
+            Location::Exterior
+        };
+        matrix.include(
+            to_linear_location(linear_events_count),
+            shaped_location,
+            Dimension::Zero,
+        );
+        let mut left_events = same_start_events
+            .iter()
+            .copied()
+            .filter(|&event| is_event_left(event))
+            .peekable();
+        while let Some(event) = left_events.next() {
+            if left_events
+                .next_if(|&next_event| {
+                    self.get_event_end(next_event) == self.get_event_end(event)
+                })
+                .is_some()
+            {
+                matrix.include(
+                    Location::Interior,
+                    Location::Boundary,
+                    Dimension::One,
+                );
+            } else if self.is_event_from_linear(event) {
+                matrix.include(
+                    Location::Interior,
+                    if self.is_event_inside(event) {
+                        Location::Interior
+                    } else {
+                        Location::Exterior
+                    },
+                    Dimension::One,
+                );
+            } else {
+                matrix.include(
+                    Location::Exterior,
+                    Location::Boundary,
+                    Dimension::One,
+                );
+            }
+        }
+    }
+
     fn get_endpoints(&self) -> &Vec<Point> {
         &self.endpoints
     }
@@ -4,6 +4,7 @@ pub(crate) mod contour;
 mod event;
 mod events_queue_key;
 pub(crate) mod linear;
+pub(crate) mod matrix;
 pub(crate) mod mixed;
 pub(crate) mod multipolygon;
 pub(crate) mod multisegment;
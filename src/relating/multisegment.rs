@@ -5,9 +5,9 @@ use traiter::numbers::Signed;
 
 use crate::bounded;
 use crate::bounded::Bounded;
+use crate::metric::SquaredMetric;
 use crate::operations::{
     CrossMultiply, DotMultiply, IntersectCrossingSegments, Orient, Square,
-    SquaredMetric,
 };
 use crate::relatable::{Relatable, Relation};
 use crate::sweeping::traits::{EventsQueue, SweepLine};
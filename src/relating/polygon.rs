@@ -5,9 +5,10 @@ use traiter::numbers::Signed;
 
 use crate::bounded;
 use crate::bounded::Bounded;
+use crate::metric::SquaredMetric;
 use crate::operations::{
     to_boxes_ids_with_intersection, CrossMultiply, DotMultiply,
-    IntersectCrossingSegments, Orient, Square, SquaredMetric,
+    IntersectCrossingSegments, Orient, Square,
 };
 use crate::relatable::{Relatable, Relation};
 use crate::sweeping::traits::{EventsQueue, SweepLine};
@@ -2,9 +2,10 @@ use std::cmp::Reverse;
 use std::collections::{BTreeSet, BinaryHeap};
 use std::ops::Bound::{Excluded, Unbounded};
 
+use crate::locatable::Location;
 use crate::operations::{IntersectCrossingSegments, Orient};
 use crate::oriented::Orientation;
-use crate::relatable::Relation;
+use crate::relatable::{Dimension, IntersectionMatrix, Relation};
 use crate::relating::event::is_event_right;
 use crate::relating::utils::all_equal;
 use crate::sweeping::traits::{EventsContainer, EventsQueue, SweepLine};
@@ -89,6 +90,34 @@ where
 }
 
 impl<Point> Operation<Point> {
+    pub(super) fn into_matrix(mut self) -> IntersectionMatrix
+    where
+        Self: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+        Point: Clone + PartialOrd,
+        for<'a> &'a Point:
+            Elemental + IntersectCrossingSegments<Output = Point> + Orient,
+        for<'a> <&'a Point as Elemental>::Coordinate: PartialEq,
+    {
+        let mut result = IntersectionMatrix::new();
+        let mut first_same_start_event =
+            unsafe { self.pop().unwrap_unchecked() };
+        let mut same_start_events = vec![first_same_start_event];
+        self.process_event(first_same_start_event);
+        while let Some(event) = self.pop() {
+            if self.get_event_start(event)
+                != self.get_event_start(first_same_start_event)
+            {
+                self.update_matrix(&same_start_events, &mut result);
+                same_start_events.clear();
+                first_same_start_event = event;
+            }
+            same_start_events.push(event);
+            self.process_event(event);
+        }
+        self.update_matrix(&same_start_events, &mut result);
+        result
+    }
+
     pub(super) fn into_relation<Scalar: PartialOrd>(
         mut self,
         first_is_subset: bool,
@@ -206,6 +235,61 @@ impl<Point> Operation<Point> {
         }
     }
 
+    fn update_matrix(
+        &self,
+        same_start_events: &[Event],
+        matrix: &mut IntersectionMatrix,
+    ) {
+        debug_assert!(!same_start_events.is_empty());
+        if !all_equal(
+            same_start_events
+                .iter()
+                .map(|&event| self.is_event_from_first_operand(event)),
+        ) {
+            matrix.include(
+                Location::Boundary,
+                Location::Boundary,
+                Dimension::Zero,
+            );
+        }
+        for &event in same_start_events {
+            if !is_event_left(event) {
+                continue;
+            }
+            let is_from_first_operand =
+                self.is_event_from_first_operand(event);
+            let entries = match self.classify_event(event) {
+                EventKind::CommonPolylineSegment => [
+                    (Location::Boundary, Location::Boundary, Dimension::One),
+                    (Location::Interior, Location::Exterior, Dimension::Two),
+                    (Location::Exterior, Location::Interior, Dimension::Two),
+                ],
+                EventKind::CommonRegionEdge => [
+                    (Location::Boundary, Location::Boundary, Dimension::One),
+                    (Location::Interior, Location::Interior, Dimension::Two),
+                    (Location::Exterior, Location::Exterior, Dimension::Two),
+                ],
+                EventKind::Inside => [
+                    (Location::Boundary, Location::Interior, Dimension::One),
+                    (Location::Interior, Location::Interior, Dimension::Two),
+                    (Location::Exterior, Location::Interior, Dimension::Two),
+                ],
+                EventKind::Outside => [
+                    (Location::Boundary, Location::Exterior, Dimension::One),
+                    (Location::Interior, Location::Exterior, Dimension::Two),
+                    (Location::Exterior, Location::Exterior, Dimension::Two),
+                ],
+            };
+            for &(location, other_location, dimension) in entries.iter() {
+                if is_from_first_operand {
+                    matrix.include(location, other_location, dimension);
+                } else {
+                    matrix.include(other_location, location, dimension);
+                }
+            }
+        }
+    }
+
     fn get_endpoints(&self) -> &Vec<Point> {
         &self.endpoints
     }
@@ -1,3 +1,5 @@
+use crate::locatable::Location;
+
 pub(super) fn all_equal<I: Iterator>(mut iterator: I) -> bool
 where
     <I as Iterator>::Item: PartialEq,
@@ -9,3 +11,13 @@ where
         }
     }
 }
+
+pub(super) fn to_linear_location(events_count: usize) -> Location {
+    if events_count == 0 {
+        Location::Exterior
+    } else if events_count % 2 == 1 {
+        Location::Boundary
+    } else {
+        Location::Interior
+    }
+}
@@ -1,8 +1,10 @@
 use crate::operations::Orient;
 use crate::oriented::Orientation;
 
+/// Opaque edge of a trapezoidation,
+/// shuffled by constructors to randomize the insertion order.
 #[derive(Clone)]
-pub(crate) struct Edge {
+pub struct Edge {
     pub(super) left_point_index: usize,
     pub(super) right_point_index: usize,
     pub(super) interior_to_left: bool,
@@ -1,7 +1,70 @@
-pub(crate) use self::trapezoidation::Trapezoidation;
+//! Seidel's randomized trapezoidal decomposition for point location.
+//!
+//! A `Trapezoidation` is built once from a `rene::geometries::Multisegment`
+//! or `rene::geometries::Polygon` and then answers point location queries
+//! in expected logarithmic time,
+//! e.g. `Trapezoidation::from_polygon_with_seed(&polygon, seed)`.
+//!
+//! ```
+//! use rene::geometries::{Contour, Point, Polygon};
+//! use rene::locatable::Locatable;
+//! use rene::seidel::Trapezoidation;
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//! let to_scalar = |value: i32| Scalar::from(BigInt::from(value));
+//! let to_point = |x: i32, y: i32| Point::new(to_scalar(x), to_scalar(y));
+//! let polygon = Polygon::new(
+//!     Contour::new(vec![
+//!         to_point(0, 0),
+//!         to_point(6, 0),
+//!         to_point(6, 6),
+//!         to_point(0, 6),
+//!     ]),
+//!     vec![Contour::new(vec![
+//!         to_point(2, 2),
+//!         to_point(2, 4),
+//!         to_point(4, 4),
+//!         to_point(4, 2),
+//!     ])],
+//! );
+//!
+//! let trapezoidation = Trapezoidation::from_polygon_with_seed(&polygon, 42);
+//! let to_trapezoids = |trapezoidation: &Trapezoidation<Point<Scalar>>| {
+//!     trapezoidation
+//!         .iter_trapezoids()
+//!         .map(|trapezoid| {
+//!             (
+//!                 trapezoid.left_point().clone(),
+//!                 trapezoid.right_point().clone(),
+//!                 trapezoid.is_component(),
+//!             )
+//!         })
+//!         .collect::<Vec<_>>()
+//! };
+//! let same_seed_trapezoidation =
+//!     Trapezoidation::from_polygon_with_seed(&polygon, 42);
+//! assert_eq!(trapezoidation.height(), same_seed_trapezoidation.height());
+//! assert_eq!(
+//!     to_trapezoids(&trapezoidation),
+//!     to_trapezoids(&same_seed_trapezoidation)
+//! );
+//! for x in -1..=7 {
+//!     for y in -1..=7 {
+//!         let point = to_point(x, y);
+//!         assert_eq!(trapezoidation.locate(&point), polygon.locate(&point));
+//!     }
+//! }
+//! ```
+
+pub use self::edge::Edge;
+pub use self::trapezoid::TrapezoidView;
+pub use self::trapezoidation::Trapezoidation;
 
 mod edge;
 mod locatable;
 mod node;
+mod to_svg;
 mod trapezoid;
 mod trapezoidation;
@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+use std::iter::once;
+
+use crate::geometries::Point;
+use crate::svg::{
+    coordinates_to_bounding_box, to_coordinates, to_path_data, Canvas, Error,
+    Style, ToSvg,
+};
+
+use super::trapezoidation::Trapezoidation;
+
+impl<Scalar> ToSvg for &Trapezoidation<Point<Scalar>>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        let mut canvas = Canvas::new(
+            coordinates_to_bounding_box(
+                self.get_endpoints()
+                    .iter()
+                    .map(to_coordinates)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter(),
+            )
+            .as_ref(),
+            style,
+        );
+        for trapezoid in self.iter_trapezoids() {
+            let left_x = to_coordinates(trapezoid.left_point())?.0;
+            let right_x = to_coordinates(trapezoid.right_point())?.0;
+            if left_x == right_x {
+                continue;
+            }
+            let (below_start, below_end) = trapezoid.below_edge_endpoints();
+            let (above_start, above_end) = trapezoid.above_edge_endpoints();
+            let below =
+                (to_coordinates(below_start)?, to_coordinates(below_end)?);
+            let above =
+                (to_coordinates(above_start)?, to_coordinates(above_end)?);
+            canvas.add_path(
+                &to_path_data(once((
+                    vec![
+                        (left_x, edge_y_at(below, left_x)),
+                        (right_x, edge_y_at(below, right_x)),
+                        (right_x, edge_y_at(above, right_x)),
+                        (left_x, edge_y_at(above, left_x)),
+                    ],
+                    true,
+                ))),
+                trapezoid.is_component(),
+            );
+        }
+        Ok(canvas.finish())
+    }
+}
+
+/// Returns ordinate of the edge's line at the given abscissa.
+fn edge_y_at(
+    ((start_x, start_y), (end_x, end_y)): ((f64, f64), (f64, f64)),
+    x: f64,
+) -> f64 {
+    if start_x == end_x {
+        start_y
+    } else {
+        start_y + (end_y - start_y) * (x - start_x) / (end_x - start_x)
+    }
+}
@@ -1,3 +1,5 @@
+use super::edge::Edge;
+
 #[derive(Clone)]
 pub(crate) struct Trapezoid {
     pub(super) left_point_index: usize,
@@ -91,3 +93,64 @@ impl Trapezoid {
         value.upper_left_leaf_index = Some(self.leaf_index);
     }
 }
+
+/// Trapezoid of a trapezoidation
+/// bounded by two edges from below & above
+/// and by two vertical lines through its left & right points.
+pub struct TrapezoidView<'a, Point> {
+    trapezoid: &'a Trapezoid,
+    edges: &'a [Edge],
+    endpoints: &'a [Point],
+}
+
+impl<'a, Point> TrapezoidView<'a, Point> {
+    pub(super) fn new(
+        trapezoid: &'a Trapezoid,
+        edges: &'a [Edge],
+        endpoints: &'a [Point],
+    ) -> Self {
+        Self {
+            trapezoid,
+            edges,
+            endpoints,
+        }
+    }
+
+    /// Returns left & right endpoints of the edge above the trapezoid.
+    #[must_use]
+    pub fn above_edge_endpoints(&self) -> (&'a Point, &'a Point) {
+        self.edge_endpoints(self.trapezoid.above_edge_index)
+    }
+
+    /// Returns left & right endpoints of the edge below the trapezoid.
+    #[must_use]
+    pub fn below_edge_endpoints(&self) -> (&'a Point, &'a Point) {
+        self.edge_endpoints(self.trapezoid.below_edge_index)
+    }
+
+    /// Checks if the trapezoid lies in the interior of the source geometry.
+    #[must_use]
+    pub fn is_component(&self) -> bool {
+        self.trapezoid.is_component
+    }
+
+    /// Returns point which vertical line bounds the trapezoid from the left.
+    #[must_use]
+    pub fn left_point(&self) -> &'a Point {
+        &self.endpoints[self.trapezoid.left_point_index]
+    }
+
+    /// Returns point which vertical line bounds the trapezoid from the right.
+    #[must_use]
+    pub fn right_point(&self) -> &'a Point {
+        &self.endpoints[self.trapezoid.right_point_index]
+    }
+
+    fn edge_endpoints(&self, edge_index: usize) -> (&'a Point, &'a Point) {
+        let edge = &self.edges[edge_index];
+        (
+            &self.endpoints[edge.left_point_index],
+            &self.endpoints[edge.right_point_index],
+        )
+    }
+}
@@ -4,7 +4,7 @@ use traiter::numbers::{One, Zeroable};
 
 use crate::bounded;
 use crate::bounded::Bounded;
-use crate::operations::Orient;
+use crate::operations::{permute, Orient};
 use crate::oriented::{Orientation, Oriented};
 use crate::traits::{
     Contoural, Elemental, Iterable, Lengthsome, Multisegmental,
@@ -14,10 +14,12 @@ use crate::traits::{
 
 use super::edge::Edge;
 use super::node::Node;
-use super::trapezoid::Trapezoid;
+use super::trapezoid::{Trapezoid, TrapezoidView};
 
+/// Seidel's trapezoidal decomposition of a multisegment or a polygon
+/// which serves as a search structure for point location.
 #[derive(Clone)]
-pub(crate) struct Trapezoidation<Point> {
+pub struct Trapezoidation<Point> {
     edges: Vec<Edge>,
     endpoints: Vec<Point>,
     nodes: Vec<Node>,
@@ -40,18 +42,70 @@ impl<Point> Trapezoidation<Point> {
         &self.nodes
     }
 
-    pub(crate) fn height(&self) -> usize {
+    /// Returns height of the underlying search tree.
+    #[must_use]
+    pub fn height(&self) -> usize {
         self.get_root().height(self.get_nodes())
     }
+
+    /// Returns iterator over trapezoids of the decomposition
+    /// including ones outside of the source geometry.
+    pub fn iter_trapezoids(
+        &self,
+    ) -> impl Iterator<Item = TrapezoidView<'_, Point>> + '_ {
+        self.nodes.iter().filter_map(move |node| match node {
+            Node::Leaf { trapezoid } => Some(TrapezoidView::new(
+                trapezoid,
+                &self.edges,
+                &self.endpoints,
+            )),
+            _ => None,
+        })
+    }
 }
 
 impl<Point> Trapezoidation<Point> {
-    pub(crate) fn from_multisegment<
+    /// Builds trapezoidation of a multisegment
+    /// with edges permuted by the given seed.
+    pub fn from_multisegment_with_seed<
+        IndexSegment,
+        Multisegment,
+        Scalar,
+        Segment,
+    >(
+        multisegment: &Multisegment,
+        seed: usize,
+    ) -> Self
+    where
+        Point: Clone + From<(Scalar, Scalar)> + PartialOrd,
+        Scalar: Clone + One,
+        for<'a> &'a Multisegment: Bounded<&'a Scalar>
+            + Multisegmental<
+                IndexSegment = IndexSegment,
+                IntoIteratorSegment = &'a Segment,
+            >,
+        for<'a> &'a IndexSegment: Segmental,
+        for<'a> &'a Point: Orient,
+        for<'a> &'a Scalar: Add<Scalar, Output = Scalar>
+            + Sub<Scalar, Output = Scalar>
+            + Sub<Output = Scalar>
+            + Zeroable,
+        for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+    {
+        Self::from_multisegment(multisegment, |edges| permute(edges, seed))
+    }
+
+    /// Builds trapezoidation of a multisegment
+    /// with edges reordered by the given shuffler.
+    ///
+    /// Expected height of the search structure is logarithmic
+    /// only if the shuffler produces a uniformly random permutation.
+    pub fn from_multisegment<
         IndexSegment,
         Multisegment,
         Scalar,
         Segment,
-        Shuffler: FnOnce(&mut Vec<Edge>),
+        Shuffler: FnOnce(&mut [Edge]),
     >(
         multisegment: &Multisegment,
         shuffler: Shuffler,
@@ -100,11 +154,43 @@ impl<Point> Trapezoidation<Point> {
         Self::from_box(multisegment.to_bounding_box(), edges, endpoints)
     }
 
-    pub(crate) fn from_polygon<
+    /// Builds trapezoidation of a polygon
+    /// with edges permuted by the given seed.
+    pub fn from_polygon_with_seed<Scalar, Contour, Polygon>(
+        polygon: &Polygon,
+        seed: usize,
+    ) -> Self
+    where
+        Point: Clone + From<(Scalar, Scalar)> + PartialOrd,
+        Scalar: Clone + One,
+        for<'a> &'a Contour: Contoural<IndexVertex = Point> + Oriented,
+        for<'a> &'a Point: Elemental + Orient,
+        for<'a> &'a Polygon: Bounded<&'a Scalar>
+            + Polygonal<Contour = &'a Contour, IntoIteratorHole = &'a Contour>,
+        for<'a> &'a Scalar: Add<Scalar, Output = Scalar>
+            + Sub<Scalar, Output = Scalar>
+            + Sub<Output = Scalar>
+            + Zeroable,
+        for<'a, 'b> &'a MultisegmentalIndexSegment<&'b Contour>: Segmental,
+        for<'a, 'b> &'a PolygonalIndexHole<&'b Polygon>: Contoural,
+        for<'a, 'b, 'c> &'a MultisegmentalIndexSegment<&'b PolygonalIndexHole<&'c Polygon>>:
+            Segmental,
+        for<'a, 'b, 'c> &'a MultivertexalIndexVertex<&'b PolygonalIndexHole<&'c Polygon>>:
+            Elemental,
+    {
+        Self::from_polygon(polygon, |edges| permute(edges, seed))
+    }
+
+    /// Builds trapezoidation of a polygon
+    /// with edges reordered by the given shuffler.
+    ///
+    /// Expected height of the search structure is logarithmic
+    /// only if the shuffler produces a uniformly random permutation.
+    pub fn from_polygon<
         Scalar,
         Contour,
         Polygon,
-        Shuffler: FnOnce(&mut Vec<Edge>),
+        Shuffler: FnOnce(&mut [Edge]),
     >(
         polygon: &Polygon,
         shuffler: Shuffler,
@@ -291,13 +377,13 @@ impl<Point> Trapezoidation<Point> {
                 Edge {
                     left_point_index: first_start_index,
                     right_point_index: last_end_index,
-                    interior_to_left: is_contour_correctly_oriented,
+                    interior_to_left: !is_contour_correctly_oriented,
                 }
             } else {
                 Edge {
                     left_point_index: last_end_index,
                     right_point_index: first_start_index,
-                    interior_to_left: !is_contour_correctly_oriented,
+                    interior_to_left: is_contour_correctly_oriented,
                 }
             },
         );
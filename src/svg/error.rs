@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// Error of rendering SVG.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Coordinate is out of IEEE 754 double range.
+    NonFiniteCoordinate,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFiniteCoordinate => formatter
+                .write_str("Coordinate is out of double precision range."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
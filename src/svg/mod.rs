@@ -0,0 +1,43 @@
+//! SVG rendering of geometries, triangulations & trapezoidations.
+//!
+//! Coordinates are rounded to the nearest doubles,
+//! the view box is the bounding box of the rendered object with margins
+//! and the y-axis points upwards,
+//! e.g. `polygon.to_svg(&Style::default())`.
+//! Colors of the style are escaped as attribute values:
+//!
+//! ```
+//! use rene::geometries::Point;
+//! use rene::svg::{Style, ToSvg};
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//! let point = Point::new(
+//!     Scalar::from(BigInt::<u32, 31>::from(1)),
+//!     Scalar::from(BigInt::<u32, 31>::from(2)),
+//! );
+//! let style = Style {
+//!     fill: String::from(r#"red" onload="alert(1)"#),
+//!     stroke: String::from("<&'>"),
+//!     ..Style::default()
+//! };
+//! let svg = (&point).to_svg(&style)?;
+//! assert!(svg.contains(r#" fill="red&quot; onload=&quot;alert(1)""#));
+//! assert!(svg.contains(r#" stroke="&lt;&amp;&#x27;&gt;""#));
+//! assert!(!svg.contains("onload=\""));
+//! # Ok::<(), rene::svg::Error>(())
+//! ```
+
+pub use self::error::Error;
+pub use self::style::Style;
+pub use self::traits::ToSvg;
+pub(crate) use self::writing::{
+    contour_to_polyline, coordinates_to_bounding_box, polygon_to_polylines,
+    to_coordinates, to_double_bounding_box, to_path_data, Canvas, Polyline,
+};
+
+mod error;
+mod style;
+mod traits;
+mod writing;
@@ -0,0 +1,26 @@
+/// Styling of rendered SVG.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Style {
+    /// Fill color of points & shaped geometries.
+    pub fill: String,
+    /// Opacity of the fill from `0` to `1`.
+    pub fill_opacity: f64,
+    /// Radius of points relative to the larger side of the view box.
+    pub point_radius: f64,
+    /// Stroke color of segments & contours.
+    pub stroke: String,
+    /// Stroke width in pixels, does not scale with the view box.
+    pub stroke_width: f64,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: String::from("#66cc99"),
+            fill_opacity: 0.5,
+            point_radius: 0.01,
+            stroke: String::from("#555555"),
+            stroke_width: 1.0,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use super::error::Error;
+use super::style::Style;
+
+pub trait ToSvg {
+    /// Returns SVG document rendering the object with the given style.
+    fn to_svg(self, style: &Style) -> Result<String, Error>;
+}
@@ -0,0 +1,252 @@
+use std::convert::TryFrom;
+use std::fmt::Write;
+
+use crate::bounded;
+use crate::geometries::{Contour, Point, Polygon};
+use crate::traits::{Elemental, Iterable, Multivertexal, Polygonal};
+
+use super::error::Error;
+use super::style::Style;
+
+const DEFAULT_MARGIN: f64 = 1.0;
+const MARGIN_RATIO: f64 = 0.05;
+const SIZE: usize = 300;
+
+/// Accumulates SVG elements within the view box.
+pub(crate) struct Canvas<'a> {
+    body: String,
+    point_radius: f64,
+    style: &'a Style,
+    view: Option<(f64, f64, f64, f64, f64)>,
+}
+
+impl<'a> Canvas<'a> {
+    /// Creates canvas with the view box enclosing the given bounding box
+    /// or with the unit view box if there is nothing to render.
+    pub(crate) fn new(
+        bounding_box: Option<&bounded::Box<f64>>,
+        style: &'a Style,
+    ) -> Self {
+        let (view, point_radius) = match bounding_box {
+            Some(bounding_box) => {
+                let (min_x, max_x, min_y, max_y) = (
+                    *bounding_box.get_min_x(),
+                    *bounding_box.get_max_x(),
+                    *bounding_box.get_min_y(),
+                    *bounding_box.get_max_y(),
+                );
+                let (width, height) = (max_x - min_x, max_y - min_y);
+                let size = width.max(height);
+                let margin = if size > 0.0 {
+                    size * MARGIN_RATIO
+                } else {
+                    DEFAULT_MARGIN
+                };
+                let (view_width, view_height) =
+                    (width + 2.0 * margin, height + 2.0 * margin);
+                (
+                    Some((
+                        min_x - margin,
+                        min_y - margin,
+                        view_width,
+                        view_height,
+                        min_y + max_y,
+                    )),
+                    style.point_radius * view_width.max(view_height),
+                )
+            }
+            None => (None, 0.0),
+        };
+        Self {
+            body: String::new(),
+            point_radius,
+            style,
+            view,
+        }
+    }
+
+    pub(crate) fn add_path(&mut self, data: &str, is_filled: bool) {
+        self.body.push_str("<path d=\"");
+        self.body.push_str(data);
+        self.body.push_str(if is_filled {
+            "\" fill-rule=\"evenodd\""
+        } else {
+            "\" fill=\"none\""
+        });
+        self.body
+            .push_str(" vector-effect=\"non-scaling-stroke\"/>");
+    }
+
+    pub(crate) fn add_point(&mut self, (x, y): (f64, f64)) {
+        let _ = write!(
+            self.body,
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" stroke=\"none\"/>",
+            x, y, self.point_radius
+        );
+    }
+
+    pub(crate) fn finish(self) -> String {
+        let mut result =
+            String::from("<svg xmlns=\"http://www.w3.org/2000/svg\"");
+        let flip = match self.view {
+            Some((min_x, min_y, width, height, flip)) => {
+                let _ = write!(
+                    result,
+                    " viewBox=\"{} {} {} {}\"",
+                    min_x, min_y, width, height
+                );
+                flip
+            }
+            None => {
+                result.push_str(" viewBox=\"0 0 1 1\"");
+                0.0
+            }
+        };
+        let _ = write!(
+            result,
+            " width=\"{size}\" height=\"{size}\">",
+            size = SIZE
+        );
+        let _ = write!(result, "<g transform=\"matrix(1 0 0 -1 0 {})\"", flip);
+        let _ = write!(
+            result,
+            " fill=\"{}\" fill-opacity=\"{}\"",
+            escape_attribute(&self.style.fill),
+            self.style.fill_opacity
+        );
+        let _ = write!(
+            result,
+            " stroke=\"{}\" stroke-width=\"{}\">",
+            escape_attribute(&self.style.stroke),
+            self.style.stroke_width
+        );
+        result.push_str(&self.body);
+        result.push_str("</g></svg>");
+        result
+    }
+}
+
+/// Escapes characters with special meaning in XML attribute values.
+fn escape_attribute(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#x27;"),
+            _ => result.push(character),
+        }
+    }
+    result
+}
+
+/// Coordinates of polyline vertices with a flag of being closed.
+pub(crate) type Polyline = (Vec<(f64, f64)>, bool);
+
+/// Returns the smallest box containing given coordinates if there are any.
+pub(crate) fn coordinates_to_bounding_box(
+    coordinates: impl Iterator<Item = (f64, f64)>,
+) -> Option<bounded::Box<f64>> {
+    coordinates
+        .fold(None, |result, (x, y)| {
+            Some(match result {
+                Some((min_x, max_x, min_y, max_y)) => {
+                    (x.min(min_x), x.max(max_x), y.min(min_y), y.max(max_y))
+                }
+                None => (x, x, y, y),
+            })
+        })
+        .map(|(min_x, max_x, min_y, max_y)| {
+            bounded::Box::new(min_x, max_x, min_y, max_y)
+        })
+}
+
+pub(crate) fn contour_to_polyline<Scalar>(
+    contour: &Contour<Scalar>,
+) -> Result<Polyline, Error>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    Ok((
+        contour
+            .vertices()
+            .iter()
+            .map(to_coordinates)
+            .collect::<Result<Vec<_>, _>>()?,
+        true,
+    ))
+}
+
+pub(crate) fn polygon_to_polylines<Scalar>(
+    polygon: &Polygon<Scalar>,
+) -> Result<Vec<Polyline>, Error>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    let mut result = vec![contour_to_polyline(polygon.border())?];
+    for hole in polygon.holes().iter() {
+        result.push(contour_to_polyline(hole)?);
+    }
+    Ok(result)
+}
+
+pub(crate) fn to_coordinates<Scalar>(
+    point: &Point<Scalar>,
+) -> Result<(f64, f64), Error>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    let (x, y) = point.coordinates();
+    Ok((to_double(x)?, to_double(y)?))
+}
+
+/// Returns path data of polylines which are closed if flagged.
+pub(crate) fn to_path_data(
+    polylines: impl Iterator<Item = Polyline>,
+) -> String {
+    let mut result = String::new();
+    for (polyline, is_closed) in polylines {
+        for (index, (x, y)) in polyline.into_iter().enumerate() {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            let _ = write!(
+                result,
+                "{}{},{}",
+                if index == 0 { 'M' } else { 'L' },
+                x,
+                y
+            );
+        }
+        if is_closed {
+            result.push_str(" Z");
+        }
+    }
+    result
+}
+
+pub(crate) fn to_double_bounding_box<Scalar>(
+    bounding_box: &bounded::Box<&Scalar>,
+) -> Result<bounded::Box<f64>, Error>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    Ok(bounded::Box::new(
+        to_double(*bounding_box.get_min_x())?,
+        to_double(*bounding_box.get_max_x())?,
+        to_double(*bounding_box.get_min_y())?,
+        to_double(*bounding_box.get_max_y())?,
+    ))
+}
+
+fn to_double<Scalar>(value: &Scalar) -> Result<f64, Error>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+{
+    f64::try_from(value)
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or(Error::NonFiniteCoordinate)
+}
@@ -160,6 +160,18 @@ where
     fn polygons(self) -> Self::Polygons;
 }
 
+pub trait Centroidal {
+    type Output;
+
+    /// centroid of the geometry's highest dimension part:
+    /// area-weighted for shaped geometries,
+    /// vertices-based for linear ones
+    fn to_centroid(self) -> Self::Output;
+    /// arithmetic mean of the geometry's segments midpoints,
+    /// for contours & shaped geometries coincides with the mean of vertices
+    fn to_vertices_centroid(self) -> Self::Output;
+}
+
 pub type ElementalCoordinate<T> = <T as Elemental>::Coordinate;
 pub type MultipolygonalIntoIteratorPolygon<T> =
     <T as Multipolygonal>::IntoIteratorPolygon;
@@ -172,6 +184,28 @@ pub type PolygonalIntoIteratorHole<T> = <T as Polygonal>::IntoIteratorHole;
 pub type SegmentalCoordinate<T> = ElementalCoordinate<SegmentalEndpoint<T>>;
 pub type SegmentalEndpoint<T> = <T as Segmental>::Endpoint;
 
+pub trait Area {
+    type Output;
+
+    fn area(self) -> Self::Output;
+    fn signed_area(self) -> Self::Output;
+}
+
+pub trait ConvexHull {
+    type Output;
+
+    /// Returns the smallest convex geometry containing the geometry.
+    fn convex_hull(self) -> Self::Output;
+}
+
+pub trait Normalize {
+    type Output;
+
+    /// Returns canonical form of the geometry
+    /// which does not depend on the order of its components.
+    fn normalize(self) -> Self::Output;
+}
+
 pub trait Intersection<Other = Self> {
     type Output;
 
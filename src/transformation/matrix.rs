@@ -0,0 +1,209 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use traiter::numbers::{One, Zero};
+
+use crate::geometries::Point;
+use crate::traits::Elemental;
+
+/// Matrix of the affine transformation
+/// ```text
+/// x' = xx * x + xy * y + x_offset
+/// y' = yx * x + yy * y + y_offset
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AffineMatrix<Scalar> {
+    xx: Scalar,
+    xy: Scalar,
+    x_offset: Scalar,
+    yx: Scalar,
+    yy: Scalar,
+    y_offset: Scalar,
+}
+
+impl<Scalar> AffineMatrix<Scalar> {
+    pub fn new(
+        xx: Scalar,
+        xy: Scalar,
+        x_offset: Scalar,
+        yx: Scalar,
+        yy: Scalar,
+        y_offset: Scalar,
+    ) -> Self {
+        Self {
+            xx,
+            xy,
+            x_offset,
+            yx,
+            yy,
+            y_offset,
+        }
+    }
+
+    pub fn get_xx(&self) -> &Scalar {
+        &self.xx
+    }
+
+    pub fn get_xy(&self) -> &Scalar {
+        &self.xy
+    }
+
+    pub fn get_x_offset(&self) -> &Scalar {
+        &self.x_offset
+    }
+
+    pub fn get_yx(&self) -> &Scalar {
+        &self.yx
+    }
+
+    pub fn get_yy(&self) -> &Scalar {
+        &self.yy
+    }
+
+    pub fn get_y_offset(&self) -> &Scalar {
+        &self.y_offset
+    }
+}
+
+impl<Scalar: One + Zero> AffineMatrix<Scalar> {
+    pub fn identity() -> Self {
+        Self::translation(Scalar::zero(), Scalar::zero())
+    }
+
+    pub fn translation(step_x: Scalar, step_y: Scalar) -> Self {
+        Self::new(
+            Scalar::one(),
+            Scalar::zero(),
+            step_x,
+            Scalar::zero(),
+            Scalar::one(),
+            step_y,
+        )
+    }
+}
+
+impl<Scalar> AffineMatrix<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + Clone
+        + Div<Output = Scalar>
+        + Neg<Output = Scalar>
+        + One
+        + PartialEq
+        + Sub<Output = Scalar>
+        + Zero,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Mul<Output = Scalar> + Sub<Output = Scalar>,
+{
+    /// Constructs scaling by the factors with the fixed origin point,
+    /// returns `None` if any of the factors is zero
+    /// since such a matrix is singular.
+    pub fn scaling(
+        factor_x: Scalar,
+        factor_y: Scalar,
+        origin: &Point<Scalar>,
+    ) -> Option<Self> {
+        if factor_x == Scalar::zero() || factor_y == Scalar::zero() {
+            return None;
+        }
+        let (origin_x, origin_y) = origin.coordinates();
+        let x_offset = origin_x - &(&factor_x * origin_x);
+        let y_offset = origin_y - &(&factor_y * origin_y);
+        Some(Self::new(
+            factor_x,
+            Scalar::zero(),
+            x_offset,
+            Scalar::zero(),
+            factor_y,
+            y_offset,
+        ))
+    }
+
+    /// Constructs reflection about the line passing through the points,
+    /// returns `None` if the points coincide.
+    pub fn reflection(
+        start: &Point<Scalar>,
+        end: &Point<Scalar>,
+    ) -> Option<Self> {
+        let (start_x, start_y) = start.coordinates();
+        let (end_x, end_y) = end.coordinates();
+        let (delta_x, delta_y) = (end_x - start_x, end_y - start_y);
+        let squared_delta_x = &delta_x * &delta_x;
+        let squared_delta_y = &delta_y * &delta_y;
+        let squared_length = squared_delta_x.clone() + squared_delta_y.clone();
+        if squared_length == Scalar::zero() {
+            return None;
+        }
+        let cosine =
+            (squared_delta_x - squared_delta_y) / squared_length.clone();
+        let deltas_product = &delta_x * &delta_y;
+        let sine = (deltas_product.clone() + deltas_product) / squared_length;
+        Some(Self::with_fixed_point(
+            cosine.clone(),
+            sine.clone(),
+            sine,
+            -cosine,
+            start,
+        ))
+    }
+
+    /// Constructs rotation by the angle with the given cosine & sine
+    /// around the center point,
+    /// returns `None` if the cosine & sine do not belong to the same angle,
+    /// e.g. rational ones come from Pythagorean triples
+    /// like `(3/5, 4/5)`.
+    pub fn rotation(
+        cosine: Scalar,
+        sine: Scalar,
+        center: &Point<Scalar>,
+    ) -> Option<Self> {
+        if &cosine * &cosine + &sine * &sine != Scalar::one() {
+            return None;
+        }
+        Some(Self::with_fixed_point(
+            cosine.clone(),
+            -sine.clone(),
+            sine,
+            cosine,
+            center,
+        ))
+    }
+
+    fn with_fixed_point(
+        xx: Scalar,
+        xy: Scalar,
+        yx: Scalar,
+        yy: Scalar,
+        point: &Point<Scalar>,
+    ) -> Self {
+        let (x, y) = point.coordinates();
+        let x_offset = x - &(&xx * x + &xy * y);
+        let y_offset = y - &(&yx * x + &yy * y);
+        Self::new(xx, xy, x_offset, yx, yy, y_offset)
+    }
+}
+
+impl<Scalar> AffineMatrix<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Clone,
+    for<'a> &'a Scalar: Mul<Output = Scalar> + Sub<Output = Scalar>,
+{
+    pub fn determinant(&self) -> Scalar {
+        &(&self.xx * &self.yy) - &(&self.xy * &self.yx)
+    }
+
+    /// Returns the matrix of applying `self` first & `other` second.
+    pub fn then(&self, other: &Self) -> Self {
+        Self::new(
+            &other.xx * &self.xx + &other.xy * &self.yx,
+            &other.xx * &self.xy + &other.xy * &self.yy,
+            &other.xx * &self.x_offset
+                + &other.xy * &self.y_offset
+                + other.x_offset.clone(),
+            &other.yx * &self.xx + &other.yy * &self.yx,
+            &other.yx * &self.xy + &other.yy * &self.yy,
+            &other.yx * &self.x_offset
+                + &other.yy * &self.y_offset
+                + other.y_offset.clone(),
+        )
+    }
+}
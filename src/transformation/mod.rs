@@ -0,0 +1,64 @@
+//! Exact affine transformations of geometries.
+//!
+//! Matrices keep all of their coefficients in the geometries' scalar type,
+//! so with rational scalars no precision is lost,
+//! e.g. `(&contour).transform(&AffineMatrix::translation(dx, dy))`.
+//! Singular matrices collapse areal geometries,
+//! so those have no image under them,
+//! while linear ones are transformed into degenerate geometries:
+//!
+//! ```
+//! use rene::geometries::{Contour, Point, Polygon};
+//! use rene::transformation::{AffineMatrix, Transform};
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//! let to_scalar = |value: i32| Scalar::from(BigInt::<u32, 31>::from(value));
+//! let to_point = |x: i32, y: i32| Point::new(to_scalar(x), to_scalar(y));
+//! let contour =
+//!     Contour::new(vec![to_point(0, 0), to_point(1, 0), to_point(0, 1)]);
+//! let origin = to_point(0, 0);
+//! assert!(
+//!     AffineMatrix::scaling(to_scalar(0), to_scalar(1), &origin).is_none()
+//! );
+//! // projection onto the x-axis
+//! let projection = AffineMatrix::new(
+//!     to_scalar(1),
+//!     to_scalar(0),
+//!     to_scalar(0),
+//!     to_scalar(0),
+//!     to_scalar(0),
+//!     to_scalar(0),
+//! );
+//! assert!(
+//!     (&Polygon::new(contour.clone(), vec![]))
+//!         .transform(&projection)
+//!         .is_none()
+//! );
+//! assert!(
+//!     (&contour).transform(&projection)
+//!         == Contour::new(vec![
+//!             to_point(0, 0),
+//!             to_point(1, 0),
+//!             to_point(0, 0),
+//!         ])
+//! );
+//! assert!((&to_point(1, 1)).transform(&projection) == to_point(1, 0));
+//! let scaling =
+//!     AffineMatrix::scaling(to_scalar(2), to_scalar(-1), &origin).unwrap();
+//! assert!(
+//!     (&contour).transform(&scaling)
+//!         == Contour::new(vec![
+//!             to_point(0, 0),
+//!             to_point(0, -1),
+//!             to_point(2, 0),
+//!         ])
+//! );
+//! ```
+
+pub use self::matrix::AffineMatrix;
+pub use self::traits::Transform;
+
+mod matrix;
+mod traits;
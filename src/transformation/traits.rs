@@ -0,0 +1,12 @@
+use super::matrix::AffineMatrix;
+
+pub trait Transform<Scalar> {
+    type Output;
+
+    /// Returns the geometry with the matrix applied to its points,
+    /// for matrices with negative determinant contours' orientations
+    /// are restored,
+    /// linear geometries may degenerate under singular matrices,
+    /// while areal ones return `None` since their images have no area.
+    fn transform(self, matrix: &AffineMatrix<Scalar>) -> Self::Output;
+}
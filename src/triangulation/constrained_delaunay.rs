@@ -1,31 +1,43 @@
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 
+use crate::bentley_ottmann::{split_segments, Intersection, Sweep};
 use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
+use crate::geometries::{Multipolygon, Multisegment, Point, Segment};
 use crate::locatable::Location;
 use crate::operations::{
     shrink_collinear_vertices, LocatePointInPointPointPointCircle, Orient,
+    ToBoundarySegments,
 };
 use crate::oriented::Orientation;
 use crate::relatable::Relation;
 use crate::relating::segment_endpoints;
 use crate::traits::{
-    Contoural, Elemental, Iterable, Lengthsome, MultisegmentalIndexSegment,
-    Multivertexal, MultivertexalIndexVertex, Polygonal, PolygonalIndexHole,
-    Segmental, Sequence,
+    Contoural, Elemental, Iterable, Lengthsome, Multisegmental,
+    MultisegmentalIndexSegment, Multivertexal, MultivertexalIndexVertex,
+    Polygonal, PolygonalIndexHole, Segmental, Sequence,
 };
 
-use super::mesh::Mesh;
-use super::operations::{BoundaryEndpoints, DelaunayTriangulatable};
-use super::quad_edge::{to_opposite_edge, QuadEdge, UNDEFINED_QUAD_EDGE};
+use super::indexed_mesh::IndexedMesh;
+use super::mesh::{EndpointLocation, Mesh};
+use super::operations::{
+    BoundaryEndpoints, DelaunayTriangulatable, FromPointsAndMultisegment,
+};
+use super::point_location::PointLocation;
+use super::quad_edge::{
+    to_opposite_edge, to_unique_edge, QuadEdge, UNDEFINED_QUAD_EDGE,
+};
 
+/// Constrained Delaunay triangulation of a polygon, a multipolygon
+/// or a set of points with constraint segments
+/// which keeps constraints as edges & excludes holes.
 #[derive(Clone)]
-pub(crate) struct ConstrainedDelaunayTriangulation<Endpoint> {
+pub struct ConstrainedDelaunayTriangulation<Endpoint> {
+    constraints: Vec<QuadEdge>,
+    exterior_faces_edges: Vec<QuadEdge>,
     left_side: QuadEdge,
     mesh: Mesh<Endpoint>,
-    polygon_vertices_positions: Vec<Vec<PolygonVertexPosition>>,
     right_side: QuadEdge,
-    triangular_holes_indices: Vec<usize>,
 }
 
 impl<Endpoint: Clone> BoundaryEndpoints<Endpoint>
@@ -34,21 +46,23 @@ where
     for<'a> &'a Endpoint: Orient,
 {
     fn get_boundary_endpoints(&self) -> Vec<&Endpoint> {
-        debug_assert!(
-            self.mesh.get_endpoints().len() >= MIN_CONTOUR_VERTICES_COUNT
-        );
-        let mut result = Vec::new();
-        let start = self.left_side;
-        let mut edge = start;
-        loop {
-            result.push(self.mesh.get_start(edge));
-            let candidate = self.mesh.to_right_from_end(edge);
-            if candidate == start {
-                break;
+        let endpoints = self.mesh.get_endpoints();
+        if endpoints.len() < MIN_CONTOUR_VERTICES_COUNT {
+            endpoints.iter().collect()
+        } else {
+            let mut result = Vec::new();
+            let start = self.left_side;
+            let mut edge = start;
+            loop {
+                result.push(self.mesh.get_start(edge));
+                let candidate = self.mesh.to_right_from_end(edge);
+                if candidate == start {
+                    break;
+                }
+                edge = candidate;
             }
-            edge = candidate;
+            shrink_collinear_vertices(&result)
         }
-        shrink_collinear_vertices(&result)
     }
 }
 
@@ -108,6 +122,7 @@ where
     for<'a> &'a Polygon:
         Polygonal<Contour = &'a Contour, IntoIteratorHole = &'a Contour>,
 {
+    /// Triangulates given polygon constraining its edges.
     fn from(polygon: &Polygon) -> Self {
         let contours_vertices = {
             let holes = polygon.holes();
@@ -182,27 +197,191 @@ where
             .enumerate()
             .filter(|(_, hole_vertices)| hole_vertices.len() == 3)
             .map(|(hole_index, _)| hole_index + 1)
-            .collect();
+            .collect::<Vec<_>>();
         let mut result = Self {
+            constraints: Vec::new(),
+            exterior_faces_edges: Vec::new(),
             left_side,
             mesh,
-            polygon_vertices_positions,
             right_side,
-            triangular_holes_indices,
         };
         let contours_sizes = contours_vertices
             .iter()
             .map(|contour| contour.len())
             .collect::<Vec<usize>>();
-        result.constrain(&contours_sizes, &contours_vertices);
-        result.bound(&contours_sizes);
-        result.cut(&contours_vertices);
+        result.constrain(
+            &contours_sizes,
+            &contours_vertices,
+            &polygon_vertices_positions,
+        );
+        result.bound(&contours_sizes, &polygon_vertices_positions);
+        result.cut(&contours_vertices, &polygon_vertices_positions);
+        result.constraints = result
+            .mesh
+            .iter_unique_edges()
+            .filter(|&edge| {
+                is_contour_edge(
+                    &result.mesh,
+                    edge,
+                    &contours_sizes,
+                    &polygon_vertices_positions,
+                )
+            })
+            .collect();
+        result.exterior_faces_edges = to_triangular_holes_edges(
+            &result.mesh,
+            &polygon_vertices_positions,
+            &triangular_holes_indices,
+        );
+        result
+    }
+}
+
+impl<Scalar: Clone + Ord> From<&Multipolygon<Scalar>>
+    for ConstrainedDelaunayTriangulation<Point<Scalar>>
+where
+    Sweep<Point<Scalar>>: for<'a> From<&'a Vec<Segment<Scalar>>>
+        + Iterator<Item = Intersection<Point<Scalar>>>,
+    for<'a> &'a Point<Scalar>: LocatePointInPointPointPointCircle + Orient,
+{
+    /// Triangulates given multipolygon constraining edges of its polygons,
+    /// vertices lying on edges of other polygons split these edges.
+    fn from(multipolygon: &Multipolygon<Scalar>) -> Self {
+        let segments = multipolygon
+            .to_boundary_segments()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let (mut result, constraints_multiplicities) =
+            Self::from_segments(Vec::new(), &to_pieces(&segments));
+        result.exclude_exterior_faces(&constraints_multiplicities);
+        result
+    }
+}
+
+impl<Scalar: Clone + Ord>
+    FromPointsAndMultisegment<Point<Scalar>, Multisegment<Scalar>>
+    for ConstrainedDelaunayTriangulation<Point<Scalar>>
+where
+    Sweep<Point<Scalar>>: for<'a> From<&'a Vec<Segment<Scalar>>>
+        + Iterator<Item = Intersection<Point<Scalar>>>,
+    for<'a> &'a Point<Scalar>: LocatePointInPointPointPointCircle + Orient,
+{
+    fn from_points_and_multisegment(
+        mut points: Vec<Point<Scalar>>,
+        multisegment: &Multisegment<Scalar>,
+    ) -> Self {
+        let mut segments = Vec::new();
+        for segment in multisegment.segments() {
+            let (start, end) = segment.endpoints();
+            if start == end {
+                // degenerate segments are treated as points
+                points.push(start.clone());
+            } else {
+                segments.push(segment.clone());
+            }
+        }
+        points.sort();
+        points.dedup();
+        let pieces = split_pieces_by_points(to_pieces(&segments), &points);
+        Self::from_segments(points, &pieces).0
+    }
+}
+
+impl<Endpoint: Clone + Ord> ConstrainedDelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    /// Triangulates given endpoints along with endpoints of the segments
+    /// constraining the segments, which should not contain endpoints inside,
+    /// returns the triangulation along with multiplicities of constraints.
+    fn from_segments(
+        mut endpoints: Vec<Endpoint>,
+        segments: &[(Endpoint, Endpoint)],
+    ) -> (Self, BTreeMap<QuadEdge, usize>) {
+        endpoints.reserve(2 * segments.len());
+        for (start, end) in segments {
+            endpoints.push(start.clone());
+            endpoints.push(end.clone());
+        }
+        endpoints.sort();
+        endpoints.dedup();
+        let pieces = to_pieces_indices_multiplicities(&endpoints, segments);
+        let mut mesh = Mesh::from(endpoints);
+        let (left_side, right_side) = mesh.delaunay_triangulation();
+        let mut result = Self {
+            constraints: Vec::new(),
+            exterior_faces_edges: Vec::new(),
+            left_side,
+            mesh,
+            right_side,
+        };
+        let mut vertices_edges = to_vertices_edges(&result.mesh);
+        let mut constraints_multiplicities = BTreeMap::new();
+        for ((start_index, end_index), multiplicity) in pieces {
+            let constraint =
+                result.set_piece(&mut vertices_edges, start_index, end_index);
+            constraints_multiplicities
+                .insert(to_unique_edge(constraint), multiplicity);
+        }
+        result.constraints =
+            constraints_multiplicities.keys().copied().collect();
+        (result, constraints_multiplicities)
+    }
+
+    /// Constrains the piece between endpoints with given indices,
+    /// returns the edge from the start of the piece to its end.
+    fn set_piece(
+        &mut self,
+        vertices_edges: &mut [QuadEdge],
+        start_index: usize,
+        end_index: usize,
+    ) -> QuadEdge {
+        let (start, end) = {
+            let endpoints = self.mesh.get_endpoints();
+            (endpoints[start_index].clone(), endpoints[end_index].clone())
+        };
+        let mut edge = vertices_edges[start_index];
+        if self.mesh.to_start_index(edge) != start_index {
+            // the edge has been swapped by previous constraints
+            edge = match self.mesh.locate_endpoint(self.left_side, &start) {
+                EndpointLocation::Vertex(edge) => edge,
+                _ => unreachable!("Pieces' endpoints are mesh vertices."),
+            };
+        }
+        let result = to_edge_with_end(&self.mesh, edge, end_index)
+            .unwrap_or_else(|| {
+                let angle_base_edge = to_angle_containing_constraint_base(
+                    &self.mesh, edge, &end,
+                );
+                let crossings = detect_crossings(
+                    &self.mesh,
+                    angle_base_edge,
+                    &start,
+                    &end,
+                );
+                set_constraint(&mut self.mesh, &start, &end, crossings);
+                unsafe {
+                    to_edge_with_end(&self.mesh, angle_base_edge, end_index)
+                        .unwrap_unchecked()
+                }
+            });
+        vertices_edges[start_index] = result;
+        vertices_edges[end_index] = to_opposite_edge(result);
         result
     }
 }
 
 impl<Endpoint> ConstrainedDelaunayTriangulation<Endpoint> {
-    pub(crate) fn is_empty(&self) -> bool {
+    /// Returns unique endpoints of the triangulation,
+    /// indices of which are used by point location.
+    pub fn get_endpoints(&self) -> &[Endpoint] {
+        self.mesh.get_endpoints()
+    }
+
+    /// Checks if the triangulation has no endpoints.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
         let result = self.mesh.is_empty();
         debug_assert_eq!(self.left_side == UNDEFINED_QUAD_EDGE, result);
         debug_assert_eq!(self.right_side == UNDEFINED_QUAD_EDGE, result);
@@ -221,6 +400,10 @@ impl<Endpoint> ConstrainedDelaunayTriangulation<Endpoint> {
         self.mesh.delete_edge(edge);
     }
 
+    fn is_exterior_face(&self, edge: QuadEdge) -> bool {
+        self.exterior_faces_edges.binary_search(&edge).is_ok()
+    }
+
     fn to_unique_boundary_edges(&self) -> Vec<QuadEdge> {
         debug_assert!(!self.is_empty());
         let mut result = Vec::new();
@@ -238,29 +421,125 @@ impl<Endpoint> ConstrainedDelaunayTriangulation<Endpoint> {
     }
 }
 
+impl<Endpoint: Clone + PartialOrd> ConstrainedDelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    /// Builds indexed representation of the triangulation.
+    pub fn to_indexed_mesh(&self) -> IndexedMesh<Endpoint> {
+        IndexedMesh::from_mesh(
+            &self.mesh,
+            self.to_triangles_base_edges(),
+            &self.constraints,
+        )
+    }
+}
+
 impl<Endpoint: PartialOrd> ConstrainedDelaunayTriangulation<Endpoint>
 where
     for<'a> &'a Endpoint: Orient,
 {
-    pub(crate) fn to_triangles_vertices(
+    /// Iterates over vertices of triangles in counterclockwise order.
+    pub fn iter_triangles_vertices(
         &self,
     ) -> impl Iterator<Item = (&Endpoint, &Endpoint, &Endpoint)> + '_ {
+        self.to_triangles_base_edges()
+            .map(move |edge| self.mesh.triangle_base_to_vertices(edge))
+    }
+
+    /// Locates the point in the triangulation,
+    /// points inside of holes are considered exterior.
+    pub fn locate_point(&self, point: &Endpoint) -> PointLocation {
+        let is_inner_face = |edge| {
+            self.mesh.is_triangle_face(edge) && !self.is_exterior_face(edge)
+        };
+        if !self.is_empty() && is_inner_face(self.left_side) {
+            match self.mesh.walk_to_endpoint(
+                self.left_side,
+                point,
+                is_inner_face,
+                2 * self.mesh.get_endpoints().len(),
+            ) {
+                Some(EndpointLocation::Exterior) | None => {}
+                Some(location) => {
+                    return self.mesh.to_point_location(location);
+                }
+            }
+        }
+        self.mesh.locate_point_by_scan(point, is_inner_face)
+    }
+
+    fn to_triangles_base_edges(&self) -> impl Iterator<Item = QuadEdge> + '_ {
         self.mesh
             .to_triangles_base_edges()
-            .filter(move |&edge| {
-                self.triangular_holes_indices.is_empty()
-                    || !are_triangular_hole_vertices(
-                        &self.polygon_vertices_positions
-                            [self.mesh.to_start_index(edge)],
-                        &self.polygon_vertices_positions
-                            [self.mesh.to_end_index(edge)],
-                        &self.polygon_vertices_positions[self
-                            .mesh
-                            .to_end_index(self.mesh.to_left_from_start(edge))],
-                        &self.triangular_holes_indices,
-                    )
+            .filter(move |&edge| !self.is_exterior_face(edge))
+    }
+
+    /// Excludes triangles separated from the outer face
+    /// by an even number of constraints counting their multiplicities.
+    fn exclude_exterior_faces(
+        &mut self,
+        constraints_multiplicities: &BTreeMap<QuadEdge, usize>,
+    ) {
+        let is_odd_constraint = |edge: QuadEdge| {
+            constraints_multiplicities
+                .get(&to_unique_edge(edge))
+                .is_some_and(|multiplicity| multiplicity % 2 == 1)
+        };
+        let triangles_edges = self
+            .mesh
+            .to_triangles_base_edges()
+            .map(|base_edge| {
+                let next_edge = self.mesh.to_left_from_end(base_edge);
+                [base_edge, next_edge, self.mesh.to_left_from_end(next_edge)]
             })
-            .map(move |edge| self.mesh.triangle_base_to_vertices(edge))
+            .collect::<Vec<_>>();
+        let edges_triangles_indices = triangles_edges
+            .iter()
+            .enumerate()
+            .flat_map(|(triangle_index, triangle_edges)| {
+                triangle_edges.map(|edge| (edge, triangle_index))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut interior_flags = vec![None; triangles_edges.len()];
+        let mut queue = Vec::new();
+        for (triangle_index, triangle_edges) in
+            triangles_edges.iter().enumerate()
+        {
+            for &edge in triangle_edges {
+                if interior_flags[triangle_index].is_none()
+                    && !edges_triangles_indices
+                        .contains_key(&to_opposite_edge(edge))
+                {
+                    interior_flags[triangle_index] =
+                        Some(is_odd_constraint(edge));
+                    queue.push(triangle_index);
+                }
+            }
+        }
+        while let Some(triangle_index) = queue.pop() {
+            let is_interior =
+                unsafe { interior_flags[triangle_index].unwrap_unchecked() };
+            for &edge in &triangles_edges[triangle_index] {
+                if let Some(&neighbour_index) =
+                    edges_triangles_indices.get(&to_opposite_edge(edge))
+                {
+                    if interior_flags[neighbour_index].is_none() {
+                        interior_flags[neighbour_index] =
+                            Some(is_interior != is_odd_constraint(edge));
+                        queue.push(neighbour_index);
+                    }
+                }
+            }
+        }
+        let mut exterior_faces_edges = triangles_edges
+            .into_iter()
+            .zip(interior_flags)
+            .filter(|(_, is_interior)| *is_interior == Some(false))
+            .flat_map(|(triangle_edges, _)| triangle_edges)
+            .collect::<Vec<_>>();
+        exterior_faces_edges.sort_unstable();
+        self.exterior_faces_edges = exterior_faces_edges;
     }
 }
 
@@ -268,7 +547,11 @@ impl<Endpoint: PartialOrd> ConstrainedDelaunayTriangulation<Endpoint>
 where
     for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
 {
-    fn bound(&mut self, contours_sizes: &[usize]) {
+    fn bound(
+        &mut self,
+        contours_sizes: &[usize],
+        polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
+    ) {
         let mut extraneous_mouths = self
             .to_unique_boundary_edges()
             .into_iter()
@@ -277,7 +560,7 @@ where
                     &self.mesh,
                     *edge,
                     contours_sizes,
-                    &self.polygon_vertices_positions,
+                    polygon_vertices_positions,
                 )
             })
             .collect::<Vec<QuadEdge>>();
@@ -289,7 +572,7 @@ where
                 &self.mesh,
                 first_candidate,
                 contours_sizes,
-                &self.polygon_vertices_positions,
+                polygon_vertices_positions,
             ) {
                 extraneous_mouths.push(first_candidate);
             }
@@ -297,7 +580,7 @@ where
                 &self.mesh,
                 second_candidate,
                 contours_sizes,
-                &self.polygon_vertices_positions,
+                polygon_vertices_positions,
             ) {
                 extraneous_mouths.push(second_candidate);
             }
@@ -308,18 +591,19 @@ where
         &mut self,
         contours_sizes: &[usize],
         contours_vertices: &[ContourVertices],
+        polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
     ) {
         let mut contours_constraints_flags = to_contours_constraints_flags(
             &self.mesh,
             contours_sizes,
-            &self.polygon_vertices_positions,
+            polygon_vertices_positions,
         );
         for edge in self.mesh.to_edges() {
             let start_index = self.mesh.to_start_index(edge);
             for &PolygonVertexPosition {
                 contour_index,
                 vertex_index,
-            } in &self.polygon_vertices_positions[start_index]
+            } in &polygon_vertices_positions[start_index]
             {
                 let next_vertex_index =
                     (vertex_index + 1) % contours_sizes[contour_index];
@@ -360,13 +644,14 @@ where
     fn cut<ContourVertices: Sequence<IndexItem = Endpoint>>(
         &mut self,
         contours_vertices: &[ContourVertices],
+        polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
     ) {
         for edge in self.mesh.to_unique_edges() {
             if is_edge_inside_hole(
                 &self.mesh,
                 edge,
                 contours_vertices,
-                &self.polygon_vertices_positions,
+                polygon_vertices_positions,
             ) {
                 self.delete_edge(edge);
             }
@@ -612,6 +897,26 @@ where
     false
 }
 
+fn is_contour_edge<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+    contours_sizes: &[usize],
+    polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
+) -> bool {
+    intersect_polygon_vertices_positions_slices::<true>(
+        &polygon_vertices_positions[mesh.to_start_index(edge)],
+        &polygon_vertices_positions[mesh.to_end_index(edge)],
+    )
+    .into_iter()
+    .any(|(start_position, end_position)| {
+        are_polygon_edge_indices(
+            start_position.vertex_index,
+            end_position.vertex_index,
+            contours_sizes[start_position.contour_index],
+        )
+    })
+}
+
 fn is_convex_quadrilateral_diagonal<Endpoint>(
     mesh: &Mesh<Endpoint>,
     edge: QuadEdge,
@@ -733,6 +1038,43 @@ fn set_criterion<Endpoint>(
     }
 }
 
+/// Splits pieces by sorted unique points lying on them.
+///
+/// Points lying on a piece are lexicographically between its endpoints,
+/// so only the points from that range are checked for collinearity.
+fn split_pieces_by_points<Endpoint: Clone + Ord>(
+    pieces: Vec<(Endpoint, Endpoint)>,
+    points: &[Endpoint],
+) -> Vec<(Endpoint, Endpoint)>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    if points.is_empty() {
+        return pieces;
+    }
+    let mut result = Vec::with_capacity(pieces.len());
+    for (start, end) in pieces {
+        let (min_endpoint, max_endpoint) = if start < end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let min_index =
+            points.partition_point(|point| point.le(&min_endpoint));
+        let max_index =
+            points.partition_point(|point| point.lt(&max_endpoint));
+        let mut piece_start = min_endpoint.clone();
+        for point in points[min_index..max_index].iter().filter(|&point| {
+            min_endpoint.orient(&max_endpoint, point) == Orientation::Collinear
+        }) {
+            result.push((piece_start, point.clone()));
+            piece_start = point.clone();
+        }
+        result.push((piece_start, max_endpoint));
+    }
+    result
+}
+
 fn to_angle_containing_constraint_base<Endpoint: PartialEq>(
     mesh: &Mesh<Endpoint>,
     mut edge: QuadEdge,
@@ -777,6 +1119,50 @@ fn to_constraint_index(
     }
 }
 
+/// Splits segments at their intersections,
+/// returns pieces between consecutive split points.
+fn to_pieces<Scalar: Clone + Ord>(
+    segments: &Vec<Segment<Scalar>>,
+) -> Vec<(Point<Scalar>, Point<Scalar>)>
+where
+    Sweep<Point<Scalar>>: for<'a> From<&'a Vec<Segment<Scalar>>>
+        + Iterator<Item = Intersection<Point<Scalar>>>,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    if segments.is_empty() {
+        return Vec::new();
+    }
+    split_segments(segments)
+        .into_iter()
+        .flat_map(|points| {
+            points
+                .windows(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Returns pairs of indices of pieces' endpoints
+/// in sorted unique endpoints with their multiplicities.
+fn to_pieces_indices_multiplicities<Endpoint: Ord>(
+    endpoints: &[Endpoint],
+    pieces: &[(Endpoint, Endpoint)],
+) -> BTreeMap<(usize, usize), usize> {
+    let mut result = BTreeMap::new();
+    for (start, end) in pieces {
+        let start_index =
+            unsafe { endpoints.binary_search(start).unwrap_unchecked() };
+        let end_index =
+            unsafe { endpoints.binary_search(end).unwrap_unchecked() };
+        debug_assert_ne!(start_index, end_index);
+        *result
+            .entry((start_index.min(end_index), start_index.max(end_index)))
+            .or_insert(0) += 1;
+    }
+    result
+}
+
 fn to_contours_constraints_flags<Endpoint>(
     mesh: &Mesh<Endpoint>,
     contours_sizes: &[usize],
@@ -812,3 +1198,61 @@ fn to_contours_constraints_flags<Endpoint>(
     }
     are_constraints_satisfied
 }
+
+/// Returns the edge with the same start as the given one
+/// which ends at the endpoint with the given index if there is any.
+fn to_edge_with_end<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+    end_index: usize,
+) -> Option<QuadEdge> {
+    let mut candidate = edge;
+    loop {
+        if mesh.to_end_index(candidate) == end_index {
+            return Some(candidate);
+        }
+        candidate = mesh.to_left_from_start(candidate);
+        if candidate == edge {
+            return None;
+        }
+    }
+}
+
+fn to_triangular_holes_edges<Endpoint: PartialOrd>(
+    mesh: &Mesh<Endpoint>,
+    polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
+    triangular_holes_indices: &[usize],
+) -> Vec<QuadEdge>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    if triangular_holes_indices.is_empty() {
+        return Vec::new();
+    }
+    let mut result = mesh
+        .to_triangles_base_edges()
+        .filter(|&edge| {
+            are_triangular_hole_vertices(
+                &polygon_vertices_positions[mesh.to_start_index(edge)],
+                &polygon_vertices_positions[mesh.to_end_index(edge)],
+                &polygon_vertices_positions
+                    [mesh.to_end_index(mesh.to_left_from_start(edge))],
+                triangular_holes_indices,
+            )
+        })
+        .flat_map(|base_edge| {
+            let next_edge = mesh.to_left_from_end(base_edge);
+            [base_edge, next_edge, mesh.to_left_from_end(next_edge)]
+        })
+        .collect::<Vec<_>>();
+    result.sort_unstable();
+    result
+}
+
+fn to_vertices_edges<Endpoint>(mesh: &Mesh<Endpoint>) -> Vec<QuadEdge> {
+    let mut result = vec![UNDEFINED_QUAD_EDGE; mesh.get_endpoints().len()];
+    for edge in mesh.iter_edges() {
+        result[mesh.to_start_index(edge)] = edge;
+    }
+    result
+}
@@ -1,12 +1,26 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::Signed;
+
+use crate::bounded::Box;
 use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
-use crate::operations::{shrink_collinear_vertices, Orient};
+use crate::geometries::Point;
+use crate::operations::{
+    shrink_collinear_vertices, LocatePointInPointPointPointCircle, Orient,
+};
+use crate::oriented::Orientation;
+use crate::traits::Elemental;
 
-use super::mesh::Mesh;
+use super::indexed_mesh::IndexedMesh;
+use super::mesh::{EndpointLocation, Mesh};
 use super::operations::{BoundaryEndpoints, DelaunayTriangulatable};
-use super::quad_edge::{QuadEdge, UNDEFINED_QUAD_EDGE};
+use super::point_location::PointLocation;
+use super::quad_edge::{to_opposite_edge, QuadEdge, UNDEFINED_QUAD_EDGE};
+use super::voronoi::VoronoiDiagram;
 
+/// Delaunay triangulation of a set of points.
 #[derive(Clone)]
-pub(crate) struct DelaunayTriangulation<Endpoint> {
+pub struct DelaunayTriangulation<Endpoint> {
     left_side: QuadEdge,
     mesh: Mesh<Endpoint>,
     right_side: QuadEdge,
@@ -41,6 +55,7 @@ impl<Endpoint: Ord> From<Vec<Endpoint>> for DelaunayTriangulation<Endpoint>
 where
     Mesh<Endpoint>: DelaunayTriangulatable,
 {
+    /// Triangulates given points, duplicates are ignored.
     fn from(mut endpoints: Vec<Endpoint>) -> Self {
         endpoints.sort();
         endpoints.dedup();
@@ -55,7 +70,15 @@ where
 }
 
 impl<Endpoint> DelaunayTriangulation<Endpoint> {
-    pub(crate) fn is_empty(&self) -> bool {
+    /// Returns unique endpoints of the triangulation,
+    /// indices of which are used by point location.
+    pub fn get_endpoints(&self) -> &[Endpoint] {
+        self.mesh.get_endpoints()
+    }
+
+    /// Checks if the triangulation has no endpoints.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
         let result = self.mesh.is_empty();
         debug_assert_eq!(self.left_side == UNDEFINED_QUAD_EDGE, result);
         debug_assert_eq!(self.right_side == UNDEFINED_QUAD_EDGE, result);
@@ -63,15 +86,227 @@ impl<Endpoint> DelaunayTriangulation<Endpoint> {
     }
 }
 
+impl<Endpoint: Clone + Ord> DelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    /// Inserts the endpoint preserving the Delaunay property,
+    /// returns `false` if the endpoint is already present.
+    pub fn insert(&mut self, endpoint: Endpoint) -> bool {
+        if self.is_degenerate() {
+            if self.mesh.get_endpoints().contains(&endpoint) {
+                return false;
+            }
+            if self.mesh.get_endpoints().len() < 2
+                || self.mesh.orient_point_to_edge(self.left_side, &endpoint)
+                    == Orientation::Collinear
+            {
+                self.rebuild(|endpoints| endpoints.push(endpoint));
+            } else {
+                self.insert_exterior(endpoint);
+            }
+            return true;
+        }
+        match self.mesh.locate_endpoint(self.left_side, &endpoint) {
+            EndpointLocation::Boundary(edge) => {
+                let endpoint_index = self.mesh.push_endpoint(endpoint);
+                if self.mesh.is_triangle_face(to_opposite_edge(edge)) {
+                    let side = self.mesh.to_right_from_start(edge);
+                    self.mesh.delete_edge(edge);
+                    let edges =
+                        self.mesh.insert_into_face(side, endpoint_index);
+                    self.mesh.legalize(edges);
+                } else {
+                    let start_index = self.mesh.to_start_index(edge);
+                    let next_edge = self.mesh.to_left_from_end(edge);
+                    self.mesh.delete_edge(edge);
+                    let (last_edge, edges) = self.mesh.insert_into_open_face(
+                        next_edge,
+                        endpoint_index,
+                        start_index,
+                    );
+                    self.mesh.legalize(edges);
+                    self.update_sides(last_edge);
+                }
+            }
+            EndpointLocation::Exterior => self.insert_exterior(endpoint),
+            EndpointLocation::Interior(edge) => {
+                let endpoint_index = self.mesh.push_endpoint(endpoint);
+                let edges = self.mesh.insert_into_face(edge, endpoint_index);
+                self.mesh.legalize(edges);
+            }
+            EndpointLocation::Vertex(_) => return false,
+        }
+        true
+    }
+
+    /// Removes the endpoint preserving the Delaunay property,
+    /// returns `false` if the endpoint is not present.
+    pub fn remove(&mut self, endpoint: &Endpoint) -> bool {
+        if self.is_degenerate() {
+            match self
+                .mesh
+                .get_endpoints()
+                .iter()
+                .position(|candidate| candidate == endpoint)
+            {
+                Some(index) => {
+                    self.rebuild(|endpoints| {
+                        endpoints.swap_remove(index);
+                    });
+                    true
+                }
+                None => false,
+            }
+        } else if let EndpointLocation::Vertex(edge) =
+            self.mesh.locate_endpoint(self.left_side, endpoint)
+        {
+            let endpoint_index = self.mesh.to_start_index(edge);
+            let maybe_outer_edge = self.mesh.remove_vertex(edge);
+            self.mesh.swap_remove_endpoint(endpoint_index);
+            if let Some(outer_edge) = maybe_outer_edge {
+                self.update_sides(to_opposite_edge(outer_edge));
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn insert_exterior(&mut self, endpoint: Endpoint) {
+        let boundary_edges = self.to_boundary_edges();
+        let are_visible = boundary_edges
+            .iter()
+            .map(|&edge| {
+                self.mesh.orient_point_to_edge(edge, &endpoint)
+                    == Orientation::Clockwise
+            })
+            .collect::<Vec<_>>();
+        let edges_count = boundary_edges.len();
+        let first_position = unsafe {
+            (0..edges_count)
+                .find(|&position| {
+                    are_visible[position]
+                        && !are_visible
+                            [(position + edges_count - 1) % edges_count]
+                })
+                .unwrap_unchecked()
+        };
+        let mut last_position = first_position;
+        while are_visible[(last_position + 1) % edges_count] {
+            last_position = (last_position + 1) % edges_count;
+        }
+        let stop_index =
+            self.mesh.to_start_index(boundary_edges[first_position]);
+        let endpoint_index = self.mesh.push_endpoint(endpoint);
+        let (last_edge, edges) = self.mesh.insert_into_open_face(
+            to_opposite_edge(boundary_edges[last_position]),
+            endpoint_index,
+            stop_index,
+        );
+        self.mesh.legalize(edges);
+        self.update_sides(last_edge);
+    }
+
+    fn rebuild(&mut self, update: impl FnOnce(&mut Vec<Endpoint>)) {
+        let mut endpoints =
+            std::mem::replace(&mut self.mesh, Mesh::from(Vec::new()))
+                .into_endpoints();
+        update(&mut endpoints);
+        *self = Self::from(endpoints);
+    }
+
+    fn to_boundary_edges(&self) -> Vec<QuadEdge> {
+        let mut result = vec![self.left_side];
+        let mut edge = self.mesh.to_right_from_end(self.left_side);
+        while edge != self.left_side {
+            result.push(edge);
+            edge = self.mesh.to_right_from_end(edge);
+        }
+        result
+    }
+
+    /// Sets sides to the counterclockwise boundary edge
+    /// from the minimal endpoint
+    /// and to the clockwise boundary edge from the maximal endpoint
+    /// by walking the boundary starting from the given edge.
+    fn update_sides(&mut self, boundary_edge: QuadEdge) {
+        let mut left_side = boundary_edge;
+        let mut right_side = to_opposite_edge(boundary_edge);
+        let mut edge = self.mesh.to_right_from_end(boundary_edge);
+        while edge != boundary_edge {
+            if self.mesh.get_start(edge) < self.mesh.get_start(left_side) {
+                left_side = edge;
+            }
+            if self.mesh.get_end(edge) > self.mesh.get_start(right_side) {
+                right_side = to_opposite_edge(edge);
+            }
+            edge = self.mesh.to_right_from_end(edge);
+        }
+        self.left_side = left_side;
+        self.right_side = right_side;
+    }
+}
+
 impl<Endpoint: PartialOrd> DelaunayTriangulation<Endpoint>
 where
     for<'a> &'a Endpoint: Orient,
 {
-    pub(crate) fn iter_triangles_vertices(
+    /// Iterates over vertices of triangles in counterclockwise order.
+    pub fn iter_triangles_vertices(
         &self,
     ) -> impl Iterator<Item = (&Endpoint, &Endpoint, &Endpoint)> {
         self.mesh.to_triangles_base_edges().map(move |base_edge| {
             self.mesh.triangle_base_to_vertices(base_edge)
         })
     }
+
+    /// Locates the point in the triangulation.
+    pub fn locate_point(&self, point: &Endpoint) -> PointLocation {
+        if self.is_degenerate() {
+            self.mesh.locate_point_by_scan(point, |_| false)
+        } else {
+            self.mesh.to_point_location(
+                self.mesh.locate_endpoint(self.left_side, point),
+            )
+        }
+    }
+
+    fn is_degenerate(&self) -> bool {
+        self.mesh.get_endpoints().len() < MIN_CONTOUR_VERTICES_COUNT
+            || !self.mesh.is_triangle_face(self.left_side)
+    }
+}
+
+impl<Endpoint: Clone + PartialOrd> DelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    /// Builds indexed representation of the triangulation.
+    pub fn to_indexed_mesh(&self) -> IndexedMesh<Endpoint> {
+        IndexedMesh::from_mesh(
+            &self.mesh,
+            self.mesh.to_triangles_base_edges(),
+            &[],
+        )
+    }
+}
+
+impl<Scalar: Clone + Ord> DelaunayTriangulation<Point<Scalar>>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar> + Orient,
+{
+    /// Builds Voronoi diagram of the triangulation's endpoints
+    /// with cells clipped to the given box.
+    pub fn to_voronoi(
+        &self,
+        bounding_box: &Box<Scalar>,
+    ) -> VoronoiDiagram<Scalar> {
+        VoronoiDiagram::from_mesh(&self.mesh, bounding_box)
+    }
 }
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+use super::mesh::Mesh;
+use super::quad_edge::{to_opposite_edge, to_unique_edge, QuadEdge};
+
+/// Indexed representation of a triangulation
+/// with triangles given by indices of the unique vertices.
+#[derive(Clone)]
+pub struct IndexedMesh<Endpoint> {
+    constrained_flags: Vec<[bool; 3]>,
+    neighbours: Vec<[Option<usize>; 3]>,
+    triangles: Vec<[usize; 3]>,
+    vertices: Vec<Endpoint>,
+}
+
+impl<Endpoint> IndexedMesh<Endpoint> {
+    /// Returns flags of triangles' edges
+    /// which lie on the border of the triangulation or are its constraints
+    /// (in the same order as the triangles' edges).
+    pub fn get_constrained_flags(&self) -> &[[bool; 3]] {
+        &self.constrained_flags
+    }
+
+    /// Returns indices of triangles adjacent to triangles' edges,
+    /// where `i`-th edge of a triangle goes from its `i`-th vertex
+    /// to the next one.
+    pub fn get_neighbours(&self) -> &[[Option<usize>; 3]] {
+        &self.neighbours
+    }
+
+    /// Returns indices of triangles' vertices in counterclockwise order
+    /// starting from the least one.
+    pub fn get_triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Returns unique vertices of the triangulation.
+    pub fn get_vertices(&self) -> &[Endpoint] {
+        &self.vertices
+    }
+}
+
+impl<Endpoint: Clone + PartialOrd> IndexedMesh<Endpoint> {
+    pub(super) fn from_mesh(
+        mesh: &Mesh<Endpoint>,
+        triangles_base_edges: impl Iterator<Item = QuadEdge>,
+        constraints: &[QuadEdge],
+    ) -> Self {
+        let triangles_base_edges = triangles_base_edges.collect::<Vec<_>>();
+        let triangles_indices = triangles_base_edges
+            .iter()
+            .enumerate()
+            .map(|(triangle_index, &base_edge)| (base_edge, triangle_index))
+            .collect::<BTreeMap<_, _>>();
+        let mut constrained_flags =
+            Vec::with_capacity(triangles_base_edges.len());
+        let mut neighbours = Vec::with_capacity(triangles_base_edges.len());
+        let mut triangles = Vec::with_capacity(triangles_base_edges.len());
+        for base_edge in triangles_base_edges {
+            let next_edge = mesh.to_left_from_end(base_edge);
+            let triangle_edges =
+                [base_edge, next_edge, mesh.to_left_from_end(next_edge)];
+            let triangle_neighbours = triangle_edges.map(|edge| {
+                to_triangle_base_edge(mesh, to_opposite_edge(edge)).and_then(
+                    |base_edge| triangles_indices.get(&base_edge).copied(),
+                )
+            });
+            constrained_flags.push([0, 1, 2].map(|index| {
+                triangle_neighbours[index].is_none()
+                    || constraints
+                        .binary_search(&to_unique_edge(triangle_edges[index]))
+                        .is_ok()
+            }));
+            neighbours.push(triangle_neighbours);
+            triangles
+                .push(triangle_edges.map(|edge| mesh.to_start_index(edge)));
+        }
+        Self {
+            constrained_flags,
+            neighbours,
+            triangles,
+            vertices: mesh.get_endpoints().to_vec(),
+        }
+    }
+}
+
+/// Returns the edge from the least vertex of the left face of the edge
+/// if the face is a triangle.
+fn to_triangle_base_edge<Endpoint: PartialOrd>(
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+) -> Option<QuadEdge> {
+    let next_edge = mesh.to_left_from_end(edge);
+    let last_edge = mesh.to_left_from_end(next_edge);
+    if mesh.to_left_from_end(last_edge) != edge {
+        return None;
+    }
+    let mut result = edge;
+    for candidate in [next_edge, last_edge] {
+        if mesh.get_start(candidate) < mesh.get_start(result) {
+            result = candidate;
+        }
+    }
+    Some(result)
+}
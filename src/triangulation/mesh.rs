@@ -7,10 +7,25 @@ use crate::operations::{
 use crate::oriented::Orientation;
 
 use super::operations::DelaunayTriangulatable;
+use super::point_location::PointLocation;
 use super::quad_edge::{
     to_opposite_edge, to_rotated_edge, QuadEdge, UNDEFINED_QUAD_EDGE,
 };
 
+const UNDEFINED_INDEX: usize = usize::MAX;
+
+/// Location of an endpoint relative to the mesh.
+pub(super) enum EndpointLocation {
+    /// Lies inside of the edge which left face is a triangle.
+    Boundary(QuadEdge),
+    /// Lies outside of the convex hull.
+    Exterior,
+    /// Lies strictly inside of the left face of the edge.
+    Interior(QuadEdge),
+    /// Coincides with the start of the edge.
+    Vertex(QuadEdge),
+}
+
 #[derive(Clone)]
 pub(super) struct Mesh<Endpoint> {
     endpoints: Vec<Endpoint>,
@@ -42,6 +57,10 @@ impl<Endpoint> Mesh<Endpoint> {
         &self.endpoints[self.to_start_index(to_opposite_edge(edge))]
     }
 
+    pub(super) fn into_endpoints(self) -> Vec<Endpoint> {
+        self.endpoints
+    }
+
     pub(super) fn is_empty(&self) -> bool {
         self.left_from_start.is_empty()
     }
@@ -71,6 +90,16 @@ impl<Endpoint> Mesh<Endpoint> {
         result
     }
 
+    /// Returns indices of adjacent endpoints for each endpoint.
+    pub(super) fn to_endpoints_neighbours(&self) -> Vec<Vec<usize>> {
+        let mut result = vec![Vec::new(); self.endpoints.len()];
+        for edge in self.iter_unique_edges() {
+            result[self.to_start_index(edge)].push(self.to_end_index(edge));
+            result[self.to_end_index(edge)].push(self.to_start_index(edge));
+        }
+        result
+    }
+
     pub(super) fn to_left_from_start(&self, edge: QuadEdge) -> QuadEdge {
         self.left_from_start[edge]
     }
@@ -110,7 +139,7 @@ impl<Endpoint> Mesh<Endpoint> {
     }
 
     fn is_deleted_edge(&self, edge: QuadEdge) -> bool {
-        self.to_left_from_start(edge) == edge
+        self.to_start_index(edge) == UNDEFINED_INDEX
     }
 }
 
@@ -154,6 +183,61 @@ impl<Endpoint> Mesh<Endpoint> {
             opposite_edge,
             self.to_right_from_start(opposite_edge),
         );
+        self.starts_indices[edge / 2] = UNDEFINED_INDEX;
+        self.starts_indices[opposite_edge / 2] = UNDEFINED_INDEX;
+    }
+
+    /// Connects the endpoint with vertices of the left face of the edge
+    /// which should be a star-shaped polygon containing the endpoint,
+    /// returns edges of the face.
+    pub(super) fn insert_into_face(
+        &mut self,
+        mut edge: QuadEdge,
+        endpoint_index: usize,
+    ) -> Vec<QuadEdge> {
+        let first =
+            self.create_edge(self.to_start_index(edge), endpoint_index);
+        self.splice_edges(first, edge);
+        let mut base = first;
+        let mut result = Vec::new();
+        loop {
+            base = self.connect_edges(edge, to_opposite_edge(base));
+            result.push(edge);
+            edge = self.to_right_from_start(base);
+            if self.to_left_from_end(edge) == first {
+                result.push(edge);
+                break result;
+            }
+        }
+    }
+
+    /// Connects the endpoint with vertices of the left face of the edge
+    /// up to the vertex with the given index,
+    /// returns the last connecting edge along with passed edges of the face.
+    pub(super) fn insert_into_open_face(
+        &mut self,
+        mut edge: QuadEdge,
+        endpoint_index: usize,
+        stop_index: usize,
+    ) -> (QuadEdge, Vec<QuadEdge>) {
+        let first =
+            self.create_edge(self.to_start_index(edge), endpoint_index);
+        self.splice_edges(first, edge);
+        let mut base = first;
+        let mut passed_edges = Vec::new();
+        loop {
+            base = self.connect_edges(edge, to_opposite_edge(base));
+            passed_edges.push(edge);
+            if self.to_end_index(edge) == stop_index {
+                break (base, passed_edges);
+            }
+            edge = self.to_right_from_start(base);
+        }
+    }
+
+    pub(super) fn push_endpoint(&mut self, endpoint: Endpoint) -> usize {
+        self.endpoints.push(endpoint);
+        self.endpoints.len() - 1
     }
 
     pub(super) fn splice_edges(&mut self, first: QuadEdge, second: QuadEdge) {
@@ -180,6 +264,21 @@ impl<Endpoint> Mesh<Endpoint> {
         self.starts_indices[edge / 2] = self.to_end_index(side);
         self.starts_indices[opposite / 2] = self.to_end_index(opposite_side);
     }
+
+    /// Removes endpoint with the given index which has no edges
+    /// by replacing it with the last one.
+    pub(super) fn swap_remove_endpoint(&mut self, index: usize) -> Endpoint {
+        let last_index = self.endpoints.len() - 1;
+        if index != last_index {
+            for start_index in &mut self.starts_indices {
+                debug_assert_ne!(*start_index, index);
+                if *start_index == last_index {
+                    *start_index = index;
+                }
+            }
+        }
+        self.endpoints.swap_remove(index)
+    }
 }
 
 impl<Endpoint> Mesh<Endpoint> {
@@ -322,7 +421,6 @@ where
     ) -> (QuadEdge, QuadEdge) {
         let (first_right_side, base_edge, second_left_side) =
             self.build_base_edge(first_right_side, second_left_side);
-        self.rise_bubble(base_edge);
         let left_side = if self.get_start(first_left_side)
             == self.get_start(first_right_side)
         {
@@ -337,6 +435,7 @@ where
         } else {
             second_right_side
         };
+        self.rise_bubble(base_edge);
         (left_side, right_side)
     }
 
@@ -429,6 +528,298 @@ where
     }
 }
 
+impl<Endpoint: PartialEq> Mesh<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    /// Checks if the left face of the edge
+    /// is a triangle with vertices in counterclockwise order.
+    pub(super) fn is_triangle_face(&self, edge: QuadEdge) -> bool {
+        let next_edge = self.to_left_from_end(edge);
+        self.to_left_from_end(self.to_left_from_end(next_edge)) == edge
+            && self.orient_point_to_edge(edge, self.get_end(next_edge))
+                == Orientation::Counterclockwise
+    }
+
+    /// Locates the endpoint by walking over triangles
+    /// starting from the left face of the given edge,
+    /// which should be a triangle.
+    pub(super) fn locate_endpoint(
+        &self,
+        edge: QuadEdge,
+        endpoint: &Endpoint,
+    ) -> EndpointLocation {
+        unsafe {
+            self.walk_to_endpoint(
+                edge,
+                endpoint,
+                |edge| self.is_triangle_face(edge),
+                usize::MAX,
+            )
+            .unwrap_unchecked()
+        }
+    }
+
+    /// Locates the endpoint by walking over inner faces
+    /// starting from the left face of the given edge,
+    /// which should be an inner triangle,
+    /// reports the endpoint as exterior if the walk leaves inner faces,
+    /// returns `None` if the walk has not finished in the given steps.
+    pub(super) fn walk_to_endpoint(
+        &self,
+        mut edge: QuadEdge,
+        endpoint: &Endpoint,
+        is_inner_face: impl Fn(QuadEdge) -> bool,
+        steps_limit: usize,
+    ) -> Option<EndpointLocation> {
+        'walk: for _ in 0..steps_limit {
+            let next_edge = self.to_left_from_end(edge);
+            let triangle_edges =
+                [edge, next_edge, self.to_left_from_end(next_edge)];
+            let mut orientations = [Orientation::Collinear; 3];
+            for (orientation, &triangle_edge) in
+                orientations.iter_mut().zip(&triangle_edges)
+            {
+                if self.get_start(triangle_edge) == endpoint {
+                    return Some(EndpointLocation::Vertex(triangle_edge));
+                }
+                *orientation =
+                    self.orient_point_to_edge(triangle_edge, endpoint);
+            }
+            for (&triangle_edge, &orientation) in
+                triangle_edges.iter().zip(&orientations)
+            {
+                if orientation == Orientation::Clockwise {
+                    let opposite_edge = to_opposite_edge(triangle_edge);
+                    if !is_inner_face(opposite_edge) {
+                        return Some(EndpointLocation::Exterior);
+                    }
+                    edge = opposite_edge;
+                    continue 'walk;
+                }
+            }
+            return Some(
+                match orientations.iter().position(|&orientation| {
+                    orientation == Orientation::Collinear
+                }) {
+                    Some(position) => {
+                        EndpointLocation::Boundary(triangle_edges[position])
+                    }
+                    None => EndpointLocation::Interior(edge),
+                },
+            );
+        }
+        None
+    }
+}
+
+impl<Endpoint: PartialOrd> Mesh<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    /// Locates the point by checking all edges & inner faces.
+    pub(super) fn locate_point_by_scan(
+        &self,
+        point: &Endpoint,
+        is_inner_face: impl Fn(QuadEdge) -> bool,
+    ) -> PointLocation {
+        if let Some(index) =
+            self.endpoints.iter().position(|endpoint| endpoint == point)
+        {
+            return PointLocation::Vertex(index);
+        }
+        if let Some(edge) = self.iter_unique_edges().find(|&edge| {
+            self.orient_point_to_edge(edge, point) == Orientation::Collinear
+                && (self.get_start(edge) < point)
+                    == (point < self.get_end(edge))
+        }) {
+            return self.to_point_location(EndpointLocation::Boundary(edge));
+        }
+        self.iter_edges()
+            .find(|&edge| {
+                is_inner_face(edge) && {
+                    let next_edge = self.to_left_from_end(edge);
+                    [edge, next_edge, self.to_left_from_end(next_edge)]
+                        .iter()
+                        .all(|&triangle_edge| {
+                            self.orient_point_to_edge(triangle_edge, point)
+                                == Orientation::Counterclockwise
+                        })
+                }
+            })
+            .map_or(PointLocation::Exterior, |edge| {
+                self.to_point_location(EndpointLocation::Interior(edge))
+            })
+    }
+
+    /// Converts the endpoint location into indices of endpoints.
+    pub(super) fn to_point_location(
+        &self,
+        location: EndpointLocation,
+    ) -> PointLocation {
+        match location {
+            EndpointLocation::Boundary(edge) => {
+                let (start_index, end_index) =
+                    (self.to_start_index(edge), self.to_end_index(edge));
+                if self.endpoints[start_index] < self.endpoints[end_index] {
+                    PointLocation::Edge(start_index, end_index)
+                } else {
+                    PointLocation::Edge(end_index, start_index)
+                }
+            }
+            EndpointLocation::Exterior => PointLocation::Exterior,
+            EndpointLocation::Interior(edge) => {
+                let next_edge = self.to_left_from_end(edge);
+                let (first, second, third) = (
+                    self.to_start_index(edge),
+                    self.to_start_index(next_edge),
+                    self.to_end_index(next_edge),
+                );
+                let endpoints = &self.endpoints;
+                if endpoints[first] < endpoints[second]
+                    && endpoints[first] < endpoints[third]
+                {
+                    PointLocation::Triangle(first, second, third)
+                } else if endpoints[second] < endpoints[third] {
+                    PointLocation::Triangle(second, third, first)
+                } else {
+                    PointLocation::Triangle(third, first, second)
+                }
+            }
+            EndpointLocation::Vertex(edge) => {
+                PointLocation::Vertex(self.to_start_index(edge))
+            }
+        }
+    }
+}
+
+impl<Endpoint: PartialEq> Mesh<Endpoint>
+where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    /// Restores the Delaunay property by flipping edges
+    /// starting from the given ones
+    /// which have inserted endpoint as the opposite vertex of the left face.
+    pub(super) fn legalize(&mut self, mut edges: Vec<QuadEdge>) {
+        while let Some(edge) = edges.pop() {
+            let opposite_edge = to_opposite_edge(edge);
+            if !self.is_triangle_face(opposite_edge) {
+                continue;
+            }
+            let side = self.to_left_from_end(opposite_edge);
+            let other_side = self.to_left_from_end(side);
+            if self.get_end(side).locate_point_in_point_point_point_circle(
+                self.get_start(edge),
+                self.get_end(edge),
+                self.get_end(self.to_left_from_end(edge)),
+            ) == Location::Interior
+            {
+                self.swap_diagonal(edge);
+                edges.push(side);
+                edges.push(other_side);
+            }
+        }
+    }
+
+    /// Deletes edges from the start of the given edge
+    /// and triangulates the resulting hole,
+    /// returns an edge of the remaining outer face
+    /// if the vertex was on the boundary.
+    pub(super) fn remove_vertex(
+        &mut self,
+        edge: QuadEdge,
+    ) -> Option<QuadEdge> {
+        let mut star = vec![edge];
+        let mut cursor = self.to_left_from_start(edge);
+        while cursor != edge {
+            star.push(cursor);
+            cursor = self.to_left_from_start(cursor);
+        }
+        let maybe_gap_position = star
+            .iter()
+            .position(|&star_edge| !self.is_triangle_face(star_edge));
+        let hole_edges = match maybe_gap_position {
+            Some(gap_position) => (1..star.len())
+                .map(|offset| {
+                    self.to_left_from_end(
+                        star[(gap_position + offset) % star.len()],
+                    )
+                })
+                .collect::<Vec<_>>(),
+            None => star
+                .iter()
+                .map(|&star_edge| self.to_left_from_end(star_edge))
+                .collect(),
+        };
+        for star_edge in star {
+            self.delete_edge(star_edge);
+        }
+        let is_closed = maybe_gap_position.is_none();
+        let hole_edges = self.fill_hole(hole_edges, is_closed);
+        if is_closed {
+            None
+        } else {
+            hole_edges.first().copied()
+        }
+    }
+
+    /// Triangulates hole bounded by the given edges
+    /// by cutting off ears with empty circumcircles,
+    /// returns remaining edges.
+    fn fill_hole(
+        &mut self,
+        mut edges: Vec<QuadEdge>,
+        is_closed: bool,
+    ) -> Vec<QuadEdge> {
+        while !is_closed || edges.len() > 3 {
+            let candidates_count = if is_closed {
+                edges.len()
+            } else {
+                edges.len() - 1
+            };
+            match (0..candidates_count)
+                .find(|&position| self.is_delaunay_ear(&edges, position))
+            {
+                Some(position) => {
+                    let next_position = (position + 1) % edges.len();
+                    let diagonal = self
+                        .connect_edges(edges[next_position], edges[position]);
+                    edges[position] = to_opposite_edge(diagonal);
+                    edges.remove(next_position);
+                }
+                None => break,
+            }
+        }
+        edges
+    }
+
+    fn is_delaunay_ear(&self, edges: &[QuadEdge], position: usize) -> bool {
+        let next_position = (position + 1) % edges.len();
+        let (first, second, third) = (
+            self.get_start(edges[position]),
+            self.get_end(edges[position]),
+            self.get_end(edges[next_position]),
+        );
+        first.orient(second, third) == Orientation::Counterclockwise
+            && edges
+                .iter()
+                .map(|&edge| self.get_end(edge))
+                .filter(|&vertex| {
+                    vertex != first && vertex != second && vertex != third
+                })
+                .chain(edges.first().map(|&edge| self.get_start(edge)).filter(
+                    |&vertex| {
+                        vertex != first && vertex != second && vertex != third
+                    },
+                ))
+                .all(|vertex| {
+                    vertex.locate_point_in_point_point_point_circle(
+                        first, second, third,
+                    ) != Location::Interior
+                })
+    }
+}
+
 impl<Endpoint: Clone + Ord> DelaunayTriangulatable for Mesh<Endpoint>
 where
     for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
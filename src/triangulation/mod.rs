@@ -1,10 +1,106 @@
-pub(crate) use self::constrained_delaunay::ConstrainedDelaunayTriangulation;
-pub(crate) use self::delaunay::DelaunayTriangulation;
-pub(crate) use self::operations::BoundaryEndpoints;
+//! Exact Delaunay & constrained Delaunay triangulations.
+//!
+//! Both triangulations are generic over endpoint type
+//! and are usually built for `rene::geometries::Point`s,
+//! e.g. `DelaunayTriangulation::from(points)`
+//! or `ConstrainedDelaunayTriangulation::from(&polygon)`,
+//! constrained triangulation of points with a multisegment is built
+//! by `FromPointsAndMultisegment::from_points_and_multisegment`.
+//!
+//! ```
+//! use rene::geometries::{Contour, Multisegment, Point, Polygon, Segment};
+//! use rene::triangulation::{
+//!     ConstrainedDelaunayTriangulation, DelaunayTriangulation,
+//!     FromPointsAndMultisegment, Orient, PointLocation,
+//! };
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//!
+//! let to_scalar = |value: i32| Scalar::from(BigInt::from(value));
+//! let to_point = |x: i32, y: i32| Point::new(to_scalar(x), to_scalar(y));
+//! let square_vertices =
+//!     vec![to_point(0, 0), to_point(4, 0), to_point(4, 4), to_point(0, 4)];
+//!
+//! fn count_triangles<Endpoint: PartialOrd>(
+//!     triangulation: &DelaunayTriangulation<Endpoint>,
+//! ) -> usize
+//! where
+//!     for<'a> &'a Endpoint: Orient,
+//! {
+//!     triangulation.iter_triangles_vertices().count()
+//! }
+//!
+//! let triangulation = DelaunayTriangulation::from(square_vertices.clone());
+//! assert_eq!(count_triangles(&triangulation), 2);
+//! assert_eq!(
+//!     triangulation.locate_point(&to_point(0, 0)),
+//!     PointLocation::Vertex(0)
+//! );
+//! assert_eq!(
+//!     triangulation.locate_point(&to_point(5, 5)),
+//!     PointLocation::Exterior
+//! );
+//!
+//! let square = Polygon::new(Contour::new(square_vertices), vec![]);
+//! let triangulation =
+//!     ConstrainedDelaunayTriangulation::<Point<Scalar>>::from(&square);
+//! assert_eq!(
+//!     triangulation.iter_triangles_vertices().collect::<Vec<_>>(),
+//!     [
+//!         (&to_point(0, 0), &to_point(4, 0), &to_point(0, 4)),
+//!         (&to_point(0, 4), &to_point(4, 0), &to_point(4, 4)),
+//!     ]
+//! );
+//! assert_eq!(
+//!     triangulation.locate_point(&to_point(2, 2)),
+//!     PointLocation::Edge(1, 2)
+//! );
+//!
+//! // diagonals are split at their crossing & at the point lying on them
+//! let diagonals = Multisegment::new(vec![
+//!     Segment::new(to_point(0, 0), to_point(4, 4)),
+//!     Segment::new(to_point(0, 4), to_point(4, 0)),
+//! ]);
+//! let triangulation: ConstrainedDelaunayTriangulation<_> =
+//!     FromPointsAndMultisegment::from_points_and_multisegment(
+//!         vec![to_point(1, 1)],
+//!         &diagonals,
+//!     );
+//! assert_eq!(
+//!     triangulation.get_endpoints(),
+//!     [
+//!         to_point(0, 0),
+//!         to_point(0, 4),
+//!         to_point(1, 1),
+//!         to_point(2, 2),
+//!         to_point(4, 0),
+//!         to_point(4, 4),
+//!     ]
+//! );
+//! assert_eq!(
+//!     triangulation.locate_point(&to_point(3, 3)),
+//!     PointLocation::Edge(3, 5)
+//! );
+//! ```
+
+pub use crate::operations::{LocatePointInPointPointPointCircle, Orient};
+
+pub use self::constrained_delaunay::ConstrainedDelaunayTriangulation;
+pub use self::delaunay::DelaunayTriangulation;
+pub use self::indexed_mesh::IndexedMesh;
+pub use self::operations::{BoundaryEndpoints, FromPointsAndMultisegment};
+pub use self::point_location::PointLocation;
 pub(crate) use self::quad_edge::QuadEdge;
+pub use self::voronoi::VoronoiDiagram;
 
 mod constrained_delaunay;
 mod delaunay;
+mod indexed_mesh;
 mod mesh;
 mod operations;
+mod point_location;
 mod quad_edge;
+mod to_svg;
+mod voronoi;
@@ -1,9 +1,23 @@
 use crate::triangulation::QuadEdge;
 
-pub(crate) trait BoundaryEndpoints<Endpoint> {
+pub trait BoundaryEndpoints<Endpoint> {
+    /// Returns endpoints of the triangulation's border
+    /// in counterclockwise order without collinear ones,
+    /// degenerate triangulations (with less than 3 endpoints)
+    /// return all of their endpoints.
     fn get_boundary_endpoints(&self) -> Vec<&Endpoint>;
 }
 
 pub(super) trait DelaunayTriangulatable {
     fn delaunay_triangulation(&mut self) -> (QuadEdge, QuadEdge);
 }
+
+pub trait FromPointsAndMultisegment<Point, Multisegment> {
+    /// Triangulates given points along with a planar straight-line graph
+    /// given by the multisegment constraining its segments,
+    /// segments are split at their intersections & at points lying on them.
+    fn from_points_and_multisegment(
+        points: Vec<Point>,
+        multisegment: &Multisegment,
+    ) -> Self;
+}
@@ -0,0 +1,15 @@
+/// Location of a point relative to a triangulation
+/// in terms of indices of the triangulation's endpoints.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PointLocation {
+    /// point lies inside of the edge with given endpoints
+    /// (starting from the least one)
+    Edge(usize, usize),
+    /// point lies outside of the triangulation
+    Exterior,
+    /// point lies strictly inside of the triangle with given vertices
+    /// (in counterclockwise order starting from the least one)
+    Triangle(usize, usize, usize),
+    /// point coincides with the given endpoint
+    Vertex(usize),
+}
@@ -9,3 +9,7 @@ pub(super) fn to_opposite_edge(edge: QuadEdge) -> QuadEdge {
 pub(super) fn to_rotated_edge(edge: QuadEdge) -> QuadEdge {
     ((edge >> 2) << 2) + ((edge + 1) & 3)
 }
+
+pub(super) fn to_unique_edge(edge: QuadEdge) -> QuadEdge {
+    (edge >> 2) << 2
+}
@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use crate::geometries::Point;
+use crate::operations::Orient;
+use crate::svg::{
+    coordinates_to_bounding_box, to_coordinates, to_path_data, Canvas, Error,
+    Polyline, Style, ToSvg,
+};
+
+use super::constrained_delaunay::ConstrainedDelaunayTriangulation;
+use super::delaunay::DelaunayTriangulation;
+
+impl<Scalar> ToSvg for &ConstrainedDelaunayTriangulation<Point<Scalar>>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    Point<Scalar>: PartialOrd,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        triangles_to_svg(self.iter_triangles_vertices(), style)
+    }
+}
+
+impl<Scalar> ToSvg for &DelaunayTriangulation<Point<Scalar>>
+where
+    for<'a> f64: TryFrom<&'a Scalar>,
+    Point<Scalar>: PartialOrd,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn to_svg(self, style: &Style) -> Result<String, Error> {
+        triangles_to_svg(self.iter_triangles_vertices(), style)
+    }
+}
+
+fn triangles_to_svg<'a, Scalar: 'a>(
+    triangles_vertices: impl Iterator<
+        Item = (&'a Point<Scalar>, &'a Point<Scalar>, &'a Point<Scalar>),
+    >,
+    style: &Style,
+) -> Result<String, Error>
+where
+    for<'b> f64: TryFrom<&'b Scalar>,
+{
+    let polylines = triangles_vertices
+        .map(|(first, second, third)| {
+            Ok((
+                vec![
+                    to_coordinates(first)?,
+                    to_coordinates(second)?,
+                    to_coordinates(third)?,
+                ],
+                true,
+            ))
+        })
+        .collect::<Result<Vec<Polyline>, Error>>()?;
+    let mut canvas = Canvas::new(
+        coordinates_to_bounding_box(
+            polylines
+                .iter()
+                .flat_map(|(vertices, _)| vertices.iter().copied()),
+        )
+        .as_ref(),
+        style,
+    );
+    if !polylines.is_empty() {
+        canvas.add_path(&to_path_data(polylines.into_iter()), true);
+    }
+    Ok(canvas.finish())
+}
@@ -0,0 +1,209 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::{Sign, Signed};
+
+use crate::bounded::Box;
+use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
+use crate::geometries::{Contour, Point, Polygon};
+use crate::operations::{shrink_collinear_vertices, Orient};
+use crate::traits::Elemental;
+
+use super::mesh::Mesh;
+
+/// Voronoi diagram of a set of points clipped to a box.
+#[derive(Clone)]
+pub struct VoronoiDiagram<Scalar> {
+    cells: Vec<Polygon<Scalar>>,
+    sites: Vec<Point<Scalar>>,
+    vertices: Vec<Point<Scalar>>,
+}
+
+impl<Scalar> VoronoiDiagram<Scalar> {
+    /// Returns cells of the sites clipped to the box
+    /// (in the same order as the sites).
+    pub fn get_cells(&self) -> &[Polygon<Scalar>] {
+        &self.cells
+    }
+
+    /// Returns sites in ascending order,
+    /// sites which cells do not intersect interior of the box are skipped.
+    pub fn get_sites(&self) -> &[Point<Scalar>] {
+        &self.sites
+    }
+
+    /// Returns unique vertices of the diagram in ascending order,
+    /// i.e. circumcenters of the Delaunay triangles.
+    pub fn get_vertices(&self) -> &[Point<Scalar>] {
+        &self.vertices
+    }
+}
+
+impl<Scalar: Clone + Ord> VoronoiDiagram<Scalar>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar> + Orient,
+{
+    pub(super) fn from_mesh(
+        mesh: &Mesh<Point<Scalar>>,
+        bounding_box: &Box<Scalar>,
+    ) -> Self {
+        let sites = mesh.get_endpoints();
+        let mut sites_cells = Vec::with_capacity(sites.len());
+        for (site, neighbours) in
+            sites.iter().zip(mesh.to_endpoints_neighbours())
+        {
+            let (min_x, max_x, min_y, max_y) = (
+                bounding_box.get_min_x(),
+                bounding_box.get_max_x(),
+                bounding_box.get_min_y(),
+                bounding_box.get_max_y(),
+            );
+            let mut vertices = vec![
+                Point::new(min_x.clone(), min_y.clone()),
+                Point::new(max_x.clone(), min_y.clone()),
+                Point::new(max_x.clone(), max_y.clone()),
+                Point::new(min_x.clone(), max_y.clone()),
+            ];
+            for neighbour_index in neighbours {
+                vertices =
+                    clip_by_bisector(&vertices, site, &sites[neighbour_index]);
+                if vertices.is_empty() {
+                    break;
+                }
+            }
+            if vertices.len() < MIN_CONTOUR_VERTICES_COUNT {
+                continue;
+            }
+            let vertices = shrink_collinear_vertices(
+                &vertices.iter().collect::<Vec<_>>(),
+            );
+            if vertices.len() >= MIN_CONTOUR_VERTICES_COUNT {
+                sites_cells.push((
+                    site.clone(),
+                    Polygon::new(
+                        Contour::new(vertices.into_iter().cloned().collect()),
+                        vec![],
+                    ),
+                ));
+            }
+        }
+        sites_cells.sort_by(|(site, _), (other_site, _)| site.cmp(other_site));
+        let (cells_sites, cells) = sites_cells.into_iter().unzip();
+        let mut vertices = mesh
+            .to_triangles_base_edges()
+            .map(|base_edge| {
+                let (first, second, third) =
+                    mesh.triangle_base_to_vertices(base_edge);
+                to_circumcenter(first, second, third)
+            })
+            .collect::<Vec<_>>();
+        vertices.sort();
+        vertices.dedup();
+        Self {
+            cells,
+            sites: cells_sites,
+            vertices,
+        }
+    }
+}
+
+/// Clips convex polygon by the half-plane of points
+/// which are not farther from the site than from the other site.
+fn clip_by_bisector<Scalar: Clone>(
+    vertices: &[Point<Scalar>],
+    site: &Point<Scalar>,
+    other_site: &Point<Scalar>,
+) -> Vec<Point<Scalar>>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Signed
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    let values = vertices
+        .iter()
+        .map(|vertex| to_bisector_value(vertex, site, other_site))
+        .collect::<Vec<_>>();
+    let mut result = Vec::with_capacity(vertices.len() + 1);
+    for index in 0..vertices.len() {
+        let next_index = (index + 1) % vertices.len();
+        let (start, end) = (&vertices[index], &vertices[next_index]);
+        let (start_value, end_value) = (&values[index], &values[next_index]);
+        let (start_sign, end_sign) = (start_value.sign(), end_value.sign());
+        if start_sign != Sign::Positive {
+            result.push(start.clone());
+        }
+        if start_sign != Sign::Zero
+            && end_sign != Sign::Zero
+            && start_sign != end_sign
+        {
+            let scale = &(start_value / &(start_value - end_value));
+            result.push(Point::new(
+                &(&(end.x() - start.x()) * scale) + start.x(),
+                &(&(end.y() - start.y()) * scale) + start.y(),
+            ));
+        }
+    }
+    result
+}
+
+/// Returns difference between squared distances
+/// from the point to the site and to the other site,
+/// which is affine in point's coordinates.
+fn to_bisector_value<Scalar>(
+    point: &Point<Scalar>,
+    site: &Point<Scalar>,
+    other_site: &Point<Scalar>,
+) -> Scalar
+where
+    for<'a> &'a Scalar:
+        Add<Output = Scalar> + Mul<Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    let (site_dx, site_dy) = (point.x() - site.x(), point.y() - site.y());
+    let (other_site_dx, other_site_dy) =
+        (point.x() - other_site.x(), point.y() - other_site.y());
+    &(&(&site_dx * &site_dx) + &(&site_dy * &site_dy))
+        - &(&(&other_site_dx * &other_site_dx)
+            + &(&other_site_dy * &other_site_dy))
+}
+
+fn to_circumcenter<Scalar>(
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+    third: &Point<Scalar>,
+) -> Point<Scalar>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    let (second_dx, second_dy) =
+        (second.x() - first.x(), second.y() - first.y());
+    let (third_dx, third_dy) = (third.x() - first.x(), third.y() - first.y());
+    let second_squared_norm =
+        &(&second_dx * &second_dx) + &(&second_dy * &second_dy);
+    let third_squared_norm =
+        &(&third_dx * &third_dx) + &(&third_dy * &third_dy);
+    let half_denominator =
+        &(&second_dx * &third_dy) - &(&second_dy * &third_dx);
+    let denominator = &half_denominator + &half_denominator;
+    Point::new(
+        &(&(&(&third_dy * &second_squared_norm)
+            - &(&second_dy * &third_squared_norm))
+            / &denominator)
+            + first.x(),
+        &(&(&(&second_dx * &third_squared_norm)
+            - &(&third_dx * &second_squared_norm))
+            / &denominator)
+            + first.y(),
+    )
+}
@@ -0,0 +1,30 @@
+use crate::geometries::{Contour, Polygon};
+use crate::oriented::{Orientation, Oriented};
+use crate::traits::{Iterable, Multivertexal};
+
+/// Returns polygon bounded by the contour regardless of its orientation.
+pub(crate) fn to_region<Scalar: Clone>(
+    contour: &Contour<Scalar>,
+) -> Polygon<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: Oriented,
+{
+    Polygon::new(
+        to_oriented_contour(contour, Orientation::Counterclockwise),
+        vec![],
+    )
+}
+
+fn to_oriented_contour<Scalar: Clone>(
+    contour: &Contour<Scalar>,
+    orientation: Orientation,
+) -> Contour<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: Oriented,
+{
+    let mut vertices = contour.vertices().iter().cloned().collect::<Vec<_>>();
+    if contour.to_orientation() != orientation {
+        vertices.reverse();
+    }
+    Contour::new(vertices)
+}
@@ -0,0 +1,9 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FillRule {
+    /// point lies in the interior if the boundary winds around it
+    /// odd number of times
+    EvenOdd,
+    /// point lies in the interior if the boundary winds around it
+    /// non-zero number of times
+    NonZero,
+}
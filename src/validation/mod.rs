@@ -0,0 +1,51 @@
+//! Structured validity reports of geometries & repairing of invalid ones.
+//!
+//! Unlike bare validity checks, reports list every detected violation
+//! with indices of offending components & points of intersections,
+//! e.g. `(&contour).validate()` is empty for a valid contour.
+//! Polygons are valid only with counterclockwise border & clockwise holes:
+//!
+//! ```
+//! use rene::geometries::{Contour, Point, Polygon};
+//! use rene::validation::{Validatable, Violation, ViolationKind};
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//! let to_point = |x: i32, y: i32| {
+//!     Point::new(
+//!         Scalar::from(BigInt::<u32, 31>::from(x)),
+//!         Scalar::from(BigInt::<u32, 31>::from(y)),
+//!     )
+//! };
+//! let vertices = vec![to_point(0, 0), to_point(1, 0), to_point(0, 1)];
+//! let polygon = Polygon::new(Contour::new(vertices.clone()), vec![]);
+//! assert!((&polygon).is_valid());
+//! let reversed = Polygon::new(
+//!     Contour::new(vertices.into_iter().rev().collect()),
+//!     vec![],
+//! );
+//! assert!(!(&reversed).is_valid());
+//! assert!(matches!(
+//!     (&reversed).validate().as_slice(),
+//!     [Violation::WrongBorderOrientation]
+//! ));
+//! assert_eq!(
+//!     (&reversed).validate()[0].kind(),
+//!     ViolationKind::WrongBorderOrientation
+//! );
+//! ```
+
+pub(crate) use self::checks::to_region;
+pub use self::fill_rule::FillRule;
+pub(crate) use self::repairing::to_valid_polygons;
+pub use self::traits::{MakeValid, Validatable};
+pub use self::violation::Violation;
+pub use self::violation_kind::ViolationKind;
+
+mod checks;
+mod fill_rule;
+mod repairing;
+mod traits;
+mod violation;
+mod violation_kind;
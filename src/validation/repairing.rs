@@ -0,0 +1,438 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::{Add, Div};
+
+use traiter::numbers::One;
+
+use crate::bentley_ottmann::{split_segments, Intersection, Sweep};
+use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
+use crate::geometries::{Contour, Point, Polygon, Segment};
+use crate::locatable::Location;
+use crate::operations::{
+    locate_point_in_region, shrink_collinear_vertices, to_arg_min,
+    CrossMultiply, Orient,
+};
+use crate::oriented::Orientation;
+use crate::traits::{Elemental, Iterable, Multisegmental, Segmental};
+
+use super::fill_rule::FillRule;
+
+type Piece<Scalar> = ((Point<Scalar>, Point<Scalar>), isize);
+
+/// Returns polygons covering the area enclosed by the contours
+/// under the fill rule.
+pub(crate) fn to_valid_polygons<'a, Scalar>(
+    contours: impl Iterator<Item = &'a Contour<Scalar>>,
+    fill_rule: FillRule,
+) -> Vec<Polygon<Scalar>>
+where
+    Scalar:
+        'a + Add<Output = Scalar> + Clone + Div<Output = Scalar> + One + Ord,
+    Segment<Scalar>: Clone,
+    Sweep<Point<Scalar>>: for<'b> From<&'b Vec<Segment<Scalar>>>
+        + Iterator<Item = Intersection<Point<Scalar>>>,
+    for<'b> &'b Contour<Scalar>:
+        Multisegmental<IndexSegment = Segment<Scalar>>,
+    for<'b> &'b Point<Scalar>: CrossMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'b Scalar>
+        + Orient,
+    for<'b> &'b Scalar: Add<Output = Scalar>,
+    for<'b> &'b Segment<Scalar>: Segmental<Endpoint = &'b Point<Scalar>>,
+{
+    let segments = contours
+        .flat_map(|contour| {
+            contour
+                .segments()
+                .iter()
+                .filter(|segment| {
+                    let (start, end) = segment.endpoints();
+                    start != end
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut pieces_windings = BTreeMap::new();
+    for points in split_segments(&segments) {
+        for (start, end) in points.iter().zip(&points[1..]) {
+            if start < end {
+                *pieces_windings
+                    .entry((start.clone(), end.clone()))
+                    .or_insert(0isize) += 1;
+            } else {
+                *pieces_windings
+                    .entry((end.clone(), start.clone()))
+                    .or_insert(0isize) -= 1;
+            }
+        }
+    }
+    let pieces = pieces_windings
+        .into_iter()
+        .filter(|&(_, winding)| winding != 0)
+        .collect::<Vec<_>>();
+    let is_inside = |winding: isize| match fill_rule {
+        FillRule::EvenOdd => winding % 2 != 0,
+        FillRule::NonZero => winding != 0,
+    };
+    let mut edges = Vec::new();
+    for (((start, end), winding), left_winding) in
+        pieces.iter().zip(to_left_windings(&pieces))
+    {
+        let is_left_inside = is_inside(left_winding);
+        if is_left_inside != is_inside(left_winding - winding) {
+            edges.push(if is_left_inside {
+                (start.clone(), end.clone())
+            } else {
+                (end.clone(), start.clone())
+            });
+        }
+    }
+    edges_to_polygons(&edges)
+}
+
+fn edges_to_polygons<Scalar>(
+    edges: &[(Point<Scalar>, Point<Scalar>)],
+) -> Vec<Polygon<Scalar>>
+where
+    Scalar: Add<Output = Scalar> + Clone + Div<Output = Scalar> + One + Ord,
+    for<'a> &'a Contour<Scalar>:
+        Multisegmental<IndexSegment = Segment<Scalar>>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + Orient,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let mut borders = Vec::new();
+    let mut holes = Vec::new();
+    for cycle in edges_to_cycles(edges) {
+        let mut vertices =
+            cycle.iter().map(|&edge| &edges[edge].0).collect::<Vec<_>>();
+        let min_vertex_index =
+            unsafe { to_arg_min(&vertices).unwrap_unchecked() };
+        // minimal vertex is never collinear with its neighbours
+        vertices.rotate_left(min_vertex_index);
+        let vertices = shrink_collinear_vertices(&vertices)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        if vertices[vertices.len() - 1].orient(&vertices[0], &vertices[1])
+            == Orientation::Counterclockwise
+        {
+            let doubled_area = to_doubled_area(&vertices);
+            borders.push((Contour::new(vertices), doubled_area));
+        } else {
+            let (start, end) = &edges[cycle[0]];
+            holes.push((Contour::new(vertices), to_midpoint(start, end)));
+        }
+    }
+    let mut borders_holes =
+        (0..borders.len()).map(|_| Vec::new()).collect::<Vec<_>>();
+    for (hole, hole_point) in holes {
+        // hole point lies on the hole only, so the innermost border
+        // which contains it in its interior is the hole's one
+        let maybe_border_index = borders
+            .iter()
+            .enumerate()
+            .filter(|(_, (border, _))| {
+                locate_point_in_region(border, &hole_point)
+                    == Location::Interior
+            })
+            .min_by(|(_, (_, first_area)), (_, (_, second_area))| {
+                first_area.cmp(second_area)
+            })
+            .map(|(index, _)| index);
+        debug_assert!(maybe_border_index.is_some());
+        if let Some(border_index) = maybe_border_index {
+            borders_holes[border_index].push(hole);
+        }
+    }
+    borders
+        .into_iter()
+        .zip(borders_holes)
+        .map(|((border, _), holes)| Polygon::new(border, holes))
+        .collect()
+}
+
+/// Splits edges into simple cycles following for each edge
+/// the outgoing edge which is the first clockwise from it.
+fn edges_to_cycles<Point: Ord>(edges: &[(Point, Point)]) -> Vec<Vec<usize>>
+where
+    for<'a> &'a Point: Orient,
+{
+    let mut outgoing_edges = BTreeMap::<&Point, Vec<usize>>::new();
+    for (edge, (start, _)) in edges.iter().enumerate() {
+        outgoing_edges.entry(start).or_default().push(edge);
+    }
+    let mut are_edges_used = vec![false; edges.len()];
+    let mut result = Vec::new();
+    for first_edge in 0..edges.len() {
+        if are_edges_used[first_edge] {
+            continue;
+        }
+        let mut path = Vec::new();
+        let mut starts_positions = BTreeMap::<&Point, usize>::new();
+        let mut edge = first_edge;
+        loop {
+            are_edges_used[edge] = true;
+            let (start, end) = &edges[edge];
+            starts_positions.insert(start, path.len());
+            path.push(edge);
+            if let Some(&position) = starts_positions.get(end) {
+                let cycle = path.split_off(position);
+                for &cycle_edge in &cycle {
+                    starts_positions.remove(&edges[cycle_edge].0);
+                }
+                result.push(cycle);
+                if path.is_empty() {
+                    break;
+                }
+            }
+            let maybe_next_edge = outgoing_edges[end]
+                .iter()
+                .copied()
+                .filter(|&candidate| !are_edges_used[candidate])
+                .min_by(|&first_candidate, &second_candidate| {
+                    compare_clockwise_angles(
+                        end,
+                        start,
+                        &edges[first_candidate].1,
+                        &edges[second_candidate].1,
+                    )
+                });
+            debug_assert!(maybe_next_edge.is_some());
+            edge = unsafe { maybe_next_edge.unwrap_unchecked() };
+        }
+    }
+    result
+}
+
+/// Compares angles from the reference ray to the rays
+/// going through the points measured clockwise.
+fn compare_clockwise_angles<Point>(
+    vertex: &Point,
+    reference: &Point,
+    first_point: &Point,
+    second_point: &Point,
+) -> Ordering
+where
+    for<'a> &'a Point: Orient,
+{
+    let to_half = |point: &Point| {
+        // rays opposite to the reference one go first in the second half
+        vertex.orient(reference, point) != Orientation::Clockwise
+    };
+    to_half(first_point)
+        .cmp(&to_half(second_point))
+        .then_with(|| match vertex.orient(first_point, second_point) {
+            Orientation::Clockwise => Ordering::Less,
+            Orientation::Collinear => Ordering::Equal,
+            Orientation::Counterclockwise => Ordering::Greater,
+        })
+}
+
+fn to_doubled_area<Scalar: Add<Output = Scalar>>(
+    vertices: &[Point<Scalar>],
+) -> Scalar
+where
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>,
+{
+    debug_assert!(vertices.len() >= MIN_CONTOUR_VERTICES_COUNT);
+    let first_vertex = &vertices[0];
+    let result = vertices[2..]
+        .iter()
+        .zip(&vertices[1..])
+        .map(|(vertex, previous_vertex)| {
+            CrossMultiply::cross_multiply(
+                first_vertex,
+                previous_vertex,
+                first_vertex,
+                vertex,
+            )
+        })
+        .reduce(|result, value| result + value);
+    unsafe { result.unwrap_unchecked() }
+}
+
+/// Returns winding numbers of regions to the left of the pieces
+/// directed from their starts to their ends.
+///
+/// Sweeps pieces from left to right keeping windings of regions
+/// right above pieces crossing the sweep line,
+/// so the region below a piece is the one above its predecessor.
+fn to_left_windings<Scalar: Ord>(pieces: &[Piece<Scalar>]) -> Vec<isize>
+where
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar> + Orient,
+{
+    let mut ends_indices = (0..pieces.len()).collect::<Vec<_>>();
+    ends_indices.sort_by(|&first_index, &second_index| {
+        pieces[first_index].0 .1.cmp(&pieces[second_index].0 .1)
+    });
+    let mut result = vec![0isize; pieces.len()];
+    let mut sweep_line = BTreeMap::new();
+    let (mut starts_position, mut ends_position) = (0, 0);
+    while starts_position < pieces.len() {
+        let point = &pieces[starts_position].0 .0;
+        while let Some(&index) = ends_indices.get(ends_position) {
+            let (start, end) = &pieces[index].0;
+            if end > point {
+                break;
+            }
+            if start.x() != end.x() {
+                sweep_line.remove(&SweepLineKey { start, end });
+            }
+            ends_position += 1;
+        }
+        let next_starts_position = starts_position
+            + pieces[starts_position..]
+                .iter()
+                .take_while(|((start, _), _)| start == point)
+                .count();
+        // pieces are sorted by their ends as well,
+        // so the vertical one goes first if there is any
+        let mut maybe_vertical_index = None;
+        let mut indices = Vec::new();
+        for (index, ((_, end), _)) in pieces
+            .iter()
+            .enumerate()
+            .take(next_starts_position)
+            .skip(starts_position)
+        {
+            if end.x() == point.x() {
+                maybe_vertical_index = Some(index);
+            } else {
+                indices.push(index);
+            }
+        }
+        // inserting from bottom to top, so each piece
+        // is inserted right above the one it is adjacent to
+        indices.sort_by(|&first_index, &second_index| {
+            match point
+                .orient(&pieces[first_index].0 .1, &pieces[second_index].0 .1)
+            {
+                Orientation::Clockwise => Ordering::Greater,
+                Orientation::Collinear => Ordering::Equal,
+                Orientation::Counterclockwise => Ordering::Less,
+            }
+        });
+        for index in indices {
+            let ((start, end), winding) = &pieces[index];
+            let left_winding = sweep_line
+                .range(..SweepLineKey { start, end })
+                .next_back()
+                .map_or(0, |(_, &below_winding)| below_winding)
+                + winding;
+            sweep_line.insert(SweepLineKey { start, end }, left_winding);
+            result[index] = left_winding;
+        }
+        if let Some(index) = maybe_vertical_index {
+            // region to the right of the vertical piece
+            // is the one right above its start
+            let right_winding = sweep_line
+                .range(
+                    ..SweepLineKey {
+                        start: point,
+                        end: point,
+                    },
+                )
+                .next_back()
+                .map_or(0, |(_, &below_winding)| below_winding);
+            result[index] = right_winding + pieces[index].1;
+        }
+        starts_position = next_starts_position;
+    }
+    result
+}
+
+/// Piece crossing the sweep line ordered from bottom to top,
+/// degenerate one stands for the point right above its location.
+struct SweepLineKey<'a, Point> {
+    start: &'a Point,
+    end: &'a Point,
+}
+
+impl<Point: PartialEq> PartialEq for SweepLineKey<'_, Point> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Point: Eq> Eq for SweepLineKey<'_, Point> {}
+
+impl<Point: Ord> PartialOrd for SweepLineKey<'_, Point>
+where
+    for<'a> &'a Point: Orient,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Point: Ord> Ord for SweepLineKey<'_, Point>
+where
+    for<'a> &'a Point: Orient,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else if self.start == self.end {
+            match other.start.orient(other.end, self.start) {
+                Orientation::Clockwise => Ordering::Less,
+                _ => Ordering::Greater,
+            }
+        } else if other.start == other.end {
+            match self.start.orient(self.end, other.start) {
+                Orientation::Clockwise => Ordering::Greater,
+                _ => Ordering::Less,
+            }
+        } else {
+            let start_orientation = self.start.orient(self.end, other.start);
+            let end_orientation = self.start.orient(self.end, other.end);
+            let other_orientation =
+                if start_orientation == Orientation::Collinear {
+                    end_orientation
+                } else if end_orientation == Orientation::Collinear
+                    || start_orientation == end_orientation
+                {
+                    start_orientation
+                } else {
+                    // pieces do not cross, so the piece lies
+                    // on one side of the line through the other one
+                    let orientation =
+                        match other.start.orient(other.end, self.start) {
+                            Orientation::Collinear => {
+                                other.start.orient(other.end, self.end)
+                            }
+                            orientation => orientation,
+                        };
+                    return match orientation {
+                        Orientation::Clockwise => Ordering::Less,
+                        _ => Ordering::Greater,
+                    };
+                };
+            match other_orientation {
+                Orientation::Clockwise => Ordering::Greater,
+                Orientation::Collinear => {
+                    (self.start, self.end).cmp(&(other.start, other.end))
+                }
+                Orientation::Counterclockwise => Ordering::Less,
+            }
+        }
+    }
+}
+
+fn to_midpoint<Scalar>(
+    start: &Point<Scalar>,
+    end: &Point<Scalar>,
+) -> Point<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Clone + Div<Output = Scalar> + One,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+{
+    let two = Scalar::one() + Scalar::one();
+    Point::new(
+        (start.x() + end.x()) / two.clone(),
+        (start.y() + end.y()) / two,
+    )
+}
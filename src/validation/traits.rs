@@ -0,0 +1,26 @@
+use super::fill_rule::FillRule;
+use super::violation::Violation;
+
+pub trait MakeValid {
+    type Output;
+
+    /// Returns valid geometry covering the same area
+    /// as the geometry's boundary encloses under the fill rule.
+    fn make_valid(self, fill_rule: FillRule) -> Self::Output;
+}
+
+pub trait Validatable {
+    type Point;
+
+    /// Returns violations of the geometry in order of detection,
+    /// empty if the geometry is valid.
+    fn validate(self) -> Vec<Violation<Self::Point>>;
+
+    /// Checks if the geometry has no violations.
+    fn is_valid(self) -> bool
+    where
+        Self: Copy,
+    {
+        self.validate().is_empty()
+    }
+}
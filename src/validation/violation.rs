@@ -0,0 +1,214 @@
+use std::fmt;
+
+use crate::constants::{
+    MIN_CONTOUR_VERTICES_COUNT, MIN_MULTIPOLYGON_POLYGONS_COUNT,
+    MIN_MULTISEGMENT_SEGMENTS_COUNT,
+};
+
+use super::violation_kind::ViolationKind;
+
+/// Reason of geometry being invalid.
+///
+/// Segments of a contour are indexed so that `i`-th segment
+/// starts at `i`-th vertex and ends at the next one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Violation<Point> {
+    /// Three consecutive vertices of a contour lie on the same line,
+    /// the middle one has the given index.
+    CollinearVertices { vertex_index: usize },
+    /// Segment has equal endpoints.
+    DegenerateSegment { segment_index: usize },
+    /// Hole lies not in the interior of the border.
+    HoleOutsideBorder { hole_index: usize },
+    /// Hole touches the border along a segment.
+    HoleTouchesBorder { hole_index: usize },
+    /// Interiors of holes intersect.
+    HolesOverlap {
+        first_hole_index: usize,
+        second_hole_index: usize,
+    },
+    /// Border of a polygon is invalid.
+    InvalidBorder { violation: Box<Self> },
+    /// Hole of a polygon is invalid.
+    InvalidHole {
+        hole_index: usize,
+        violation: Box<Self>,
+    },
+    /// Polygon of a multipolygon is invalid.
+    InvalidPolygon {
+        polygon_index: usize,
+        violation: Box<Self>,
+    },
+    /// Interiors of polygons of a multipolygon intersect.
+    PolygonsOverlap {
+        first_polygon_index: usize,
+        second_polygon_index: usize,
+    },
+    /// Segments cross at the point.
+    SegmentsCross {
+        first_segment_index: usize,
+        second_segment_index: usize,
+        point: Point,
+    },
+    /// Segments share the part from start to end.
+    SegmentsOverlap {
+        first_segment_index: usize,
+        second_segment_index: usize,
+        start: Point,
+        end: Point,
+    },
+    /// Non-adjacent segments of a contour touch at the point.
+    SegmentsTouch {
+        first_segment_index: usize,
+        second_segment_index: usize,
+        point: Point,
+    },
+    /// Multipolygon has less polygons than required.
+    TooFewPolygons { count: usize },
+    /// Multisegment has less segments than required.
+    TooFewSegments { count: usize },
+    /// Contour has less vertices than required.
+    TooFewVertices { count: usize },
+    /// Border of a polygon is not oriented counterclockwise.
+    WrongBorderOrientation,
+    /// Hole of a polygon is not oriented clockwise.
+    WrongHoleOrientation { hole_index: usize },
+}
+
+impl<Point> Violation<Point> {
+    /// Returns the kind of the violation.
+    pub fn kind(&self) -> ViolationKind {
+        match self {
+            Self::CollinearVertices { .. } => ViolationKind::CollinearVertices,
+            Self::DegenerateSegment { .. } => ViolationKind::DegenerateSegment,
+            Self::HoleOutsideBorder { .. } => ViolationKind::HoleOutsideBorder,
+            Self::HoleTouchesBorder { .. } => ViolationKind::HoleTouchesBorder,
+            Self::HolesOverlap { .. } => ViolationKind::HolesOverlap,
+            Self::InvalidBorder { .. } => ViolationKind::InvalidBorder,
+            Self::InvalidHole { .. } => ViolationKind::InvalidHole,
+            Self::InvalidPolygon { .. } => ViolationKind::InvalidPolygon,
+            Self::PolygonsOverlap { .. } => ViolationKind::PolygonsOverlap,
+            Self::SegmentsCross { .. } => ViolationKind::SegmentsCross,
+            Self::SegmentsOverlap { .. } => ViolationKind::SegmentsOverlap,
+            Self::SegmentsTouch { .. } => ViolationKind::SegmentsTouch,
+            Self::TooFewPolygons { .. } => ViolationKind::TooFewPolygons,
+            Self::TooFewSegments { .. } => ViolationKind::TooFewSegments,
+            Self::TooFewVertices { .. } => ViolationKind::TooFewVertices,
+            Self::WrongBorderOrientation => {
+                ViolationKind::WrongBorderOrientation
+            }
+            Self::WrongHoleOrientation { .. } => {
+                ViolationKind::WrongHoleOrientation
+            }
+        }
+    }
+}
+
+impl<Point: fmt::Display> fmt::Display for Violation<Point> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CollinearVertices { vertex_index } => {
+                formatter.write_fmt(format_args!(
+                    "vertex {} is collinear with its neighbours",
+                    vertex_index
+                ))
+            }
+            Self::DegenerateSegment { segment_index } => formatter.write_fmt(
+                format_args!("segment {} is degenerate", segment_index),
+            ),
+            Self::HoleOutsideBorder { hole_index } => {
+                formatter.write_fmt(format_args!(
+                    "hole {} lies not in the interior of the border",
+                    hole_index
+                ))
+            }
+            Self::HoleTouchesBorder { hole_index } => {
+                formatter.write_fmt(format_args!(
+                    "hole {} touches the border along a segment",
+                    hole_index
+                ))
+            }
+            Self::HolesOverlap {
+                first_hole_index,
+                second_hole_index,
+            } => formatter.write_fmt(format_args!(
+                "holes {} and {} overlap",
+                first_hole_index, second_hole_index
+            )),
+            Self::InvalidBorder { violation } => formatter
+                .write_fmt(format_args!("border is invalid: {}", violation)),
+            Self::InvalidHole {
+                hole_index,
+                violation,
+            } => formatter.write_fmt(format_args!(
+                "hole {} is invalid: {}",
+                hole_index, violation
+            )),
+            Self::InvalidPolygon {
+                polygon_index,
+                violation,
+            } => formatter.write_fmt(format_args!(
+                "polygon {} is invalid: {}",
+                polygon_index, violation
+            )),
+            Self::PolygonsOverlap {
+                first_polygon_index,
+                second_polygon_index,
+            } => formatter.write_fmt(format_args!(
+                "polygons {} and {} overlap",
+                first_polygon_index, second_polygon_index
+            )),
+            Self::SegmentsCross {
+                first_segment_index,
+                second_segment_index,
+                point,
+            } => formatter.write_fmt(format_args!(
+                "segments {} and {} cross at {}",
+                first_segment_index, second_segment_index, point
+            )),
+            Self::SegmentsOverlap {
+                first_segment_index,
+                second_segment_index,
+                start,
+                end,
+            } => formatter.write_fmt(format_args!(
+                "segments {} and {} overlap from {} to {}",
+                first_segment_index, second_segment_index, start, end
+            )),
+            Self::SegmentsTouch {
+                first_segment_index,
+                second_segment_index,
+                point,
+            } => formatter.write_fmt(format_args!(
+                "segments {} and {} touch at {}",
+                first_segment_index, second_segment_index, point
+            )),
+            Self::TooFewPolygons { count } => {
+                formatter.write_fmt(format_args!(
+                    "expected at least {} polygons, but found {}",
+                    MIN_MULTIPOLYGON_POLYGONS_COUNT, count
+                ))
+            }
+            Self::TooFewSegments { count } => {
+                formatter.write_fmt(format_args!(
+                    "expected at least {} segments, but found {}",
+                    MIN_MULTISEGMENT_SEGMENTS_COUNT, count
+                ))
+            }
+            Self::TooFewVertices { count } => {
+                formatter.write_fmt(format_args!(
+                    "expected at least {} vertices, but found {}",
+                    MIN_CONTOUR_VERTICES_COUNT, count
+                ))
+            }
+            Self::WrongBorderOrientation => {
+                formatter.write_str("border is not oriented counterclockwise")
+            }
+            Self::WrongHoleOrientation { hole_index } => formatter.write_fmt(
+                format_args!("hole {} is not oriented clockwise", hole_index),
+            ),
+        }
+    }
+}
+
+impl<Point: fmt::Debug + fmt::Display> std::error::Error for Violation<Point> {}
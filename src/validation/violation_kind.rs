@@ -0,0 +1,38 @@
+/// Kind of a geometry validity violation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ViolationKind {
+    /// three consecutive vertices of a contour lie on the same line
+    CollinearVertices,
+    /// segment has equal endpoints
+    DegenerateSegment,
+    /// hole lies not in the interior of the border
+    HoleOutsideBorder,
+    /// hole touches the border along a segment
+    HoleTouchesBorder,
+    /// interiors of holes intersect
+    HolesOverlap,
+    /// border of a polygon is invalid
+    InvalidBorder,
+    /// hole of a polygon is invalid
+    InvalidHole,
+    /// polygon of a multipolygon is invalid
+    InvalidPolygon,
+    /// interiors of polygons of a multipolygon intersect
+    PolygonsOverlap,
+    /// segments cross
+    SegmentsCross,
+    /// segments share a part
+    SegmentsOverlap,
+    /// non-adjacent segments of a contour touch
+    SegmentsTouch,
+    /// multipolygon has less polygons than required
+    TooFewPolygons,
+    /// multisegment has less segments than required
+    TooFewSegments,
+    /// contour has less vertices than required
+    TooFewVertices,
+    /// border of a polygon is not oriented counterclockwise
+    WrongBorderOrientation,
+    /// hole of a polygon is not oriented clockwise
+    WrongHoleOrientation,
+}
@@ -0,0 +1,16 @@
+/// Bit of the geometry type which marks the exact extension encoding.
+pub const EXACT_FLAG: u32 = 0x1000_0000;
+
+pub(crate) const BIG_ENDIAN_BYTE_ORDER: u8 = 0;
+pub(crate) const LITTLE_ENDIAN_BYTE_ORDER: u8 = 1;
+
+pub(crate) const POINT_TYPE: u32 = 1;
+pub(crate) const LINE_STRING_TYPE: u32 = 2;
+pub(crate) const POLYGON_TYPE: u32 = 3;
+pub(crate) const MULTI_POINT_TYPE: u32 = 4;
+pub(crate) const MULTI_LINE_STRING_TYPE: u32 = 5;
+pub(crate) const MULTI_POLYGON_TYPE: u32 = 6;
+pub(crate) const GEOMETRY_COLLECTION_TYPE: u32 = 7;
+
+pub(crate) const EXTENDED_DIMENSIONS_FLAGS: u32 = 0xc000_0000;
+pub(crate) const MAX_ISO_GEOMETRY_TYPE: u32 = 4000;
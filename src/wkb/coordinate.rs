@@ -0,0 +1,83 @@
+use std::convert::TryFrom;
+use std::ops::Div;
+
+use rithm::fraction::Fraction;
+use traiter::numbers::{Endianness, FromBytes, ToBytes, Zeroable};
+
+/// Constructs scalar from well-known binary coordinate.
+pub trait FromWkbCoordinate: Sized {
+    /// Exactly converts finite double,
+    /// returns `None` for infinities & NaNs.
+    fn from_double(value: f64) -> Option<Self>;
+
+    /// Converts two's complement numerator & denominator bytes,
+    /// returns `None` for empty bytes or zero denominator.
+    fn from_exact_components(
+        numerator: &[u8],
+        denominator: &[u8],
+        endianness: Endianness,
+    ) -> Option<Self>;
+}
+
+/// Converts scalar to well-known binary coordinate.
+pub trait ToWkbCoordinate {
+    /// Returns the nearest double,
+    /// or `None` if the value is out of its range.
+    fn to_double(&self) -> Option<f64>;
+
+    /// Returns two's complement numerator & denominator bytes.
+    fn to_exact_components(
+        &self,
+        endianness: Endianness,
+    ) -> (Vec<u8>, Vec<u8>);
+}
+
+impl<Component: FromBytes> FromWkbCoordinate for Fraction<Component>
+where
+    for<'a> &'a Component: Zeroable,
+    Fraction<Component>:
+        Div<Output = Fraction<Component>> + From<Component> + TryFrom<f64>,
+{
+    fn from_double(value: f64) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+
+    fn from_exact_components(
+        numerator: &[u8],
+        denominator: &[u8],
+        endianness: Endianness,
+    ) -> Option<Self> {
+        if numerator.is_empty() || denominator.is_empty() {
+            return None;
+        }
+        let denominator = Component::from_bytes(denominator, endianness);
+        if denominator.is_zero() {
+            None
+        } else {
+            Some(
+                Self::from(Component::from_bytes(numerator, endianness))
+                    / Self::from(denominator),
+            )
+        }
+    }
+}
+
+impl<Component> ToWkbCoordinate for Fraction<Component>
+where
+    for<'a> &'a Component: ToBytes<Output = Vec<u8>>,
+    for<'a> f64: TryFrom<&'a Fraction<Component>>,
+{
+    fn to_double(&self) -> Option<f64> {
+        f64::try_from(self).ok().filter(|value| value.is_finite())
+    }
+
+    fn to_exact_components(
+        &self,
+        endianness: Endianness,
+    ) -> (Vec<u8>, Vec<u8>) {
+        (
+            self.numerator().to_bytes(endianness),
+            self.denominator().to_bytes(endianness),
+        )
+    }
+}
@@ -0,0 +1,9 @@
+/// Encoding of coordinates in well-known binary.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Encoding {
+    /// Standard IEEE 754 double coordinates,
+    /// values which are not exactly representable get rounded.
+    Double,
+    /// Exact numerators & denominators of coordinates.
+    Exact,
+}
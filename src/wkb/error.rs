@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// Error of reading or writing well-known binary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Bytes ended before the geometry was complete.
+    UnexpectedEnd,
+    /// Byte order marker at the given offset is neither `0` nor `1`.
+    InvalidByteOrder(usize),
+    /// Geometry type at the given offset
+    /// does not correspond to the target geometry.
+    UnexpectedGeometryType(usize),
+    /// Coordinate at the given offset is not finite or malformed.
+    InvalidCoordinate(usize),
+    /// Points at the given offset do not form the target geometry.
+    InvalidGeometry(usize, &'static str),
+    /// Bytes continue after the geometry at the given offset.
+    TrailingBytes(usize),
+    /// Coordinate is out of IEEE 754 double range.
+    NonFiniteCoordinate,
+    /// Count or length does not fit into 32 bits.
+    CountOverflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => {
+                formatter.write_str("Unexpected end of well-known binary.")
+            }
+            Self::InvalidByteOrder(position) => formatter.write_fmt(
+                format_args!("Invalid byte order at position {}.", position),
+            ),
+            Self::UnexpectedGeometryType(position) => {
+                formatter.write_fmt(format_args!(
+                    "Unexpected geometry type at position {}.",
+                    position
+                ))
+            }
+            Self::InvalidCoordinate(position) => formatter.write_fmt(
+                format_args!("Invalid coordinate at position {}.", position),
+            ),
+            Self::InvalidGeometry(position, reason) => {
+                formatter.write_fmt(format_args!(
+                    "Invalid geometry at position {}: {}.",
+                    position, reason
+                ))
+            }
+            Self::TrailingBytes(position) => formatter.write_fmt(
+                format_args!("Trailing bytes at position {}.", position),
+            ),
+            Self::NonFiniteCoordinate => formatter
+                .write_str("Coordinate is out of double precision range."),
+            Self::CountOverflow => {
+                formatter.write_str("Count does not fit into 32 bits.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,129 @@
+//! Well-known binary representation of geometries.
+//!
+//! Besides the standard encoding with IEEE 754 double coordinates
+//! (lossy, for interoperability) geometries can be written
+//! with the exact extension encoding
+//! which stores numerators & denominators of coordinates
+//! as length-prefixed two's complement integers
+//! and is flagged by the [`EXACT_FLAG`] bit of the geometry type,
+//! e.g. `Polygon::<Fraction>::from_wkb(&polygon.to_wkb(Encoding::Exact)?)`
+//! reads back exactly the same polygon.
+//!
+//! ```
+//! use rene::geometries::{
+//!     Contour, Empty, Multipolygon, Multisegment, Point, Polygon, Segment,
+//! };
+//! use rene::wkb::{Encoding, FromWkb, ToWkb, EXACT_FLAG};
+//! use rithm::big_int::BigInt;
+//! use rithm::fraction::Fraction;
+//!
+//! type Scalar = Fraction<BigInt<u32, 31>>;
+//! let to_scalar = |numerator: i32, denominator: i32| {
+//!     Scalar::from(BigInt::<u32, 31>::from(numerator))
+//!         / Scalar::from(BigInt::<u32, 31>::from(denominator))
+//! };
+//! let to_point = |x: i32, y: i32| {
+//!     Point::new(to_scalar(x, 1), to_scalar(y, 1))
+//! };
+//! let to_contour = |coordinates: &[(i32, i32)]| {
+//!     Contour::new(
+//!         coordinates.iter().map(|&(x, y)| to_point(x, y)).collect(),
+//!     )
+//! };
+//! let border = to_contour(&[(0, 0), (6, 0), (6, 6), (0, 6)]);
+//! let hole = to_contour(&[(2, 2), (2, 4), (4, 4), (4, 2)]);
+//! let polygon = Polygon::new(border.clone(), vec![hole]);
+//! let multipolygon = Multipolygon::new(vec![
+//!     polygon.clone(),
+//!     Polygon::new(to_contour(&[(7, 0), (9, 0), (9, 2)]), vec![]),
+//! ]);
+//! let segment = Segment::new(to_point(0, 0), to_point(1, 1));
+//! let multisegment = Multisegment::new(vec![
+//!     segment.clone(),
+//!     Segment::new(to_point(2, 0), to_point(3, 1)),
+//! ]);
+//! for encoding in [Encoding::Double, Encoding::Exact] {
+//!     let point = to_point(1, -2);
+//!     assert!(Point::from_wkb(&(&point).to_wkb(encoding)?)? == point);
+//!     assert!(Segment::from_wkb(&(&segment).to_wkb(encoding)?)? == segment);
+//!     assert!(
+//!         Multisegment::from_wkb(&(&multisegment).to_wkb(encoding)?)?
+//!             == multisegment
+//!     );
+//!     assert!(Contour::from_wkb(&(&border).to_wkb(encoding)?)? == border);
+//!     assert!(Polygon::from_wkb(&(&polygon).to_wkb(encoding)?)? == polygon);
+//!     assert!(
+//!         Multipolygon::from_wkb(&(&multipolygon).to_wkb(encoding)?)?
+//!             == multipolygon
+//!     );
+//!     assert!(Empty::from_wkb(&(&Empty::new()).to_wkb(encoding)?).is_ok());
+//! }
+//!
+//! // only the exact encoding preserves non-dyadic coordinates
+//! let point = Point::new(to_scalar(1, 3), to_scalar(-2, 1));
+//! let exact_bytes = (&point).to_wkb(Encoding::Exact)?;
+//! assert!(Point::<Scalar>::from_wkb(&exact_bytes)? == point);
+//! let double_bytes = (&point).to_wkb(Encoding::Double)?;
+//! assert!(Point::<Scalar>::from_wkb(&double_bytes)? != point);
+//!
+//! // little-endian header with flagged type,
+//! // then length-prefixed numerator & denominator per coordinate
+//! let geometry_type = u32::from_le_bytes([
+//!     exact_bytes[1],
+//!     exact_bytes[2],
+//!     exact_bytes[3],
+//!     exact_bytes[4],
+//! ]);
+//! assert_eq!(exact_bytes[0], 1);
+//! assert_eq!(geometry_type, 1 | EXACT_FLAG);
+//! assert_eq!(
+//!     exact_bytes[5..],
+//!     [1, 0, 0, 0, 1, 1, 0, 0, 0, 3, 1, 0, 0, 0, 0xfe, 1, 0, 0, 0, 1]
+//! );
+//! let double_type = u32::from_le_bytes([
+//!     double_bytes[1],
+//!     double_bytes[2],
+//!     double_bytes[3],
+//!     double_bytes[4],
+//! ]);
+//! assert_eq!(double_type & EXACT_FLAG, 0);
+//!
+//! // big-endian input is read as well
+//! let mut big_endian_bytes = vec![0, 0, 0, 0, 1];
+//! big_endian_bytes.extend_from_slice(&0.5f64.to_be_bytes());
+//! big_endian_bytes.extend_from_slice(&(-2f64).to_be_bytes());
+//! assert!(
+//!     Point::<Scalar>::from_wkb(&big_endian_bytes)?
+//!         == Point::new(to_scalar(1, 2), to_scalar(-2, 1))
+//! );
+//!
+//! // malformed input is rejected
+//! let mut zero_denominator_bytes = exact_bytes.clone();
+//! zero_denominator_bytes[14] = 0;
+//! assert!(Point::<Scalar>::from_wkb(&zero_denominator_bytes).is_err());
+//! assert!(Point::<Scalar>::from_wkb(&exact_bytes[..exact_bytes.len() - 1])
+//!     .is_err());
+//! assert!(Point::<Scalar>::from_wkb(&[exact_bytes.as_slice(), &[0]].concat())
+//!     .is_err());
+//! # Ok::<(), rene::wkb::Error>(())
+//! ```
+
+pub use self::constants::EXACT_FLAG;
+pub(crate) use self::constants::{
+    GEOMETRY_COLLECTION_TYPE, LINE_STRING_TYPE, MULTI_LINE_STRING_TYPE,
+    MULTI_POINT_TYPE, MULTI_POLYGON_TYPE, POINT_TYPE, POLYGON_TYPE,
+};
+pub use self::coordinate::{FromWkbCoordinate, ToWkbCoordinate};
+pub use self::encoding::Encoding;
+pub use self::error::Error;
+pub(crate) use self::reading::Reader;
+pub use self::traits::{FromWkb, ToWkb};
+pub(crate) use self::writing::Writer;
+
+mod constants;
+mod coordinate;
+mod encoding;
+mod error;
+mod reading;
+mod traits;
+mod writing;
@@ -0,0 +1,228 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::Endianness;
+
+use crate::geometries::{
+    to_contour, to_line_string, to_polygon, to_segment, Contour, Point,
+    Polygon, Segment,
+};
+
+use super::constants::{
+    BIG_ENDIAN_BYTE_ORDER, EXACT_FLAG, EXTENDED_DIMENSIONS_FLAGS,
+    LITTLE_ENDIAN_BYTE_ORDER, MAX_ISO_GEOMETRY_TYPE, POINT_TYPE,
+};
+use super::coordinate::FromWkbCoordinate;
+use super::error::Error;
+
+const DOUBLE_SIZE: usize = 8;
+
+/// Byte order & coordinates encoding of a geometry.
+#[derive(Clone, Copy)]
+pub(crate) struct Format {
+    endianness: Endianness,
+    is_exact: bool,
+}
+
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        if self.position == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(Error::TrailingBytes(self.position))
+        }
+    }
+
+    /// Reads the body of an empty geometry of the given type.
+    pub(crate) fn expect_empty(
+        &mut self,
+        geometry_type: u32,
+        format: Format,
+    ) -> Result<(), Error> {
+        let start = self.position;
+        let is_empty = if geometry_type == POINT_TYPE && !format.is_exact {
+            let (x, y) =
+                (self.read_double(format)?, self.read_double(format)?);
+            x.is_nan() && y.is_nan()
+        } else {
+            self.read_count(format)? == 0
+        };
+        if is_empty {
+            Ok(())
+        } else {
+            Err(Error::InvalidGeometry(start, "geometry should be empty"))
+        }
+    }
+
+    /// Reads byte order & one of the given geometry types,
+    /// returns format with the found type.
+    pub(crate) fn read_header(
+        &mut self,
+        geometry_types: &[u32],
+    ) -> Result<(Format, u32), Error> {
+        let endianness = match self.read_bytes(1)?[0] {
+            BIG_ENDIAN_BYTE_ORDER => Endianness::Big,
+            LITTLE_ENDIAN_BYTE_ORDER => Endianness::Little,
+            _ => return Err(Error::InvalidByteOrder(self.position - 1)),
+        };
+        let start = self.position;
+        let geometry_type = self.read_u32(endianness)?;
+        let format = Format {
+            endianness,
+            is_exact: geometry_type & EXACT_FLAG != 0,
+        };
+        let geometry_type = geometry_type & !EXACT_FLAG;
+        if geometry_types.contains(&geometry_type) {
+            Ok((format, geometry_type))
+        } else if geometry_type & EXTENDED_DIMENSIONS_FLAGS != 0
+            || (MAX_ISO_GEOMETRY_TYPE / 4..MAX_ISO_GEOMETRY_TYPE)
+                .contains(&geometry_type)
+        {
+            Err(Error::InvalidGeometry(
+                start,
+                "only two-dimensional coordinates are supported",
+            ))
+        } else {
+            Err(Error::UnexpectedGeometryType(start))
+        }
+    }
+
+    pub(crate) fn read_line_string_body<Scalar: FromWkbCoordinate>(
+        &mut self,
+        format: Format,
+    ) -> Result<Vec<Point<Scalar>>, Error> {
+        let start = self.position;
+        to_line_string(self.read_list(format, Self::read_point_body)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    pub(crate) fn read_list<Item>(
+        &mut self,
+        format: Format,
+        mut read_item: impl FnMut(&mut Self, Format) -> Result<Item, Error>,
+    ) -> Result<Vec<Item>, Error> {
+        let count = self.read_count(format)?;
+        let mut result = Vec::new();
+        for _ in 0..count {
+            result.push(read_item(self, format)?);
+        }
+        Ok(result)
+    }
+
+    /// Reads a list of geometries with their own headers.
+    pub(crate) fn read_nested_list<Item>(
+        &mut self,
+        format: Format,
+        geometry_type: u32,
+        mut read_body: impl FnMut(&mut Self, Format) -> Result<Item, Error>,
+    ) -> Result<Vec<Item>, Error> {
+        self.read_list(format, |reader, _| {
+            let (format, _) = reader.read_header(&[geometry_type])?;
+            read_body(reader, format)
+        })
+    }
+
+    pub(crate) fn read_point_body<Scalar: FromWkbCoordinate>(
+        &mut self,
+        format: Format,
+    ) -> Result<Point<Scalar>, Error> {
+        let x = self.read_coordinate(format)?;
+        let y = self.read_coordinate(format)?;
+        Ok(Point::new(x, y))
+    }
+
+    pub(crate) fn read_polygon_body<
+        Scalar: Clone + FromWkbCoordinate + PartialEq,
+    >(
+        &mut self,
+        format: Format,
+    ) -> Result<Polygon<Scalar>, Error> {
+        let start = self.position;
+        to_polygon(self.read_list(format, Self::read_ring_body)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    pub(crate) fn read_ring_body<
+        Scalar: Clone + FromWkbCoordinate + PartialEq,
+    >(
+        &mut self,
+        format: Format,
+    ) -> Result<Contour<Scalar>, Error> {
+        let start = self.position;
+        to_contour(self.read_list(format, Self::read_point_body)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    pub(crate) fn read_segment_body<Scalar: FromWkbCoordinate>(
+        &mut self,
+        format: Format,
+    ) -> Result<Segment<Scalar>, Error> {
+        let start = self.position;
+        to_segment(self.read_list(format, Self::read_point_body)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(Error::UnexpectedEnd)?;
+        let result = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(result)
+    }
+
+    fn read_coordinate<Scalar: FromWkbCoordinate>(
+        &mut self,
+        format: Format,
+    ) -> Result<Scalar, Error> {
+        let start = self.position;
+        let result = if format.is_exact {
+            let numerator_size = self.read_count(format)?;
+            let numerator = self.read_bytes(numerator_size)?;
+            let denominator_size = self.read_count(format)?;
+            let denominator = self.read_bytes(denominator_size)?;
+            Scalar::from_exact_components(
+                numerator,
+                denominator,
+                format.endianness,
+            )
+        } else {
+            Scalar::from_double(self.read_double(format)?)
+        };
+        result.ok_or(Error::InvalidCoordinate(start))
+    }
+
+    fn read_count(&mut self, format: Format) -> Result<usize, Error> {
+        let start = self.position;
+        usize::try_from(self.read_u32(format.endianness)?)
+            .map_err(|_| Error::InvalidGeometry(start, "count is too large"))
+    }
+
+    fn read_double(&mut self, format: Format) -> Result<f64, Error> {
+        let mut bytes = [0u8; DOUBLE_SIZE];
+        bytes.copy_from_slice(self.read_bytes(DOUBLE_SIZE)?);
+        Ok(match format.endianness {
+            Endianness::Big => f64::from_be_bytes(bytes),
+            Endianness::Little => f64::from_le_bytes(bytes),
+        })
+    }
+
+    fn read_u32(&mut self, endianness: Endianness) -> Result<u32, Error> {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.read_bytes(4)?);
+        Ok(match endianness {
+            Endianness::Big => u32::from_be_bytes(bytes),
+            Endianness::Little => u32::from_le_bytes(bytes),
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use super::encoding::Encoding;
+use super::error::Error;
+
+pub trait FromWkb: Sized {
+    /// Reads geometry from its well-known binary representation
+    /// in either encoding & byte order.
+    fn from_wkb(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+pub trait ToWkb {
+    /// Returns little-endian well-known binary representation
+    /// of the geometry in the given encoding.
+    fn to_wkb(self, encoding: Encoding) -> Result<Vec<u8>, Error>;
+}
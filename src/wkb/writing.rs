@@ -0,0 +1,118 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::Endianness;
+
+use crate::geometries::{Contour, Point, Polygon};
+use crate::traits::{
+    Elemental, Iterable, Lengthsome, Multivertexal, Polygonal,
+};
+
+use super::constants::{EXACT_FLAG, LITTLE_ENDIAN_BYTE_ORDER};
+use super::coordinate::ToWkbCoordinate;
+use super::encoding::Encoding;
+use super::error::Error;
+
+pub(crate) struct Writer {
+    encoding: Encoding,
+    output: Vec<u8>,
+}
+
+impl Writer {
+    pub(crate) fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            output: Vec::new(),
+        }
+    }
+
+    pub(crate) fn finish(self) -> Vec<u8> {
+        self.output
+    }
+
+    pub(crate) fn write_count(&mut self, count: usize) -> Result<(), Error> {
+        self.write_u32(
+            u32::try_from(count).map_err(|_| Error::CountOverflow)?,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn write_header(&mut self, geometry_type: u32) {
+        self.output.push(LITTLE_ENDIAN_BYTE_ORDER);
+        self.write_u32(match self.encoding {
+            Encoding::Double => geometry_type,
+            Encoding::Exact => geometry_type | EXACT_FLAG,
+        });
+    }
+
+    pub(crate) fn write_point_body<Scalar: ToWkbCoordinate>(
+        &mut self,
+        point: &Point<Scalar>,
+    ) -> Result<(), Error> {
+        let (x, y) = point.coordinates();
+        self.write_coordinate(x)?;
+        self.write_coordinate(y)
+    }
+
+    pub(crate) fn write_points_body<'a, Scalar: 'a + ToWkbCoordinate>(
+        &mut self,
+        points: impl ExactSizeIterator<Item = &'a Point<Scalar>>,
+    ) -> Result<(), Error> {
+        self.write_count(points.len())?;
+        for point in points {
+            self.write_point_body(point)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_polygon_body<Scalar: ToWkbCoordinate>(
+        &mut self,
+        polygon: &Polygon<Scalar>,
+    ) -> Result<(), Error> {
+        let holes = polygon.holes();
+        self.write_count(holes.len() + 1)?;
+        self.write_ring_body(polygon.border())?;
+        for hole in holes.iter() {
+            self.write_ring_body(hole)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_ring_body<Scalar: ToWkbCoordinate>(
+        &mut self,
+        contour: &Contour<Scalar>,
+    ) -> Result<(), Error> {
+        let vertices = contour.vertices();
+        self.write_count(vertices.len() + 1)?;
+        for vertex in vertices.iter().chain(vertices.iter().take(1)) {
+            self.write_point_body(vertex)?;
+        }
+        Ok(())
+    }
+
+    fn write_coordinate<Scalar: ToWkbCoordinate>(
+        &mut self,
+        coordinate: &Scalar,
+    ) -> Result<(), Error> {
+        match self.encoding {
+            Encoding::Double => {
+                let value = coordinate
+                    .to_double()
+                    .ok_or(Error::NonFiniteCoordinate)?;
+                self.output.extend_from_slice(&value.to_le_bytes());
+            }
+            Encoding::Exact => {
+                let (numerator, denominator) =
+                    coordinate.to_exact_components(Endianness::Little);
+                for component in [numerator, denominator].iter() {
+                    self.write_count(component.len())?;
+                    self.output.extend_from_slice(component);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.output.extend_from_slice(&value.to_le_bytes());
+    }
+}
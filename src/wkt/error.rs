@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// Error of reading or writing well-known text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Text ended before the geometry was complete.
+    UnexpectedEnd,
+    /// Unexpected character at the given byte offset.
+    UnexpectedCharacter(usize),
+    /// Geometry tag at the given byte offset
+    /// does not correspond to the target geometry.
+    UnexpectedTag(usize),
+    /// Coordinate at the given byte offset is not a valid decimal.
+    InvalidCoordinate(usize),
+    /// Points at the given byte offset do not form the target geometry.
+    InvalidGeometry(usize, &'static str),
+    /// Coordinate has no finite decimal representation.
+    NonDecimalCoordinate,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => {
+                formatter.write_str("Unexpected end of well-known text.")
+            }
+            Self::UnexpectedCharacter(position) => formatter.write_fmt(
+                format_args!("Unexpected character at position {}.", position),
+            ),
+            Self::UnexpectedTag(position) => {
+                formatter.write_fmt(format_args!(
+                    "Unexpected geometry tag at position {}.",
+                    position
+                ))
+            }
+            Self::InvalidCoordinate(position) => formatter.write_fmt(
+                format_args!("Invalid coordinate at position {}.", position),
+            ),
+            Self::InvalidGeometry(position, reason) => {
+                formatter.write_fmt(format_args!(
+                    "Invalid geometry at position {}: {}.",
+                    position, reason
+                ))
+            }
+            Self::NonDecimalCoordinate => formatter
+                .write_str("Coordinate has no finite decimal representation."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,16 @@
+//! Well-known text representation of geometries.
+//!
+//! Coordinates are read & written as exact decimals,
+//! e.g. `Polygon::<Fraction>::from_wkt("POLYGON ((0 0, 1 0, 0 0.5, 0 0))")`.
+
+pub use self::error::Error;
+pub(crate) use self::parsing::Parser;
+pub use self::traits::{FromWkt, ToWkt};
+pub(crate) use self::writing::{
+    write_point_text, write_points_text, write_polygon_text, write_ring_text,
+};
+
+mod error;
+mod parsing;
+mod traits;
+mod writing;
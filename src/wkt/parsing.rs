@@ -0,0 +1,198 @@
+use crate::decimal::FromDecimal;
+use crate::geometries::{
+    to_contour, to_line_string, to_segment, Contour, Point, Polygon, Segment,
+};
+
+use super::error::Error;
+
+pub(crate) struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    pub(crate) fn finish(mut self) -> Result<(), Error> {
+        self.skip_whitespaces();
+        if self.position == self.text.len() {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedCharacter(self.position))
+        }
+    }
+
+    pub(crate) fn expect_empty(&mut self) -> Result<(), Error> {
+        self.skip_whitespaces();
+        let start = self.position;
+        if self.parse_word().eq_ignore_ascii_case("EMPTY") {
+            Ok(())
+        } else {
+            self.position = start;
+            Err(self.to_unexpected_character_error())
+        }
+    }
+
+    pub(crate) fn parse_line_string_text<Scalar: FromDecimal>(
+        &mut self,
+    ) -> Result<Vec<Point<Scalar>>, Error> {
+        self.skip_whitespaces();
+        let start = self.position;
+        to_line_string(self.parse_list(Self::parse_point)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    pub(crate) fn parse_list<Item>(
+        &mut self,
+        mut parse_item: impl FnMut(&mut Self) -> Result<Item, Error>,
+    ) -> Result<Vec<Item>, Error> {
+        self.expect_character('(')?;
+        let mut result = vec![parse_item(self)?];
+        while self.parse_separator()? {
+            result.push(parse_item(self)?);
+        }
+        Ok(result)
+    }
+
+    pub(crate) fn parse_point_text<Scalar: FromDecimal>(
+        &mut self,
+    ) -> Result<Point<Scalar>, Error> {
+        self.expect_character('(')?;
+        let result = self.parse_point()?;
+        self.expect_character(')')?;
+        Ok(result)
+    }
+
+    pub(crate) fn parse_polygon_text<
+        Scalar: Clone + FromDecimal + PartialEq,
+    >(
+        &mut self,
+    ) -> Result<Polygon<Scalar>, Error> {
+        let mut contours = self.parse_list(Self::parse_ring_text)?;
+        let border = contours.remove(0);
+        Ok(Polygon::new(border, contours))
+    }
+
+    pub(crate) fn parse_ring_text<Scalar: Clone + FromDecimal + PartialEq>(
+        &mut self,
+    ) -> Result<Contour<Scalar>, Error> {
+        self.skip_whitespaces();
+        let start = self.position;
+        to_contour(self.parse_list(Self::parse_point)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    pub(crate) fn parse_segment_text<Scalar: FromDecimal>(
+        &mut self,
+    ) -> Result<Segment<Scalar>, Error> {
+        self.skip_whitespaces();
+        let start = self.position;
+        to_segment(self.parse_list(Self::parse_point)?)
+            .map_err(|reason| Error::InvalidGeometry(start, reason))
+    }
+
+    /// Parses one of the given tags case-insensitively
+    /// and returns its index.
+    pub(crate) fn parse_tag(&mut self, tags: &[&str]) -> Result<usize, Error> {
+        self.skip_whitespaces();
+        let start = self.position;
+        let word = self.parse_word();
+        if word.is_empty() {
+            return Err(self.to_unexpected_character_error());
+        }
+        let result = tags
+            .iter()
+            .position(|tag| tag.eq_ignore_ascii_case(word))
+            .ok_or(Error::UnexpectedTag(start))?;
+        self.skip_whitespaces();
+        let dimension_start = self.position;
+        let dimension = self.parse_word();
+        if ["M", "Z", "ZM"]
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(dimension))
+        {
+            Err(Error::InvalidGeometry(
+                dimension_start,
+                "only two-dimensional coordinates are supported",
+            ))
+        } else {
+            self.position = dimension_start;
+            Ok(result)
+        }
+    }
+
+    fn expect_character(&mut self, character: char) -> Result<(), Error> {
+        self.skip_whitespaces();
+        if self.text[self.position..].starts_with(character) {
+            self.position += character.len_utf8();
+            Ok(())
+        } else {
+            Err(self.to_unexpected_character_error())
+        }
+    }
+
+    fn parse_coordinate<Scalar: FromDecimal>(
+        &mut self,
+    ) -> Result<Scalar, Error> {
+        self.skip_whitespaces();
+        let start = self.position;
+        let length = self.text[start..]
+            .find(|character: char| {
+                !(character.is_ascii_digit()
+                    || matches!(character, '+' | '-' | '.' | 'e' | 'E'))
+            })
+            .unwrap_or(self.text.len() - start);
+        if length == 0 {
+            return Err(self.to_unexpected_character_error());
+        }
+        self.position += length;
+        Scalar::from_decimal(&self.text[start..self.position])
+            .ok_or(Error::InvalidCoordinate(start))
+    }
+
+    fn parse_point<Scalar: FromDecimal>(
+        &mut self,
+    ) -> Result<Point<Scalar>, Error> {
+        let x = self.parse_coordinate()?;
+        let y = self.parse_coordinate()?;
+        Ok(Point::new(x, y))
+    }
+
+    fn parse_separator(&mut self) -> Result<bool, Error> {
+        self.skip_whitespaces();
+        match self.text[self.position..].chars().next() {
+            Some(',') => {
+                self.position += 1;
+                Ok(true)
+            }
+            Some(')') => {
+                self.position += 1;
+                Ok(false)
+            }
+            _ => Err(self.to_unexpected_character_error()),
+        }
+    }
+
+    fn parse_word(&mut self) -> &'a str {
+        let start = self.position;
+        self.position += self.text[start..]
+            .find(|character: char| !character.is_ascii_alphabetic())
+            .unwrap_or(self.text.len() - start);
+        &self.text[start..self.position]
+    }
+
+    fn skip_whitespaces(&mut self) {
+        self.position =
+            self.text.len() - self.text[self.position..].trim_start().len();
+    }
+
+    fn to_unexpected_character_error(&self) -> Error {
+        if self.position == self.text.len() {
+            Error::UnexpectedEnd
+        } else {
+            Error::UnexpectedCharacter(self.position)
+        }
+    }
+}
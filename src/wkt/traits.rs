@@ -0,0 +1,11 @@
+use super::error::Error;
+
+pub trait FromWkt: Sized {
+    /// Parses geometry from its well-known text representation.
+    fn from_wkt(text: &str) -> Result<Self, Error>;
+}
+
+pub trait ToWkt {
+    /// Returns well-known text representation of the geometry.
+    fn to_wkt(self) -> Result<String, Error>;
+}
@@ -0,0 +1,63 @@
+use crate::decimal::ToDecimal;
+use crate::geometries::{Contour, Point, Polygon};
+use crate::traits::{Elemental, Iterable, Multivertexal, Polygonal};
+
+use super::error::Error;
+
+pub(crate) fn write_point_text<Scalar: ToDecimal>(
+    point: &Point<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    output.push('(');
+    write_point(point, output)?;
+    output.push(')');
+    Ok(())
+}
+
+pub(crate) fn write_points_text<'a, Scalar: 'a + ToDecimal>(
+    points: impl Iterator<Item = &'a Point<Scalar>>,
+    output: &mut String,
+) -> Result<(), Error> {
+    output.push('(');
+    for (index, point) in points.enumerate() {
+        if index > 0 {
+            output.push_str(", ");
+        }
+        write_point(point, output)?;
+    }
+    output.push(')');
+    Ok(())
+}
+
+pub(crate) fn write_polygon_text<Scalar: ToDecimal>(
+    polygon: &Polygon<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    output.push('(');
+    write_ring_text(polygon.border(), output)?;
+    for hole in polygon.holes().iter() {
+        output.push_str(", ");
+        write_ring_text(hole, output)?;
+    }
+    output.push(')');
+    Ok(())
+}
+
+pub(crate) fn write_ring_text<Scalar: ToDecimal>(
+    contour: &Contour<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    let vertices = contour.vertices();
+    write_points_text(vertices.iter().chain(vertices.iter().take(1)), output)
+}
+
+fn write_point<Scalar: ToDecimal>(
+    point: &Point<Scalar>,
+    output: &mut String,
+) -> Result<(), Error> {
+    let (x, y) = point.coordinates();
+    output.push_str(&x.to_decimal().ok_or(Error::NonDecimalCoordinate)?);
+    output.push(' ');
+    output.push_str(&y.to_decimal().ok_or(Error::NonDecimalCoordinate)?);
+    Ok(())
+}
@@ -11,3 +11,6 @@ boxes_like = _strategies.scalars_strategies.flatmap(
     lambda scalars: st.builds(_Box, scalars, scalars, scalars, scalars)
 )
 boxes = _strategies.boxes
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+scalars = _strategies.scalars
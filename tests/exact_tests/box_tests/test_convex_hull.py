@@ -0,0 +1,32 @@
+from hypothesis import given
+
+from rene._exact import orient
+from rene.exact import Box, Point, Polygon, Segment
+from tests.utils import pack_convex_hull, to_convex_hull
+
+from . import strategies
+
+
+@given(strategies.boxes)
+def test_basic(box: Box) -> None:
+    result = box.convex_hull()
+
+    assert isinstance(result, (Point, Polygon, Segment))
+
+
+@given(strategies.boxes)
+def test_value(box: Box) -> None:
+    result = box.convex_hull()
+
+    assert result == pack_convex_hull(
+        to_convex_hull(
+            [
+                Point(box.min_x, box.min_y),
+                Point(box.max_x, box.min_y),
+                Point(box.max_x, box.max_y),
+                Point(box.min_x, box.max_y),
+            ],
+            orient,
+        )
+    )
+
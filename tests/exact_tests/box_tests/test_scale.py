@@ -0,0 +1,27 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Box
+
+from . import strategies
+
+
+@given(strategies.boxes, strategies.scalars, strategies.scalars)
+def test_basic(box: Box, factor_x: Fraction, factor_y: Fraction) -> None:
+    result = box.scale(factor_x, factor_y)
+
+    assert isinstance(result, Box)
+
+
+@given(strategies.boxes)
+def test_identity(box: Box) -> None:
+    assert box.scale(1, 1) == box
+
+
+@given(strategies.boxes, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    box: Box, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = box.scale(factor_x, factor_y)
+
+    assert result == box.transform(((factor_x, 0, 0), (0, factor_y, 0)))
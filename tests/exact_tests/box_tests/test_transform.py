@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rene.exact import Box
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.boxes, strategies.affine_matrices)
+def test_basic(box: Box, matrix: AffineMatrix) -> None:
+    result = box.transform(matrix)
+
+    assert isinstance(result, Box)
+
+
+@given(strategies.boxes)
+def test_identity(box: Box) -> None:
+    assert box.transform(((1, 0, 0), (0, 1, 0))) == box
@@ -0,0 +1,29 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Box
+
+from . import strategies
+
+
+@given(strategies.boxes, strategies.scalars, strategies.scalars)
+def test_basic(box: Box, step_x: Fraction, step_y: Fraction) -> None:
+    result = box.translate(step_x, step_y)
+
+    assert isinstance(result, Box)
+
+
+@given(strategies.boxes, strategies.scalars, strategies.scalars)
+def test_round_trip(box: Box, step_x: Fraction, step_y: Fraction) -> None:
+    result = box.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == box
+
+
+@given(strategies.boxes, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    box: Box, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = box.translate(step_x, step_y)
+
+    assert result == box.transform(((1, 0, step_x), (0, 1, step_y)))
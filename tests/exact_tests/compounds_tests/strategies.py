@@ -14,6 +14,19 @@ compounds = (
     | _strategies.polygons
     | _strategies.multipolygons
 )
+non_empty_compounds = (
+    _strategies.segments
+    | _strategies.multisegments
+    | _strategies.contours
+    | _strategies.polygons
+    | _strategies.multipolygons
+)
+hausdorff_metrizables = (
+    _strategies.multisegments
+    | _strategies.contours
+    | _strategies.polygons
+    | _strategies.multipolygons
+)
 idempotent_linear_compounds = _strategies.segments | _strategies.multisegments
 linear_compounds = idempotent_linear_compounds | _strategies.contours
 shaped_compounds = _strategies.polygons | _strategies.multipolygons
@@ -42,3 +55,7 @@ closed_idempotent_compounds_triplets = (
     _closed_idempotent_compounds_strategies.flatmap(to_triplets)
 )
 points = _strategies.points
+metrizables = points | non_empty_compounds
+invalid_length_patterns = _st.text(alphabet='TF*012', max_size=20).filter(
+    lambda pattern: len(pattern) != 9
+)
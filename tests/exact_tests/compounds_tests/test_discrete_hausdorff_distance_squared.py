@@ -0,0 +1,67 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.enums import Location
+from rene.exact import Point
+from tests.exact_tests.hints import HausdorffMetrizable
+
+from . import strategies
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_basic(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    result = first.discrete_hausdorff_distance_squared(second)
+
+    assert isinstance(result, tuple)
+    assert len(result) == 3
+    squared_distance, point, other_point = result
+    assert isinstance(squared_distance, Fraction)
+    assert isinstance(point, Point)
+    assert isinstance(other_point, Point)
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_witnesses(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    (
+        squared_distance,
+        point,
+        other_point,
+    ) = first.discrete_hausdorff_distance_squared(second)
+
+    assert point.distance_squared(other_point) == squared_distance
+    assert first.locate(point) is not Location.EXTERIOR
+    assert second.locate(other_point) is not Location.EXTERIOR
+
+
+@given(strategies.hausdorff_metrizables)
+def test_reflexivity(metrizable: HausdorffMetrizable) -> None:
+    squared_distance, _, _ = metrizable.discrete_hausdorff_distance_squared(
+        metrizable
+    )
+
+    assert squared_distance == 0
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_symmetry(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    squared_distance, _, _ = first.discrete_hausdorff_distance_squared(second)
+    other_squared_distance, _, _ = second.discrete_hausdorff_distance_squared(
+        first
+    )
+
+    assert squared_distance == other_squared_distance
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_connection_with_distance_squared(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    squared_distance, _, _ = first.discrete_hausdorff_distance_squared(second)
+
+    assert squared_distance >= first.distance_squared(second)
@@ -0,0 +1,56 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.enums import Location
+from rene.exact import Point
+from tests.exact_tests.hints import Metrizable
+from tests.utils import reverse_compound_coordinates, reverse_point_coordinates
+
+from . import strategies
+
+
+@given(strategies.metrizables, strategies.metrizables)
+def test_basic(first: Metrizable, second: Metrizable) -> None:
+    result = first.distance_squared(second)
+
+    assert isinstance(result, Fraction)
+
+
+@given(strategies.metrizables, strategies.metrizables)
+def test_value(first: Metrizable, second: Metrizable) -> None:
+    assert first.distance_squared(second) >= 0
+
+
+@given(strategies.metrizables)
+def test_reflexivity(metrizable: Metrizable) -> None:
+    assert metrizable.distance_squared(metrizable) == 0
+
+
+@given(strategies.metrizables, strategies.metrizables)
+def test_symmetry(first: Metrizable, second: Metrizable) -> None:
+    assert first.distance_squared(second) == second.distance_squared(first)
+
+
+@given(strategies.non_empty_compounds, strategies.points)
+def test_connection_with_locate(compound: Metrizable, point: Point) -> None:
+    assert (compound.distance_squared(point) == 0) is (
+        compound.locate(point) is not Location.EXTERIOR
+    )
+
+
+@given(strategies.non_empty_compounds, strategies.non_empty_compounds)
+def test_reversals(first: Metrizable, second: Metrizable) -> None:
+    assert first.distance_squared(
+        second
+    ) == reverse_compound_coordinates(first).distance_squared(
+        reverse_compound_coordinates(second)
+    )
+
+
+@given(strategies.non_empty_compounds, strategies.points)
+def test_points_reversals(compound: Metrizable, point: Point) -> None:
+    assert compound.distance_squared(
+        point
+    ) == reverse_compound_coordinates(compound).distance_squared(
+        reverse_point_coordinates(point)
+    )
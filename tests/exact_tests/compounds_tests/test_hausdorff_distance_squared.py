@@ -0,0 +1,123 @@
+import math
+
+from hypothesis import given
+
+from rene.exact import (
+    Contour,
+    Multipolygon,
+    Multisegment,
+    QuadraticSurd,
+    Segment,
+)
+from tests.exact_tests.hints import HausdorffMetrizable
+
+from . import strategies
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_basic(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    result = first.hausdorff_distance_squared(second)
+
+    assert isinstance(result, tuple)
+    assert len(result) == 3
+    squared_distance, point, other_point = result
+    assert isinstance(squared_distance, QuadraticSurd)
+    assert isinstance(point, tuple)
+    assert len(point) == 2
+    assert all(isinstance(coordinate, QuadraticSurd) for coordinate in point)
+    assert isinstance(other_point, tuple)
+    assert len(other_point) == 2
+    assert all(
+        isinstance(coordinate, QuadraticSurd) for coordinate in other_point
+    )
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_witnesses(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    squared_distance, point, other_point = first.hausdorff_distance_squared(
+        second
+    )
+
+    assert math.isclose(
+        (float(point[0]) - float(other_point[0])) ** 2
+        + (float(point[1]) - float(other_point[1])) ** 2,
+        float(squared_distance),
+        rel_tol=1e-6,
+        abs_tol=1e-6,
+    )
+
+
+@given(strategies.hausdorff_metrizables)
+def test_reflexivity(metrizable: HausdorffMetrizable) -> None:
+    squared_distance, _, _ = metrizable.hausdorff_distance_squared(metrizable)
+
+    assert squared_distance == QuadraticSurd(0, 0, 0)
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_symmetry(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    squared_distance, _, _ = first.hausdorff_distance_squared(second)
+    other_squared_distance, _, _ = second.hausdorff_distance_squared(first)
+
+    assert squared_distance == other_squared_distance
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_connection_with_distance_squared(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    squared_distance, _, _ = first.hausdorff_distance_squared(second)
+
+    assert squared_distance >= QuadraticSurd(
+        first.distance_squared(second), 0, 0
+    )
+
+
+@given(strategies.hausdorff_metrizables, strategies.hausdorff_metrizables)
+def test_connection_with_vertices_to_boundary_distances(
+    first: HausdorffMetrizable, second: HausdorffMetrizable
+) -> None:
+    squared_distance, _, _ = first.hausdorff_distance_squared(second)
+
+    first_segments, second_segments = (
+        _to_boundary_segments(first),
+        _to_boundary_segments(second),
+    )
+    assert all(
+        squared_distance
+        >= QuadraticSurd(
+            min(
+                vertex.distance_squared(other_segment)
+                for other_segment in other_segments
+            ),
+            0,
+            0,
+        )
+        for segments, other_segments in [
+            (first_segments, second_segments),
+            (second_segments, first_segments),
+        ]
+        for segment in segments
+        for vertex in (segment.start, segment.end)
+    )
+
+
+def _to_boundary_segments(metrizable: HausdorffMetrizable) -> list[Segment]:
+    if isinstance(metrizable, (Contour, Multisegment)):
+        return list(metrizable.segments)
+    return [
+        segment
+        for polygon in (
+            metrizable.polygons
+            if isinstance(metrizable, Multipolygon)
+            else [metrizable]
+        )
+        for contour in (polygon.border, *polygon.holes)
+        for segment in contour.segments
+    ]
@@ -0,0 +1,48 @@
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import Point, nearest_points
+from tests.exact_tests.hints import Metrizable
+
+from . import strategies
+
+
+@given(strategies.metrizables, strategies.metrizables)
+def test_basic(first: Metrizable, second: Metrizable) -> None:
+    result = first.nearest_points(second)
+
+    assert isinstance(result, tuple)
+    assert len(result) == 2
+    assert all(isinstance(element, Point) for element in result)
+
+
+@given(strategies.metrizables, strategies.metrizables)
+def test_connection_with_distance_squared(
+    first: Metrizable, second: Metrizable
+) -> None:
+    point, other_point = first.nearest_points(second)
+
+    assert point.distance_squared(other_point) == first.distance_squared(
+        second
+    )
+
+
+@given(strategies.metrizables, strategies.metrizables)
+def test_connection_with_method(first: Metrizable, second: Metrizable) -> None:
+    result = nearest_points(first, second)
+
+    assert result == first.nearest_points(second)
+
+
+@given(strategies.non_empty_compounds, strategies.non_empty_compounds)
+def test_membership(first: Metrizable, second: Metrizable) -> None:
+    point, other_point = first.nearest_points(second)
+
+    assert first.locate(point) is not Location.EXTERIOR
+    assert second.locate(other_point) is not Location.EXTERIOR
+
+
+@given(strategies.non_empty_compounds, strategies.points)
+def test_points(compound: Metrizable, point: Point) -> None:
+    assert compound.nearest_points(point)[1] == point
+    assert point.nearest_points(compound)[0] == point
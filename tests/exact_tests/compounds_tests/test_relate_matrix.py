@@ -0,0 +1,46 @@
+from hypothesis import given
+
+from tests.exact_tests.hints import Compound
+from tests.utils import reverse_compound_coordinates
+
+from . import strategies
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_basic(first: Compound, second: Compound) -> None:
+    result = first.relate_matrix(second)
+
+    assert isinstance(result, str)
+    assert len(result) == 9
+    assert set(result) <= set('F012')
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_exteriors(first: Compound, second: Compound) -> None:
+    assert first.relate_matrix(second)[-1] == '2'
+
+
+@given(strategies.non_empty_compounds)
+def test_reflexivity(compound: Compound) -> None:
+    result = compound.relate_matrix(compound)
+
+    assert result[0] != 'F'
+    assert all(result[index] == 'F' for index in (2, 5, 6, 7))
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_transposition(first: Compound, second: Compound) -> None:
+    assert first.relate_matrix(second) == _to_transposed(
+        second.relate_matrix(first)
+    )
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_reversals(first: Compound, second: Compound) -> None:
+    assert first.relate_matrix(second) == reverse_compound_coordinates(
+        first
+    ).relate_matrix(reverse_compound_coordinates(second))
+
+
+def _to_transposed(matrix: str) -> str:
+    return ''.join(matrix[(index % 3) * 3 + index // 3] for index in range(9))
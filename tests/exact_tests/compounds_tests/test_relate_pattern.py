@@ -0,0 +1,52 @@
+import pytest
+from hypothesis import given
+
+from tests.exact_tests.hints import Compound
+
+from . import strategies
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_basic(first: Compound, second: Compound) -> None:
+    result = first.relate_pattern(second, '*********')
+
+    assert isinstance(result, bool)
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_wildcards(first: Compound, second: Compound) -> None:
+    assert first.relate_pattern(second, '*********')
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_own_matrix(first: Compound, second: Compound) -> None:
+    assert first.relate_pattern(second, first.relate_matrix(second))
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_non_empty_entries(first: Compound, second: Compound) -> None:
+    matrix = first.relate_matrix(second)
+
+    assert all(
+        first.relate_pattern(second, '*' * index + 'T' + '*' * (8 - index))
+        is (entry != 'F')
+        for index, entry in enumerate(matrix)
+    )
+
+
+@given(
+    strategies.compounds,
+    strategies.compounds,
+    strategies.invalid_length_patterns,
+)
+def test_invalid_length(
+    first: Compound, second: Compound, pattern: str
+) -> None:
+    with pytest.raises(ValueError):
+        first.relate_pattern(second, pattern)
+
+
+@given(strategies.compounds, strategies.compounds)
+def test_invalid_character(first: Compound, second: Compound) -> None:
+    with pytest.raises(ValueError):
+        first.relate_pattern(second, 'T*F**FFFx')
@@ -1,3 +1,9 @@
+from hypothesis import strategies as _st
+
 from tests.exact_tests import strategies as _strategies
 
+multipolygons = _strategies.multipolygons
+multisegments = _strategies.multisegments
 polygons = _strategies.polygons
+points = _strategies.points
+points_lists = _st.lists(points)
@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rene.exact import ConstrainedDelaunayTriangulation, Polygon
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    result = triangulation._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
@@ -0,0 +1,36 @@
+from hypothesis import given
+
+from rene.exact import ConstrainedDelaunayTriangulation, Multipolygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = ConstrainedDelaunayTriangulation.from_multipolygon(multipolygon)
+
+    assert isinstance(result, ConstrainedDelaunayTriangulation)
+
+
+@given(strategies.multipolygons)
+def test_endpoints(multipolygon: Multipolygon) -> None:
+    result = ConstrainedDelaunayTriangulation.from_multipolygon(multipolygon)
+
+    assert list(result.endpoints) == sorted(
+        {
+            vertex
+            for polygon in multipolygon.polygons
+            for contour in [polygon.border, *polygon.holes]
+            for vertex in contour.vertices
+        }
+    )
+
+
+@given(strategies.multipolygons)
+def test_triangles(multipolygon: Multipolygon) -> None:
+    result = ConstrainedDelaunayTriangulation.from_multipolygon(multipolygon)
+
+    assert (
+        sum(triangle.area for triangle in result.triangles)
+        == multipolygon.area
+    )
@@ -0,0 +1,51 @@
+from hypothesis import given
+
+from rene.enums import Relation
+from rene.exact import (
+    ConstrainedDelaunayTriangulation,
+    Multisegment,
+    Point,
+    Segment,
+)
+
+from . import strategies
+
+
+@given(strategies.points_lists, strategies.multisegments)
+def test_basic(points: list[Point], multisegment: Multisegment) -> None:
+    result = ConstrainedDelaunayTriangulation.from_points_and_multisegment(
+        points, multisegment
+    )
+
+    assert isinstance(result, ConstrainedDelaunayTriangulation)
+
+
+@given(strategies.points_lists, strategies.multisegments)
+def test_endpoints(points: list[Point], multisegment: Multisegment) -> None:
+    result = ConstrainedDelaunayTriangulation.from_points_and_multisegment(
+        points, multisegment
+    )
+
+    assert set(points) <= set(result.endpoints)
+    assert all(
+        segment.start in result.endpoints and segment.end in result.endpoints
+        for segment in multisegment.segments
+    )
+
+
+@given(strategies.points_lists, strategies.multisegments)
+def test_constraints(points: list[Point], multisegment: Multisegment) -> None:
+    result = ConstrainedDelaunayTriangulation.from_points_and_multisegment(
+        points, multisegment
+    )
+
+    assert all(
+        Segment(start, end).relate_to(segment) is not Relation.CROSS
+        for triangle in result.triangles
+        for start, end in zip(
+            triangle.vertices,
+            [*triangle.vertices[1:], triangle.vertices[0]],
+            strict=True,
+        )
+        for segment in multisegment.segments
+    )
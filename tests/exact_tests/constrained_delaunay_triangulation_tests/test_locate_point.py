@@ -0,0 +1,76 @@
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import (
+    ConstrainedDelaunayTriangulation,
+    Contour,
+    Point,
+    Polygon,
+    Segment,
+)
+
+from . import strategies
+
+
+@given(strategies.polygons, strategies.points)
+def test_basic(polygon: Polygon, point: Point) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    result = triangulation.locate_point(point)
+
+    assert isinstance(result, tuple)
+    assert len(result) == 2
+    indices, geometry = result
+    assert isinstance(indices, tuple)
+    assert all(isinstance(index, int) for index in indices)
+    assert len(indices) <= 3
+    assert isinstance(
+        geometry, (type(None), Point, Segment, Contour)[len(indices)]
+    )
+
+
+@given(strategies.polygons)
+def test_vertices(polygon: Polygon) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    endpoints = triangulation.endpoints
+    assert all(
+        triangulation.locate_point(vertex)
+        == ((endpoints.index(vertex),), vertex)
+        for contour in [polygon.border, *polygon.holes]
+        for vertex in contour.vertices
+    )
+
+
+@given(strategies.polygons, strategies.points)
+def test_geometry(polygon: Polygon, point: Point) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    indices, geometry = triangulation.locate_point(point)
+
+    endpoints = triangulation.endpoints
+    if len(indices) == 0:
+        assert polygon.locate(point) is Location.EXTERIOR
+    elif len(indices) == 1:
+        assert geometry == endpoints[indices[0]] == point
+    elif len(indices) == 2:
+        assert geometry == Segment(*[endpoints[index] for index in indices])
+        assert geometry.locate(point) is Location.BOUNDARY
+        assert polygon.locate(point) is not Location.EXTERIOR
+    else:
+        assert geometry == Contour([endpoints[index] for index in indices])
+        assert geometry in triangulation.triangles
+        assert Polygon(geometry, []).locate(point) is Location.INTERIOR
+        assert polygon.locate(point) is Location.INTERIOR
+
+
+@given(strategies.polygons, strategies.points)
+def test_indices_order(polygon: Polygon, point: Point) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    indices, _ = triangulation.locate_point(point)
+
+    endpoints = triangulation.endpoints
+    assert all(
+        endpoints[indices[0]] < endpoints[index] for index in indices[1:]
+    )
@@ -0,0 +1,99 @@
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import (
+    ConstrainedDelaunayTriangulation,
+    Contour,
+    IndexedMesh,
+    Point,
+    Polygon,
+)
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    result = triangulation.to_indexed_mesh()
+
+    assert isinstance(result, IndexedMesh)
+    assert (
+        len(result.triangles)
+        == len(result.neighbours)
+        == len(result.constrained_flags)
+    )
+    assert list(result.vertices) == list(triangulation.endpoints)
+
+
+@given(strategies.polygons)
+def test_triangles(polygon: Polygon) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    result = triangulation.to_indexed_mesh()
+
+    vertices = result.vertices
+    assert [
+        Contour([vertices[index] for index in triangle])
+        for triangle in result.triangles
+    ] == list(triangulation.triangles)
+
+
+@given(strategies.polygons)
+def test_neighbours(polygon: Polygon) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    result = triangulation.to_indexed_mesh()
+
+    triangles, neighbours = result.triangles, result.neighbours
+    assert all(
+        neighbour is None
+        or (
+            _to_edges(triangles[neighbour])[
+                neighbours[neighbour].index(triangle_index)
+            ]
+            == _to_edges(triangle)[edge_index][::-1]
+        )
+        for triangle_index, (triangle, triangle_neighbours) in enumerate(
+            zip(triangles, neighbours)
+        )
+        for edge_index, neighbour in enumerate(triangle_neighbours)
+    )
+
+
+@given(strategies.polygons)
+def test_constrained_flags(polygon: Polygon) -> None:
+    triangulation = ConstrainedDelaunayTriangulation.from_polygon(polygon)
+
+    result = triangulation.to_indexed_mesh()
+
+    assert all(
+        flag is (neighbour is None)
+        for flags, triangle_neighbours in zip(
+            result.constrained_flags, result.neighbours
+        )
+        for flag, neighbour in zip(flags, triangle_neighbours)
+    )
+    assert all(
+        (
+            polygon.locate(
+                _to_midpoint(
+                    result.vertices[start_index], result.vertices[end_index]
+                )
+            )
+            is Location.BOUNDARY
+        )
+        is flag
+        for triangle, flags in zip(result.triangles, result.constrained_flags)
+        for (start_index, end_index), flag in zip(_to_edges(triangle), flags)
+    )
+
+
+def _to_edges(triangle: tuple[int, int, int], /) -> list[tuple[int, int]]:
+    first, second, third = triangle
+    return [(first, second), (second, third), (third, first)]
+
+
+def _to_midpoint(start: Point, end: Point, /) -> Point:
+    return Point((start.x + end.x) / 2, (start.y + end.y) / 2)
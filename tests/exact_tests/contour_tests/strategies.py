@@ -8,6 +8,7 @@ from tests.exact_tests import strategies as _strategies
 
 points = _strategies.points
 non_zero_integers = _strategies.non_zero_integers
+fill_rules = _strategies.fill_rules
 contours_vertices = _strategies.contours_vertices
 contours_like_vertices = _st.lists(
     points, unique=True, min_size=_MIN_CONTOUR_VERTICES_COUNT
@@ -17,3 +18,8 @@ invalid_count_contours_vertices = _st.lists(
 )
 contours_like = _st.builds(_Contour, contours_like_vertices)
 contours = _strategies.contours
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+singular_affine_matrices = _strategies.singular_affine_matrices
+scalars = _strategies.scalars
+non_zero_scalars = scalars.filter(bool)
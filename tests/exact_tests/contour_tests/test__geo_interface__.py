@@ -0,0 +1,14 @@
+from hypothesis import given
+
+from rene.exact import Contour
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    result = contour.__geo_interface__
+
+    assert isinstance(result, dict)
+    assert result['type'] == 'LineString'
+    assert isinstance(result['coordinates'], tuple)
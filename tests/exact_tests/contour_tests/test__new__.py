@@ -21,3 +21,19 @@ def test_basic(vertices: Sequence[Point]) -> None:
 def test_invalid_vertices_count(vertices: Sequence[Point]) -> None:
     with pytest.raises(ValueError):
         Contour(vertices)
+
+
+@given(strategies.contours_vertices)
+def test_validated(vertices: Sequence[Point]) -> None:
+    result = Contour(vertices, validate=True)
+
+    assert result == Contour(vertices)
+
+
+@given(strategies.contours_like_vertices)
+def test_validated_invalid(vertices: Sequence[Point]) -> None:
+    if Contour(vertices).validate():
+        with pytest.raises(ValueError):
+            Contour(vertices, validate=True)
+    else:
+        assert Contour(vertices, validate=True) == Contour(vertices)
@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Contour
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    result = contour._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
@@ -0,0 +1,27 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Contour
+from tests.utils import reverse_contour_vertices, rotate_contour
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    assert isinstance(contour.area, Fraction)
+
+
+@given(strategies.contours)
+def test_value(contour: Contour) -> None:
+    assert contour.area >= 0
+
+
+@given(strategies.contours)
+def test_reversals(contour: Contour) -> None:
+    assert contour.area == reverse_contour_vertices(contour).area
+
+
+@given(strategies.contours, strategies.non_zero_integers)
+def test_vertices_rotations(contour: Contour, offset: int) -> None:
+    assert contour.area == rotate_contour(contour, offset).area
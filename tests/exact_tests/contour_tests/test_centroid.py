@@ -0,0 +1,47 @@
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import Contour, Point, Polygon
+from tests.utils import (
+    implication,
+    is_point_inside_box,
+    reverse_contour_coordinates,
+    reverse_contour_vertices,
+    reverse_point_coordinates,
+    rotate_contour,
+)
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    result = contour.centroid
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.contours)
+def test_relations(contour: Contour) -> None:
+    result = contour.centroid
+
+    assert is_point_inside_box(result, contour.bounding_box)
+    assert implication(
+        contour.is_valid() and len(contour.vertices) == 3,
+        Polygon(contour, []).locate(result) is Location.INTERIOR,
+    )
+
+
+@given(strategies.contours)
+def test_reversals(contour: Contour) -> None:
+    result = contour.centroid
+
+    assert result == reverse_contour_vertices(contour).centroid
+    assert reverse_point_coordinates(result) == (
+        reverse_contour_coordinates(contour).centroid
+    )
+
+
+@given(strategies.contours, strategies.non_zero_integers)
+def test_vertices_rotations(contour: Contour, offset: int) -> None:
+    assert contour.centroid == rotate_contour(contour, offset).centroid
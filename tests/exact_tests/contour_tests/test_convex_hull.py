@@ -0,0 +1,54 @@
+from hypothesis import given
+
+from rene._exact import orient
+from rene.enums import Location
+from rene.exact import Contour, Polygon
+from tests.utils import (
+    pack_convex_hull,
+    reverse_contour_vertices,
+    rotate_contour,
+    to_convex_hull,
+)
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    result = contour.convex_hull()
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.contours)
+def test_value(contour: Contour) -> None:
+    result = contour.convex_hull()
+
+    assert result == pack_convex_hull(to_convex_hull(contour.vertices, orient))
+
+
+@given(strategies.contours)
+def test_relations(contour: Contour) -> None:
+    result = contour.convex_hull()
+
+    assert not result.holes
+    assert result.area >= contour.area
+    assert all(
+        result.locate(vertex) is not Location.EXTERIOR
+        for vertex in contour.vertices
+    )
+
+
+@given(strategies.contours)
+def test_idempotence(contour: Contour) -> None:
+    result = contour.convex_hull()
+
+    assert result.convex_hull() == result
+
+
+@given(strategies.contours, strategies.non_zero_integers)
+def test_permutations(contour: Contour, offset: int) -> None:
+    result = contour.convex_hull()
+
+    assert result == reverse_contour_vertices(contour).convex_hull()
+    assert result == rotate_contour(contour, offset).convex_hull()
@@ -0,0 +1,21 @@
+from hypothesis import given
+
+from rene.exact import Contour
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_round_trip(contour: Contour) -> None:
+    geo_interface = contour.__geo_interface__
+
+    result = Contour.from_geo_interface(geo_interface)
+
+    assert result.__geo_interface__ == geo_interface
+
+
+@given(strategies.contours)
+def test_geo_interface_provider(contour: Contour) -> None:
+    result = Contour.from_geo_interface(contour)
+
+    assert result.__geo_interface__ == contour.__geo_interface__
@@ -0,0 +1,20 @@
+from hypothesis import given
+
+from rene.exact import Contour
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_round_trip(contour: Contour) -> None:
+    wkt = to_maybe_wkt(contour)
+
+    assert wkt is None or Contour.from_wkt(wkt) == contour
+
+
+@given(strategies.contours)
+def test_case_insensitivity(contour: Contour) -> None:
+    wkt = to_maybe_wkt(contour)
+
+    assert wkt is None or Contour.from_wkt(wkt.lower()) == contour
@@ -0,0 +1,28 @@
+from hypothesis import given
+
+from rene.enums import FillRule
+from rene.exact import Contour, Empty, Multipolygon, Polygon
+
+from . import strategies
+
+
+@given(strategies.contours_like, strategies.fill_rules)
+def test_basic(contour: Contour, fill_rule: FillRule) -> None:
+    result = contour.make_valid(fill_rule)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.contours_like, strategies.fill_rules)
+def test_validity(contour: Contour, fill_rule: FillRule) -> None:
+    result = contour.make_valid(fill_rule)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.contours, strategies.fill_rules)
+def test_valid(contour: Contour, fill_rule: FillRule) -> None:
+    result = contour.make_valid(fill_rule)
+
+    assert isinstance(result, Polygon)
+    assert result.area == contour.area
@@ -0,0 +1,34 @@
+from hypothesis import given
+
+from rene.exact import Contour
+from tests.utils import reverse_contour_vertices, rotate_contour
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    result = contour.normalize()
+
+    assert isinstance(result, Contour)
+
+
+@given(strategies.contours)
+def test_idempotence(contour: Contour) -> None:
+    result = contour.normalize()
+
+    assert repr(result.normalize()) == repr(result)
+
+
+@given(strategies.contours)
+def test_reversals(contour: Contour) -> None:
+    assert repr(contour.normalize()) == repr(
+        reverse_contour_vertices(contour).normalize()
+    )
+
+
+@given(strategies.contours, strategies.non_zero_integers)
+def test_rotations(contour: Contour, offset: int) -> None:
+    assert repr(contour.normalize()) == repr(
+        rotate_contour(contour, offset).normalize()
+    )
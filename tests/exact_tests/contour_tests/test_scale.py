@@ -0,0 +1,46 @@
+import pytest
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Contour
+
+from . import strategies
+
+
+@given(
+    strategies.contours,
+    strategies.non_zero_scalars,
+    strategies.non_zero_scalars,
+)
+def test_basic(
+    contour: Contour, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = contour.scale(factor_x, factor_y)
+
+    assert isinstance(result, Contour)
+
+
+@given(strategies.contours, strategies.scalars)
+def test_zero_factor(contour: Contour, factor: Fraction) -> None:
+    with pytest.raises(ValueError):
+        contour.scale(0, factor)
+    with pytest.raises(ValueError):
+        contour.scale(factor, 0)
+
+
+@given(strategies.contours)
+def test_identity(contour: Contour) -> None:
+    assert contour.scale(1, 1) == contour
+
+
+@given(
+    strategies.contours,
+    strategies.non_zero_scalars,
+    strategies.non_zero_scalars,
+)
+def test_connection_with_transform(
+    contour: Contour, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = contour.scale(factor_x, factor_y)
+
+    assert result == contour.transform(((factor_x, 0, 0), (0, factor_y, 0)))
@@ -0,0 +1,39 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.enums import Orientation
+from rene.exact import Contour
+from tests.utils import implication, reverse_contour_vertices, rotate_contour
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    assert isinstance(contour.signed_area, Fraction)
+
+
+@given(strategies.contours)
+def test_value(contour: Contour) -> None:
+    assert abs(contour.signed_area) == contour.area
+
+
+@given(strategies.contours)
+def test_orientation(contour: Contour) -> None:
+    assert implication(
+        contour.is_valid(),
+        (contour.signed_area > 0)
+        is (contour.orientation is Orientation.COUNTERCLOCKWISE),
+    )
+
+
+@given(strategies.contours)
+def test_reversals(contour: Contour) -> None:
+    assert (
+        contour.signed_area == -reverse_contour_vertices(contour).signed_area
+    )
+
+
+@given(strategies.contours, strategies.non_zero_integers)
+def test_vertices_rotations(contour: Contour, offset: int) -> None:
+    assert contour.signed_area == rotate_contour(contour, offset).signed_area
@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Contour
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.contours)
+def test_basic(contour: Contour) -> None:
+    result = to_maybe_wkt(contour)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('LINEARRING ')
+    )
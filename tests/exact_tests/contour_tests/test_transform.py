@@ -0,0 +1,33 @@
+from hypothesis import given
+
+from rene.exact import Contour
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.contours, strategies.non_degenerate_affine_matrices)
+def test_basic(contour: Contour, matrix: AffineMatrix) -> None:
+    result = contour.transform(matrix)
+
+    assert isinstance(result, Contour)
+
+
+@given(strategies.contours, strategies.singular_affine_matrices)
+def test_singular(contour: Contour, matrix: AffineMatrix) -> None:
+    result = contour.transform(matrix)
+
+    assert isinstance(result, Contour)
+    assert result.area == 0
+
+
+@given(strategies.contours)
+def test_identity(contour: Contour) -> None:
+    assert contour.transform(((1, 0, 0), (0, 1, 0))) == contour
+
+
+@given(strategies.contours, strategies.non_degenerate_affine_matrices)
+def test_orientation(contour: Contour, matrix: AffineMatrix) -> None:
+    result = contour.transform(matrix)
+
+    assert result.orientation is contour.orientation
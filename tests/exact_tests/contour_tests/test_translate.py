@@ -0,0 +1,31 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Contour
+
+from . import strategies
+
+
+@given(strategies.contours, strategies.scalars, strategies.scalars)
+def test_basic(contour: Contour, step_x: Fraction, step_y: Fraction) -> None:
+    result = contour.translate(step_x, step_y)
+
+    assert isinstance(result, Contour)
+
+
+@given(strategies.contours, strategies.scalars, strategies.scalars)
+def test_round_trip(
+    contour: Contour, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = contour.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == contour
+
+
+@given(strategies.contours, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    contour: Contour, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = contour.translate(step_x, step_y)
+
+    assert result == contour.transform(((1, 0, step_x), (0, 1, step_y)))
@@ -0,0 +1,25 @@
+from hypothesis import given
+
+from rene.enums import ViolationKind
+from rene.exact import Contour, Violation
+
+from . import strategies
+
+
+@given(strategies.contours_like)
+def test_basic(contour: Contour) -> None:
+    result = contour.validate()
+
+    assert isinstance(result, list)
+    assert all(
+        isinstance(violation, Violation)
+        and isinstance(violation.kind, ViolationKind)
+        for violation in result
+    )
+
+
+@given(strategies.contours)
+def test_valid(contour: Contour) -> None:
+    result = contour.validate()
+
+    assert result == []
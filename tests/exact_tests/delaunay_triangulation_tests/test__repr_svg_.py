@@ -0,0 +1,20 @@
+from collections.abc import Sequence
+
+from hypothesis import given
+
+from rene.exact import DelaunayTriangulation, Point
+
+from . import strategies
+
+
+@given(strategies.points_lists)
+def test_basic(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
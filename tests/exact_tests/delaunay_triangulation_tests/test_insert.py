@@ -0,0 +1,97 @@
+from collections.abc import Sequence
+
+from hypothesis import given
+
+from rene._exact import orient
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
+from rene.exact import Contour, DelaunayTriangulation, Point
+from tests.utils import (
+    is_contour_triangular,
+    is_point_inside_circumcircle,
+    to_convex_hull,
+)
+
+from . import strategies
+
+
+@given(strategies.points_lists, strategies.points)
+def test_basic(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.insert(point)
+
+    assert isinstance(result, bool)
+    assert result is (point not in points)
+
+
+@given(strategies.points_lists, strategies.points)
+def test_border(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    triangulation.insert(point)
+
+    convex_hull = to_convex_hull([*points, point], orient)
+    assert len(
+        convex_hull
+    ) < MIN_CONTOUR_VERTICES_COUNT or triangulation.border == Contour(
+        convex_hull
+    )
+
+
+@given(strategies.points_lists, strategies.points)
+def test_triangles(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    triangulation.insert(point)
+
+    triangles = triangulation.triangles
+    assert len(triangles) == len(
+        DelaunayTriangulation.from_points([*points, point]).triangles
+    )
+    assert all(is_contour_triangular(triangle) for triangle in triangles)
+
+
+@given(strategies.points_lists, strategies.points)
+def test_delaunay_criterion(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    triangulation.insert(point)
+
+    assert all(
+        not any(
+            is_point_inside_circumcircle(vertex, *triangle.vertices)
+            for triangle in triangulation.triangles
+        )
+        for vertex in [*points, point]
+    )
+
+
+@given(strategies.points_lists, strategies.points)
+def test_idempotence(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+    triangulation.insert(point)
+    triangles = triangulation.triangles
+
+    result = triangulation.insert(point)
+
+    assert not result
+    assert triangulation.triangles == triangles
+
+
+@given(strategies.points_lists)
+def test_step(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points([])
+
+    for point in points:
+        triangulation.insert(point)
+
+    assert len(triangulation.triangles) == len(
+        DelaunayTriangulation.from_points(points).triangles
+    )
+    assert all(
+        not any(
+            is_point_inside_circumcircle(point, *triangle.vertices)
+            for triangle in triangulation.triangles
+        )
+        for point in points
+    )
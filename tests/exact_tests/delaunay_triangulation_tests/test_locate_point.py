@@ -0,0 +1,88 @@
+from collections.abc import Sequence
+
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import (
+    Contour,
+    DelaunayTriangulation,
+    Point,
+    Polygon,
+    Segment,
+)
+
+from . import strategies
+
+
+@given(strategies.points_lists, strategies.points)
+def test_basic(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.locate_point(point)
+
+    assert isinstance(result, tuple)
+    assert len(result) == 2
+    indices, geometry = result
+    assert isinstance(indices, tuple)
+    assert all(isinstance(index, int) for index in indices)
+    assert len(indices) <= 3
+    assert isinstance(
+        geometry, (type(None), Point, Segment, Contour)[len(indices)]
+    )
+
+
+@given(strategies.points_lists)
+def test_vertices(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    endpoints = triangulation.endpoints
+    assert all(
+        triangulation.locate_point(point) == ((endpoints.index(point),), point)
+        for point in points
+    )
+
+
+@given(strategies.points_lists, strategies.points)
+def test_geometry(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    indices, geometry = triangulation.locate_point(point)
+
+    endpoints = triangulation.endpoints
+    if len(indices) == 0:
+        assert point not in endpoints
+        triangles = triangulation.triangles
+        assert (
+            all(
+                Polygon(triangle, []).locate(point) is Location.EXTERIOR
+                for triangle in triangles
+            )
+            if triangles
+            else (
+                len(endpoints) < 2
+                or Segment(min(endpoints), max(endpoints)).locate(point)
+                is Location.EXTERIOR
+            )
+        )
+    elif len(indices) == 1:
+        assert geometry == endpoints[indices[0]] == point
+    elif len(indices) == 2:
+        assert geometry == Segment(*[endpoints[index] for index in indices])
+        assert geometry.locate(point) is Location.BOUNDARY
+        assert point not in endpoints
+    else:
+        assert geometry == Contour([endpoints[index] for index in indices])
+        assert geometry in triangulation.triangles
+        assert Polygon(geometry, []).locate(point) is Location.INTERIOR
+
+
+@given(strategies.points_lists, strategies.points)
+def test_indices_order(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    indices, _ = triangulation.locate_point(point)
+
+    endpoints = triangulation.endpoints
+    assert all(
+        endpoints[indices[0]] < endpoints[index] for index in indices[1:]
+    )
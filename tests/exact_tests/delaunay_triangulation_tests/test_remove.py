@@ -0,0 +1,95 @@
+from collections.abc import Sequence
+
+from hypothesis import given
+
+from rene._exact import orient
+from rene.constants import MIN_CONTOUR_VERTICES_COUNT
+from rene.exact import Contour, DelaunayTriangulation, Point
+from tests.utils import (
+    is_contour_triangular,
+    is_point_inside_circumcircle,
+    to_convex_hull,
+)
+
+from . import strategies
+
+
+@given(strategies.points_lists, strategies.points)
+def test_basic(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.remove(point)
+
+    assert isinstance(result, bool)
+    assert result is (point in points)
+
+
+@given(strategies.points_lists)
+def test_border(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+    point, rest_points = points[0], [
+        candidate for candidate in points if candidate != points[0]
+    ]
+
+    triangulation.remove(point)
+
+    convex_hull = to_convex_hull(rest_points, orient)
+    assert len(
+        convex_hull
+    ) < MIN_CONTOUR_VERTICES_COUNT or triangulation.border == Contour(
+        convex_hull
+    )
+
+
+@given(strategies.points_lists)
+def test_triangles(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+    point, rest_points = points[0], [
+        candidate for candidate in points if candidate != points[0]
+    ]
+
+    triangulation.remove(point)
+
+    triangles = triangulation.triangles
+    assert len(triangles) == len(
+        DelaunayTriangulation.from_points(rest_points).triangles
+    )
+    assert all(is_contour_triangular(triangle) for triangle in triangles)
+    assert all(point not in triangle.vertices for triangle in triangles)
+
+
+@given(strategies.points_lists)
+def test_delaunay_criterion(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    triangulation.remove(points[0])
+
+    assert all(
+        not any(
+            is_point_inside_circumcircle(point, *triangle.vertices)
+            for triangle in triangulation.triangles
+        )
+        for point in points
+        if point != points[0]
+    )
+
+
+@given(strategies.points_lists, strategies.points)
+def test_round_trip(points: Sequence[Point], point: Point) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+    triangles_count = len(triangulation.triangles)
+
+    if triangulation.insert(point):
+        assert triangulation.remove(point)
+
+    assert len(triangulation.triangles) == triangles_count
+
+
+@given(strategies.points_lists)
+def test_step(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    for point in points:
+        triangulation.remove(point)
+
+    assert not triangulation
@@ -0,0 +1,101 @@
+from collections.abc import Sequence
+
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import (
+    Contour,
+    DelaunayTriangulation,
+    IndexedMesh,
+    Point,
+    Polygon,
+)
+
+from . import strategies
+
+
+@given(strategies.points_lists)
+def test_basic(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_indexed_mesh()
+
+    assert isinstance(result, IndexedMesh)
+    assert (
+        len(result.triangles)
+        == len(result.neighbours)
+        == len(result.constrained_flags)
+    )
+    assert list(result.vertices) == list(triangulation.endpoints)
+
+
+@given(strategies.points_lists)
+def test_triangles(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_indexed_mesh()
+
+    vertices = result.vertices
+    assert [
+        Contour([vertices[index] for index in triangle])
+        for triangle in result.triangles
+    ] == list(triangulation.triangles)
+
+
+@given(strategies.points_lists)
+def test_neighbours(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_indexed_mesh()
+
+    triangles, neighbours = result.triangles, result.neighbours
+    assert all(
+        neighbour is None
+        or (
+            _to_edges(triangles[neighbour])[
+                neighbours[neighbour].index(triangle_index)
+            ]
+            == _to_edges(triangle)[edge_index][::-1]
+        )
+        for triangle_index, (triangle, triangle_neighbours) in enumerate(
+            zip(triangles, neighbours)
+        )
+        for edge_index, neighbour in enumerate(triangle_neighbours)
+    )
+
+
+@given(strategies.points_lists)
+def test_constrained_flags(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_indexed_mesh()
+
+    assert all(
+        flag is (neighbour is None)
+        for flags, triangle_neighbours in zip(
+            result.constrained_flags, result.neighbours
+        )
+        for flag, neighbour in zip(flags, triangle_neighbours)
+    )
+    assert not result.triangles or all(
+        (
+            Polygon(triangulation.border, []).locate(
+                _to_midpoint(
+                    result.vertices[start_index], result.vertices[end_index]
+                )
+            )
+            is Location.BOUNDARY
+        )
+        is flag
+        for triangle, flags in zip(result.triangles, result.constrained_flags)
+        for (start_index, end_index), flag in zip(_to_edges(triangle), flags)
+    )
+
+
+def _to_edges(triangle: tuple[int, int, int], /) -> list[tuple[int, int]]:
+    first, second, third = triangle
+    return [(first, second), (second, third), (third, first)]
+
+
+def _to_midpoint(start: Point, end: Point, /) -> Point:
+    return Point((start.x + end.x) / 2, (start.y + end.y) / 2)
@@ -0,0 +1,79 @@
+from collections.abc import Sequence
+
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Box, DelaunayTriangulation, Point, VoronoiDiagram
+from tests.utils import is_point_inside_box, to_distinct
+
+from . import strategies
+
+
+@given(strategies.points_lists, strategies.boxes)
+def test_basic(points: Sequence[Point], box: Box) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_voronoi(box)
+
+    assert isinstance(result, VoronoiDiagram)
+    assert len(result.cells) == len(result.sites)
+    assert list(result.sites) == sorted(result.sites)
+    assert set(result.sites) <= set(points)
+
+
+@given(strategies.points_lists, strategies.boxes)
+def test_cells(points: Sequence[Point], box: Box) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_voronoi(box)
+
+    assert sum(cell.area for cell in result.cells) == (
+        (box.max_x - box.min_x) * (box.max_y - box.min_y)
+    )
+    assert all(
+        is_point_inside_box(vertex, box)
+        and all(
+            _to_squared_distance(vertex, site)
+            <= _to_squared_distance(vertex, point)
+            for point in points
+        )
+        for site, cell in zip(result.sites, result.cells)
+        for vertex in cell.border.vertices
+    )
+
+
+@given(strategies.points_lists, strategies.boxes)
+def test_vertices(points: Sequence[Point], box: Box) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.to_voronoi(box)
+
+    vertices = result.vertices
+    assert list(vertices) == sorted(to_distinct(vertices))
+    assert all(
+        any(
+            _to_squared_distance(vertex, first)
+            == _to_squared_distance(vertex, second)
+            == _to_squared_distance(vertex, third)
+            for vertex in vertices
+        )
+        for first, second, third in (
+            triangle.vertices for triangle in triangulation.triangles
+        )
+    )
+
+
+@given(strategies.points, strategies.boxes)
+def test_base_case(point: Point, box: Box) -> None:
+    triangulation = DelaunayTriangulation.from_points([point])
+
+    result = triangulation.to_voronoi(box)
+
+    assert len(result.vertices) == 0
+    assert len(result.cells) == int(
+        box.min_x < box.max_x and box.min_y < box.max_y
+    )
+
+
+def _to_squared_distance(first: Point, second: Point, /) -> Fraction:
+    return (first.x - second.x) ** 2 + (first.y - second.y) ** 2
@@ -0,0 +1,6 @@
+from tests.exact_tests import strategies as _strategies
+
+empty_geometries = _strategies.empty_geometries
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+scalars = _strategies.scalars
@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Empty
+
+from . import strategies
+
+
+@given(strategies.empty_geometries)
+def test_basic(empty: Empty) -> None:
+    result = empty._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
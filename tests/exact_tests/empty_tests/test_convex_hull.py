@@ -0,0 +1,17 @@
+from hypothesis import given
+
+from rene.exact import Empty
+
+from . import strategies
+
+
+@given(strategies.empty_geometries)
+def test_basic(empty: Empty) -> None:
+    result = empty.convex_hull()
+
+    assert isinstance(result, Empty)
+
+
+@given(strategies.empty_geometries)
+def test_value(empty: Empty) -> None:
+    assert empty.convex_hull() == empty
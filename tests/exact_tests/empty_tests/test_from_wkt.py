@@ -0,0 +1,20 @@
+from hypothesis import given
+
+from rene.exact import Empty
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.empty_geometries)
+def test_round_trip(empty: Empty) -> None:
+    wkt = to_maybe_wkt(empty)
+
+    assert wkt is None or Empty.from_wkt(wkt) == empty
+
+
+@given(strategies.empty_geometries)
+def test_case_insensitivity(empty: Empty) -> None:
+    wkt = to_maybe_wkt(empty)
+
+    assert wkt is None or Empty.from_wkt(wkt.lower()) == empty
@@ -0,0 +1,27 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Empty
+
+from . import strategies
+
+
+@given(strategies.empty_geometries, strategies.scalars, strategies.scalars)
+def test_basic(empty: Empty, factor_x: Fraction, factor_y: Fraction) -> None:
+    result = empty.scale(factor_x, factor_y)
+
+    assert isinstance(result, Empty)
+
+
+@given(strategies.empty_geometries)
+def test_identity(empty: Empty) -> None:
+    assert empty.scale(1, 1) == empty
+
+
+@given(strategies.empty_geometries, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    empty: Empty, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = empty.scale(factor_x, factor_y)
+
+    assert result == empty.transform(((factor_x, 0, 0), (0, factor_y, 0)))
@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Empty
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.empty_geometries)
+def test_basic(empty: Empty) -> None:
+    result = to_maybe_wkt(empty)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('GEOMETRYCOLLECTION ')
+    )
@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rene.exact import Empty
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.empty_geometries, strategies.affine_matrices)
+def test_basic(empty: Empty, matrix: AffineMatrix) -> None:
+    result = empty.transform(matrix)
+
+    assert isinstance(result, Empty)
+
+
+@given(strategies.empty_geometries)
+def test_identity(empty: Empty) -> None:
+    assert empty.transform(((1, 0, 0), (0, 1, 0))) == empty
@@ -0,0 +1,29 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Empty
+
+from . import strategies
+
+
+@given(strategies.empty_geometries, strategies.scalars, strategies.scalars)
+def test_basic(empty: Empty, step_x: Fraction, step_y: Fraction) -> None:
+    result = empty.translate(step_x, step_y)
+
+    assert isinstance(result, Empty)
+
+
+@given(strategies.empty_geometries, strategies.scalars, strategies.scalars)
+def test_round_trip(empty: Empty, step_x: Fraction, step_y: Fraction) -> None:
+    result = empty.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == empty
+
+
+@given(strategies.empty_geometries, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    empty: Empty, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = empty.translate(step_x, step_y)
+
+    assert result == empty.transform(((1, 0, step_x), (0, 1, step_y)))
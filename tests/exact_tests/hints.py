@@ -1,6 +1,10 @@
 from typing import TypeVar
 
-from rene import exact as _exact
+from rithm.fraction import Fraction as _Fraction
+
+from rene import exact as _exact, hints as _hints
+
+AffineMatrix = _hints.AffineMatrix[_Fraction]
 
 Compound = (
     _exact.Contour
@@ -10,6 +14,17 @@ Compound = (
     | _exact.Polygon
     | _exact.Segment
 )
+HausdorffMetrizable = (
+    _exact.Contour | _exact.Multipolygon | _exact.Multisegment | _exact.Polygon
+)
+Metrizable = (
+    _exact.Contour
+    | _exact.Multipolygon
+    | _exact.Multisegment
+    | _exact.Point
+    | _exact.Polygon
+    | _exact.Segment
+)
 # here and after we use `TypeVar` instead of `Union` because of
 # https://github.com/python/mypy/issues/6478
 CompoundT = TypeVar(
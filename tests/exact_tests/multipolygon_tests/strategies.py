@@ -6,6 +6,7 @@ from tests.exact_tests import strategies as _strategies
 
 points = _strategies.points
 non_zero_integers = _strategies.non_zero_integers
+fill_rules = _strategies.fill_rules
 multipolygons_polygons = _strategies.multipolygons_polygons
 multipolygons_like_polygons = strategies.lists(
     _strategies.polygons, unique=True, min_size=MIN_MULTIPOLYGON_POLYGONS_COUNT
@@ -19,6 +20,11 @@ multipolygons_like = strategies.builds(
     Multipolygon, multipolygons_like_polygons
 )
 multipolygons = _strategies.multipolygons
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+singular_affine_matrices = _strategies.singular_affine_matrices
+scalars = _strategies.scalars
+non_zero_scalars = scalars.filter(bool)
 compounds = (
     _strategies.empty_geometries
     | _strategies.segments
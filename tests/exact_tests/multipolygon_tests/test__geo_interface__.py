@@ -0,0 +1,14 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = multipolygon.__geo_interface__
+
+    assert isinstance(result, dict)
+    assert result['type'] == 'MultiPolygon'
+    assert isinstance(result['coordinates'], tuple)
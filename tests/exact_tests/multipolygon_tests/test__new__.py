@@ -21,3 +21,19 @@ def test_basic(polygons: Sequence[Polygon]) -> None:
 def test_invalid_polygons_count(polygons: Sequence[Polygon]) -> None:
     with pytest.raises(ValueError):
         Multipolygon(polygons)
+
+
+@given(strategies.multipolygons_polygons)
+def test_validated(polygons: Sequence[Polygon]) -> None:
+    result = Multipolygon(polygons, validate=True)
+
+    assert result == Multipolygon(polygons)
+
+
+@given(strategies.multipolygons_like_polygons)
+def test_validated_invalid(polygons: Sequence[Polygon]) -> None:
+    if Multipolygon(polygons).validate():
+        with pytest.raises(ValueError):
+            Multipolygon(polygons, validate=True)
+    else:
+        assert Multipolygon(polygons, validate=True) == Multipolygon(polygons)
@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = multipolygon._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>') and 'fill-rule="evenodd"' in result
+    )
@@ -0,0 +1,35 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon, Point
+from tests.utils import (
+    is_point_inside_box,
+    reverse_multipolygon_coordinates,
+    reverse_multipolygon_polygons,
+    reverse_point_coordinates,
+)
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = multipolygon.centroid
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.multipolygons)
+def test_relations(multipolygon: Multipolygon) -> None:
+    result = multipolygon.centroid
+
+    assert is_point_inside_box(result, multipolygon.bounding_box)
+
+
+@given(strategies.multipolygons)
+def test_reversals(multipolygon: Multipolygon) -> None:
+    result = multipolygon.centroid
+
+    assert result == reverse_multipolygon_polygons(multipolygon).centroid
+    assert reverse_point_coordinates(result) == (
+        reverse_multipolygon_coordinates(multipolygon).centroid
+    )
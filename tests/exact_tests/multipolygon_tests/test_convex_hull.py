@@ -0,0 +1,50 @@
+from hypothesis import given
+
+from rene._exact import orient
+from rene.exact import Multipolygon, Polygon
+from tests.utils import (
+    pack_convex_hull,
+    reverse_multipolygon_polygons,
+    to_convex_hull,
+)
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = multipolygon.convex_hull()
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.multipolygons)
+def test_value(multipolygon: Multipolygon) -> None:
+    result = multipolygon.convex_hull()
+
+    assert result == pack_convex_hull(
+        to_convex_hull(
+            [
+                vertex
+                for polygon in multipolygon.polygons
+                for vertex in polygon.border.vertices
+            ],
+            orient,
+        )
+    )
+
+
+@given(strategies.multipolygons)
+def test_relations(multipolygon: Multipolygon) -> None:
+    result = multipolygon.convex_hull()
+
+    assert not result.holes
+    assert result.area >= multipolygon.area
+
+
+@given(strategies.multipolygons)
+def test_reversals(multipolygon: Multipolygon) -> None:
+    assert (
+        multipolygon.convex_hull()
+        == reverse_multipolygon_polygons(multipolygon).convex_hull()
+    )
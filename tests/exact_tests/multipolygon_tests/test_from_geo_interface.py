@@ -0,0 +1,21 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_round_trip(multipolygon: Multipolygon) -> None:
+    geo_interface = multipolygon.__geo_interface__
+
+    result = Multipolygon.from_geo_interface(geo_interface)
+
+    assert result.__geo_interface__ == geo_interface
+
+
+@given(strategies.multipolygons)
+def test_geo_interface_provider(multipolygon: Multipolygon) -> None:
+    result = Multipolygon.from_geo_interface(multipolygon)
+
+    assert result.__geo_interface__ == multipolygon.__geo_interface__
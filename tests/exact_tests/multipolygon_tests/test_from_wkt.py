@@ -0,0 +1,20 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_round_trip(multipolygon: Multipolygon) -> None:
+    wkt = to_maybe_wkt(multipolygon)
+
+    assert wkt is None or Multipolygon.from_wkt(wkt) == multipolygon
+
+
+@given(strategies.multipolygons)
+def test_case_insensitivity(multipolygon: Multipolygon) -> None:
+    wkt = to_maybe_wkt(multipolygon)
+
+    assert wkt is None or Multipolygon.from_wkt(wkt.lower()) == multipolygon
@@ -0,0 +1,41 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+from tests.utils import (
+    equivalence,
+    reverse_multipolygon_coordinates,
+    reverse_multipolygon_polygons,
+    rotate_multipolygon,
+)
+
+from . import strategies
+
+
+@given(strategies.multipolygons_like)
+def test_basic(multipolygon: Multipolygon) -> None:
+    assert isinstance(multipolygon.is_valid(), bool)
+
+
+@given(strategies.multipolygons)
+def test_valid(multipolygon: Multipolygon) -> None:
+    assert multipolygon.is_valid()
+
+
+@given(strategies.multipolygons)
+def test_reversals(multipolygon: Multipolygon) -> None:
+    assert equivalence(
+        multipolygon.is_valid(),
+        reverse_multipolygon_coordinates(multipolygon).is_valid(),
+    )
+    assert equivalence(
+        multipolygon.is_valid(),
+        reverse_multipolygon_polygons(multipolygon).is_valid(),
+    )
+
+
+@given(strategies.multipolygons, strategies.non_zero_integers)
+def test_rotations(multipolygon: Multipolygon, offset: int) -> None:
+    assert equivalence(
+        multipolygon.is_valid(),
+        rotate_multipolygon(multipolygon, offset).is_valid(),
+    )
@@ -0,0 +1,28 @@
+from hypothesis import given
+
+from rene.enums import FillRule
+from rene.exact import Empty, Multipolygon, Polygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons_like, strategies.fill_rules)
+def test_basic(multipolygon: Multipolygon, fill_rule: FillRule) -> None:
+    result = multipolygon.make_valid(fill_rule)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.multipolygons_like, strategies.fill_rules)
+def test_validity(multipolygon: Multipolygon, fill_rule: FillRule) -> None:
+    result = multipolygon.make_valid(fill_rule)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.multipolygons)
+def test_valid(multipolygon: Multipolygon) -> None:
+    result = multipolygon.make_valid(FillRule.EVEN_ODD)
+
+    assert not isinstance(result, Empty)
+    assert result.area == multipolygon.area
@@ -0,0 +1,34 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+from tests.utils import reverse_multipolygon_polygons, rotate_multipolygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = multipolygon.normalize()
+
+    assert isinstance(result, Multipolygon)
+
+
+@given(strategies.multipolygons)
+def test_idempotence(multipolygon: Multipolygon) -> None:
+    result = multipolygon.normalize()
+
+    assert repr(result.normalize()) == repr(result)
+
+
+@given(strategies.multipolygons)
+def test_reversals(multipolygon: Multipolygon) -> None:
+    assert repr(multipolygon.normalize()) == repr(
+        reverse_multipolygon_polygons(multipolygon).normalize()
+    )
+
+
+@given(strategies.multipolygons, strategies.non_zero_integers)
+def test_rotations(multipolygon: Multipolygon, offset: int) -> None:
+    assert repr(multipolygon.normalize()) == repr(
+        rotate_multipolygon(multipolygon, offset).normalize()
+    )
@@ -0,0 +1,48 @@
+import pytest
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Multipolygon
+
+from . import strategies
+
+
+@given(
+    strategies.multipolygons,
+    strategies.non_zero_scalars,
+    strategies.non_zero_scalars,
+)
+def test_basic(
+    multipolygon: Multipolygon, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = multipolygon.scale(factor_x, factor_y)
+
+    assert isinstance(result, Multipolygon)
+
+
+@given(strategies.multipolygons, strategies.scalars)
+def test_zero_factor(multipolygon: Multipolygon, factor: Fraction) -> None:
+    with pytest.raises(ValueError):
+        multipolygon.scale(0, factor)
+    with pytest.raises(ValueError):
+        multipolygon.scale(factor, 0)
+
+
+@given(strategies.multipolygons)
+def test_identity(multipolygon: Multipolygon) -> None:
+    assert multipolygon.scale(1, 1) == multipolygon
+
+
+@given(
+    strategies.multipolygons,
+    strategies.non_zero_scalars,
+    strategies.non_zero_scalars,
+)
+def test_connection_with_transform(
+    multipolygon: Multipolygon, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = multipolygon.scale(factor_x, factor_y)
+
+    assert result == multipolygon.transform(
+        ((factor_x, 0, 0), (0, factor_y, 0))
+    )
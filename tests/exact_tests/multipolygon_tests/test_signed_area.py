@@ -0,0 +1,28 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Multipolygon
+from tests.utils import reverse_multipolygon_polygons
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    assert isinstance(multipolygon.signed_area, Fraction)
+
+
+@given(strategies.multipolygons)
+def test_value(multipolygon: Multipolygon) -> None:
+    assert multipolygon.signed_area == sum(
+        [polygon.signed_area for polygon in multipolygon.polygons],
+        Fraction(0),
+    )
+
+
+@given(strategies.multipolygons)
+def test_reversals(multipolygon: Multipolygon) -> None:
+    assert (
+        multipolygon.signed_area
+        == reverse_multipolygon_polygons(multipolygon).signed_area
+    )
@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Multipolygon
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = to_maybe_wkt(multipolygon)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('MULTIPOLYGON ')
+    )
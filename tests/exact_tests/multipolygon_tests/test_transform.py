@@ -0,0 +1,37 @@
+import pytest
+from hypothesis import given
+
+from rene.exact import Multipolygon
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.multipolygons, strategies.non_degenerate_affine_matrices)
+def test_basic(multipolygon: Multipolygon, matrix: AffineMatrix) -> None:
+    result = multipolygon.transform(matrix)
+
+    assert isinstance(result, Multipolygon)
+
+
+@given(strategies.multipolygons, strategies.singular_affine_matrices)
+def test_singular(multipolygon: Multipolygon, matrix: AffineMatrix) -> None:
+    with pytest.raises(ValueError):
+        multipolygon.transform(matrix)
+
+
+@given(strategies.multipolygons)
+def test_identity(multipolygon: Multipolygon) -> None:
+    assert multipolygon.transform(((1, 0, 0), (0, 1, 0))) == multipolygon
+
+
+@given(strategies.multipolygons, strategies.non_degenerate_affine_matrices)
+def test_orientation(multipolygon: Multipolygon, matrix: AffineMatrix) -> None:
+    result = multipolygon.transform(matrix)
+
+    assert all(
+        result_polygon.border.orientation is polygon.border.orientation
+        for result_polygon, polygon in zip(
+            result.polygons, multipolygon.polygons
+        )
+    )
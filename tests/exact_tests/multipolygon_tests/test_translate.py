@@ -0,0 +1,33 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Multipolygon
+
+from . import strategies
+
+
+@given(strategies.multipolygons, strategies.scalars, strategies.scalars)
+def test_basic(
+    multipolygon: Multipolygon, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = multipolygon.translate(step_x, step_y)
+
+    assert isinstance(result, Multipolygon)
+
+
+@given(strategies.multipolygons, strategies.scalars, strategies.scalars)
+def test_round_trip(
+    multipolygon: Multipolygon, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = multipolygon.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == multipolygon
+
+
+@given(strategies.multipolygons, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    multipolygon: Multipolygon, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = multipolygon.translate(step_x, step_y)
+
+    assert result == multipolygon.transform(((1, 0, step_x), (0, 1, step_y)))
@@ -0,0 +1,25 @@
+from hypothesis import given
+
+from rene.enums import ViolationKind
+from rene.exact import Multipolygon, Violation
+
+from . import strategies
+
+
+@given(strategies.multipolygons_like)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = multipolygon.validate()
+
+    assert isinstance(result, list)
+    assert all(
+        isinstance(violation, Violation)
+        and isinstance(violation.kind, ViolationKind)
+        for violation in result
+    )
+
+
+@given(strategies.multipolygons)
+def test_valid(multipolygon: Multipolygon) -> None:
+    result = multipolygon.validate()
+
+    assert result == []
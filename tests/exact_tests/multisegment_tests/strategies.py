@@ -14,3 +14,6 @@ multisegments_like = strategies.builds(
     Multisegment, multisegments_like_segments
 )
 multisegments = _strategies.multisegments
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+scalars = _strategies.scalars
@@ -0,0 +1,14 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_basic(multisegment: Multisegment) -> None:
+    result = multisegment.__geo_interface__
+
+    assert isinstance(result, dict)
+    assert result['type'] == 'MultiLineString'
+    assert isinstance(result['coordinates'], tuple)
@@ -1,5 +1,6 @@
 from collections.abc import Sequence
 
+import pytest
 from hypothesis import given
 
 from rene.exact import Multisegment, Segment
@@ -14,3 +15,19 @@ def test_basic(segments: Sequence[Segment]) -> None:
 
     assert isinstance(result, Multisegment)
     assert are_sequences_equivalent(result.segments, segments)
+
+
+@given(strategies.multisegments_segments)
+def test_validated(segments: Sequence[Segment]) -> None:
+    result = Multisegment(segments, validate=True)
+
+    assert result == Multisegment(segments)
+
+
+@given(strategies.multisegments_like_segments)
+def test_validated_invalid(segments: Sequence[Segment]) -> None:
+    if Multisegment(segments).validate():
+        with pytest.raises(ValueError):
+            Multisegment(segments, validate=True)
+    else:
+        assert Multisegment(segments, validate=True) == Multisegment(segments)
@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_basic(multisegment: Multisegment) -> None:
+    result = multisegment._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
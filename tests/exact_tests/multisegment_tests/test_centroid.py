@@ -0,0 +1,44 @@
+from hypothesis import given
+
+from rene.exact import Multisegment, Point
+from tests.utils import (
+    is_point_inside_box,
+    reverse_multisegment,
+    reverse_multisegment_coordinates,
+    reverse_point_coordinates,
+    rotate_multisegment,
+)
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_basic(multisegment: Multisegment) -> None:
+    result = multisegment.centroid
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.multisegments)
+def test_relations(multisegment: Multisegment) -> None:
+    result = multisegment.centroid
+
+    assert is_point_inside_box(result, multisegment.bounding_box)
+
+
+@given(strategies.multisegments)
+def test_reversals(multisegment: Multisegment) -> None:
+    result = multisegment.centroid
+
+    assert result == reverse_multisegment(multisegment).centroid
+    assert reverse_point_coordinates(result) == (
+        reverse_multisegment_coordinates(multisegment).centroid
+    )
+
+
+@given(strategies.multisegments, strategies.non_zero_integers)
+def test_rotations(multisegment: Multisegment, offset: int) -> None:
+    assert (
+        multisegment.centroid
+        == rotate_multisegment(multisegment, offset).centroid
+    )
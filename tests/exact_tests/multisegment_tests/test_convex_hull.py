@@ -0,0 +1,56 @@
+from hypothesis import given
+
+from rene._exact import orient
+from rene.enums import Location
+from rene.exact import Multisegment, Point, Polygon, Segment
+from tests.utils import (
+    pack_convex_hull,
+    reverse_multisegment,
+    rotate_multisegment,
+    to_convex_hull,
+)
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_basic(multisegment: Multisegment) -> None:
+    result = multisegment.convex_hull()
+
+    assert isinstance(result, (Point, Polygon, Segment))
+
+
+@given(strategies.multisegments)
+def test_value(multisegment: Multisegment) -> None:
+    result = multisegment.convex_hull()
+
+    assert result == pack_convex_hull(
+        to_convex_hull(
+            [
+                endpoint
+                for segment in multisegment.segments
+                for endpoint in (segment.start, segment.end)
+            ],
+            orient,
+        )
+    )
+
+
+@given(strategies.multisegments)
+def test_relations(multisegment: Multisegment) -> None:
+    result = multisegment.convex_hull()
+
+    assert not isinstance(result, Point)
+    assert all(
+        result.locate(segment.start) is not Location.EXTERIOR
+        and result.locate(segment.end) is not Location.EXTERIOR
+        for segment in multisegment.segments
+    )
+
+
+@given(strategies.multisegments, strategies.non_zero_integers)
+def test_permutations(multisegment: Multisegment, offset: int) -> None:
+    result = multisegment.convex_hull()
+
+    assert result == reverse_multisegment(multisegment).convex_hull()
+    assert result == rotate_multisegment(multisegment, offset).convex_hull()
@@ -0,0 +1,21 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_round_trip(multisegment: Multisegment) -> None:
+    geo_interface = multisegment.__geo_interface__
+
+    result = Multisegment.from_geo_interface(geo_interface)
+
+    assert result.__geo_interface__ == geo_interface
+
+
+@given(strategies.multisegments)
+def test_geo_interface_provider(multisegment: Multisegment) -> None:
+    result = Multisegment.from_geo_interface(multisegment)
+
+    assert result.__geo_interface__ == multisegment.__geo_interface__
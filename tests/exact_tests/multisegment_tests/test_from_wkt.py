@@ -0,0 +1,20 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_round_trip(multisegment: Multisegment) -> None:
+    wkt = to_maybe_wkt(multisegment)
+
+    assert wkt is None or Multisegment.from_wkt(wkt) == multisegment
+
+
+@given(strategies.multisegments)
+def test_case_insensitivity(multisegment: Multisegment) -> None:
+    wkt = to_maybe_wkt(multisegment)
+
+    assert wkt is None or Multisegment.from_wkt(wkt.lower()) == multisegment
@@ -0,0 +1,34 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+from tests.utils import reverse_multisegment, rotate_multisegment
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_basic(multisegment: Multisegment) -> None:
+    result = multisegment.normalize()
+
+    assert isinstance(result, Multisegment)
+
+
+@given(strategies.multisegments)
+def test_idempotence(multisegment: Multisegment) -> None:
+    result = multisegment.normalize()
+
+    assert repr(result.normalize()) == repr(result)
+
+
+@given(strategies.multisegments)
+def test_reversals(multisegment: Multisegment) -> None:
+    assert repr(multisegment.normalize()) == repr(
+        reverse_multisegment(multisegment).normalize()
+    )
+
+
+@given(strategies.multisegments, strategies.non_zero_integers)
+def test_rotations(multisegment: Multisegment, offset: int) -> None:
+    assert repr(multisegment.normalize()) == repr(
+        rotate_multisegment(multisegment, offset).normalize()
+    )
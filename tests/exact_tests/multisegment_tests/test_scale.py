@@ -0,0 +1,31 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Multisegment
+
+from . import strategies
+
+
+@given(strategies.multisegments, strategies.scalars, strategies.scalars)
+def test_basic(
+    multisegment: Multisegment, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = multisegment.scale(factor_x, factor_y)
+
+    assert isinstance(result, Multisegment)
+
+
+@given(strategies.multisegments)
+def test_identity(multisegment: Multisegment) -> None:
+    assert multisegment.scale(1, 1) == multisegment
+
+
+@given(strategies.multisegments, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    multisegment: Multisegment, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = multisegment.scale(factor_x, factor_y)
+
+    assert result == multisegment.transform(
+        ((factor_x, 0, 0), (0, factor_y, 0))
+    )
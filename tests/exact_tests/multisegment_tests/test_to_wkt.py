@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.multisegments)
+def test_basic(multisegment: Multisegment) -> None:
+    result = to_maybe_wkt(multisegment)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('MULTILINESTRING ')
+    )
@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rene.exact import Multisegment
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.multisegments, strategies.affine_matrices)
+def test_basic(multisegment: Multisegment, matrix: AffineMatrix) -> None:
+    result = multisegment.transform(matrix)
+
+    assert isinstance(result, Multisegment)
+
+
+@given(strategies.multisegments)
+def test_identity(multisegment: Multisegment) -> None:
+    assert multisegment.transform(((1, 0, 0), (0, 1, 0))) == multisegment
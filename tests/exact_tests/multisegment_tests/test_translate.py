@@ -0,0 +1,33 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Multisegment
+
+from . import strategies
+
+
+@given(strategies.multisegments, strategies.scalars, strategies.scalars)
+def test_basic(
+    multisegment: Multisegment, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = multisegment.translate(step_x, step_y)
+
+    assert isinstance(result, Multisegment)
+
+
+@given(strategies.multisegments, strategies.scalars, strategies.scalars)
+def test_round_trip(
+    multisegment: Multisegment, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = multisegment.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == multisegment
+
+
+@given(strategies.multisegments, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    multisegment: Multisegment, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = multisegment.translate(step_x, step_y)
+
+    assert result == multisegment.transform(((1, 0, step_x), (0, 1, step_y)))
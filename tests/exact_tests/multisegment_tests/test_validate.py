@@ -0,0 +1,25 @@
+from hypothesis import given
+
+from rene.enums import ViolationKind
+from rene.exact import Multisegment, Violation
+
+from . import strategies
+
+
+@given(strategies.multisegments_like)
+def test_basic(multisegment: Multisegment) -> None:
+    result = multisegment.validate()
+
+    assert isinstance(result, list)
+    assert all(
+        isinstance(violation, Violation)
+        and isinstance(violation.kind, ViolationKind)
+        for violation in result
+    )
+
+
+@given(strategies.multisegments)
+def test_valid(multisegment: Multisegment) -> None:
+    result = multisegment.validate()
+
+    assert result == []
@@ -6,3 +6,8 @@ integers = _st.integers()
 non_zero_integers = integers.filter(bool)
 scalars = _strategies.scalars_strategies.flatmap(lambda strategy: strategy)
 points = _strategies.points
+out_of_range_exponents = _st.integers(min_value=4097) | _st.integers(
+    max_value=-4097
+)
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
@@ -0,0 +1,14 @@
+from hypothesis import given
+
+from rene.exact import Point
+
+from . import strategies
+
+
+@given(strategies.points)
+def test_basic(point: Point) -> None:
+    result = point.__geo_interface__
+
+    assert isinstance(result, dict)
+    assert result['type'] == 'Point'
+    assert isinstance(result['coordinates'], tuple)
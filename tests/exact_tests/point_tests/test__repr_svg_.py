@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Point
+
+from . import strategies
+
+
+@given(strategies.points)
+def test_basic(point: Point) -> None:
+    result = point._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
@@ -0,0 +1,17 @@
+from hypothesis import given
+
+from rene.exact import Point
+
+from . import strategies
+
+
+@given(strategies.points)
+def test_basic(point: Point) -> None:
+    result = point.convex_hull()
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.points)
+def test_value(point: Point) -> None:
+    assert point.convex_hull() == point
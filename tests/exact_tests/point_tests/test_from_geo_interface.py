@@ -0,0 +1,21 @@
+from hypothesis import given
+
+from rene.exact import Point
+
+from . import strategies
+
+
+@given(strategies.points)
+def test_round_trip(point: Point) -> None:
+    geo_interface = point.__geo_interface__
+
+    result = Point.from_geo_interface(geo_interface)
+
+    assert result.__geo_interface__ == geo_interface
+
+
+@given(strategies.points)
+def test_geo_interface_provider(point: Point) -> None:
+    result = Point.from_geo_interface(point)
+
+    assert result.__geo_interface__ == point.__geo_interface__
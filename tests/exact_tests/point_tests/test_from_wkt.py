@@ -0,0 +1,27 @@
+import pytest
+from hypothesis import given
+
+from rene.exact import Point
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.points)
+def test_round_trip(point: Point) -> None:
+    wkt = to_maybe_wkt(point)
+
+    assert wkt is None or Point.from_wkt(wkt) == point
+
+
+@given(strategies.points)
+def test_case_insensitivity(point: Point) -> None:
+    wkt = to_maybe_wkt(point)
+
+    assert wkt is None or Point.from_wkt(wkt.lower()) == point
+
+
+@given(strategies.out_of_range_exponents)
+def test_out_of_range_exponent(exponent: int) -> None:
+    with pytest.raises(ValueError):
+        Point.from_wkt(f'POINT (1e{exponent} 0)')
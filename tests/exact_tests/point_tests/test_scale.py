@@ -0,0 +1,27 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Point
+
+from . import strategies
+
+
+@given(strategies.points, strategies.scalars, strategies.scalars)
+def test_basic(point: Point, factor_x: Fraction, factor_y: Fraction) -> None:
+    result = point.scale(factor_x, factor_y)
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.points)
+def test_identity(point: Point) -> None:
+    assert point.scale(1, 1) == point
+
+
+@given(strategies.points, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    point: Point, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = point.scale(factor_x, factor_y)
+
+    assert result == point.transform(((factor_x, 0, 0), (0, factor_y, 0)))
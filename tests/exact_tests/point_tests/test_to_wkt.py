@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Point
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.points)
+def test_basic(point: Point) -> None:
+    result = to_maybe_wkt(point)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('POINT ')
+    )
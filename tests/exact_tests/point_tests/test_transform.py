@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rene.exact import Point
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.points, strategies.affine_matrices)
+def test_basic(point: Point, matrix: AffineMatrix) -> None:
+    result = point.transform(matrix)
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.points)
+def test_identity(point: Point) -> None:
+    assert point.transform(((1, 0, 0), (0, 1, 0))) == point
@@ -0,0 +1,29 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Point
+
+from . import strategies
+
+
+@given(strategies.points, strategies.scalars, strategies.scalars)
+def test_basic(point: Point, step_x: Fraction, step_y: Fraction) -> None:
+    result = point.translate(step_x, step_y)
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.points, strategies.scalars, strategies.scalars)
+def test_round_trip(point: Point, step_x: Fraction, step_y: Fraction) -> None:
+    result = point.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == point
+
+
+@given(strategies.points, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    point: Point, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = point.translate(step_x, step_y)
+
+    assert result == point.transform(((1, 0, step_x), (0, 1, step_y)))
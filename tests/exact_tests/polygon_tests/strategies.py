@@ -6,15 +6,20 @@ from tests.exact_tests import strategies as _strategies
 
 integers = _st.integers()
 non_zero_integers = integers.filter(bool)
+fill_rules = _strategies.fill_rules
 scalars = (
     integers
     | _st.fractions()
     | _st.builds(_Fraction, integers, non_zero_integers)
     | _st.floats(allow_infinity=False, allow_nan=False)
 )
+non_zero_scalars = scalars.filter(bool)
 points = _st.builds(_Point, scalars, scalars)
 polygons_components = _strategies.polygons_components
 polygons = _strategies.polygons
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+singular_affine_matrices = _strategies.singular_affine_matrices
 compounds = (
     _strategies.empty_geometries
     | _strategies.segments
@@ -0,0 +1,14 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = polygon.__geo_interface__
+
+    assert isinstance(result, dict)
+    assert result['type'] == 'Polygon'
+    assert isinstance(result['coordinates'], tuple)
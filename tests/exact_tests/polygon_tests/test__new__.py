@@ -1,9 +1,10 @@
 from collections.abc import Sequence
 
+import pytest
 from hypothesis import given
 
 from rene.exact import Contour, Polygon
-from tests.utils import are_sequences_equivalent
+from tests.utils import are_sequences_equivalent, reverse_contour_vertices
 
 from . import strategies
 
@@ -17,3 +18,24 @@ def test_basic(components: tuple[Contour, Sequence[Contour]]) -> None:
     assert isinstance(result, Polygon)
     assert result.border == border
     assert are_sequences_equivalent(result.holes, holes)
+
+
+@given(strategies.polygons_components)
+def test_validated(components: tuple[Contour, Sequence[Contour]]) -> None:
+    border, holes = components
+
+    result = Polygon(border, holes, validate=True)
+
+    assert result == Polygon(border, holes)
+
+
+@given(strategies.polygons_components)
+def test_validated_orientation(
+    components: tuple[Contour, Sequence[Contour]],
+) -> None:
+    border, holes = components
+    reversed_border = reverse_contour_vertices(border)
+
+    assert not Polygon(reversed_border, holes).is_valid()
+    with pytest.raises(ValueError):
+        Polygon(reversed_border, holes, validate=True)
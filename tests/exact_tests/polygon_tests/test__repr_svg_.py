@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = polygon._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>') and 'fill-rule="evenodd"' in result
+    )
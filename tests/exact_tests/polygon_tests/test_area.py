@@ -0,0 +1,25 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Polygon
+from tests.utils import reverse_polygon_coordinates, reverse_polygon_holes
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    assert isinstance(polygon.area, Fraction)
+
+
+@given(strategies.polygons)
+def test_value(polygon: Polygon) -> None:
+    assert polygon.area == polygon.border.area - sum(
+        [hole.area for hole in polygon.holes], Fraction(0)
+    )
+
+
+@given(strategies.polygons)
+def test_reversals(polygon: Polygon) -> None:
+    assert polygon.area == reverse_polygon_holes(polygon).area
+    assert polygon.area == reverse_polygon_coordinates(polygon).area
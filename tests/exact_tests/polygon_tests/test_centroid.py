@@ -0,0 +1,35 @@
+from hypothesis import given
+
+from rene.exact import Point, Polygon
+from tests.utils import (
+    implication,
+    reverse_point_coordinates,
+    reverse_polygon_coordinates,
+    reverse_polygon_holes,
+)
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = polygon.centroid
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.polygons)
+def test_holeless(polygon: Polygon) -> None:
+    assert implication(
+        not polygon.holes, polygon.centroid == polygon.border.centroid
+    )
+
+
+@given(strategies.polygons)
+def test_reversals(polygon: Polygon) -> None:
+    result = polygon.centroid
+
+    assert result == reverse_polygon_holes(polygon).centroid
+    assert reverse_point_coordinates(result) == (
+        reverse_polygon_coordinates(polygon).centroid
+    )
@@ -0,0 +1,35 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.utils import reverse_polygon_holes
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = polygon.convex_hull()
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.polygons)
+def test_value(polygon: Polygon) -> None:
+    result = polygon.convex_hull()
+
+    assert result == polygon.border.convex_hull()
+
+
+@given(strategies.polygons)
+def test_relations(polygon: Polygon) -> None:
+    result = polygon.convex_hull()
+
+    assert not result.holes
+    assert result.area >= polygon.area
+
+
+@given(strategies.polygons)
+def test_reversals(polygon: Polygon) -> None:
+    assert (
+        polygon.convex_hull() == reverse_polygon_holes(polygon).convex_hull()
+    )
@@ -0,0 +1,21 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_round_trip(polygon: Polygon) -> None:
+    geo_interface = polygon.__geo_interface__
+
+    result = Polygon.from_geo_interface(geo_interface)
+
+    assert result.__geo_interface__ == geo_interface
+
+
+@given(strategies.polygons)
+def test_geo_interface_provider(polygon: Polygon) -> None:
+    result = Polygon.from_geo_interface(polygon)
+
+    assert result.__geo_interface__ == polygon.__geo_interface__
@@ -0,0 +1,20 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_round_trip(polygon: Polygon) -> None:
+    wkt = to_maybe_wkt(polygon)
+
+    assert wkt is None or Polygon.from_wkt(wkt) == polygon
+
+
+@given(strategies.polygons)
+def test_case_insensitivity(polygon: Polygon) -> None:
+    wkt = to_maybe_wkt(polygon)
+
+    assert wkt is None or Polygon.from_wkt(wkt.lower()) == polygon
@@ -0,0 +1,50 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.utils import (
+    equivalence,
+    reverse_contour_vertices,
+    reverse_polygon_coordinates,
+    reverse_polygon_holes,
+    rotate_polygon_border,
+    rotate_polygon_holes,
+)
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    assert isinstance(polygon.is_valid(), bool)
+
+
+@given(strategies.polygons)
+def test_valid(polygon: Polygon) -> None:
+    assert polygon.is_valid()
+
+
+@given(strategies.polygons)
+def test_border_orientation(polygon: Polygon) -> None:
+    assert not Polygon(
+        reverse_contour_vertices(polygon.border), polygon.holes
+    ).is_valid()
+
+
+@given(strategies.polygons)
+def test_reversals(polygon: Polygon) -> None:
+    assert equivalence(
+        polygon.is_valid(), reverse_polygon_coordinates(polygon).is_valid()
+    )
+    assert equivalence(
+        polygon.is_valid(), reverse_polygon_holes(polygon).is_valid()
+    )
+
+
+@given(strategies.polygons, strategies.non_zero_integers)
+def test_rotations(polygon: Polygon, offset: int) -> None:
+    assert equivalence(
+        polygon.is_valid(), rotate_polygon_border(polygon, offset).is_valid()
+    )
+    assert equivalence(
+        polygon.is_valid(), rotate_polygon_holes(polygon, offset).is_valid()
+    )
@@ -0,0 +1,28 @@
+from hypothesis import given
+
+from rene.enums import FillRule
+from rene.exact import Empty, Multipolygon, Polygon
+
+from . import strategies
+
+
+@given(strategies.polygons, strategies.fill_rules)
+def test_basic(polygon: Polygon, fill_rule: FillRule) -> None:
+    result = polygon.make_valid(fill_rule)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.polygons, strategies.fill_rules)
+def test_validity(polygon: Polygon, fill_rule: FillRule) -> None:
+    result = polygon.make_valid(fill_rule)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.polygons)
+def test_valid(polygon: Polygon) -> None:
+    result = polygon.make_valid(FillRule.EVEN_ODD)
+
+    assert isinstance(result, Polygon)
+    assert result.area == polygon.area
@@ -0,0 +1,45 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.utils import (
+    reverse_polygon_holes,
+    rotate_each_polygon_hole,
+    rotate_polygon_border,
+    rotate_polygon_holes,
+)
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = polygon.normalize()
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.polygons)
+def test_idempotence(polygon: Polygon) -> None:
+    result = polygon.normalize()
+
+    assert repr(result.normalize()) == repr(result)
+
+
+@given(strategies.polygons)
+def test_reversals(polygon: Polygon) -> None:
+    assert repr(polygon.normalize()) == repr(
+        reverse_polygon_holes(polygon).normalize()
+    )
+
+
+@given(strategies.polygons, strategies.non_zero_integers)
+def test_rotations(polygon: Polygon, offset: int) -> None:
+    assert repr(polygon.normalize()) == repr(
+        rotate_polygon_border(polygon, offset).normalize()
+    )
+    assert repr(polygon.normalize()) == repr(
+        rotate_polygon_holes(polygon, offset).normalize()
+    )
+    assert repr(polygon.normalize()) == repr(
+        rotate_each_polygon_hole(polygon, offset).normalize()
+    )
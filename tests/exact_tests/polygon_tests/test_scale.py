@@ -0,0 +1,46 @@
+import pytest
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Polygon
+
+from . import strategies
+
+
+@given(
+    strategies.polygons,
+    strategies.non_zero_scalars,
+    strategies.non_zero_scalars,
+)
+def test_basic(
+    polygon: Polygon, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = polygon.scale(factor_x, factor_y)
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.polygons, strategies.scalars)
+def test_zero_factor(polygon: Polygon, factor: Fraction) -> None:
+    with pytest.raises(ValueError):
+        polygon.scale(0, factor)
+    with pytest.raises(ValueError):
+        polygon.scale(factor, 0)
+
+
+@given(strategies.polygons)
+def test_identity(polygon: Polygon) -> None:
+    assert polygon.scale(1, 1) == polygon
+
+
+@given(
+    strategies.polygons,
+    strategies.non_zero_scalars,
+    strategies.non_zero_scalars,
+)
+def test_connection_with_transform(
+    polygon: Polygon, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = polygon.scale(factor_x, factor_y)
+
+    assert result == polygon.transform(((factor_x, 0, 0), (0, factor_y, 0)))
@@ -0,0 +1,27 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Polygon
+from tests.utils import reverse_polygon_holes
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    assert isinstance(polygon.signed_area, Fraction)
+
+
+@given(strategies.polygons)
+def test_value(polygon: Polygon) -> None:
+    assert abs(polygon.signed_area) == polygon.area
+
+
+@given(strategies.polygons)
+def test_border_orientation(polygon: Polygon) -> None:
+    assert (polygon.signed_area < 0) is (polygon.border.signed_area < 0)
+
+
+@given(strategies.polygons)
+def test_reversals(polygon: Polygon) -> None:
+    assert polygon.signed_area == reverse_polygon_holes(polygon).signed_area
@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = to_maybe_wkt(polygon)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('POLYGON ')
+    )
@@ -0,0 +1,36 @@
+import pytest
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.polygons, strategies.non_degenerate_affine_matrices)
+def test_basic(polygon: Polygon, matrix: AffineMatrix) -> None:
+    result = polygon.transform(matrix)
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.polygons, strategies.singular_affine_matrices)
+def test_singular(polygon: Polygon, matrix: AffineMatrix) -> None:
+    with pytest.raises(ValueError):
+        polygon.transform(matrix)
+
+
+@given(strategies.polygons)
+def test_identity(polygon: Polygon) -> None:
+    assert polygon.transform(((1, 0, 0), (0, 1, 0))) == polygon
+
+
+@given(strategies.polygons, strategies.non_degenerate_affine_matrices)
+def test_orientation(polygon: Polygon, matrix: AffineMatrix) -> None:
+    result = polygon.transform(matrix)
+
+    assert result.border.orientation is polygon.border.orientation
+    assert all(
+        result_hole.orientation is hole.orientation
+        for result_hole, hole in zip(result.holes, polygon.holes)
+    )
@@ -0,0 +1,31 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Polygon
+
+from . import strategies
+
+
+@given(strategies.polygons, strategies.scalars, strategies.scalars)
+def test_basic(polygon: Polygon, step_x: Fraction, step_y: Fraction) -> None:
+    result = polygon.translate(step_x, step_y)
+
+    assert isinstance(result, Polygon)
+
+
+@given(strategies.polygons, strategies.scalars, strategies.scalars)
+def test_round_trip(
+    polygon: Polygon, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = polygon.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == polygon
+
+
+@given(strategies.polygons, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    polygon: Polygon, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = polygon.translate(step_x, step_y)
+
+    assert result == polygon.transform(((1, 0, step_x), (0, 1, step_y)))
@@ -0,0 +1,25 @@
+from hypothesis import given
+
+from rene.enums import ViolationKind
+from rene.exact import Polygon, Violation
+
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    result = polygon.validate()
+
+    assert isinstance(result, list)
+    assert all(
+        isinstance(violation, Violation)
+        and isinstance(violation.kind, ViolationKind)
+        for violation in result
+    )
+
+
+@given(strategies.polygons)
+def test_valid(polygon: Polygon) -> None:
+    result = polygon.validate()
+
+    assert result == []
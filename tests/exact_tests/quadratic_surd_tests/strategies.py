@@ -0,0 +1,13 @@
+from hypothesis import strategies as _st
+
+from rene.exact import QuadraticSurd
+from tests.exact_tests import strategies as _strategies
+
+scalars = _strategies.scalars
+non_negative_scalars = scalars.map(abs)
+negative_scalars = _strategies.non_zero_integers.map(
+    lambda value: -abs(value)
+)
+quadratic_surds = _st.builds(
+    QuadraticSurd, scalars, scalars, non_negative_scalars
+)
@@ -0,0 +1,32 @@
+from hypothesis import given
+
+from rene.exact import QuadraticSurd
+from tests.utils import equivalence, implication
+
+from . import strategies
+
+
+@given(strategies.quadratic_surds)
+def test_reflexivity(quadratic_surd: QuadraticSurd) -> None:
+    assert quadratic_surd == quadratic_surd
+
+
+@given(strategies.quadratic_surds, strategies.quadratic_surds)
+def test_symmetry(first: QuadraticSurd, second: QuadraticSurd) -> None:
+    assert equivalence(first == second, second == first)
+
+
+@given(
+    strategies.quadratic_surds,
+    strategies.quadratic_surds,
+    strategies.quadratic_surds,
+)
+def test_transitivity(
+    first: QuadraticSurd, second: QuadraticSurd, third: QuadraticSurd
+) -> None:
+    assert implication(first == second and second == third, first == third)
+
+
+@given(strategies.quadratic_surds, strategies.quadratic_surds)
+def test_alternatives(first: QuadraticSurd, second: QuadraticSurd) -> None:
+    assert equivalence(first == second, not first != second)
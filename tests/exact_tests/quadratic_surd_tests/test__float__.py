@@ -0,0 +1,31 @@
+import math
+
+from hypothesis import given
+
+from rene.exact import QuadraticSurd
+from tests.hints import Scalar
+
+from . import strategies
+
+
+@given(strategies.quadratic_surds)
+def test_basic(quadratic_surd: QuadraticSurd) -> None:
+    result = float(quadratic_surd)
+
+    assert isinstance(result, float)
+
+
+@given(strategies.scalars)
+def test_rational(rational: Scalar) -> None:
+    result = float(QuadraticSurd(rational, 0, 0))
+
+    assert math.isclose(result, float(rational))
+
+
+@given(strategies.scalars, strategies.non_negative_scalars)
+def test_connection_with_sqrt(irrational: Scalar, radicand: Scalar) -> None:
+    result = float(QuadraticSurd(0, irrational, radicand))
+
+    assert math.isclose(
+        result, float(irrational) * math.sqrt(float(radicand))
+    )
@@ -0,0 +1,35 @@
+from hypothesis import given
+
+from rene.exact import QuadraticSurd
+from tests.utils import equivalence, implication
+
+from . import strategies
+
+
+@given(strategies.quadratic_surds)
+def test_irreflexivity(quadratic_surd: QuadraticSurd) -> None:
+    assert not quadratic_surd < quadratic_surd
+
+
+@given(strategies.quadratic_surds, strategies.quadratic_surds)
+def test_trichotomy(first: QuadraticSurd, second: QuadraticSurd) -> None:
+    assert (first < second) + (first == second) + (second < first) == 1
+
+
+@given(
+    strategies.quadratic_surds,
+    strategies.quadratic_surds,
+    strategies.quadratic_surds,
+)
+def test_transitivity(
+    first: QuadraticSurd, second: QuadraticSurd, third: QuadraticSurd
+) -> None:
+    assert implication(first < second and second < third, first < third)
+
+
+@given(strategies.quadratic_surds, strategies.quadratic_surds)
+def test_alternatives(first: QuadraticSurd, second: QuadraticSurd) -> None:
+    assert equivalence(first < second, second > first)
+    assert equivalence(first < second, not first >= second)
+    assert equivalence(first < second, first <= second and first != second)
+
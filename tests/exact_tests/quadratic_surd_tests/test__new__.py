@@ -0,0 +1,40 @@
+import pytest
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import QuadraticSurd
+from tests.hints import Scalar
+
+from . import strategies
+
+
+@given(
+    strategies.scalars, strategies.scalars, strategies.non_negative_scalars
+)
+def test_basic(rational: Scalar, irrational: Scalar, radicand: Scalar) -> None:
+    result = QuadraticSurd(rational, irrational, radicand)
+
+    assert isinstance(result, QuadraticSurd)
+    assert isinstance(result.rational, Fraction)
+    assert isinstance(result.irrational, Fraction)
+    assert isinstance(result.radicand, Fraction)
+    assert result.rational == rational
+    assert (result.irrational == 0) is (irrational == 0 or radicand == 0)
+    assert (result.radicand == 0) is (irrational == 0 or radicand == 0)
+
+
+@given(strategies.scalars, strategies.scalars, strategies.scalars)
+def test_perfect_square(
+    rational: Scalar, irrational: Scalar, root: Scalar
+) -> None:
+    result = QuadraticSurd(rational, irrational, root * root)
+
+    assert result == QuadraticSurd(rational + irrational * abs(root), 0, 0)
+
+
+@given(strategies.scalars, strategies.scalars, strategies.negative_scalars)
+def test_negative_radicand(
+    rational: Scalar, irrational: Scalar, radicand: Scalar
+) -> None:
+    with pytest.raises(ValueError):
+        QuadraticSurd(rational, irrational, radicand)
@@ -0,0 +1,17 @@
+from hypothesis import given
+from rithm import fraction, integer
+
+from rene import exact
+from rene.exact import QuadraticSurd
+
+from . import strategies
+
+
+@given(strategies.quadratic_surds)
+def test_round_trip(quadratic_surd: QuadraticSurd) -> None:
+    result = repr(quadratic_surd)
+
+    assert (
+        eval(result, {**vars(exact), **vars(fraction), **vars(integer)})
+        == quadratic_surd
+    )
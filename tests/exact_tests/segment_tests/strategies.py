@@ -3,6 +3,9 @@ from tests.exact_tests import strategies as _strategies
 points = _strategies.points
 segments_endpoints = _strategies.segments_endpoints
 segments = _strategies.segments
+affine_matrices = _strategies.affine_matrices
+non_degenerate_affine_matrices = _strategies.non_degenerate_affine_matrices
+scalars = _strategies.scalars
 relatable_compounds = (
     _strategies.contours | _strategies.multisegments | _strategies.segments
 )
@@ -0,0 +1,14 @@
+from hypothesis import given
+
+from rene.exact import Segment
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_basic(segment: Segment) -> None:
+    result = segment.__geo_interface__
+
+    assert isinstance(result, dict)
+    assert result['type'] == 'LineString'
+    assert isinstance(result['coordinates'], tuple)
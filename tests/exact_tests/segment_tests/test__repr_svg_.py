@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Segment
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_basic(segment: Segment) -> None:
+    result = segment._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
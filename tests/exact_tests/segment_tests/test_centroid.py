@@ -0,0 +1,35 @@
+from hypothesis import given
+
+from rene.enums import Location
+from rene.exact import Point, Segment
+from tests.utils import (
+    reverse_point_coordinates,
+    reverse_segment_coordinates,
+    reverse_segment_endpoints,
+)
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_basic(segment: Segment) -> None:
+    result = segment.centroid
+
+    assert isinstance(result, Point)
+
+
+@given(strategies.segments)
+def test_relations(segment: Segment) -> None:
+    result = segment.centroid
+
+    assert segment.locate(result) is not Location.EXTERIOR
+
+
+@given(strategies.segments)
+def test_reversals(segment: Segment) -> None:
+    result = segment.centroid
+
+    assert result == reverse_segment_endpoints(segment).centroid
+    assert reverse_point_coordinates(result) == (
+        reverse_segment_coordinates(segment).centroid
+    )
@@ -0,0 +1,36 @@
+from hypothesis import given
+
+from rene._exact import orient
+from rene.exact import Segment
+from tests.utils import (
+    pack_convex_hull,
+    reverse_segment_endpoints,
+    to_convex_hull,
+)
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_basic(segment: Segment) -> None:
+    result = segment.convex_hull()
+
+    assert isinstance(result, Segment)
+
+
+@given(strategies.segments)
+def test_value(segment: Segment) -> None:
+    result = segment.convex_hull()
+
+    assert result == segment
+    assert result == pack_convex_hull(
+        to_convex_hull([segment.start, segment.end], orient)
+    )
+
+
+@given(strategies.segments)
+def test_reversals(segment: Segment) -> None:
+    assert (
+        segment.convex_hull()
+        == reverse_segment_endpoints(segment).convex_hull()
+    )
@@ -0,0 +1,21 @@
+from hypothesis import given
+
+from rene.exact import Segment
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_round_trip(segment: Segment) -> None:
+    geo_interface = segment.__geo_interface__
+
+    result = Segment.from_geo_interface(geo_interface)
+
+    assert result.__geo_interface__ == geo_interface
+
+
+@given(strategies.segments)
+def test_geo_interface_provider(segment: Segment) -> None:
+    result = Segment.from_geo_interface(segment)
+
+    assert result.__geo_interface__ == segment.__geo_interface__
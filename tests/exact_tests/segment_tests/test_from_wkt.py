@@ -0,0 +1,20 @@
+from hypothesis import given
+
+from rene.exact import Segment
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_round_trip(segment: Segment) -> None:
+    wkt = to_maybe_wkt(segment)
+
+    assert wkt is None or Segment.from_wkt(wkt) == segment
+
+
+@given(strategies.segments)
+def test_case_insensitivity(segment: Segment) -> None:
+    wkt = to_maybe_wkt(segment)
+
+    assert wkt is None or Segment.from_wkt(wkt.lower()) == segment
@@ -0,0 +1,29 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Segment
+
+from . import strategies
+
+
+@given(strategies.segments, strategies.scalars, strategies.scalars)
+def test_basic(
+    segment: Segment, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = segment.scale(factor_x, factor_y)
+
+    assert isinstance(result, Segment)
+
+
+@given(strategies.segments)
+def test_identity(segment: Segment) -> None:
+    assert segment.scale(1, 1) == segment
+
+
+@given(strategies.segments, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    segment: Segment, factor_x: Fraction, factor_y: Fraction
+) -> None:
+    result = segment.scale(factor_x, factor_y)
+
+    assert result == segment.transform(((factor_x, 0, 0), (0, factor_y, 0)))
@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.exact import Segment
+from tests.utils import to_maybe_wkt
+
+from . import strategies
+
+
+@given(strategies.segments)
+def test_basic(segment: Segment) -> None:
+    result = to_maybe_wkt(segment)
+
+    assert result is None or (
+        isinstance(result, str) and result.startswith('LINESTRING ')
+    )
@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rene.exact import Segment
+from tests.exact_tests.hints import AffineMatrix
+
+from . import strategies
+
+
+@given(strategies.segments, strategies.affine_matrices)
+def test_basic(segment: Segment, matrix: AffineMatrix) -> None:
+    result = segment.transform(matrix)
+
+    assert isinstance(result, Segment)
+
+
+@given(strategies.segments)
+def test_identity(segment: Segment) -> None:
+    assert segment.transform(((1, 0, 0), (0, 1, 0))) == segment
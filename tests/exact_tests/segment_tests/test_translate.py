@@ -0,0 +1,31 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Segment
+
+from . import strategies
+
+
+@given(strategies.segments, strategies.scalars, strategies.scalars)
+def test_basic(segment: Segment, step_x: Fraction, step_y: Fraction) -> None:
+    result = segment.translate(step_x, step_y)
+
+    assert isinstance(result, Segment)
+
+
+@given(strategies.segments, strategies.scalars, strategies.scalars)
+def test_round_trip(
+    segment: Segment, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = segment.translate(step_x, step_y)
+
+    assert result.translate(-step_x, -step_y) == segment
+
+
+@given(strategies.segments, strategies.scalars, strategies.scalars)
+def test_connection_with_transform(
+    segment: Segment, step_x: Fraction, step_y: Fraction
+) -> None:
+    result = segment.translate(step_x, step_y)
+
+    assert result == segment.transform(((1, 0, step_x), (0, 1, step_y)))
@@ -8,6 +8,7 @@ from ground.context import Context
 from hypothesis import strategies
 from hypothesis_geometry import planar
 
+from rene.enums import FillRule
 from rene.exact import (
     Box,
     Contour,
@@ -29,12 +30,39 @@ integers = strategies.integers(MIN_VALUE, MAX_VALUE)
 non_zero_integers = strategies.integers(MIN_VALUE, -1) | strategies.integers(
     1, MAX_VALUE
 )
+fill_rules = strategies.sampled_from([FillRule.EVEN_ODD, FillRule.NON_ZERO])
 scalars_strategies = strategies.sampled_from(
     [
         integers,
         strategies.fractions(MIN_VALUE, MAX_VALUE, max_denominator=MAX_VALUE),
     ]
 )
+scalars = scalars_strategies.flatmap(lambda strategy: strategy)
+affine_matrices = strategies.tuples(
+    strategies.tuples(scalars, scalars, scalars),
+    strategies.tuples(scalars, scalars, scalars),
+)
+non_degenerate_affine_matrices = affine_matrices.filter(
+    lambda matrix: matrix[0][0] * matrix[1][1] != matrix[0][1] * matrix[1][0]
+)
+
+
+def to_singular_affine_matrix(
+    first_row: tuple[Scalar, Scalar, Scalar],
+    factor: Scalar,
+    y_offset: Scalar,
+    /,
+) -> tuple[tuple[Scalar, Scalar, Scalar], tuple[Scalar, Scalar, Scalar]]:
+    xx, xy, _ = first_row
+    return first_row, (factor * xx, factor * xy, y_offset)
+
+
+singular_affine_matrices = strategies.builds(
+    to_singular_affine_matrix,
+    strategies.tuples(scalars, scalars, scalars),
+    scalars,
+    scalars,
+)
 empty_geometries = strategies.builds(Empty)
 
 
@@ -0,0 +1,16 @@
+from hypothesis import given
+
+from rene.exact import Trapezoidation
+
+from . import strategies
+
+
+@given(strategies.trapezoidations)
+def test_basic(trapezoidation: Trapezoidation) -> None:
+    result = trapezoidation._repr_svg_()
+
+    assert result is None or (
+        isinstance(result, str)
+        and result.startswith('<svg ')
+        and result.endswith('</svg>')
+    )
@@ -1,7 +1,7 @@
 from hypothesis import given
 
 from rene.enums import Location
-from rene.exact import Point, Trapezoidation
+from rene.exact import Point, Polygon, Trapezoidation
 
 from . import strategies
 
@@ -11,3 +11,10 @@ def test_basic(trapezoidation: Trapezoidation, point: Point) -> None:
     result = trapezoidation.locate(point)
 
     assert isinstance(result, Location)
+
+
+@given(strategies.polygons, strategies.points)
+def test_polygon_consistency(polygon: Polygon, point: Point) -> None:
+    trapezoidation = Trapezoidation.from_polygon(polygon)
+
+    assert trapezoidation.locate(point) is polygon.locate(point)
@@ -81,6 +81,16 @@ def is_segment_inside_box(segment: _SegmentT, box: _BoxT, /) -> bool:
     )
 
 
+def pack_convex_hull(
+    vertices: Sequence[exact.Point], /
+) -> exact.Point | exact.Polygon | exact.Segment:
+    if len(vertices) == 1:
+        return vertices[0]
+    if len(vertices) == 2:
+        return exact.Segment(vertices[0], vertices[1])
+    return exact.Polygon(exact.Contour(vertices), [])
+
+
 def reverse_box_coordinates(box: _BoxT, /) -> _BoxT:
     return type(box)(box.min_y, box.max_y, box.min_x, box.max_x)
 
@@ -273,6 +283,13 @@ def to_max_convex_hull(
     return lower[:-1] + upper[:-1] or points
 
 
+def to_maybe_wkt(geometry: Any, /) -> str | None:
+    try:
+        return geometry.to_wkt()
+    except ValueError:
+        return None
+
+
 def to_pairs(
     values: _st.SearchStrategy[_T], /
 ) -> _st.SearchStrategy[tuple[_T, _T]]:
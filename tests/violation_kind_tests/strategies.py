@@ -0,0 +1,25 @@
+from hypothesis import strategies
+
+from rene.enums import ViolationKind
+
+violations_kinds = strategies.sampled_from(
+    [
+        ViolationKind.COLLINEAR_VERTICES,
+        ViolationKind.DEGENERATE_SEGMENT,
+        ViolationKind.HOLE_OUTSIDE_BORDER,
+        ViolationKind.HOLE_TOUCHES_BORDER,
+        ViolationKind.HOLES_OVERLAP,
+        ViolationKind.INVALID_BORDER,
+        ViolationKind.INVALID_HOLE,
+        ViolationKind.INVALID_POLYGON,
+        ViolationKind.POLYGONS_OVERLAP,
+        ViolationKind.SEGMENTS_CROSS,
+        ViolationKind.SEGMENTS_OVERLAP,
+        ViolationKind.SEGMENTS_TOUCH,
+        ViolationKind.TOO_FEW_POLYGONS,
+        ViolationKind.TOO_FEW_SEGMENTS,
+        ViolationKind.TOO_FEW_VERTICES,
+        ViolationKind.WRONG_BORDER_ORIENTATION,
+        ViolationKind.WRONG_HOLE_ORIENTATION,
+    ]
+)
@@ -0,0 +1,27 @@
+from hypothesis import given
+
+from rene.enums import ViolationKind
+from tests.utils import equivalence, implication
+
+from . import strategies
+
+
+@given(strategies.violations_kinds)
+def test_reflexivity(violation_kind: ViolationKind) -> None:
+    assert violation_kind == violation_kind
+
+
+@given(strategies.violations_kinds, strategies.violations_kinds)
+def test_symmetry(first: ViolationKind, second: ViolationKind) -> None:
+    assert equivalence(first == second, second == first)
+
+
+@given(
+    strategies.violations_kinds,
+    strategies.violations_kinds,
+    strategies.violations_kinds,
+)
+def test_transitivity(
+    first: ViolationKind, second: ViolationKind, third: ViolationKind
+) -> None:
+    assert implication(first == second and second == third, first == third)
@@ -0,0 +1,15 @@
+from hypothesis import given
+
+from rene.enums import ViolationKind
+
+from . import strategies
+
+
+@given(strategies.violations_kinds)
+def test_round_trip(violation_kind: ViolationKind) -> None:
+    result = repr(violation_kind)
+
+    assert (
+        eval(result, {ViolationKind.__qualname__: ViolationKind})
+        is violation_kind
+    )